@@ -65,6 +65,12 @@ pub(crate) const INTERNAL_NAMED_NODE_MAP_OWNER_NODE_KEY: &str =
     "\u{0}\u{0}bt_named_node_map:owner_node";
 pub(crate) const INTERNAL_SCOPE_DEPTH_KEY: &str = "\u{0}\u{0}bt_scope_depth";
 pub(crate) const INTERNAL_GLOBAL_SYNC_NAMES_KEY: &str = "\u{0}\u{0}bt_global_sync_names";
+pub(crate) const INTERNAL_MODULE_SCOPE_KEY: &str = "\u{0}\u{0}bt_module_scope";
+pub(crate) const INTERNAL_MODULE_IMPORT_KEY_PREFIX: &str = "\u{0}\u{0}bt_module_import:";
+pub(crate) const INTERNAL_MODULE_NAMESPACE_MODULE_KEY: &str =
+    "\u{0}\u{0}bt_module_namespace:module";
+pub(crate) const INTERNAL_MODULE_NAMESPACE_EXPORT_KEY: &str =
+    "\u{0}\u{0}bt_module_namespace:export";
 pub(crate) const INTERNAL_NAVIGATOR_OBJECT_KEY: &str = "\u{0}\u{0}bt_navigator";
 pub(crate) const INTERNAL_CLIPBOARD_OBJECT_KEY: &str = "\u{0}\u{0}bt_clipboard";
 pub(crate) const INTERNAL_CLIPBOARD_READ_TEXT_DEFAULT_KEY: &str =
@@ -116,6 +122,7 @@ pub(crate) const INTERNAL_FETCH_REQUEST_OBJECT_KEY: &str = "\u{0}\u{0}bt_fetch:r
 pub(crate) const INTERNAL_FETCH_REQUEST_INPUT_KEY: &str = "\u{0}\u{0}bt_fetch:request:input";
 pub(crate) const INTERNAL_FETCH_REQUEST_URL_KEY: &str = "\u{0}\u{0}bt_fetch:request:url";
 pub(crate) const INTERNAL_FETCH_REQUEST_METHOD_KEY: &str = "\u{0}\u{0}bt_fetch:request:method";
+pub(crate) const INTERNAL_FETCH_REQUEST_BODY_KEY: &str = "\u{0}\u{0}bt_fetch:request:body";
 pub(crate) const INTERNAL_HEADERS_OBJECT_KEY: &str = "\u{0}\u{0}bt_fetch:headers";
 pub(crate) const INTERNAL_HEADERS_ENTRIES_KEY: &str = "\u{0}\u{0}bt_fetch:headers:entries";
 pub(crate) const INTERNAL_DOM_PARSER_OBJECT_KEY: &str = "\u{0}\u{0}bt_dom_parser";
@@ -123,9 +130,20 @@ pub(crate) const INTERNAL_PARSED_DOCUMENT_OBJECT_KEY: &str = "\u{0}\u{0}bt_parse
 pub(crate) const INTERNAL_PARSED_DOCUMENT_ROOT_NODE_KEY: &str =
     "\u{0}\u{0}bt_parsed_document:root_node";
 pub(crate) const INTERNAL_EVENT_TARGET_OBJECT_KEY: &str = "\u{0}\u{0}bt_event_target:object";
+pub(crate) const INTERNAL_ABORT_CONTROLLER_OBJECT_KEY: &str =
+    "\u{0}\u{0}bt_abort_controller:object";
+pub(crate) const INTERNAL_LAYOUT_OBSERVER_ID_KEY: &str = "\u{0}\u{0}bt_layout_observer:id";
+pub(crate) const INTERNAL_LAYOUT_OBSERVER_ENTRY_KEY: &str = "\u{0}\u{0}bt_layout_observer:entry";
+pub(crate) const INTERNAL_MUTATION_OBSERVER_ID_KEY: &str = "\u{0}\u{0}bt_mutation_observer:id";
+pub(crate) const INTERNAL_ABORT_SIGNAL_OBJECT_KEY: &str = "\u{0}\u{0}bt_abort_signal:object";
+pub(crate) const INTERNAL_ABORT_SIGNAL_DEPENDENTS_KEY: &str =
+    "\u{0}\u{0}bt_abort_signal:dependents";
+pub(crate) const INTERNAL_DOM_EXCEPTION_OBJECT_KEY: &str = "\u{0}\u{0}bt_dom_exception:object";
 pub(crate) const INTERNAL_MATCH_MEDIA_OBJECT_KEY: &str = "\u{0}\u{0}bt_match_media:object";
 pub(crate) const INTERNAL_MATCH_MEDIA_QUERY_KEY: &str = "\u{0}\u{0}bt_match_media:query";
 pub(crate) const INTERNAL_EVENT_OBJECT_KEY: &str = "\u{0}\u{0}bt_event:object";
+pub(crate) const INTERNAL_EVENT_INTERFACE_KEY: &str = "\u{0}\u{0}bt_event:interface";
+pub(crate) const INTERNAL_WINDOW_PROXY_CONTEXT_KEY: &str = "\u{0}\u{0}bt_window_proxy:context";
 pub(crate) const INTERNAL_HASH_CHANGE_EVENT_OBJECT_KEY: &str = "\u{0}\u{0}bt_event:hashchange";
 pub(crate) const INTERNAL_ERROR_EVENT_OBJECT_KEY: &str = "\u{0}\u{0}bt_event:error";
 pub(crate) const INTERNAL_BEFORE_UNLOAD_EVENT_OBJECT_KEY: &str = "\u{0}\u{0}bt_event:beforeunload";
@@ -241,6 +259,8 @@ pub(crate) const DEFAULT_RANGE_INPUT_MIN: f64 = 0.0;
 pub(crate) const DEFAULT_RANGE_INPUT_MAX: f64 = 100.0;
 pub(crate) const FILE_INPUT_FAKEPATH_PREFIX: &str = "C:\\fakepath\\";
 pub(crate) const DEFAULT_LOCALE: &str = "en-US";
+// Time value of an invalid Date (`NaN` in JS).
+pub(crate) const INVALID_DATE_TIME_MS: i64 = i64::MIN;
 
 pub type Result<T> = std::result::Result<T, Error>;
 
@@ -356,14 +376,77 @@ impl MockFile {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct DomRect {
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+impl DomRect {
+    pub fn new(x: f64, y: f64, width: f64, height: f64) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    pub(crate) fn right(&self) -> f64 {
+        self.x + self.width
+    }
+
+    pub(crate) fn bottom(&self) -> f64 {
+        self.y + self.height
+    }
+
+    pub(crate) fn contains_point(&self, x: f64, y: f64) -> bool {
+        x >= self.x && x < self.right() && y >= self.y && y < self.bottom()
+    }
+
+    pub(crate) fn translated(&self, dx: f64, dy: f64) -> Self {
+        Self::new(self.x + dx, self.y + dy, self.width, self.height)
+    }
+
+    // Edge-inclusive, so touching rects still intersect with a zero area.
+    pub(crate) fn intersection(&self, other: &DomRect) -> Option<DomRect> {
+        let left = self.x.max(other.x);
+        let top = self.y.max(other.y);
+        let right = self.right().min(other.right());
+        let bottom = self.bottom().min(other.bottom());
+        if right < left || bottom < top {
+            return None;
+        }
+        Some(Self::new(left, top, right - left, bottom - top))
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub(crate) struct NodeId(pub(crate) usize);
 
 #[derive(Debug, Clone)]
 pub(crate) enum NodeType {
     Document,
+    DocumentType(DocumentType),
     Element(Element),
     Text(String),
+    Comment(String),
+    ProcessingInstruction(ProcessingInstruction),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct DocumentType {
+    pub(crate) name: String,
+    pub(crate) public_id: String,
+    pub(crate) system_id: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ProcessingInstruction {
+    pub(crate) target: String,
+    pub(crate) data: String,
 }
 
 #[derive(Debug, Clone)]
@@ -391,6 +474,77 @@ pub(crate) struct Element {
     pub(crate) selection_direction: String,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum MutationRecordType {
+    ChildList,
+    Attributes,
+    CharacterData,
+}
+
+impl MutationRecordType {
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            Self::ChildList => "childList",
+            Self::Attributes => "attributes",
+            Self::CharacterData => "characterData",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct MutationObserverOptions {
+    pub(crate) child_list: bool,
+    pub(crate) attributes: bool,
+    pub(crate) character_data: bool,
+    pub(crate) subtree: bool,
+    pub(crate) attribute_old_value: bool,
+    pub(crate) character_data_old_value: bool,
+    pub(crate) attribute_filter: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct MutationObserverRegistration {
+    pub(crate) observer: usize,
+    pub(crate) target: NodeId,
+    pub(crate) options: MutationObserverOptions,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct MutationRecordData {
+    pub(crate) kind: MutationRecordType,
+    pub(crate) target: NodeId,
+    pub(crate) added_nodes: Vec<NodeId>,
+    pub(crate) removed_nodes: Vec<NodeId>,
+    pub(crate) previous_sibling: Option<NodeId>,
+    pub(crate) next_sibling: Option<NodeId>,
+    pub(crate) attribute_name: Option<String>,
+    pub(crate) old_value: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct CustomElementInstance {
+    pub(crate) name: String,
+    pub(crate) document: NodeId,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum CustomElementReaction {
+    Upgrade(NodeId),
+    Connected(NodeId),
+    Disconnected(NodeId),
+    Adopted {
+        node: NodeId,
+        old_document: NodeId,
+        new_document: NodeId,
+    },
+    AttributeChanged {
+        node: NodeId,
+        name: String,
+        old_value: Option<String>,
+        new_value: Option<String>,
+    },
+}
+
 #[derive(Debug, Clone)]
 pub(crate) struct Dom {
     pub(crate) nodes: Vec<Node>,
@@ -398,6 +552,15 @@ pub(crate) struct Dom {
     pub(crate) id_index: HashMap<String, Vec<NodeId>>,
     pub(crate) active_element: Option<NodeId>,
     pub(crate) active_pseudo_element: Option<NodeId>,
+    pub(crate) hover_element: Option<NodeId>,
+    pub(crate) mutation_observers: Vec<MutationObserverRegistration>,
+    pub(crate) pending_mutation_records: Vec<(usize, MutationRecordData)>,
+    pub(crate) layout_rects: HashMap<NodeId, DomRect>,
+    // Definitions live in the script-side registry; the DOM only needs to
+    // know whether mutations can produce custom element reactions.
+    pub(crate) custom_elements_defined: bool,
+    pub(crate) custom_elements: HashMap<NodeId, CustomElementInstance>,
+    pub(crate) pending_custom_element_reactions: Vec<CustomElementReaction>,
 }
 
 pub(crate) fn has_class(element: &Element, class_name: &str) -> bool {
@@ -1143,14 +1306,6 @@ pub(crate) fn class_tokens(class_attr: Option<&str>) -> Vec<String> {
         .unwrap_or_default()
 }
 
-pub(crate) fn set_class_attr(element: &mut Element, classes: &[String]) {
-    if classes.is_empty() {
-        element.attrs.remove("class");
-    } else {
-        element.attrs.insert("class".to_string(), classes.join(" "));
-    }
-}
-
 pub(crate) fn dataset_key_to_attr_name(key: &str) -> String {
     format!("data-{}", js_prop_to_css_name(key))
 }
@@ -1169,21 +1324,30 @@ pub(crate) fn js_prop_to_css_name(prop: &str) -> String {
 }
 
 pub(crate) fn parse_style_declarations(style_attr: Option<&str>) -> Vec<(String, String)> {
-    let mut out = Vec::new();
-    let Some(style_attr) = style_attr else {
-        return out;
-    };
+    let mut out: Vec<(String, String)> = Vec::new();
+    for (name, value) in parse_style_declaration_list(style_attr.unwrap_or_default()) {
+        if let Some(pos) = out.iter().position(|(existing, _)| existing == &name) {
+            out[pos].1 = value;
+        } else {
+            out.push((name, value));
+        }
+    }
+    out
+}
 
+// Every declaration in source order, including repeated property names.
+pub(crate) fn parse_style_declaration_list(style_text: &str) -> Vec<(String, String)> {
+    let mut out = Vec::new();
     let mut start = 0usize;
     let mut i = 0usize;
-    let bytes = style_attr.as_bytes();
+    let bytes = style_text.as_bytes();
     let mut paren_depth = 0isize;
     let mut quote: Option<u8> = None;
 
     while i < bytes.len() {
         let ch = bytes[i];
         match (quote, ch) {
-            (Some(q), _) if ch == b'\\' => {
+            (Some(_), b'\\') => {
                 if i + 1 < bytes.len() {
                     i += 2;
                     continue;
@@ -1199,8 +1363,7 @@ pub(crate) fn parse_style_declarations(style_attr: Option<&str>) -> Vec<(String,
             (None, b'(') => paren_depth += 1,
             (None, b')') => paren_depth = paren_depth.saturating_sub(1),
             (None, b';') if paren_depth == 0 => {
-                let decl = &style_attr[start..i];
-                push_style_declaration(decl, &mut out);
+                out.extend(split_style_declaration(&style_text[start..i]));
                 start = i + 1;
             }
             _ => {}
@@ -1208,16 +1371,14 @@ pub(crate) fn parse_style_declarations(style_attr: Option<&str>) -> Vec<(String,
         i += 1;
     }
 
-    let decl = &style_attr[start..];
-    push_style_declaration(decl, &mut out);
-
+    out.extend(split_style_declaration(&style_text[start..]));
     out
 }
 
-pub(crate) fn push_style_declaration(raw_decl: &str, out: &mut Vec<(String, String)>) {
+pub(crate) fn split_style_declaration(raw_decl: &str) -> Option<(String, String)> {
     let decl = raw_decl.trim();
     if decl.is_empty() {
-        return;
+        return None;
     }
 
     let bytes = decl.as_bytes();
@@ -1229,7 +1390,7 @@ pub(crate) fn push_style_declaration(raw_decl: &str, out: &mut Vec<(String, Stri
     while i < bytes.len() {
         let ch = bytes[i];
         match (quote, ch) {
-            (Some(q), _) if ch == b'\\' => {
+            (Some(_), b'\\') => {
                 if i + 1 < bytes.len() {
                     i += 2;
                     continue;
@@ -1249,22 +1410,12 @@ pub(crate) fn push_style_declaration(raw_decl: &str, out: &mut Vec<(String, Stri
         i += 1;
     }
 
-    let Some(colon) = colon else {
-        return;
-    };
-
+    let colon = colon?;
     let name = decl[..colon].trim().to_ascii_lowercase();
     if name.is_empty() {
-        return;
-    }
-
-    let value = decl[colon + 1..].trim().to_string();
-
-    if let Some(pos) = out.iter().position(|(existing, _)| existing == &name) {
-        out[pos].1 = value;
-    } else {
-        out.push((name, value));
+        return None;
     }
+    Some((name, decl[colon + 1..].trim().to_string()))
 }
 
 pub(crate) fn serialize_style_declarations(decls: &[(String, String)]) -> String {
@@ -20,9 +20,11 @@ mod checked_attr_tree_mutation;
 mod class_query_basics;
 mod connectivity_tree_traversal;
 mod core_nodes_identity;
+mod custom_element_reactions;
 mod dataset_style_layout_props;
 mod dump_misc;
 mod form_control_value_selection;
+mod mutation_records;
 mod select_option_sync;
 mod selector_matching_pseudo;
 mod text_html_content;
@@ -158,7 +158,7 @@ impl Dom {
     pub(crate) fn set_attr(&mut self, node_id: NodeId, name: &str, value: &str) -> Result<()> {
         let connected = self.is_connected(node_id);
         let mut details_open_group_to_enforce = None;
-        let (is_option, lowered) = {
+        let (is_option, lowered, old_value) = {
             let element = self.element_mut(node_id).ok_or_else(|| {
                 Error::ScriptRuntime("setAttribute target is not an element".into())
             })?;
@@ -166,7 +166,7 @@ impl Dom {
             let is_details = element.tag_name.eq_ignore_ascii_case("details");
             let was_file_input = is_file_input_element(element);
             let lowered = name.to_ascii_lowercase();
-            element.attrs.insert(lowered.clone(), value.to_string());
+            let old_value = element.attrs.insert(lowered.clone(), value.to_string());
 
             if lowered == "value" {
                 if is_file_input_element(element) {
@@ -333,8 +333,9 @@ impl Dom {
                     .cloned()
                     .filter(|name| !name.is_empty());
             }
-            (is_option, lowered)
+            (is_option, lowered, old_value)
         };
+        self.record_attribute_mutation(node_id, &lowered, old_value);
 
         if lowered == "checked" {
             self.set_checked(node_id, true)?;
@@ -392,12 +393,12 @@ impl Dom {
     pub(crate) fn remove_attr(&mut self, node_id: NodeId, name: &str) -> Result<()> {
         let lowered = name.to_ascii_lowercase();
         let connected = self.is_connected(node_id);
-        let is_option = {
+        let (is_option, old_value) = {
             let element = self.element_mut(node_id).ok_or_else(|| {
                 Error::ScriptRuntime("removeAttribute target is not an element".into())
             })?;
             let is_option = element.tag_name.eq_ignore_ascii_case("option");
-            element.attrs.remove(&lowered);
+            let old_value = element.attrs.remove(&lowered);
 
             if lowered == "value" {
                 element.value = if is_color_input_element(element) {
@@ -440,8 +441,11 @@ impl Dom {
             } else if lowered == "required" {
                 element.required = false;
             }
-            is_option
+            (is_option, old_value)
         };
+        if old_value.is_some() {
+            self.record_attribute_mutation(node_id, &lowered, old_value);
+        }
 
         if lowered == "id" && connected {
             self.rebuild_id_index();
@@ -517,11 +521,13 @@ impl Dom {
             cursor = self.parent(node);
         }
 
+        self.record_child_removal(child);
         if let Some(old_parent) = self.parent(child) {
             self.nodes[old_parent.0].children.retain(|id| *id != child);
         }
         self.nodes[child.0].parent = Some(parent);
         self.nodes[parent.0].children.push(child);
+        self.record_child_insertion(child);
         self.rebuild_id_index();
         Ok(())
     }
@@ -585,6 +591,7 @@ impl Dom {
             cursor = self.parent(node);
         }
 
+        self.record_child_removal(child);
         if let Some(old_parent) = self.parent(child) {
             self.nodes[old_parent.0].children.retain(|id| *id != child);
         }
@@ -601,6 +608,7 @@ impl Dom {
 
         self.nodes[child.0].parent = Some(parent);
         self.nodes[parent.0].children.insert(index, child);
+        self.record_child_insertion(child);
         self.rebuild_id_index();
         Ok(())
     }
@@ -670,6 +678,7 @@ impl Dom {
             cursor = self.parent(node);
         }
 
+        self.record_child_removal(new_child);
         if let Some(old_parent) = self.parent(new_child) {
             self.nodes[old_parent.0]
                 .children
@@ -685,6 +694,7 @@ impl Dom {
         self.nodes[new_child.0].parent = Some(parent);
         self.nodes[parent.0].children[index] = new_child;
         self.nodes[old_child.0].parent = None;
+        self.record_child_replacement(new_child, old_child);
         self.rebuild_id_index();
         Ok(())
     }
@@ -714,6 +724,7 @@ impl Dom {
                 "removeChild target is not a direct child".into(),
             ));
         }
+        self.record_child_removal(child);
         self.nodes[parent.0].children.retain(|id| *id != child);
         self.nodes[child.0].parent = None;
         self.rebuild_id_index();
@@ -8,26 +8,37 @@ impl Dom {
         Ok(has_class(element, class_name))
     }
 
-    pub(crate) fn class_add(&mut self, node_id: NodeId, class_name: &str) -> Result<()> {
+    fn class_list_tokens(&self, node_id: NodeId) -> Result<Vec<String>> {
         let element = self
-            .element_mut(node_id)
+            .element(node_id)
             .ok_or_else(|| Error::ScriptRuntime("classList target is not an element".into()))?;
-        let mut classes = class_tokens(element.attrs.get("class").map(String::as_str));
+        Ok(class_tokens(element.attrs.get("class").map(String::as_str)))
+    }
+
+    // Goes through `set_attr` / `remove_attr` so observers and
+    // `attributeChangedCallback` see classList edits like any other write.
+    fn write_class_tokens(&mut self, node_id: NodeId, classes: &[String]) -> Result<()> {
+        if !classes.is_empty() {
+            return self.set_attr(node_id, "class", &classes.join(" "));
+        }
+        if self.attr(node_id, "class").is_some() {
+            return self.remove_attr(node_id, "class");
+        }
+        Ok(())
+    }
+
+    pub(crate) fn class_add(&mut self, node_id: NodeId, class_name: &str) -> Result<()> {
+        let mut classes = self.class_list_tokens(node_id)?;
         if !classes.iter().any(|name| name == class_name) {
             classes.push(class_name.to_string());
         }
-        set_class_attr(element, &classes);
-        Ok(())
+        self.write_class_tokens(node_id, &classes)
     }
 
     pub(crate) fn class_remove(&mut self, node_id: NodeId, class_name: &str) -> Result<()> {
-        let element = self
-            .element_mut(node_id)
-            .ok_or_else(|| Error::ScriptRuntime("classList target is not an element".into()))?;
-        let mut classes = class_tokens(element.attrs.get("class").map(String::as_str));
+        let mut classes = self.class_list_tokens(node_id)?;
         classes.retain(|name| name != class_name);
-        set_class_attr(element, &classes);
-        Ok(())
+        self.write_class_tokens(node_id, &classes)
     }
 
     pub(crate) fn class_toggle(&mut self, node_id: NodeId, class_name: &str) -> Result<bool> {
@@ -47,10 +58,7 @@ impl Dom {
         old_class_name: &str,
         new_class_name: &str,
     ) -> Result<bool> {
-        let element = self
-            .element_mut(node_id)
-            .ok_or_else(|| Error::ScriptRuntime("classList target is not an element".into()))?;
-        let classes = class_tokens(element.attrs.get("class").map(String::as_str));
+        let classes = self.class_list_tokens(node_id)?;
         if !classes.iter().any(|name| name == old_class_name) {
             return Ok(false);
         }
@@ -75,7 +83,7 @@ impl Dom {
                 next.push(class_name);
             }
         }
-        set_class_attr(element, &next);
+        self.write_class_tokens(node_id, &next)?;
         Ok(true)
     }
 
@@ -47,6 +47,9 @@ impl Dom {
         let html = self.create_element(self.root, "html".to_string(), HashMap::new());
         let body = self.create_element(html, "body".to_string(), HashMap::new());
         for child in root_children {
+            if matches!(self.nodes[child.0].node_type, NodeType::DocumentType(_)) {
+                continue;
+            }
             self.append_child(body, child)?;
         }
         Ok(body)
@@ -84,7 +87,11 @@ impl Dom {
                         Self::index_id_map(&mut next, id, node);
                     }
                 }
-                NodeType::Document | NodeType::Text(_) => {}
+                NodeType::Document
+                | NodeType::DocumentType(_)
+                | NodeType::Text(_)
+                | NodeType::Comment(_)
+                | NodeType::ProcessingInstruction(_) => {}
             }
             for child in self.nodes[node.0].children.iter().rev() {
                 stack.push(*child);
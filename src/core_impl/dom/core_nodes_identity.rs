@@ -21,6 +21,13 @@ impl Dom {
             id_index: HashMap::new(),
             active_element: None,
             active_pseudo_element: None,
+            hover_element: None,
+            mutation_observers: Vec::new(),
+            pending_mutation_records: Vec::new(),
+            layout_rects: HashMap::new(),
+            custom_elements_defined: false,
+            custom_elements: HashMap::new(),
+            pending_custom_element_reactions: Vec::new(),
         }
     }
 
@@ -121,6 +128,49 @@ impl Dom {
         self.create_node(Some(parent), NodeType::Text(text))
     }
 
+    pub(crate) fn create_detached_comment(&mut self, data: String) -> NodeId {
+        self.create_node(None, NodeType::Comment(data))
+    }
+
+    pub(crate) fn create_detached_processing_instruction(
+        &mut self,
+        target: String,
+        data: String,
+    ) -> NodeId {
+        self.create_node(
+            None,
+            NodeType::ProcessingInstruction(ProcessingInstruction { target, data }),
+        )
+    }
+
+    pub(crate) fn doctype(&self) -> Option<NodeId> {
+        self.nodes[self.root.0]
+            .children
+            .iter()
+            .copied()
+            .find(|child| matches!(self.nodes[child.0].node_type, NodeType::DocumentType(_)))
+    }
+
+    pub(crate) fn character_data(&self, node_id: NodeId) -> Option<&str> {
+        match &self.nodes[node_id.0].node_type {
+            NodeType::Text(text) | NodeType::Comment(text) => Some(text),
+            NodeType::ProcessingInstruction(instruction) => Some(&instruction.data),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn set_character_data(&mut self, node_id: NodeId, value: String) -> bool {
+        let old_value = match &mut self.nodes[node_id.0].node_type {
+            NodeType::Text(text) | NodeType::Comment(text) => std::mem::replace(text, value),
+            NodeType::ProcessingInstruction(instruction) => {
+                std::mem::replace(&mut instruction.data, value)
+            }
+            _ => return false,
+        };
+        self.record_character_data_mutation(node_id, old_value);
+        true
+    }
+
     pub(crate) fn element(&self, node_id: NodeId) -> Option<&Element> {
         match &self.nodes[node_id.0].node_type {
             NodeType::Element(element) => Some(element),
@@ -170,6 +220,14 @@ impl Dom {
         self.active_pseudo_element = node;
     }
 
+    pub(crate) fn hover_element(&self) -> Option<NodeId> {
+        self.hover_element
+    }
+
+    pub(crate) fn set_hover_element(&mut self, node: Option<NodeId>) {
+        self.hover_element = node;
+    }
+
     pub(crate) fn by_id(&self, id: &str) -> Option<NodeId> {
         self.id_index.get(id).and_then(|ids| ids.first().copied())
     }
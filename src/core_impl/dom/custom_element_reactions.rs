@@ -0,0 +1,132 @@
+use super::*;
+
+impl Dom {
+    pub(crate) fn is_valid_custom_element_name(name: &str) -> bool {
+        const RESERVED: [&str; 8] = [
+            "annotation-xml",
+            "color-profile",
+            "font-face",
+            "font-face-src",
+            "font-face-uri",
+            "font-face-format",
+            "font-face-name",
+            "missing-glyph",
+        ];
+        let mut chars = name.chars();
+        if !chars.next().is_some_and(|first| first.is_ascii_lowercase()) {
+            return false;
+        }
+        name.contains('-')
+            && !RESERVED.contains(&name)
+            && chars.all(|ch| {
+                matches!(ch, '-' | '.' | '_' | '0'..='9' | 'a'..='z' | '\u{B7}')
+                    || (!ch.is_ascii() && ch >= '\u{C0}')
+            })
+    }
+
+    // The Document at the top of `node`'s tree, if the tree is rooted in one.
+    pub(crate) fn node_document(&self, node: NodeId) -> Option<NodeId> {
+        let mut top = node;
+        while let Some(parent) = self.parent(top) {
+            top = parent;
+        }
+        matches!(self.nodes[top.0].node_type, NodeType::Document).then_some(top)
+    }
+
+    pub(crate) fn is_defined_element(&self, node: NodeId) -> bool {
+        let Some(element) = self.element(node) else {
+            return false;
+        };
+        if self.custom_elements.contains_key(&node) {
+            return true;
+        }
+        let local_name = element.tag_name.to_ascii_lowercase();
+        !Self::is_valid_custom_element_name(&local_name) && !element.attrs.contains_key("is")
+    }
+
+    pub(crate) fn mark_custom_element(&mut self, node: NodeId, name: &str) {
+        let document = self.node_document(node).unwrap_or(self.root);
+        self.custom_elements.insert(
+            node,
+            CustomElementInstance {
+                name: name.to_string(),
+                document,
+            },
+        );
+    }
+
+    pub(crate) fn take_pending_custom_element_reactions(&mut self) -> Vec<CustomElementReaction> {
+        std::mem::take(&mut self.pending_custom_element_reactions)
+    }
+
+    pub(crate) fn inclusive_element_descendants(&self, node: NodeId) -> Vec<NodeId> {
+        let mut out = Vec::new();
+        self.collect_elements_dfs(node, &mut out);
+        out
+    }
+
+    pub(crate) fn queue_custom_element_child_list_reactions(
+        &mut self,
+        target: NodeId,
+        added_nodes: &[NodeId],
+        removed_nodes: &[NodeId],
+    ) {
+        if !self.custom_elements_defined {
+            return;
+        }
+        let Some(document) = self.node_document(target) else {
+            return;
+        };
+        for removed in removed_nodes {
+            for node in self.inclusive_element_descendants(*removed) {
+                if self.custom_elements.contains_key(&node) {
+                    self.pending_custom_element_reactions
+                        .push(CustomElementReaction::Disconnected(node));
+                }
+            }
+        }
+        for added in added_nodes {
+            for node in self.inclusive_element_descendants(*added) {
+                let Some(instance) = self.custom_elements.get_mut(&node) else {
+                    // The upgrade itself looks up whether a definition applies.
+                    if document == self.root && !self.is_defined_element(node) {
+                        self.pending_custom_element_reactions
+                            .push(CustomElementReaction::Upgrade(node));
+                    }
+                    continue;
+                };
+                if instance.document != document {
+                    let old_document = std::mem::replace(&mut instance.document, document);
+                    self.pending_custom_element_reactions
+                        .push(CustomElementReaction::Adopted {
+                            node,
+                            old_document,
+                            new_document: document,
+                        });
+                }
+                self.pending_custom_element_reactions
+                    .push(CustomElementReaction::Connected(node));
+            }
+        }
+    }
+
+    pub(crate) fn queue_custom_element_attribute_reaction(
+        &mut self,
+        node: NodeId,
+        name: &str,
+        old_value: Option<String>,
+    ) {
+        // `observedAttributes` is checked when the reaction runs.
+        if !self.custom_elements.contains_key(&node) {
+            return;
+        }
+        let new_value = self.attr(node, name);
+        self.pending_custom_element_reactions
+            .push(CustomElementReaction::AttributeChanged {
+                node,
+                name: name.to_string(),
+                old_value,
+                new_value,
+            });
+    }
+}
@@ -91,9 +91,22 @@ fn parse_border_width_from_shorthand(value: &str) -> Option<f64> {
 #[derive(Clone, Copy)]
 enum BorderSide {
     Top,
+    Right,
+    Bottom,
     Left,
 }
 
+impl BorderSide {
+    fn name(self) -> &'static str {
+        match self {
+            Self::Top => "top",
+            Self::Right => "right",
+            Self::Bottom => "bottom",
+            Self::Left => "left",
+        }
+    }
+}
+
 fn parse_border_width_side_from_shorthand(value: &str, side: BorderSide) -> Option<f64> {
     let tokens = value
         .split_whitespace()
@@ -108,19 +121,90 @@ fn parse_border_width_side_from_shorthand(value: &str, side: BorderSide) -> Opti
         values.push(parse_css_border_width_token(token)?);
     }
 
-    let (top, left) = match values.len() {
-        1 => (values[0], values[0]),
-        2 => (values[0], values[1]),
-        3 => (values[0], values[1]),
-        _ => (values[0], values[3]),
+    let (top, right, bottom, left) = match values.len() {
+        1 => (values[0], values[0], values[0], values[0]),
+        2 => (values[0], values[1], values[0], values[1]),
+        3 => (values[0], values[1], values[2], values[1]),
+        _ => (values[0], values[1], values[2], values[3]),
     };
 
     Some(match side {
         BorderSide::Top => top,
+        BorderSide::Right => right,
+        BorderSide::Bottom => bottom,
         BorderSide::Left => left,
     })
 }
 
+fn style_border_width(style_decls: &[(String, String)], side: BorderSide) -> f64 {
+    let find = |name: &str| {
+        style_decls
+            .iter()
+            .find(|(decl, _)| decl == name)
+            .map(|(_, value)| value.as_str())
+    };
+    find(&format!("border-{}-width", side.name()))
+        .and_then(parse_css_border_width_token)
+        .or_else(|| {
+            find(&format!("border-{}", side.name())).and_then(parse_border_width_from_shorthand)
+        })
+        .or_else(|| {
+            find("border-width")
+                .and_then(|value| parse_border_width_side_from_shorthand(value, side))
+        })
+        .or_else(|| find("border").and_then(parse_border_width_from_shorthand))
+        .unwrap_or(0.0)
+}
+
+fn style_horizontal_padding(style_decls: &[(String, String)]) -> (f64, f64) {
+    let mut padding_left = 0.0;
+    let mut padding_right = 0.0;
+
+    if let Some((_, value)) = style_decls.iter().find(|(name, _)| name == "padding") {
+        if let Some((left, right)) = parse_padding_horizontal_shorthand(value) {
+            padding_left = left;
+            padding_right = right;
+        }
+    }
+    if let Some((_, value)) = style_decls.iter().find(|(name, _)| name == "padding-left") {
+        if let Some(parsed) = parse_css_pixel_value(value) {
+            padding_left = parsed;
+        }
+    }
+    if let Some((_, value)) = style_decls.iter().find(|(name, _)| name == "padding-right") {
+        if let Some(parsed) = parse_css_pixel_value(value) {
+            padding_right = parsed;
+        }
+    }
+    (padding_left, padding_right)
+}
+
+fn style_vertical_padding(style_decls: &[(String, String)]) -> (f64, f64) {
+    let mut padding_top = 0.0;
+    let mut padding_bottom = 0.0;
+
+    if let Some((_, value)) = style_decls.iter().find(|(name, _)| name == "padding") {
+        if let Some((top, bottom)) = parse_padding_vertical_shorthand(value) {
+            padding_top = top;
+            padding_bottom = bottom;
+        }
+    }
+    if let Some((_, value)) = style_decls.iter().find(|(name, _)| name == "padding-top") {
+        if let Some(parsed) = parse_css_pixel_value(value) {
+            padding_top = parsed;
+        }
+    }
+    if let Some((_, value)) = style_decls
+        .iter()
+        .find(|(name, _)| name == "padding-bottom")
+    {
+        if let Some(parsed) = parse_css_pixel_value(value) {
+            padding_bottom = parsed;
+        }
+    }
+    (padding_top, padding_bottom)
+}
+
 impl Dom {
     pub(crate) fn dataset_get(&self, node_id: NodeId, key: &str) -> Result<Option<String>> {
         if self.element(node_id).is_none() {
@@ -158,7 +242,7 @@ impl Dom {
     pub(crate) fn style_set(&mut self, node_id: NodeId, key: &str, value: &str) -> Result<()> {
         let name = js_prop_to_css_name(key);
         let element = self
-            .element_mut(node_id)
+            .element(node_id)
             .ok_or_else(|| Error::ScriptRuntime("style target is not an element".into()))?;
 
         let mut decls = parse_style_declarations(element.attrs.get("style").map(String::as_str));
@@ -172,25 +256,70 @@ impl Dom {
             decls.push((name, value.to_string()));
         }
 
-        if decls.is_empty() {
-            // Keep an empty style attribute to match CSSStyleDeclaration behavior.
-            element.attrs.insert("style".to_string(), String::new());
-        } else {
-            element
-                .attrs
-                .insert("style".to_string(), serialize_style_declarations(&decls));
-        }
+        // An empty declaration list keeps an empty style attribute, matching
+        // CSSStyleDeclaration behavior.
+        self.set_attr(node_id, "style", &serialize_style_declarations(&decls))
+    }
 
+    pub(crate) fn set_layout_rect(&mut self, node_id: NodeId, rect: DomRect) -> Result<()> {
+        if self.element(node_id).is_none() {
+            return Err(Error::ScriptRuntime(
+                "layout rect target is not an element".into(),
+            ));
+        }
+        self.layout_rects.insert(node_id, rect);
         Ok(())
     }
 
+    // The border box in document coordinates; elements without an override get
+    // an empty box at the origin. Whether the element is rendered at all is up
+    // to the style cascade, see `Harness::element_layout_rect`.
+    pub(crate) fn layout_rect(&self, node_id: NodeId) -> Option<DomRect> {
+        self.element(node_id)?;
+        Some(self.layout_rects.get(&node_id).copied().unwrap_or_default())
+    }
+
+    // Content box of an element, positioned relative to its padding box as
+    // `ResizeObserverEntry.contentRect` expects.
+    pub(crate) fn layout_content_rect(&self, node_id: NodeId) -> Option<DomRect> {
+        let rect = self.layout_rect(node_id)?;
+        let element = self.element(node_id)?;
+        let style_decls = parse_style_declarations(element.attrs.get("style").map(String::as_str));
+        let (padding_left, padding_right) = style_horizontal_padding(&style_decls);
+        let (padding_top, padding_bottom) = style_vertical_padding(&style_decls);
+        let width = rect.width
+            - style_border_width(&style_decls, BorderSide::Left)
+            - style_border_width(&style_decls, BorderSide::Right)
+            - padding_left
+            - padding_right;
+        let height = rect.height
+            - style_border_width(&style_decls, BorderSide::Top)
+            - style_border_width(&style_decls, BorderSide::Bottom)
+            - padding_top
+            - padding_bottom;
+        Some(DomRect::new(
+            padding_left,
+            padding_top,
+            width.max(0.0),
+            height.max(0.0),
+        ))
+    }
+
+    fn layout_rect_override(&self, node_id: NodeId) -> Option<DomRect> {
+        self.layout_rects.get(&node_id)?;
+        self.layout_rect(node_id)
+    }
+
     pub(crate) fn offset_left(&self, node_id: NodeId) -> Result<i64> {
         if self.element(node_id).is_none() {
             return Err(Error::ScriptRuntime(
                 "offsetLeft target is not an element".into(),
             ));
         }
-        Ok(0)
+        Ok(self
+            .layout_rect_override(node_id)
+            .map(|rect| rect.x.round() as i64)
+            .unwrap_or(0))
     }
 
     pub(crate) fn offset_top(&self, node_id: NodeId) -> Result<i64> {
@@ -199,7 +328,10 @@ impl Dom {
                 "offsetTop target is not an element".into(),
             ));
         }
-        Ok(0)
+        Ok(self
+            .layout_rect_override(node_id)
+            .map(|rect| rect.y.round() as i64)
+            .unwrap_or(0))
     }
 
     pub(crate) fn offset_width(&self, node_id: NodeId) -> Result<i64> {
@@ -208,7 +340,10 @@ impl Dom {
                 "offsetWidth target is not an element".into(),
             ));
         }
-        Ok(0)
+        Ok(self
+            .layout_rect_override(node_id)
+            .map(|rect| clamp_layout_px_to_i64(rect.width.round()))
+            .unwrap_or(0))
     }
 
     pub(crate) fn offset_height(&self, node_id: NodeId) -> Result<i64> {
@@ -217,7 +352,10 @@ impl Dom {
                 "offsetHeight target is not an element".into(),
             ));
         }
-        Ok(0)
+        Ok(self
+            .layout_rect_override(node_id)
+            .map(|rect| clamp_layout_px_to_i64(rect.height.round()))
+            .unwrap_or(0))
     }
 
     pub(crate) fn client_width(&self, node_id: NodeId) -> Result<i64> {
@@ -239,31 +377,20 @@ impl Dom {
             return Ok(0);
         }
 
+        if let Some(rect) = self.layout_rects.get(&node_id) {
+            return Ok(clamp_layout_px_to_i64(
+                rect.width
+                    - style_border_width(&style_decls, BorderSide::Left)
+                    - style_border_width(&style_decls, BorderSide::Right),
+            ));
+        }
+
         let css_width = style_decls
             .iter()
             .find(|(name, _)| name == "width")
             .and_then(|(_, value)| parse_css_pixel_value(value))
             .unwrap_or(0.0);
-
-        let mut padding_left = 0.0;
-        let mut padding_right = 0.0;
-
-        if let Some((_, value)) = style_decls.iter().find(|(name, _)| name == "padding") {
-            if let Some((left, right)) = parse_padding_horizontal_shorthand(value) {
-                padding_left = left;
-                padding_right = right;
-            }
-        }
-        if let Some((_, value)) = style_decls.iter().find(|(name, _)| name == "padding-left") {
-            if let Some(parsed) = parse_css_pixel_value(value) {
-                padding_left = parsed;
-            }
-        }
-        if let Some((_, value)) = style_decls.iter().find(|(name, _)| name == "padding-right") {
-            if let Some(parsed) = parse_css_pixel_value(value) {
-                padding_right = parsed;
-            }
-        }
+        let (padding_left, padding_right) = style_horizontal_padding(&style_decls);
 
         Ok(clamp_layout_px_to_i64(
             css_width + padding_left + padding_right,
@@ -289,34 +416,20 @@ impl Dom {
             return Ok(0);
         }
 
+        if let Some(rect) = self.layout_rects.get(&node_id) {
+            return Ok(clamp_layout_px_to_i64(
+                rect.height
+                    - style_border_width(&style_decls, BorderSide::Top)
+                    - style_border_width(&style_decls, BorderSide::Bottom),
+            ));
+        }
+
         let css_height = style_decls
             .iter()
             .find(|(name, _)| name == "height")
             .and_then(|(_, value)| parse_css_pixel_value(value))
             .unwrap_or(0.0);
-
-        let mut padding_top = 0.0;
-        let mut padding_bottom = 0.0;
-
-        if let Some((_, value)) = style_decls.iter().find(|(name, _)| name == "padding") {
-            if let Some((top, bottom)) = parse_padding_vertical_shorthand(value) {
-                padding_top = top;
-                padding_bottom = bottom;
-            }
-        }
-        if let Some((_, value)) = style_decls.iter().find(|(name, _)| name == "padding-top") {
-            if let Some(parsed) = parse_css_pixel_value(value) {
-                padding_top = parsed;
-            }
-        }
-        if let Some((_, value)) = style_decls
-            .iter()
-            .find(|(name, _)| name == "padding-bottom")
-        {
-            if let Some(parsed) = parse_css_pixel_value(value) {
-                padding_bottom = parsed;
-            }
-        }
+        let (padding_top, padding_bottom) = style_vertical_padding(&style_decls);
 
         Ok(clamp_layout_px_to_i64(
             css_height + padding_top + padding_bottom,
@@ -342,33 +455,10 @@ impl Dom {
             return Ok(0);
         }
 
-        let border_left_width = style_decls
-            .iter()
-            .find(|(name, _)| name == "border-left-width")
-            .and_then(|(_, value)| parse_css_border_width_token(value))
-            .or_else(|| {
-                style_decls
-                    .iter()
-                    .find(|(name, _)| name == "border-left")
-                    .and_then(|(_, value)| parse_border_width_from_shorthand(value))
-            })
-            .or_else(|| {
-                style_decls
-                    .iter()
-                    .find(|(name, _)| name == "border-width")
-                    .and_then(|(_, value)| {
-                        parse_border_width_side_from_shorthand(value, BorderSide::Left)
-                    })
-            })
-            .or_else(|| {
-                style_decls
-                    .iter()
-                    .find(|(name, _)| name == "border")
-                    .and_then(|(_, value)| parse_border_width_from_shorthand(value))
-            })
-            .unwrap_or(0.0);
-
-        Ok(clamp_layout_px_to_i64(border_left_width))
+        Ok(clamp_layout_px_to_i64(style_border_width(
+            &style_decls,
+            BorderSide::Left,
+        )))
     }
 
     pub(crate) fn client_top(&self, node_id: NodeId) -> Result<i64> {
@@ -390,33 +480,10 @@ impl Dom {
             return Ok(0);
         }
 
-        let border_top_width = style_decls
-            .iter()
-            .find(|(name, _)| name == "border-top-width")
-            .and_then(|(_, value)| parse_css_border_width_token(value))
-            .or_else(|| {
-                style_decls
-                    .iter()
-                    .find(|(name, _)| name == "border-top")
-                    .and_then(|(_, value)| parse_border_width_from_shorthand(value))
-            })
-            .or_else(|| {
-                style_decls
-                    .iter()
-                    .find(|(name, _)| name == "border-width")
-                    .and_then(|(_, value)| {
-                        parse_border_width_side_from_shorthand(value, BorderSide::Top)
-                    })
-            })
-            .or_else(|| {
-                style_decls
-                    .iter()
-                    .find(|(name, _)| name == "border")
-                    .and_then(|(_, value)| parse_border_width_from_shorthand(value))
-            })
-            .unwrap_or(0.0);
-
-        Ok(clamp_layout_px_to_i64(border_top_width))
+        Ok(clamp_layout_px_to_i64(style_border_width(
+            &style_decls,
+            BorderSide::Top,
+        )))
     }
 
     pub(crate) fn scroll_width(&self, node_id: NodeId) -> Result<i64> {
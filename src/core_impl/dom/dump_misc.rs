@@ -11,6 +11,11 @@ impl Dom {
                 out
             }
             NodeType::Text(text) => escape_html_text_for_serialization(text),
+            NodeType::Comment(data) => format!("<!--{data}-->"),
+            NodeType::DocumentType(doctype) => format!("<!DOCTYPE {}>", doctype.name),
+            NodeType::ProcessingInstruction(instruction) => {
+                format!("<?{} {}>", instruction.target, instruction.data)
+            }
             NodeType::Element(element) => {
                 let mut out = String::new();
                 out.push('<');
@@ -0,0 +1,217 @@
+use super::*;
+
+impl Dom {
+    pub(crate) fn observe_mutations(
+        &mut self,
+        observer: usize,
+        target: NodeId,
+        options: MutationObserverOptions,
+    ) {
+        if let Some(registration) = self
+            .mutation_observers
+            .iter_mut()
+            .find(|registration| registration.observer == observer && registration.target == target)
+        {
+            registration.options = options;
+            return;
+        }
+        self.mutation_observers.push(MutationObserverRegistration {
+            observer,
+            target,
+            options,
+        });
+    }
+
+    pub(crate) fn disconnect_mutation_observer(&mut self, observer: usize) {
+        self.mutation_observers
+            .retain(|registration| registration.observer != observer);
+        self.pending_mutation_records
+            .retain(|(pending, _)| *pending != observer);
+    }
+
+    pub(crate) fn take_pending_mutation_records(&mut self) -> Vec<(usize, MutationRecordData)> {
+        std::mem::take(&mut self.pending_mutation_records)
+    }
+
+    fn sibling_after(&self, parent: NodeId, node: NodeId) -> Option<NodeId> {
+        let children = &self.nodes[parent.0].children;
+        let index = children.iter().position(|child| *child == node)?;
+        children.get(index + 1).copied()
+    }
+
+    fn sibling_before(&self, parent: NodeId, node: NodeId) -> Option<NodeId> {
+        let children = &self.nodes[parent.0].children;
+        let index = children.iter().position(|child| *child == node)?;
+        index.checked_sub(1).map(|index| children[index])
+    }
+
+    // Records `child` leaving its current parent, before it is detached.
+    pub(crate) fn record_child_removal(&mut self, child: NodeId) {
+        if self.mutation_observers.is_empty() && !self.custom_elements_defined {
+            return;
+        }
+        let Some(parent) = self.parent(child) else {
+            return;
+        };
+        let previous_sibling = self.sibling_before(parent, child);
+        let next_sibling = self.sibling_after(parent, child);
+        self.record_child_list(
+            parent,
+            Vec::new(),
+            vec![child],
+            previous_sibling,
+            next_sibling,
+        );
+    }
+
+    // Records `child` as inserted, after it has been attached.
+    pub(crate) fn record_child_insertion(&mut self, child: NodeId) {
+        if self.mutation_observers.is_empty() && !self.custom_elements_defined {
+            return;
+        }
+        let Some(parent) = self.parent(child) else {
+            return;
+        };
+        let previous_sibling = self.sibling_before(parent, child);
+        let next_sibling = self.sibling_after(parent, child);
+        self.record_child_list(
+            parent,
+            vec![child],
+            Vec::new(),
+            previous_sibling,
+            next_sibling,
+        );
+    }
+
+    // Records `old_child` swapped out for `new_child`, after the swap.
+    pub(crate) fn record_child_replacement(&mut self, new_child: NodeId, old_child: NodeId) {
+        if self.mutation_observers.is_empty() && !self.custom_elements_defined {
+            return;
+        }
+        let Some(parent) = self.parent(new_child) else {
+            return;
+        };
+        let previous_sibling = self.sibling_before(parent, new_child);
+        let next_sibling = self.sibling_after(parent, new_child);
+        self.record_child_list(
+            parent,
+            vec![new_child],
+            vec![old_child],
+            previous_sibling,
+            next_sibling,
+        );
+    }
+
+    pub(crate) fn record_child_list(
+        &mut self,
+        target: NodeId,
+        added_nodes: Vec<NodeId>,
+        removed_nodes: Vec<NodeId>,
+        previous_sibling: Option<NodeId>,
+        next_sibling: Option<NodeId>,
+    ) {
+        if added_nodes.is_empty() && removed_nodes.is_empty() {
+            return;
+        }
+        self.queue_custom_element_child_list_reactions(target, &added_nodes, &removed_nodes);
+        self.queue_mutation_record(MutationRecordData {
+            kind: MutationRecordType::ChildList,
+            target,
+            added_nodes,
+            removed_nodes,
+            previous_sibling,
+            next_sibling,
+            attribute_name: None,
+            old_value: None,
+        });
+    }
+
+    pub(crate) fn record_attribute_mutation(
+        &mut self,
+        target: NodeId,
+        name: &str,
+        old_value: Option<String>,
+    ) {
+        self.queue_custom_element_attribute_reaction(target, name, old_value.clone());
+        self.queue_mutation_record(MutationRecordData {
+            kind: MutationRecordType::Attributes,
+            target,
+            added_nodes: Vec::new(),
+            removed_nodes: Vec::new(),
+            previous_sibling: None,
+            next_sibling: None,
+            attribute_name: Some(name.to_string()),
+            old_value,
+        });
+    }
+
+    pub(crate) fn record_character_data_mutation(&mut self, target: NodeId, old_value: String) {
+        self.queue_mutation_record(MutationRecordData {
+            kind: MutationRecordType::CharacterData,
+            target,
+            added_nodes: Vec::new(),
+            removed_nodes: Vec::new(),
+            previous_sibling: None,
+            next_sibling: None,
+            attribute_name: None,
+            old_value: Some(old_value),
+        });
+    }
+
+    // "Queue a mutation record": every observer registered on the target, or
+    // on an ancestor with `subtree`, gets at most one copy of the record.
+    fn queue_mutation_record(&mut self, record: MutationRecordData) {
+        if self.mutation_observers.is_empty() {
+            return;
+        }
+        let mut interested: Vec<(usize, bool)> = Vec::new();
+        let mut cursor = Some(record.target);
+        while let Some(node) = cursor {
+            for registration in &self.mutation_observers {
+                if registration.target != node {
+                    continue;
+                }
+                let options = &registration.options;
+                if node != record.target && !options.subtree {
+                    continue;
+                }
+                let (observed, wants_old_value) = match record.kind {
+                    MutationRecordType::ChildList => (options.child_list, false),
+                    MutationRecordType::Attributes => {
+                        let name = record.attribute_name.as_deref().unwrap_or_default();
+                        let filtered_out = options
+                            .attribute_filter
+                            .as_ref()
+                            .is_some_and(|filter| !filter.iter().any(|entry| entry == name));
+                        (
+                            options.attributes && !filtered_out,
+                            options.attribute_old_value,
+                        )
+                    }
+                    MutationRecordType::CharacterData => {
+                        (options.character_data, options.character_data_old_value)
+                    }
+                };
+                if !observed {
+                    continue;
+                }
+                match interested
+                    .iter_mut()
+                    .find(|(observer, _)| *observer == registration.observer)
+                {
+                    Some((_, old_value)) => *old_value |= wants_old_value,
+                    None => interested.push((registration.observer, wants_old_value)),
+                }
+            }
+            cursor = self.parent(node);
+        }
+
+        for (observer, wants_old_value) in interested {
+            let mut record = record.clone();
+            if !wants_old_value {
+                record.old_value = None;
+            }
+            self.pending_mutation_records.push((observer, record));
+        }
+    }
+}
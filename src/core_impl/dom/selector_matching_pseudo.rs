@@ -110,9 +110,21 @@ impl Dom {
                             .is_some_and(|active| self.is_descendant_of(active, node_id))
                     }
                 }
-                SelectorPseudoClass::Active => self
-                    .element(node_id)
-                    .is_some_and(|_| self.active_pseudo_element == Some(node_id)),
+                // Both states also apply to the ancestors of the element under the pointer.
+                SelectorPseudoClass::Active => {
+                    self.element(node_id).is_some()
+                        && self.active_pseudo_element.is_some_and(|active| {
+                            active == node_id || self.is_descendant_of(active, node_id)
+                        })
+                }
+                SelectorPseudoClass::Hover => {
+                    self.element(node_id).is_some()
+                        && self.hover_element.is_some_and(|hovered| {
+                            hovered == node_id || self.is_descendant_of(hovered, node_id)
+                        })
+                }
+                SelectorPseudoClass::Defined => self.is_defined_element(node_id),
+                SelectorPseudoClass::Root => self.document_element() == Some(node_id),
                 SelectorPseudoClass::NthOfType(selector) => {
                     self.is_nth_element_of_type(node_id, selector)
                 }
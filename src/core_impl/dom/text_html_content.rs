@@ -29,47 +29,54 @@ impl Dom {
 
     pub(crate) fn text_content(&self, node_id: NodeId) -> String {
         match &self.nodes[node_id.0].node_type {
-            NodeType::Document => {
-                let mut out = String::new();
-                for child in &self.nodes[node_id.0].children {
-                    out.push_str(&self.text_content(*child));
-                }
-                out
-            }
+            NodeType::Document => self.descendant_text_content(node_id),
             NodeType::Element(element) => {
                 if element.tag_name.eq_ignore_ascii_case("br") {
                     return "\n".to_string();
                 }
-                let mut out = String::new();
-                for child in &self.nodes[node_id.0].children {
-                    out.push_str(&self.text_content(*child));
-                }
-                out
+                self.descendant_text_content(node_id)
+            }
+            NodeType::Text(text) | NodeType::Comment(text) => text.clone(),
+            NodeType::ProcessingInstruction(instruction) => instruction.data.clone(),
+            NodeType::DocumentType(_) => String::new(),
+        }
+    }
+
+    fn descendant_text_content(&self, node_id: NodeId) -> String {
+        let mut out = String::new();
+        for child in &self.nodes[node_id.0].children {
+            if matches!(
+                self.nodes[child.0].node_type,
+                NodeType::Text(_) | NodeType::Element(_)
+            ) {
+                out.push_str(&self.text_content(*child));
             }
-            NodeType::Text(text) => text.clone(),
         }
+        out
     }
 
     pub(crate) fn set_text_content(&mut self, node_id: NodeId, value: &str) -> Result<()> {
         match &mut self.nodes[node_id.0].node_type {
-            NodeType::Document => {
-                // Per DOM behavior, setting textContent on Document is a no-op.
+            NodeType::Document | NodeType::DocumentType(_) => {
+                // Per DOM behavior, setting textContent on Document and DocumentType is a no-op.
                 return Ok(());
             }
-            NodeType::Text(text) => {
-                *text = value.to_string();
+            NodeType::Text(_) | NodeType::Comment(_) | NodeType::ProcessingInstruction(_) => {
+                self.set_character_data(node_id, value.to_string());
                 return Ok(());
             }
             NodeType::Element(_) => {}
         }
 
         let old_children = std::mem::take(&mut self.nodes[node_id.0].children);
-        for child in old_children {
+        for child in &old_children {
             self.nodes[child.0].parent = None;
         }
         if !value.is_empty() {
             self.create_text(node_id, value.to_string());
         }
+        let added = self.nodes[node_id.0].children.clone();
+        self.record_child_list(node_id, added, old_children, None, None);
         self.rebuild_id_index();
         Ok(())
     }
@@ -120,7 +127,7 @@ impl Dom {
         let fragment = self.parse_html_fragment_for_context(html, context_tag.as_deref())?;
 
         let old_children = std::mem::take(&mut self.nodes[node_id.0].children);
-        for child in old_children {
+        for child in &old_children {
             self.nodes[child.0].parent = None;
         }
 
@@ -128,6 +135,8 @@ impl Dom {
         for child in children {
             let _ = self.clone_subtree_from_dom(&fragment, child, Some(node_id), sanitize)?;
         }
+        let added = self.nodes[node_id.0].children.clone();
+        self.record_child_list(node_id, added, old_children, None, None);
 
         self.normalize_implied_table_bodies()?;
         self.rebuild_id_index();
@@ -167,18 +176,25 @@ impl Dom {
         let context_tag = self.tag_name(parent).map(|tag| tag.to_string());
         let fragment = self.parse_html_fragment_for_context(html, context_tag.as_deref())?;
 
+        let previous_sibling = index
+            .checked_sub(1)
+            .map(|previous| self.nodes[parent.0].children[previous]);
+        let next_sibling = self.nodes[parent.0].children.get(index + 1).copied();
         self.nodes[parent.0].children.remove(index);
         self.nodes[node_id.0].parent = None;
 
         let mut insert_at = index;
+        let mut added = Vec::new();
         let children = fragment.nodes[fragment.root.0].children.clone();
         for child in children {
             if let Some(cloned) = self.clone_subtree_from_dom(&fragment, child, None, true)? {
                 self.nodes[cloned.0].parent = Some(parent);
                 self.nodes[parent.0].children.insert(insert_at, cloned);
                 insert_at += 1;
+                added.push(cloned);
             }
         }
+        self.record_child_list(parent, added, vec![node_id], previous_sibling, next_sibling);
 
         self.normalize_implied_table_bodies()?;
         self.rebuild_id_index();
@@ -232,6 +248,10 @@ impl Dom {
         let ParseOutput {
             dom: mut fragment, ..
         } = parse_html(html)?;
+        // A DOCTYPE in fragment markup is ignored, as in the HTML fragment parsing algorithm.
+        if let Some(doctype) = fragment.doctype() {
+            fragment.remove_child(fragment.root, doctype)?;
+        }
         if let Some(tag) = context_tag {
             normalize_table_fragment_for_context(&mut fragment, tag)?;
         }
@@ -261,7 +281,7 @@ impl Dom {
                 }
                 NodeType::Element(clone)
             }
-            NodeType::Text(text) => NodeType::Text(text.clone()),
+            other => other.clone(),
         };
 
         let node = self.create_node(parent, node_type);
@@ -1,3 +1,4 @@
+use super::html_entities::NAMED_CHARACTER_REFERENCES;
 use super::*;
 
 pub(super) fn unescape_string(src: &str) -> String {
@@ -87,146 +88,128 @@ pub(super) fn unescape_string(src: &str) -> String {
 }
 
 fn decode_html_character_references(src: &str) -> String {
-    if !src.contains('&') {
-        return src.to_string();
-    }
-
-    fn is_entity_token_char(ch: char) -> bool {
-        ch.is_ascii_alphanumeric() || ch == '#' || ch == 'x' || ch == 'X'
-    }
+    decode_character_references(src, false)
+}
 
-    fn decode_numeric(value: &str) -> Option<char> {
-        let codepoint =
-            if let Some(hex) = value.strip_prefix("x").or_else(|| value.strip_prefix("X")) {
-                u32::from_str_radix(hex, 16).ok()?
-            } else {
-                u32::from_str_radix(value, 10).ok()?
-            };
-        char::from_u32(codepoint)
-    }
+fn decode_html_attribute_character_references(src: &str) -> String {
+    decode_character_references(src, true)
+}
 
-    fn decode_named(value: &str) -> Option<char> {
-        match value {
-            "amp" => Some('&'),
-            "lt" => Some('<'),
-            "gt" => Some('>'),
-            "quot" => Some('"'),
-            "apos" => Some('\''),
-            "nbsp" => Some('\u{00A0}'),
-            "divide" => Some('÷'),
-            "times" => Some('×'),
-            "ensp" => Some('\u{2002}'),
-            "emsp" => Some('\u{2003}'),
-            "thinsp" => Some('\u{2009}'),
-            "copy" => Some('©'),
-            "reg" => Some('®'),
-            "trade" => Some('™'),
-            "euro" => Some('€'),
-            "pound" => Some('£'),
-            "yen" => Some('¥'),
-            "laquo" => Some('«'),
-            "raquo" => Some('»'),
-            "ldquo" => Some('“'),
-            "rdquo" => Some('”'),
-            "lsquo" => Some('‘'),
-            "rsquo" => Some('’'),
-            "hellip" => Some('…'),
-            "middot" => Some('·'),
-            "frac14" => Some('¼'),
-            "frac12" => Some('½'),
-            "frac34" => Some('¾'),
-            "frac13" => Some('\u{2153}'),
-            "frac15" => Some('\u{2155}'),
-            "frac16" => Some('\u{2159}'),
-            "frac18" => Some('\u{215B}'),
-            "frac23" => Some('\u{2154}'),
-            "frac25" => Some('\u{2156}'),
-            "frac35" => Some('\u{2157}'),
-            "frac38" => Some('\u{215C}'),
-            "frac45" => Some('\u{2158}'),
-            "frac56" => Some('\u{215A}'),
-            "frac58" => Some('\u{215E}'),
-            "not" => Some('¬'),
-            "deg" => Some('°'),
-            "plusmn" => Some('±'),
-            "larr" => Some('←'),
-            "rarr" => Some('→'),
-            _ => None,
-        }
+fn decode_character_references(src: &str, in_attribute: bool) -> String {
+    if !src.contains('&') {
+        return src.to_string();
     }
 
     let mut out = String::with_capacity(src.len());
-    let mut i = 0usize;
-
-    while i < src.len() {
-        let ch = src[i..].chars().next().unwrap_or_default();
-        if ch != '&' {
-            out.push(ch);
-            i += ch.len_utf8();
-            continue;
-        }
-
-        let tail = &src[i + 1..];
-        let mut semicolon_end = None;
-        if let Some(semicolon_pos) = tail.find(';') {
-            match tail.find('&') {
-                Some(next_amp_pos) if next_amp_pos < semicolon_pos => {}
-                _ => semicolon_end = Some(semicolon_pos),
+    let mut rest = src;
+    while let Some(amp) = rest.find('&') {
+        out.push_str(&rest[..amp]);
+        let tail = &rest[amp + 1..];
+        let consumed = if let Some(numeric) = tail.strip_prefix('#') {
+            decode_numeric_character_reference(numeric).map(|(value, len)| {
+                out.push(value);
+                len + 1
+            })
+        } else {
+            decode_named_character_reference(tail, in_attribute).map(|(value, len)| {
+                out.push_str(value);
+                len
+            })
+        };
+        match consumed {
+            Some(len) => rest = &tail[len..],
+            None => {
+                out.push('&');
+                rest = tail;
             }
         }
+    }
+    out.push_str(rest);
+    out
+}
 
-        let Some(end_offset) = semicolon_end else {
-            let entity_end = tail
-                .char_indices()
-                .find_map(|(idx, ch)| {
-                    if is_entity_token_char(ch) {
-                        None
-                    } else {
-                        Some(idx)
-                    }
-                })
-                .unwrap_or(tail.len());
-
-            if entity_end == 0 {
-                out.push('&');
-                i += 1;
-                continue;
-            }
+fn decode_named_character_reference(
+    src: &str,
+    in_attribute: bool,
+) -> Option<(&'static str, usize)> {
+    const MAX_NAME_LEN: usize = 32;
+
+    let lookup = |name: &str| {
+        NAMED_CHARACTER_REFERENCES
+            .binary_search_by(|(candidate, _)| (*candidate).cmp(name))
+            .ok()
+            .map(|index| NAMED_CHARACTER_REFERENCES[index].1)
+    };
 
-            let raw = &tail[..entity_end];
-            let decoded = if let Some(rest) = raw.strip_prefix('#') {
-                decode_numeric(rest)
-            } else {
-                decode_named(raw)
-            };
+    let name_len = src
+        .bytes()
+        .take(MAX_NAME_LEN)
+        .take_while(u8::is_ascii_alphanumeric)
+        .count();
+    // Names are alphanumeric, so a `;`-terminated match must span the whole run.
+    if src[name_len..].starts_with(';') {
+        if let Some(value) = lookup(&src[..=name_len]) {
+            return Some((value, name_len + 1));
+        }
+    }
 
-            if let Some(value) = decoded {
-                out.push(value);
-                i += entity_end + 1;
-            } else {
-                out.push('&');
-                i += 1;
-            }
+    for len in (1..=name_len).rev() {
+        let Some(value) = lookup(&src[..len]) else {
             continue;
         };
+        // Legacy references without `;` stay literal in attribute values when followed
+        // by `=` or an alphanumeric, so query strings like `?a=1&copy=2` survive.
+        let next = src.as_bytes().get(len);
+        if in_attribute && next.is_some_and(|b| *b == b'=' || b.is_ascii_alphanumeric()) {
+            return None;
+        }
+        return Some((value, len));
+    }
+    None
+}
 
-        let raw = &tail[..end_offset];
-        let decoded = if let Some(rest) = raw.strip_prefix('#') {
-            decode_numeric(rest)
-        } else {
-            decode_named(raw)
-        };
+fn decode_numeric_character_reference(src: &str) -> Option<(char, usize)> {
+    let (digits_start, radix) = match src.as_bytes().first() {
+        Some(b'x' | b'X') => (1, 16),
+        _ => (0, 10),
+    };
+    let digits_len = src[digits_start..]
+        .chars()
+        .take_while(|ch| ch.is_digit(radix))
+        .count();
+    if digits_len == 0 {
+        return None;
+    }
 
-        if let Some(value) = decoded {
-            out.push(value);
-            i += end_offset + 2;
-        } else {
-            out.push('&');
-            i += 1;
-        }
+    let digits = &src[digits_start..digits_start + digits_len];
+    let codepoint = digits
+        .chars()
+        .try_fold(0u32, |acc, ch| {
+            acc.checked_mul(radix)?.checked_add(ch.to_digit(radix)?)
+        })
+        .unwrap_or(u32::MAX);
+    let mut len = digits_start + digits_len;
+    if src[len..].starts_with(';') {
+        len += 1;
     }
+    Some((numeric_character_reference_value(codepoint), len))
+}
 
-    out
+fn numeric_character_reference_value(codepoint: u32) -> char {
+    // Windows-1252 mappings for C1 control references, per the HTML tokenizer.
+    const C1_REPLACEMENTS: [u32; 32] = [
+        0x20AC, 0x81, 0x201A, 0x0192, 0x201E, 0x2026, 0x2020, 0x2021, 0x02C6, 0x2030, 0x0160,
+        0x2039, 0x0152, 0x8D, 0x017D, 0x8F, 0x90, 0x2018, 0x2019, 0x201C, 0x201D, 0x2022, 0x2013,
+        0x2014, 0x02DC, 0x2122, 0x0161, 0x203A, 0x0153, 0x9D, 0x017E, 0x0178,
+    ];
+
+    let codepoint = match codepoint {
+        0 => return '\u{FFFD}',
+        0x80..=0x9F => C1_REPLACEMENTS[(codepoint - 0x80) as usize],
+        other => other,
+    };
+    // Surrogates and values beyond U+10FFFF have no `char` and become U+FFFD.
+    char::from_u32(codepoint).unwrap_or('\u{FFFD}')
 }
 
 pub(super) fn parse_html(html: &str) -> Result<ParseOutput> {
@@ -239,11 +222,12 @@ pub(super) fn parse_html(html: &str) -> Result<ParseOutput> {
 
     while i < bytes.len() {
         if starts_with_at(bytes, i, b"<!--") {
-            if let Some(end) = find_subslice(bytes, i + 4, b"-->") {
-                i = end + 3;
-            } else {
-                return Err(Error::HtmlParse("unclosed HTML comment".into()));
-            }
+            let (data, next) = parse_comment(html, i)?;
+            let parent = *stack
+                .last()
+                .ok_or_else(|| Error::HtmlParse("missing parent element".into()))?;
+            dom.create_node(Some(parent), NodeType::Comment(data));
+            i = next;
             continue;
         }
 
@@ -265,8 +249,29 @@ pub(super) fn parse_html(html: &str) -> Result<ParseOutput> {
                 continue;
             }
 
-            if starts_with_at(bytes, i, b"<!") {
-                i = parse_declaration_tag(html, i)?;
+            if starts_with_at(bytes, i, b"<!") || starts_with_at(bytes, i, b"<?") {
+                let next = if bytes[i + 1] == b'?' {
+                    find_subslice(bytes, i + 2, b">")
+                        .map(|end| end + 1)
+                        .ok_or_else(|| Error::HtmlParse("unclosed processing instruction".into()))?
+                } else {
+                    parse_declaration_tag(html, i)?
+                };
+                let parent = *stack
+                    .last()
+                    .ok_or_else(|| Error::HtmlParse("missing parent element".into()))?;
+                let content = html.get(i + 1..next - 1).unwrap_or_default();
+                if let Some(doctype) = parse_doctype_declaration(content) {
+                    // A DOCTYPE is only honored before the document element.
+                    if parent == dom.root && dom.document_element().is_none() {
+                        dom.create_node(Some(parent), NodeType::DocumentType(doctype));
+                    }
+                } else {
+                    // Other declarations and processing instructions become bogus comments.
+                    let data = content.strip_prefix('!').unwrap_or(content);
+                    dom.create_node(Some(parent), NodeType::Comment(data.to_string()));
+                }
+                i = next;
                 continue;
             }
 
@@ -311,18 +316,31 @@ pub(super) fn parse_html(html: &str) -> Result<ParseOutput> {
                     if !script_body.is_empty() {
                         dom.create_text(node, script_body.to_string());
                     }
-                    if executable_script {
-                        if let Some(src) = script_src_attr.as_deref() {
-                            if let Some(source) = decode_data_script_source(src)? {
-                                scripts.push(ScriptSource {
-                                    code: source,
-                                    is_module: module_script,
-                                });
+                    // Browsers with module support skip `nomodule` classic scripts.
+                    let skipped = !module_script && dom.attr(node, "nomodule").is_some();
+                    if executable_script && !skipped {
+                        let timing = if module_script {
+                            if dom.attr(node, "async").is_some() {
+                                ScriptTiming::Async
+                            } else {
+                                ScriptTiming::Defer
                             }
-                        } else if !script_body.is_empty() {
+                        } else if script_src_attr.is_none() {
+                            ScriptTiming::ParserBlocking
+                        } else if dom.attr(node, "async").is_some() {
+                            ScriptTiming::Async
+                        } else if dom.attr(node, "defer").is_some() {
+                            ScriptTiming::Defer
+                        } else {
+                            ScriptTiming::ParserBlocking
+                        };
+                        if script_src_attr.is_some() || !script_body.is_empty() {
                             scripts.push(ScriptSource {
+                                element: node,
                                 code: script_body.to_string(),
+                                src: script_src_attr,
                                 is_module: module_script,
+                                timing,
                             });
                         }
                     }
@@ -671,27 +689,6 @@ fn is_module_script_type(raw_type: Option<&str>) -> bool {
     media_type == "module"
 }
 
-fn decode_data_script_source(src: &str) -> Result<Option<String>> {
-    let src = src.trim();
-    let Some(rest) = src.strip_prefix("data:") else {
-        return Ok(None);
-    };
-    let Some((meta, payload)) = rest.split_once(',') else {
-        return Err(Error::HtmlParse(format!(
-            "invalid script src data URL: {src}"
-        )));
-    };
-    let is_base64 = meta
-        .split(';')
-        .skip(1)
-        .any(|part| part.trim().eq_ignore_ascii_case("base64"));
-    if is_base64 {
-        let decoded = decode_base64_to_binary_string(payload)?;
-        return Ok(Some(decoded));
-    }
-    Ok(Some(decode_uri_like(payload, true)?))
-}
-
 fn parse_start_tag(
     html: &str,
     at: usize,
@@ -781,6 +778,68 @@ fn parse_start_tag(
     Ok((tag, attrs, self_closing, i))
 }
 
+fn parse_comment(html: &str, at: usize) -> Result<(String, usize)> {
+    let bytes = html.as_bytes();
+    let body_start = at + 4;
+    if starts_with_at(bytes, body_start, b">") {
+        return Ok((String::new(), body_start + 1));
+    }
+    if starts_with_at(bytes, body_start, b"->") {
+        return Ok((String::new(), body_start + 2));
+    }
+    let end = find_subslice(bytes, body_start, b"-->")
+        .ok_or_else(|| Error::HtmlParse("unclosed HTML comment".into()))?;
+    let data = html.get(body_start..end).unwrap_or_default().to_string();
+    Ok((data, end + 3))
+}
+
+fn parse_doctype_declaration(content: &str) -> Option<DocumentType> {
+    let rest = content.strip_prefix('!')?;
+    if !rest
+        .get(..7)
+        .is_some_and(|keyword| keyword.eq_ignore_ascii_case("doctype"))
+    {
+        return None;
+    }
+    let mut rest = rest[7..].trim_start();
+    let name_end = rest
+        .find(|ch: char| ch.is_ascii_whitespace())
+        .unwrap_or(rest.len());
+    let name = rest[..name_end].to_ascii_lowercase();
+    rest = rest[name_end..].trim_start();
+
+    let mut quoted = Vec::new();
+    let keyword = rest
+        .get(..6)
+        .map(|keyword| keyword.to_ascii_uppercase())
+        .unwrap_or_default();
+    if keyword == "PUBLIC" || keyword == "SYSTEM" {
+        rest = &rest[6..];
+        loop {
+            rest = rest.trim_start();
+            let Some(quote) = rest.chars().next().filter(|ch| *ch == '"' || *ch == '\'') else {
+                break;
+            };
+            let Some(close) = rest[1..].find(quote) else {
+                break;
+            };
+            quoted.push(rest[1..1 + close].to_string());
+            rest = &rest[close + 2..];
+        }
+    }
+    let mut quoted = quoted.into_iter();
+    let (public_id, system_id) = if keyword == "PUBLIC" {
+        (quoted.next(), quoted.next())
+    } else {
+        (None, quoted.next())
+    };
+    Some(DocumentType {
+        name,
+        public_id: public_id.unwrap_or_default(),
+        system_id: system_id.unwrap_or_default(),
+    })
+}
+
 fn parse_declaration_tag(html: &str, at: usize) -> Result<usize> {
     let bytes = html.as_bytes();
     let mut i = at;
@@ -878,7 +937,7 @@ fn parse_attr_value(html: &str, bytes: &[u8], i: &mut usize) -> Result<String> {
             .ok_or_else(|| Error::HtmlParse("invalid attribute value".into()))?
             .to_string();
         *i += 1;
-        return Ok(decode_html_character_references(&value));
+        return Ok(decode_html_attribute_character_references(&value));
     }
 
     let start = *i;
@@ -894,7 +953,7 @@ fn parse_attr_value(html: &str, bytes: &[u8], i: &mut usize) -> Result<String> {
         .get(start..*i)
         .ok_or_else(|| Error::HtmlParse("invalid attribute value".into()))?
         .to_string();
-    Ok(decode_html_character_references(&value))
+    Ok(decode_html_attribute_character_references(&value))
 }
 
 fn skip_ws(bytes: &[u8], i: &mut usize) {
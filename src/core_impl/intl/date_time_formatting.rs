@@ -5,8 +5,8 @@ impl Harness {
         timestamp_ms: i64,
         time_zone: &str,
     ) -> IntlDateTimeComponents {
-        let offset_minutes = Self::intl_time_zone_offset_minutes(time_zone, timestamp_ms);
-        let adjusted = timestamp_ms.saturating_add(offset_minutes.saturating_mul(60_000));
+        let offset_seconds = Self::time_zone_offset_at(time_zone, timestamp_ms).seconds;
+        let adjusted = timestamp_ms.saturating_add(offset_seconds.saturating_mul(1_000));
         let (year, month, day, hour, minute, second, millisecond) =
             Self::date_components_utc(adjusted);
         let days = adjusted.div_euclid(86_400_000);
@@ -20,7 +20,7 @@ impl Harness {
             second,
             millisecond,
             weekday,
-            offset_minutes,
+            timestamp_ms,
         }
     }
 
@@ -327,27 +327,34 @@ impl Harness {
     pub(crate) fn intl_time_zone_name(
         locale: &str,
         time_zone: &str,
-        offset_minutes: i64,
+        components: &IntlDateTimeComponents,
         style: &str,
     ) -> String {
         if time_zone == "UTC" {
             return "GMT".to_string();
         }
-        if time_zone == "Australia/Sydney"
-            && style == "short"
-            && Self::intl_locale_region(locale) == Some("AU")
-        {
-            return "AEDT".to_string();
+        let offset = Self::time_zone_offset_at(time_zone, components.timestamp_ms);
+        let english = Self::intl_locale_family(locale) == "en";
+        if style == "long" {
+            if let Some(name) = Self::time_zone_long_name(time_zone, &offset).filter(|_| english) {
+                return name.to_string();
+            }
+            return Self::format_time_zone_gmt_offset(offset.seconds, true);
         }
-        let sign = if offset_minutes >= 0 { '+' } else { '-' };
-        let total = offset_minutes.abs();
-        let hour = total / 60;
-        let minute = total % 60;
-        if minute == 0 {
-            format!("GMT{sign}{hour}")
-        } else {
-            format!("GMT{sign}{hour}:{minute:02}")
+        // Short abbreviations are only used where the locale's region uses them.
+        let regional_abbreviations: &[&str] = match Self::intl_locale_region(locale) {
+            None | Some("US") => &[
+                "EST", "EDT", "CST", "CDT", "MST", "MDT", "PST", "PDT", "AKST", "AKDT", "HST",
+                "HDT",
+            ],
+            Some("AU") => &["AEST", "AEDT", "ACST", "ACDT", "AWST"],
+            Some("NZ") => &["NZST", "NZDT"],
+            _ => &[],
+        };
+        if english && regional_abbreviations.contains(&offset.abbreviation.as_str()) {
+            return offset.abbreviation;
         }
+        Self::format_time_zone_gmt_offset(offset.seconds, false)
     }
 
     pub(crate) fn intl_append_time_parts(
@@ -471,12 +478,7 @@ impl Harness {
             }
             parts.push(IntlPart {
                 part_type: "timeZoneName".to_string(),
-                value: Self::intl_time_zone_name(
-                    locale,
-                    &options.time_zone,
-                    components.offset_minutes,
-                    style,
-                ),
+                value: Self::intl_time_zone_name(locale, &options.time_zone, components, style),
             });
         }
     }
@@ -282,7 +282,7 @@ impl Harness {
                 .unwrap_or_else(|| "gregory".to_string()),
             numbering_system: Self::intl_locale_unicode_extension_value(locale, "nu")
                 .unwrap_or_else(|| Self::intl_default_numbering_system_for_locale(locale)),
-            time_zone: "UTC".to_string(),
+            time_zone: self.scheduler.time_zone.clone(),
             date_style: None,
             time_style: None,
             weekday: None,
@@ -605,32 +605,8 @@ impl Harness {
         }
     }
 
-    pub(crate) fn intl_time_zone_registry() -> &'static [(&'static str, i64)] {
-        &[
-            ("UTC", 0),
-            ("Africa/Cairo", 2 * 60),
-            ("America/Los_Angeles", -8 * 60),
-            ("America/New_York", -5 * 60),
-            ("Asia/Jerusalem", 2 * 60),
-            ("Asia/Kolkata", 5 * 60 + 30),
-            ("Asia/Seoul", 9 * 60),
-            ("Asia/Shanghai", 8 * 60),
-            ("Asia/Tokyo", 9 * 60),
-            ("Australia/Sydney", 11 * 60),
-            ("Europe/Berlin", 60),
-            ("Europe/London", 0),
-            ("Europe/Paris", 60),
-        ]
-    }
-
     pub(crate) fn intl_supported_time_zone_values() -> Vec<String> {
-        let mut values = Self::intl_time_zone_registry()
-            .iter()
-            .map(|(name, _)| (*name).to_string())
-            .collect::<Vec<_>>();
-        values.sort();
-        values.dedup();
-        values
+        Self::time_zone_names()
     }
 
     pub(crate) fn intl_normalize_time_zone(input: &str) -> Option<String> {
@@ -641,19 +617,8 @@ impl Harness {
         ) {
             return Some("UTC".to_string());
         }
-        Self::intl_time_zone_registry()
-            .iter()
-            .find_map(|(canonical, _)| {
-                canonical
-                    .eq_ignore_ascii_case(trimmed)
-                    .then(|| (*canonical).to_string())
-            })
-    }
-
-    pub(crate) fn intl_time_zone_offset_minutes(time_zone: &str, _timestamp_ms: i64) -> i64 {
-        Self::intl_time_zone_registry()
-            .iter()
-            .find_map(|(name, offset)| (*name == time_zone).then_some(*offset))
-            .unwrap_or(0)
+        jiff_tzdb::get(trimmed)
+            .filter(|(name, _)| Self::is_known_time_zone(name))
+            .map(|(name, _)| name.to_string())
     }
 }
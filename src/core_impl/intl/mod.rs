@@ -10,3 +10,4 @@ mod locale_metadata_display_names;
 mod relative_time_segmenter;
 mod resolver_helpers_collator;
 mod segmenter_locale_data;
+mod time_zone_rules;
@@ -0,0 +1,465 @@
+use super::*;
+
+// Parsed zones are cached per thread; the raw TZif data is embedded via `jiff-tzdb`.
+thread_local! {
+    static TIME_ZONE_RULES: RefCell<HashMap<&'static str, Rc<TimeZoneRules>>> =
+        RefCell::new(HashMap::new());
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct TimeZoneOffset {
+    pub(crate) seconds: i64,
+    pub(crate) is_dst: bool,
+    pub(crate) abbreviation: String,
+}
+
+impl TimeZoneOffset {
+    fn utc() -> Self {
+        Self {
+            seconds: 0,
+            is_dst: false,
+            abbreviation: "UTC".to_string(),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct TimeZoneRules {
+    transitions: Vec<(i64, usize)>,
+    types: Vec<TimeZoneOffset>,
+    footer: Option<PosixTimeZone>,
+}
+
+// The TZ-string footer that extends a zone past its last listed transition.
+#[derive(Debug)]
+struct PosixTimeZone {
+    standard: TimeZoneOffset,
+    daylight: Option<(TimeZoneOffset, PosixTransition, PosixTransition)>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct PosixTransition {
+    date: PosixDate,
+    seconds: i64,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum PosixDate {
+    JulianNoLeap(i64),
+    JulianZero(i64),
+    MonthWeekDay { month: u32, week: i64, weekday: i64 },
+}
+
+impl TimeZoneRules {
+    fn parse(data: &[u8]) -> Option<Self> {
+        let header = |at: usize| -> Option<(u8, [usize; 6])> {
+            let bytes = data.get(at..at + 44)?;
+            if &bytes[..4] != b"TZif" {
+                return None;
+            }
+            let mut counts = [0usize; 6];
+            for (index, count) in counts.iter_mut().enumerate() {
+                let start = 20 + index * 4;
+                *count = u32::from_be_bytes(bytes[start..start + 4].try_into().ok()?) as usize;
+            }
+            Some((bytes[4], counts))
+        };
+        let (version, counts) = header(0)?;
+        let [isutcnt, isstdcnt, leapcnt, timecnt, typecnt, charcnt] = counts;
+        let (mut at, time_size, counts) = if version >= b'2' {
+            let v1_len = timecnt * 5 + typecnt * 6 + charcnt + leapcnt * 8 + isstdcnt + isutcnt;
+            let (_, counts) = header(44 + v1_len)?;
+            (88 + v1_len, 8, counts)
+        } else {
+            (44, 4, counts)
+        };
+        let [isutcnt, isstdcnt, leapcnt, timecnt, typecnt, charcnt] = counts;
+        let mut take = |len: usize| -> Option<&[u8]> {
+            let bytes = data.get(at..at + len)?;
+            at += len;
+            Some(bytes)
+        };
+        let times = take(timecnt * time_size)?;
+        let indices = take(timecnt)?;
+        let infos = take(typecnt * 6)?;
+        let chars = take(charcnt)?;
+        take(leapcnt * (time_size + 4) + isstdcnt + isutcnt)?;
+        let footer = (version >= b'2')
+            .then(|| data.get(at..))
+            .flatten()
+            .and_then(|rest| std::str::from_utf8(rest).ok())
+            .map(|rest| rest.trim_matches('\n'))
+            .filter(|rest| !rest.is_empty())
+            .and_then(PosixTimeZone::parse);
+
+        let types = infos
+            .chunks_exact(6)
+            .map(|info| {
+                let start = usize::from(info[5]);
+                let abbreviation = chars
+                    .get(start..)
+                    .and_then(|rest| rest.split(|byte| *byte == 0).next())
+                    .unwrap_or_default();
+                TimeZoneOffset {
+                    seconds: i64::from(i32::from_be_bytes([info[0], info[1], info[2], info[3]])),
+                    is_dst: info[4] != 0,
+                    abbreviation: String::from_utf8_lossy(abbreviation).into_owned(),
+                }
+            })
+            .collect::<Vec<_>>();
+        if types.is_empty() {
+            return None;
+        }
+        let transitions = times
+            .chunks_exact(time_size)
+            .zip(indices)
+            .map(|(time, index)| {
+                let at = if time_size == 8 {
+                    i64::from_be_bytes(time.try_into().unwrap_or_default())
+                } else {
+                    i64::from(i32::from_be_bytes(time.try_into().unwrap_or_default()))
+                };
+                (at, usize::from(*index).min(types.len() - 1))
+            })
+            .collect();
+        Some(Self {
+            transitions,
+            types,
+            footer,
+        })
+    }
+
+    fn offset_at(&self, seconds: i64) -> TimeZoneOffset {
+        let index = self.transitions.partition_point(|(at, _)| *at <= seconds);
+        if index == self.transitions.len() {
+            if let Some(footer) = &self.footer {
+                return footer.offset_at(seconds);
+            }
+        }
+        match index.checked_sub(1) {
+            Some(index) => self.types[self.transitions[index].1].clone(),
+            None => self.types[0].clone(),
+        }
+    }
+}
+
+impl PosixTimeZone {
+    fn parse(spec: &str) -> Option<Self> {
+        let mut rest = spec;
+        let standard_name = Self::take_name(&mut rest)?;
+        let standard_seconds = -Self::take_time(&mut rest)?;
+        let standard = TimeZoneOffset {
+            seconds: standard_seconds,
+            is_dst: false,
+            abbreviation: standard_name,
+        };
+        if rest.is_empty() {
+            return Some(Self {
+                standard,
+                daylight: None,
+            });
+        }
+        let daylight_name = Self::take_name(&mut rest)?;
+        let daylight_seconds = if rest.starts_with(',') {
+            standard_seconds + 3_600
+        } else {
+            -Self::take_time(&mut rest)?
+        };
+        rest = rest.strip_prefix(',')?;
+        let start = Self::take_transition(&mut rest)?;
+        rest = rest.strip_prefix(',')?;
+        let end = Self::take_transition(&mut rest)?;
+        let daylight = TimeZoneOffset {
+            seconds: daylight_seconds,
+            is_dst: true,
+            abbreviation: daylight_name,
+        };
+        Some(Self {
+            standard,
+            daylight: Some((daylight, start, end)),
+        })
+    }
+
+    fn take_name(rest: &mut &str) -> Option<String> {
+        if let Some(quoted) = rest.strip_prefix('<') {
+            let end = quoted.find('>')?;
+            let name = quoted[..end].to_string();
+            *rest = &quoted[end + 1..];
+            return Some(name);
+        }
+        let end = rest
+            .find(|ch: char| !ch.is_ascii_alphabetic())
+            .unwrap_or(rest.len());
+        if end < 3 {
+            return None;
+        }
+        let name = rest[..end].to_string();
+        *rest = &rest[end..];
+        Some(name)
+    }
+
+    fn take_number(rest: &mut &str) -> Option<i64> {
+        let end = rest
+            .find(|ch: char| !ch.is_ascii_digit())
+            .unwrap_or(rest.len());
+        let value = rest[..end].parse::<i64>().ok()?;
+        *rest = &rest[end..];
+        Some(value)
+    }
+
+    // `[+-]hh[:mm[:ss]]` in seconds; POSIX offsets count west of Greenwich.
+    fn take_time(rest: &mut &str) -> Option<i64> {
+        let sign = if let Some(stripped) = rest.strip_prefix('-') {
+            *rest = stripped;
+            -1
+        } else {
+            *rest = rest.strip_prefix('+').unwrap_or(rest);
+            1
+        };
+        let mut total = Self::take_number(rest)? * 3_600;
+        for unit in [60, 1] {
+            let Some(stripped) = rest.strip_prefix(':') else {
+                break;
+            };
+            *rest = stripped;
+            total += Self::take_number(rest)? * unit;
+        }
+        Some(sign * total)
+    }
+
+    fn take_transition(rest: &mut &str) -> Option<PosixTransition> {
+        let date = if let Some(stripped) = rest.strip_prefix('M') {
+            *rest = stripped;
+            let month = Self::take_number(rest)?;
+            *rest = rest.strip_prefix('.')?;
+            let week = Self::take_number(rest)?;
+            *rest = rest.strip_prefix('.')?;
+            let weekday = Self::take_number(rest)?;
+            if !(1..=12).contains(&month) || !(1..=5).contains(&week) || !(0..=6).contains(&weekday)
+            {
+                return None;
+            }
+            PosixDate::MonthWeekDay {
+                month: month as u32,
+                week,
+                weekday,
+            }
+        } else if let Some(stripped) = rest.strip_prefix('J') {
+            *rest = stripped;
+            PosixDate::JulianNoLeap(Self::take_number(rest)?)
+        } else {
+            PosixDate::JulianZero(Self::take_number(rest)?)
+        };
+        let seconds = match rest.strip_prefix('/') {
+            Some(stripped) => {
+                *rest = stripped;
+                Self::take_time(rest)?
+            }
+            None => 2 * 3_600,
+        };
+        Some(PosixTransition { date, seconds })
+    }
+
+    fn offset_at(&self, seconds: i64) -> TimeZoneOffset {
+        let Some((daylight, start, end)) = &self.daylight else {
+            return self.standard.clone();
+        };
+        let local_days = (seconds + self.standard.seconds).div_euclid(86_400);
+        let (year, ..) = Harness::civil_from_days(local_days);
+        // DST starts on the standard-time clock and ends on the daylight-time clock.
+        let start_at = start.local_seconds(year) - self.standard.seconds;
+        let end_at = end.local_seconds(year) - daylight.seconds;
+        let in_daylight = if start_at < end_at {
+            start_at <= seconds && seconds < end_at
+        } else {
+            !(end_at <= seconds && seconds < start_at)
+        };
+        if in_daylight {
+            daylight.clone()
+        } else {
+            self.standard.clone()
+        }
+    }
+}
+
+impl PosixTransition {
+    fn local_seconds(&self, year: i64) -> i64 {
+        let new_year = Harness::days_from_civil(year, 1, 1);
+        let days = match self.date {
+            PosixDate::JulianNoLeap(day) => {
+                let leap_shift = i64::from(Harness::is_leap_year(year) && day >= 60);
+                new_year + day - 1 + leap_shift
+            }
+            PosixDate::JulianZero(day) => new_year + day,
+            PosixDate::MonthWeekDay {
+                month,
+                week,
+                weekday,
+            } => {
+                let first = Harness::days_from_civil(year, month, 1);
+                let first_weekday = (first + 4).rem_euclid(7);
+                let month_end = first + i64::from(Harness::days_in_month(year, month));
+                let mut day = first + (weekday - first_weekday).rem_euclid(7) + (week - 1) * 7;
+                while day >= month_end {
+                    day -= 7;
+                }
+                day
+            }
+        };
+        days * 86_400 + self.seconds
+    }
+}
+
+impl Harness {
+    fn time_zone_rules(time_zone: &str) -> Option<Rc<TimeZoneRules>> {
+        let (name, data) = jiff_tzdb::get(time_zone)?;
+        TIME_ZONE_RULES.with(|cache| {
+            if let Some(rules) = cache.borrow().get(name) {
+                return Some(rules.clone());
+            }
+            let rules = Rc::new(TimeZoneRules::parse(data)?);
+            cache.borrow_mut().insert(name, rules.clone());
+            Some(rules)
+        })
+    }
+
+    pub(crate) fn time_zone_offset_at(time_zone: &str, timestamp_ms: i64) -> TimeZoneOffset {
+        Self::time_zone_rules(time_zone)
+            .map(|rules| rules.offset_at(timestamp_ms.div_euclid(1_000)))
+            .unwrap_or_else(TimeZoneOffset::utc)
+    }
+
+    // Maps a wall-clock time to UTC like ECMAScript's `UTC(t)`: a repeated
+    // time takes the earlier instant and a skipped time keeps the offset from
+    // before the transition.
+    pub(crate) fn time_zone_local_to_utc_ms(time_zone: &str, local_ms: i64) -> i64 {
+        let offset_ms = |at: i64| Self::time_zone_offset_at(time_zone, at).seconds * 1_000;
+        let before = offset_ms(local_ms.saturating_sub(86_400_000));
+        let after = offset_ms(local_ms.saturating_add(86_400_000));
+        for offset in [before.max(after), before.min(after)] {
+            let utc = local_ms.saturating_sub(offset);
+            if offset_ms(utc) == offset {
+                return utc;
+            }
+        }
+        local_ms.saturating_sub(before)
+    }
+
+    pub(crate) fn is_known_time_zone(time_zone: &str) -> bool {
+        Self::time_zone_rules(time_zone).is_some()
+    }
+
+    pub(crate) fn time_zone_names() -> Vec<String> {
+        const AREAS: &[&str] = &[
+            "Africa",
+            "America",
+            "Antarctica",
+            "Arctic",
+            "Asia",
+            "Atlantic",
+            "Australia",
+            "Europe",
+            "Indian",
+            "Pacific",
+        ];
+        let mut names = jiff_tzdb::available()
+            .filter(|name| {
+                name.split_once('/')
+                    .is_some_and(|(area, _)| AREAS.contains(&area))
+            })
+            .map(str::to_string)
+            .collect::<Vec<_>>();
+        names.push("UTC".to_string());
+        names.sort();
+        names.dedup();
+        names
+    }
+
+    // English display names for the common zone abbreviations. Abbreviations
+    // shared by several regions (CST, IST, PST, ...) are only named for the
+    // zones known to use them; anything else falls back to a GMT offset, as
+    // browsers do.
+    pub(crate) fn time_zone_long_name(
+        time_zone: &str,
+        offset: &TimeZoneOffset,
+    ) -> Option<&'static str> {
+        if time_zone == "UTC" {
+            return Some("Coordinated Universal Time");
+        }
+        let north_american = ["America/", "US/", "Canada/", "Mexico/"]
+            .iter()
+            .any(|prefix| time_zone.starts_with(prefix))
+            || matches!(time_zone, "CST6CDT" | "PST8PDT");
+        let name = match (offset.abbreviation.as_str(), time_zone) {
+            ("IST", "Asia/Kolkata" | "Asia/Calcutta") => "India Standard Time",
+            ("IST", "Asia/Jerusalem" | "Asia/Tel_Aviv" | "Israel") => "Israel Standard Time",
+            ("IDT", "Asia/Jerusalem" | "Asia/Tel_Aviv" | "Israel") => "Israel Daylight Time",
+            ("IST", "Europe/Dublin" | "Eire") => "Irish Standard Time",
+            ("CST", "America/Havana" | "Cuba") => "Cuba Standard Time",
+            ("CDT", "America/Havana" | "Cuba") => "Cuba Daylight Time",
+            (
+                "CST",
+                "Asia/Shanghai" | "Asia/Chongqing" | "Asia/Chungking" | "Asia/Harbin"
+                | "Asia/Macau" | "Asia/Macao" | "PRC",
+            ) => "China Standard Time",
+            ("CST", "Asia/Taipei" | "ROC") => "Taipei Standard Time",
+            ("PST", "Asia/Manila") => "Philippine Standard Time",
+            ("CST", _) if north_american => "Central Standard Time",
+            ("CDT", _) if north_american => "Central Daylight Time",
+            ("PST", _) if north_american => "Pacific Standard Time",
+            ("PDT", _) if north_american => "Pacific Daylight Time",
+            ("IST" | "IDT" | "CST" | "CDT" | "PST" | "PDT", _) => return None,
+            ("EST", _) => "Eastern Standard Time",
+            ("EDT", _) => "Eastern Daylight Time",
+            ("MST", _) => "Mountain Standard Time",
+            ("MDT", _) => "Mountain Daylight Time",
+            ("AKST", _) => "Alaska Standard Time",
+            ("AKDT", _) => "Alaska Daylight Time",
+            ("HST", _) => "Hawaii-Aleutian Standard Time",
+            ("HDT", _) => "Hawaii-Aleutian Daylight Time",
+            ("AST", _) => "Atlantic Standard Time",
+            ("ADT", _) => "Atlantic Daylight Time",
+            ("NST", _) => "Newfoundland Standard Time",
+            ("NDT", _) => "Newfoundland Daylight Time",
+            ("GMT", _) => "Greenwich Mean Time",
+            ("BST", _) => "British Summer Time",
+            ("WET", _) => "Western European Standard Time",
+            ("WEST", _) => "Western European Summer Time",
+            ("CET", _) => "Central European Standard Time",
+            ("CEST", _) => "Central European Summer Time",
+            ("EET", _) => "Eastern European Standard Time",
+            ("EEST", _) => "Eastern European Summer Time",
+            ("MSK", _) => "Moscow Standard Time",
+            ("SAST", _) => "South Africa Standard Time",
+            ("WAT", _) => "West Africa Standard Time",
+            ("CAT", _) => "Central Africa Time",
+            ("EAT", _) => "East Africa Time",
+            ("PKT", _) => "Pakistan Standard Time",
+            ("HKT", _) => "Hong Kong Standard Time",
+            ("JST", _) => "Japan Standard Time",
+            ("KST", _) => "Korean Standard Time",
+            ("AWST", _) => "Australian Western Standard Time",
+            ("ACST", _) => "Australian Central Standard Time",
+            ("ACDT", _) => "Australian Central Daylight Time",
+            ("AEST", _) => "Australian Eastern Standard Time",
+            ("AEDT", _) => "Australian Eastern Daylight Time",
+            ("NZST", _) => "New Zealand Standard Time",
+            ("NZDT", _) => "New Zealand Daylight Time",
+            _ => return None,
+        };
+        Some(name)
+    }
+
+    pub(crate) fn format_time_zone_gmt_offset(offset_seconds: i64, with_minutes: bool) -> String {
+        let sign = if offset_seconds >= 0 { '+' } else { '-' };
+        let total_minutes = offset_seconds.abs() / 60;
+        let (hour, minute) = (total_minutes / 60, total_minutes % 60);
+        if with_minutes {
+            format!("GMT{sign}{hour:02}:{minute:02}")
+        } else if minute == 0 {
+            format!("GMT{sign}{hour}")
+        } else {
+            format!("GMT{sign}{hour}:{minute:02}")
+        }
+    }
+}
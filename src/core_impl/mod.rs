@@ -3,6 +3,7 @@ use super::*;
 mod dom;
 mod form_controls;
 mod html;
+mod html_entities;
 mod intl;
 mod parser;
 mod runtime;
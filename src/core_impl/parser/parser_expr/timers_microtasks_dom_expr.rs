@@ -628,7 +628,13 @@ pub(crate) fn parse_dom_access(src: &str) -> Result<Option<(DomQuery, DomProp)>>
             DomProp::NodeEventHandler(event_name.to_ascii_lowercase())
         }
         _ => {
-            if matches!(target, DomQuery::DocumentRoot) && head == "cookie" && nested.is_none() {
+            if matches!(target, DomQuery::DocumentRoot)
+                && matches!(
+                    head.as_str(),
+                    "cookie" | "doctype" | "childNodes" | "firstChild" | "lastChild"
+                )
+                && nested.is_none()
+            {
                 return Ok(None);
             }
             if matches!(target, DomQuery::DocumentRoot) && starts_with_window_member_access(src) {
@@ -317,7 +317,15 @@ pub(crate) fn parse_class_expr(src: &str) -> Result<Option<Expr>> {
     }
     cursor.skip_ws();
 
-    let parsed_name = cursor.parse_identifier();
+    let name_start = cursor.pos();
+    let parsed_name = match cursor.parse_identifier() {
+        // `class extends Base {}` is anonymous; `extends` is not its name.
+        Some(name) if name == "extends" => {
+            cursor.set_pos(name_start);
+            None
+        }
+        other => other,
+    };
     if parsed_name.is_some() {
         cursor.skip_ws();
     }
@@ -179,6 +179,35 @@ fn parse_listener_capture_arg(src: &str) -> Result<bool> {
     ))
 }
 
+// Options other than a literal `capture` (e.g. `signal`) are only known at runtime,
+// so those registrations are left to the member-call path.
+fn listener_options_need_runtime_eval(src: &str) -> Result<bool> {
+    let src = src.trim();
+    if src == "true" || src == "false" {
+        return Ok(false);
+    }
+    if !src.starts_with('{') {
+        return Ok(true);
+    }
+    let mut cursor = Cursor::new(src);
+    let options_src = cursor.read_balanced_block(b'{', b'}')?;
+    cursor.skip_ws();
+    if !cursor.eof() {
+        return Ok(true);
+    }
+    parse_listener_capture_from_options_object(&options_src)?;
+    Ok(split_top_level_by_char(&options_src, b',')
+        .iter()
+        .map(|entry| entry.trim())
+        .filter(|entry| !entry.is_empty())
+        .any(|entry| {
+            entry
+                .split_once(':')
+                .and_then(|(raw_key, _)| parse_listener_option_key(raw_key))
+                .is_none_or(|key| key != "capture")
+        }))
+}
+
 pub(crate) fn parse_listener_mutation_stmt(stmt: &str) -> Result<Option<Stmt>> {
     let stmt = stmt.trim();
     let mut cursor = Cursor::new(stmt);
@@ -211,6 +240,9 @@ pub(crate) fn parse_listener_mutation_stmt(stmt: &str) -> Result<Option<Stmt>> {
             "add/removeEventListener requires 2 or 3 arguments: {stmt}"
         )));
     }
+    if args.len() == 3 && listener_options_need_runtime_eval(args[2])? {
+        return Ok(None);
+    }
     let event_type = parse_expr(args[0].trim())?;
     let callback = parse_listener_callback_src(args[1].trim())?;
     let capture = if args.len() == 3 {
@@ -5,6 +5,33 @@ impl Harness {
         Value::Date(Rc::new(RefCell::new(timestamp_ms)))
     }
 
+    // What `getTime()` reports: `NaN` for an invalid Date.
+    pub(crate) fn date_time_value(timestamp_ms: i64) -> Value {
+        if timestamp_ms == INVALID_DATE_TIME_MS {
+            Value::Float(f64::NAN)
+        } else {
+            Value::Number(timestamp_ms)
+        }
+    }
+
+    pub(crate) fn date_time_value_f64(timestamp_ms: i64) -> f64 {
+        if timestamp_ms == INVALID_DATE_TIME_MS {
+            f64::NAN
+        } else {
+            timestamp_ms as f64
+        }
+    }
+
+    // ECMAScript TimeClip: non-finite values and values beyond 8.64e15 ms
+    // make the Date invalid.
+    pub(crate) fn time_clip_ms(value: f64) -> i64 {
+        if !value.is_finite() || value.abs() > 8.64e15 {
+            INVALID_DATE_TIME_MS
+        } else {
+            value.trunc() as i64
+        }
+    }
+
     pub(crate) fn resolve_date_from_env(
         &self,
         env: &HashMap<String, Value>,
@@ -30,8 +57,10 @@ impl Harness {
     pub(crate) fn coerce_date_timestamp_ms(&self, value: &Value) -> i64 {
         match value {
             Value::Date(value) => *value.borrow(),
-            Value::String(value) => Self::parse_date_string_to_epoch_ms(value).unwrap_or(0),
-            _ => Self::value_to_i64(value),
+            Value::String(value) => self
+                .parse_date_string_in_time_zone(value)
+                .unwrap_or(INVALID_DATE_TIME_MS),
+            _ => Self::time_clip_ms(Self::coerce_number_for_global(value)),
         }
     }
 
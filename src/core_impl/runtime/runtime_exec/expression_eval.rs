@@ -7,6 +7,8 @@ mod calls_timers_binary;
 mod core_date_intl;
 mod dom_platform;
 mod events_unary_control;
+mod fetch_mock_matching;
+mod fetch_pending_responses;
 mod json_object_array;
 mod regex_numbers_builtins;
 mod string_webapi;
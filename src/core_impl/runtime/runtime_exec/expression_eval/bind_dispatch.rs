@@ -18,7 +18,7 @@ impl Harness {
             .scheduler
             .task_queue
             .iter_mut()
-            .filter(|task| task.id == *timer_id)
+            .filter(|task| task.id == *timer_id && !Self::is_internal_scheduler_task(task))
         {
             task.env.insert(name.to_string(), value.clone());
             let env_snapshot = task.env.to_map();
@@ -70,11 +70,17 @@ impl Harness {
         event_param: &Option<String>,
         event: &EventState,
     ) -> Result<Value> {
-        stacker::maybe_grow(
+        let value = stacker::maybe_grow(
             Self::EVAL_EXPR_STACK_RED_ZONE,
             Self::EVAL_EXPR_STACK_SIZE,
             || self.eval_expr_impl(expr, env, event_param, event),
-        )
+        );
+        // [CEReactions]: reactions queued by a DOM method run before the
+        // expression that called it completes.
+        if !self.dom.pending_custom_element_reactions.is_empty() {
+            self.run_custom_element_reactions()?;
+        }
+        value
     }
 
     fn eval_expr_impl(
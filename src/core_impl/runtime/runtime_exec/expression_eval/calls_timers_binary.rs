@@ -304,19 +304,8 @@ impl Harness {
         attribute_type: Option<&str>,
         referrer: &str,
     ) -> Result<Value> {
-        let cache_key = self.resolve_module_specifier_key(specifier, referrer);
-        if let Some(cached) = self.script_runtime.module_namespace_cache.get(&cache_key) {
-            return Ok(cached.clone());
-        }
-
-        let exports = self.load_module_exports(specifier, attribute_type, referrer)?;
-        let mut entries = exports.into_iter().collect::<Vec<_>>();
-        entries.sort_by(|(left, _), (right, _)| left.cmp(right));
-        let namespace = Self::new_object_value(entries);
-        self.script_runtime
-            .module_namespace_cache
-            .insert(cache_key, namespace.clone());
-        Ok(namespace)
+        let module = self.load_module(specifier, attribute_type, referrer)?;
+        Ok(self.module_namespace(&module))
     }
 
     fn dynamic_import_attribute_type_from_options_value(
@@ -577,9 +566,9 @@ impl Harness {
         }
         let referrer = self.current_import_meta_referrer()?;
         let specifier = args[0].as_string();
-        let resolved =
-            Self::resolve_url_string(&specifier, Some(&referrer)).unwrap_or_else(|| specifier);
-        Ok(Value::String(resolved))
+        Ok(Value::String(
+            self.resolve_module_specifier_key(&specifier, &referrer)?,
+        ))
     }
 
     fn eval_new_target_value(&self, env: &HashMap<String, Value>) -> Result<Value> {
@@ -656,6 +645,8 @@ impl Harness {
                             )));
                         };
                         callee
+                    } else if let Some(callee) = self.resolve_module_import_binding(target, env)? {
+                        callee
                     } else if let Some(callee) = env.get(target).cloned() {
                         callee
                     } else if let Some(callee) = self.resolve_pending_function_decl(target, env) {
@@ -946,6 +937,15 @@ impl Harness {
                     }
 
                     if let Value::Node(node) = &receiver {
+                        if let Some(callee) = self.custom_element_member(*node, member)? {
+                            return self.execute_callable_value_with_this_and_env_and_sync(
+                                &callee,
+                                &evaluated_args,
+                                event,
+                                env,
+                                Some(receiver.clone()),
+                            );
+                        }
                         if let Some(value) =
                             self.eval_node_member_call(*node, member, &evaluated_args, event)?
                         {
@@ -1208,6 +1208,11 @@ impl Harness {
                                 return Ok(value);
                             }
                         }
+                        if let Some(value) =
+                            self.eval_abort_member_call(object, member, &evaluated_args)?
+                        {
+                            return Ok(value);
+                        }
                         let is_headers_object = {
                             let entries = object.borrow();
                             Self::is_headers_object(&entries)
@@ -1526,6 +1531,8 @@ impl Harness {
                         } else {
                             Err(Error::ScriptRuntime(format!("unknown variable: {name}")))
                         }
+                    } else if let Some(value) = self.resolve_module_import_binding(name, env)? {
+                        Ok(value)
                     } else if let Some(value) = env.get(name).cloned() {
                         Ok(value)
                     } else if let Some(value) = self.resolve_pending_function_decl(name, env) {
@@ -1560,6 +1567,7 @@ impl Harness {
                 }
                 Expr::CreateElement(tag_name) => {
                     let node = self.dom.create_detached_element(tag_name.clone());
+                    self.upgrade_created_element(node)?;
                     Ok(Value::Node(node))
                 }
                 Expr::CreateTextNode(text) => {
@@ -29,8 +29,15 @@ impl Harness {
                         let mut values = Vec::with_capacity(args.len());
                         for arg in args {
                             let value = self.eval_expr(arg, env, event_param, event)?;
-                            values.push(Self::value_to_i64(&value));
+                            values.push(Self::coerce_number_for_global(&value));
                         }
+                        if values.iter().any(|value| !value.is_finite()) {
+                            return Ok(Self::new_date_value(INVALID_DATE_TIME_MS));
+                        }
+                        let values = values
+                            .into_iter()
+                            .map(|value| value.trunc() as i64)
+                            .collect::<Vec<_>>();
 
                         let mut year = values.first().copied().unwrap_or(0);
                         if (0..=99).contains(&year) {
@@ -43,7 +50,7 @@ impl Harness {
                         let second = values.get(5).copied().unwrap_or(0);
                         let millisecond = values.get(6).copied().unwrap_or(0);
 
-                        Self::utc_timestamp_ms_from_components(
+                        let local_ms = Self::utc_timestamp_ms_from_components(
                             year,
                             month,
                             day,
@@ -51,13 +58,14 @@ impl Harness {
                             minute,
                             second,
                             millisecond,
-                        )
+                        );
+                        Self::time_clip_ms(self.local_time_to_utc_ms(local_ms) as f64)
                     };
                     Ok(Self::new_date_value(timestamp_ms))
                 }
                 Expr::DateParse(value) => {
                     let value = self.eval_expr(value, env, event_param, event)?.as_string();
-                    if let Some(timestamp_ms) = Self::parse_date_string_to_epoch_ms(&value) {
+                    if let Some(timestamp_ms) = self.parse_date_string_in_time_zone(&value) {
                         Ok(Value::Number(timestamp_ms))
                     } else {
                         Ok(Value::Float(f64::NAN))
@@ -67,8 +75,15 @@ impl Harness {
                     let mut values = Vec::with_capacity(args.len());
                     for arg in args {
                         let value = self.eval_expr(arg, env, event_param, event)?;
-                        values.push(Self::value_to_i64(&value));
+                        values.push(Self::coerce_number_for_global(&value));
+                    }
+                    if values.iter().any(|value| !value.is_finite()) {
+                        return Ok(Value::Float(f64::NAN));
                     }
+                    let values = values
+                        .into_iter()
+                        .map(|value| value.trunc() as i64)
+                        .collect::<Vec<_>>();
 
                     let mut year = values.first().copied().unwrap_or(0);
                     if (0..=99).contains(&year) {
@@ -92,54 +107,38 @@ impl Harness {
                     )))
                 }
                 Expr::DateGetTime(target) => {
-                    let date = self.resolve_date_from_env(env, target)?;
-                    Ok(Value::Number(*date.borrow()))
+                    self.eval_date_getter_on_variable(env, target, "getTime")
                 }
                 Expr::DateSetTime { target, value } => {
                     let date = self.resolve_date_from_env(env, target)?;
                     let value = self.eval_expr(value, env, event_param, event)?;
-                    let timestamp_ms = Self::value_to_i64(&value);
-                    *date.borrow_mut() = timestamp_ms;
-                    Ok(Value::Number(timestamp_ms))
+                    Ok(self
+                        .eval_date_member_call(&date, "setTime", &[value])?
+                        .unwrap_or(Value::Undefined))
                 }
                 Expr::DateToIsoString(target) => {
-                    let date = self.resolve_date_from_env(env, target)?;
-                    Ok(Value::String(Self::format_iso_8601_utc(*date.borrow())))
+                    self.eval_date_getter_on_variable(env, target, "toISOString")
                 }
                 Expr::DateGetUTCFullYear(target) => {
-                    let date = self.resolve_date_from_env(env, target)?;
-                    let (year, ..) = Self::date_components_utc(*date.borrow());
-                    Ok(Value::Number(year))
+                    self.eval_date_getter_on_variable(env, target, "getUTCFullYear")
                 }
                 Expr::DateGetFullYear(target) => {
-                    let date = self.resolve_date_from_env(env, target)?;
-                    let (year, ..) = Self::date_components_utc(*date.borrow());
-                    Ok(Value::Number(year))
+                    self.eval_date_getter_on_variable(env, target, "getFullYear")
                 }
                 Expr::DateGetMonth(target) => {
-                    let date = self.resolve_date_from_env(env, target)?;
-                    let (_, month, ..) = Self::date_components_utc(*date.borrow());
-                    Ok(Value::Number((month as i64) - 1))
+                    self.eval_date_getter_on_variable(env, target, "getMonth")
                 }
                 Expr::DateGetDate(target) => {
-                    let date = self.resolve_date_from_env(env, target)?;
-                    let (_, _, day, ..) = Self::date_components_utc(*date.borrow());
-                    Ok(Value::Number(day as i64))
+                    self.eval_date_getter_on_variable(env, target, "getDate")
                 }
                 Expr::DateGetHours(target) => {
-                    let date = self.resolve_date_from_env(env, target)?;
-                    let (_, _, _, hour, ..) = Self::date_components_utc(*date.borrow());
-                    Ok(Value::Number(hour as i64))
+                    self.eval_date_getter_on_variable(env, target, "getHours")
                 }
                 Expr::DateGetMinutes(target) => {
-                    let date = self.resolve_date_from_env(env, target)?;
-                    let (_, _, _, _, minute, ..) = Self::date_components_utc(*date.borrow());
-                    Ok(Value::Number(minute as i64))
+                    self.eval_date_getter_on_variable(env, target, "getMinutes")
                 }
                 Expr::DateGetSeconds(target) => {
-                    let date = self.resolve_date_from_env(env, target)?;
-                    let (_, _, _, _, _, second, _) = Self::date_components_utc(*date.borrow());
-                    Ok(Value::Number(second as i64))
+                    self.eval_date_getter_on_variable(env, target, "getSeconds")
                 }
                 Expr::IntlFormatterConstruct {
                     kind,
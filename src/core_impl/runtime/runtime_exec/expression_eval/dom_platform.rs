@@ -47,6 +47,13 @@ impl Harness {
                         }
                     }
                     let node = self.resolve_dom_query_required_runtime(target, env)?;
+                    if self.dom.element(node).is_none() {
+                        if let Some(value) = Self::object_key_from_dom_prop(prop)
+                            .and_then(|key| self.character_data_or_doctype_property(node, key))
+                        {
+                            return Ok(value);
+                        }
+                    }
                     match prop {
                         DomProp::Attributes => {
                             self.dom.element(node).ok_or_else(|| {
@@ -400,10 +407,18 @@ impl Harness {
                             .map(Value::String)
                             .unwrap_or(Value::Undefined)),
                         DomProp::Style(prop) => Ok(Value::String(self.dom.style_get(node, prop)?)),
-                        DomProp::OffsetWidth => Ok(Value::Number(self.dom.offset_width(node)?)),
-                        DomProp::OffsetHeight => Ok(Value::Number(self.dom.offset_height(node)?)),
-                        DomProp::OffsetLeft => Ok(Value::Number(self.dom.offset_left(node)?)),
-                        DomProp::OffsetTop => Ok(Value::Number(self.dom.offset_top(node)?)),
+                        DomProp::OffsetWidth => Ok(Value::Number(
+                            self.rendered_offset_value(node, self.dom.offset_width(node)?),
+                        )),
+                        DomProp::OffsetHeight => Ok(Value::Number(
+                            self.rendered_offset_value(node, self.dom.offset_height(node)?),
+                        )),
+                        DomProp::OffsetLeft => Ok(Value::Number(
+                            self.rendered_offset_value(node, self.dom.offset_left(node)?),
+                        )),
+                        DomProp::OffsetTop => Ok(Value::Number(
+                            self.rendered_offset_value(node, self.dom.offset_top(node)?),
+                        )),
                         DomProp::ScrollWidth => Ok(Value::Number(self.dom.scroll_width(node)?)),
                         DomProp::ScrollHeight => Ok(Value::Number(self.dom.scroll_height(node)?)),
                         DomProp::ScrollLeft => Ok(Value::Number(self.dom.scroll_left(node)?)),
@@ -521,7 +536,11 @@ impl Harness {
                             .last_element_child(node)
                             .map(Value::Node)
                             .unwrap_or(Value::Null)),
-                        DomProp::CurrentScript => Ok(Value::Null),
+                        DomProp::CurrentScript => Ok(self
+                            .dom_runtime
+                            .current_script
+                            .map(Value::Node)
+                            .unwrap_or(Value::Null)),
                         DomProp::FormsLength => Ok(Value::Number(
                             self.dom.query_selector_all("form")?.len() as i64,
                         )),
@@ -1234,6 +1253,15 @@ impl Harness {
         parsed.max(0.0).trunc() as i64
     }
 
+    // Layout overrides only apply while the cascade renders the element.
+    fn rendered_offset_value(&self, node: NodeId, value: i64) -> i64 {
+        if self.is_element_rendered(node) {
+            value
+        } else {
+            0
+        }
+    }
+
     fn client_width_property_value(&self, node: NodeId) -> Result<i64> {
         let is_document_html_element = self.dom.document_element() == Some(node)
             && self
@@ -1276,7 +1304,10 @@ impl Harness {
     pub(crate) fn node_type_number(&self, node: NodeId) -> i64 {
         match &self.dom.nodes[node.0].node_type {
             NodeType::Document => 9,
+            NodeType::DocumentType(_) => 10,
             NodeType::Text(_) => 3,
+            NodeType::Comment(_) => 8,
+            NodeType::ProcessingInstruction(_) => 7,
             NodeType::Element(element)
                 if element.tag_name.eq_ignore_ascii_case("#document-fragment") =>
             {
@@ -1492,7 +1523,10 @@ impl Harness {
     pub(crate) fn node_name(&self, node: NodeId) -> String {
         match &self.dom.nodes[node.0].node_type {
             NodeType::Document => "#document".to_string(),
+            NodeType::DocumentType(doctype) => doctype.name.clone(),
             NodeType::Text(_) => "#text".to_string(),
+            NodeType::Comment(_) => "#comment".to_string(),
+            NodeType::ProcessingInstruction(instruction) => instruction.target.clone(),
             NodeType::Element(element)
                 if element.tag_name.eq_ignore_ascii_case("#document-fragment") =>
             {
@@ -1514,20 +1548,51 @@ impl Harness {
     }
 
     pub(crate) fn node_value(&self, node: NodeId) -> Value {
-        match &self.dom.nodes[node.0].node_type {
-            NodeType::Text(text) => Value::String(text.clone()),
-            _ => Value::Null,
-        }
+        self.dom
+            .character_data(node)
+            .map(|data| Value::String(data.to_string()))
+            .unwrap_or(Value::Null)
     }
 
     pub(crate) fn node_text_content_value(&self, node: NodeId) -> Value {
-        if matches!(self.dom.nodes[node.0].node_type, NodeType::Document) {
+        if matches!(
+            self.dom.nodes[node.0].node_type,
+            NodeType::Document | NodeType::DocumentType(_)
+        ) {
             Value::Null
         } else {
             Value::String(self.dom.text_content(node))
         }
     }
 
+    pub(crate) fn character_data_or_doctype_property(
+        &self,
+        node: NodeId,
+        key: &str,
+    ) -> Option<Value> {
+        match (&self.dom.nodes[node.0].node_type, key) {
+            (NodeType::DocumentType(doctype), "name") => Some(Value::String(doctype.name.clone())),
+            (NodeType::DocumentType(doctype), "publicId") => {
+                Some(Value::String(doctype.public_id.clone()))
+            }
+            (NodeType::DocumentType(doctype), "systemId") => {
+                Some(Value::String(doctype.system_id.clone()))
+            }
+            (NodeType::ProcessingInstruction(instruction), "target") => {
+                Some(Value::String(instruction.target.clone()))
+            }
+            (_, "data") => self
+                .dom
+                .character_data(node)
+                .map(|data| Value::String(data.to_string())),
+            (_, "length") => self
+                .dom
+                .character_data(node)
+                .map(|data| Value::Number(data.encode_utf16().count() as i64)),
+            _ => None,
+        }
+    }
+
     pub(crate) fn node_root(&self, node: NodeId) -> NodeId {
         let mut current = node;
         while let Some(parent) = self.dom.parent(current) {
@@ -1637,7 +1702,17 @@ impl Harness {
         let right_node = &self.dom.nodes[right.0];
         let metadata_equal = match (&left_node.node_type, &right_node.node_type) {
             (NodeType::Document, NodeType::Document) => true,
-            (NodeType::Text(left_text), NodeType::Text(right_text)) => left_text == right_text,
+            (NodeType::Text(left_text), NodeType::Text(right_text))
+            | (NodeType::Comment(left_text), NodeType::Comment(right_text)) => {
+                left_text == right_text
+            }
+            (NodeType::DocumentType(left_doctype), NodeType::DocumentType(right_doctype)) => {
+                left_doctype == right_doctype
+            }
+            (
+                NodeType::ProcessingInstruction(left_instruction),
+                NodeType::ProcessingInstruction(right_instruction),
+            ) => left_instruction == right_instruction,
             (NodeType::Element(left_element), NodeType::Element(right_element)) => {
                 left_element
                     .tag_name
@@ -1926,6 +2001,17 @@ impl Harness {
                     .map(Value::Node)
                     .unwrap_or(Value::Null),
             ),
+            "doctype" => Some(
+                self.dom.nodes[root.0]
+                    .children
+                    .iter()
+                    .copied()
+                    .find(|child| {
+                        matches!(self.dom.nodes[child.0].node_type, NodeType::DocumentType(_))
+                    })
+                    .map(Value::Node)
+                    .unwrap_or(Value::Null),
+            ),
             "contentType" => Some(Value::String("text/html".to_string())),
             "URL" | "documentURI" => Some(Value::String("about:blank".to_string())),
             "createTreeWalker"
@@ -1938,6 +2024,8 @@ impl Harness {
             | "createElement"
             | "createElementNS"
             | "createTextNode"
+            | "createComment"
+            | "createProcessingInstruction"
             | "createAttribute"
             | "createDocumentFragment"
             | "createRange"
@@ -1967,8 +2055,10 @@ impl Harness {
         match self.node_type_number(node) {
             1 => 0x1,
             3 => 0x4,
+            7 => 0x40,
             8 => 0x80,
             9 => 0x100,
+            10 => 0x200,
             11 => 0x400,
             _ => 0,
         }
@@ -2203,6 +2293,41 @@ impl Harness {
                 let node = self.dom.create_detached_text(text);
                 Ok(Some(Value::Node(node)))
             }
+            "createComment" => {
+                if evaluated_args.len() != 1 {
+                    return Err(Error::ScriptRuntime(
+                        "createComment requires exactly one argument".into(),
+                    ));
+                }
+                let data = evaluated_args[0].as_string();
+                let node = self.dom.create_detached_comment(data);
+                Ok(Some(Value::Node(node)))
+            }
+            "createProcessingInstruction" => {
+                if evaluated_args.len() != 2 {
+                    return Err(Error::ScriptRuntime(
+                        "createProcessingInstruction requires exactly two arguments".into(),
+                    ));
+                }
+                let target = evaluated_args[0].as_string();
+                let data = evaluated_args[1].as_string();
+                if !is_valid_create_attribute_name(&target) {
+                    return Err(Error::ScriptRuntime(
+                        "InvalidCharacterError: processing instruction target is not a valid XML name"
+                            .into(),
+                    ));
+                }
+                if data.contains("?>") {
+                    return Err(Error::ScriptRuntime(
+                        "InvalidCharacterError: processing instruction data cannot contain '?>'"
+                            .into(),
+                    ));
+                }
+                let node = self
+                    .dom
+                    .create_detached_processing_instruction(target, data);
+                Ok(Some(Value::Node(node)))
+            }
             "createAttribute" => {
                 if evaluated_args.len() != 1 {
                     return Err(Error::ScriptRuntime(
@@ -346,8 +346,10 @@ impl Harness {
                 let js_type = match inner.as_ref() {
                     Expr::Var(name) => {
                         self.ensure_binding_initialized(env, name)?;
+                        let import = self.resolve_module_import_binding(name, env)?;
                         self.resolve_listener_capture_pending_value(name)
                             .flatten()
+                            .or(import)
                             .or_else(|| env.get(name).cloned())
                             .or_else(|| self.resolve_pending_function_decl(name, env))
                             .as_ref()
@@ -466,7 +468,7 @@ impl Harness {
                             return Err(Error::ScriptThrown(ThrownValue::new(reason)));
                         }
                         None => {
-                            if !self.scheduler.microtask_queue.is_empty() {
+                            if self.has_pending_microtasks() {
                                 self.run_microtask_queue()?;
                                 continue;
                             }
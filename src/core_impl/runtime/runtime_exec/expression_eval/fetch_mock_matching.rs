@@ -0,0 +1,165 @@
+use super::*;
+
+impl Harness {
+    pub(crate) fn fetch_request_body_bytes(&self, body: &Value) -> Option<Vec<u8>> {
+        match body {
+            Value::Undefined | Value::Null => None,
+            Value::Object(entries) => {
+                let entries = entries.borrow();
+                if Self::is_url_search_params_object(&entries) {
+                    let pairs = Self::url_search_params_pairs_from_object_entries(&entries);
+                    return Some(serialize_url_search_params_pairs(&pairs).into_bytes());
+                }
+                drop(entries);
+                Some(self.blob_part_bytes(body))
+            }
+            other => Some(self.blob_part_bytes(other)),
+        }
+    }
+
+    pub(crate) fn resolve_fetch_mock(
+        &mut self,
+        input: &str,
+        request: &FetchRequest,
+    ) -> Option<FetchMockResponse> {
+        if let Some(handler) = self.platform_mocks.fetch_handler.as_ref() {
+            match (handler.0.borrow_mut())(request) {
+                FetchOutcome::Response(response) => return Some(response),
+                FetchOutcome::NetworkError => return None,
+                FetchOutcome::Fallthrough => {}
+            }
+        }
+        self.platform_mocks
+            .fetch_mock_rules
+            .iter()
+            .rev()
+            .find(|rule| Self::fetch_matcher_matches(&rule.matcher, input, request))
+            .map(|rule| rule.response.clone())
+            .or_else(|| self.platform_mocks.fetch_mocks.get(input).cloned())
+            .or_else(|| self.platform_mocks.fetch_mocks.get(&request.url).cloned())
+    }
+
+    fn fetch_matcher_matches(matcher: &FetchMatcher, input: &str, request: &FetchRequest) -> bool {
+        if matcher
+            .method
+            .as_ref()
+            .is_some_and(|method| !method.eq_ignore_ascii_case(&request.method))
+        {
+            return false;
+        }
+
+        let pattern_has_query = matcher.url.contains('?');
+        let url_matches = [input, request.url.as_str()].into_iter().any(|candidate| {
+            let candidate = candidate.split('#').next().unwrap_or_default();
+            let candidate = if pattern_has_query {
+                candidate
+            } else {
+                candidate.split('?').next().unwrap_or_default()
+            };
+            Self::fetch_url_glob_matches(&matcher.url, candidate)
+        });
+        if !url_matches {
+            return false;
+        }
+
+        if !matcher.query.is_empty() {
+            let query = request
+                .url
+                .split('#')
+                .next()
+                .and_then(|url| url.split_once('?'))
+                .map(|(_, query)| parse_url_search_params_pairs_from_query_string(query))
+                .unwrap_or_default();
+            if !matcher
+                .query
+                .iter()
+                .all(|expected| query.iter().any(|actual| actual == expected))
+            {
+                return false;
+            }
+        }
+
+        if !matcher.headers.iter().all(|(name, value)| {
+            request
+                .header(name)
+                .is_some_and(|actual| actual.trim() == value.trim())
+        }) {
+            return false;
+        }
+
+        if let Some(expected) = &matcher.json_body {
+            let Some(body) = request.body_text() else {
+                return false;
+            };
+            let (Ok(expected), Ok(actual)) = (
+                Self::parse_json_text(expected),
+                Self::parse_json_text(&body),
+            ) else {
+                return false;
+            };
+            if !Self::fetch_json_values_equal(&expected, &actual) {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn fetch_url_glob_matches(pattern: &str, candidate: &str) -> bool {
+        let Some((first, rest)) = pattern.split_once('*') else {
+            return pattern == candidate;
+        };
+        let Some(mut remaining) = candidate.strip_prefix(first) else {
+            return false;
+        };
+        let mut segments = rest.split('*').peekable();
+        while let Some(segment) = segments.next() {
+            if segments.peek().is_none() {
+                return remaining.ends_with(segment);
+            }
+            match remaining.find(segment) {
+                Some(index) => remaining = &remaining[index + segment.len()..],
+                None => return false,
+            }
+        }
+        true
+    }
+
+    fn fetch_json_values_equal(left: &Value, right: &Value) -> bool {
+        match (left, right) {
+            (Value::Null, Value::Null) => true,
+            (Value::Bool(left), Value::Bool(right)) => left == right,
+            (Value::String(left), Value::String(right)) => left == right,
+            (Value::Number(_) | Value::Float(_), Value::Number(_) | Value::Float(_)) => {
+                Self::coerce_number_for_global(left) == Self::coerce_number_for_global(right)
+            }
+            (Value::Array(left), Value::Array(right)) => {
+                let left = left.borrow();
+                let right = right.borrow();
+                left.len() == right.len()
+                    && left
+                        .iter()
+                        .zip(right.iter())
+                        .all(|(left, right)| Self::fetch_json_values_equal(left, right))
+            }
+            (Value::Object(left), Value::Object(right)) => {
+                let left = left.borrow();
+                let right = right.borrow();
+                let public_keys = |entries: &ObjectValue| {
+                    entries
+                        .iter()
+                        .filter(|(key, _)| !Self::is_internal_object_key(key))
+                        .count()
+                };
+                public_keys(&left) == public_keys(&right)
+                    && left
+                        .iter()
+                        .filter(|(key, _)| !Self::is_internal_object_key(key))
+                        .all(|(key, value)| {
+                            Self::object_get_entry(&right, key)
+                                .is_some_and(|other| Self::fetch_json_values_equal(value, &other))
+                        })
+            }
+            _ => false,
+        }
+    }
+}
@@ -0,0 +1,90 @@
+use super::*;
+
+impl Harness {
+    pub(crate) fn defer_fetch_response(
+        &mut self,
+        method: String,
+        url: String,
+        response: Option<FetchMockResponse>,
+        signal: Option<Rc<RefCell<ObjectValue>>>,
+    ) -> Value {
+        let id = self.scheduler.allocate_internal_task_id();
+        let started_at_ms = self.scheduler.now_ms;
+        let due_at_ms = response
+            .as_ref()
+            .map(|response| started_at_ms.saturating_add(response.latency_ms.max(0)));
+        if let Some(due_at) = due_at_ms {
+            let order = self.scheduler.allocate_task_order();
+            self.scheduler.task_queue.push(ScheduledTask {
+                id,
+                due_at,
+                order,
+                kind: ScheduledTaskKind::FetchResponse,
+                interval_ms: None,
+                callback: TimerCallback::Reference(String::new()),
+                callback_args: Vec::new(),
+                env: ScriptEnv::default(),
+            });
+        }
+        self.trace_timer_line(format!(
+            "[timer] schedule fetch id={} url={} due_at={}",
+            id,
+            url,
+            due_at_ms
+                .map(|value| value.to_string())
+                .unwrap_or_else(|| "manual".into())
+        ));
+
+        let promise = self.new_pending_promise();
+        self.platform_mocks.pending_fetches.push(PendingFetchState {
+            info: PendingFetch {
+                id,
+                method,
+                url,
+                started_at_ms,
+                due_at_ms,
+            },
+            promise: promise.clone(),
+            response,
+            signal,
+        });
+        Value::Promise(promise)
+    }
+
+    pub(crate) fn take_pending_fetch(&mut self, id: i64) -> Option<PendingFetchState> {
+        let index = self
+            .platform_mocks
+            .pending_fetches
+            .iter()
+            .position(|pending| pending.info.id == id)?;
+        self.scheduler
+            .task_queue
+            .retain(|task| task.id != id || !matches!(task.kind, ScheduledTaskKind::FetchResponse));
+        Some(self.platform_mocks.pending_fetches.remove(index))
+    }
+
+    pub(crate) fn settle_pending_fetch(
+        &mut self,
+        pending: PendingFetchState,
+        outcome: std::result::Result<FetchMockResponse, Value>,
+    ) -> Result<()> {
+        self.trace_timer_line(format!(
+            "[timer] settle fetch id={} url={} outcome={} now_ms={}",
+            pending.info.id,
+            pending.info.url,
+            if outcome.is_ok() { "response" } else { "error" },
+            self.scheduler.now_ms
+        ));
+        self.run_in_task_context(|this| {
+            match &outcome {
+                Ok(response) => {
+                    let value =
+                        this.new_fetch_response_value_from_mock(&pending.info.url, response);
+                    this.promise_resolve(&pending.promise, value)?;
+                }
+                Err(reason) => this.promise_reject(&pending.promise, reason.clone()),
+            }
+            Ok(())
+        })
+    }
+}
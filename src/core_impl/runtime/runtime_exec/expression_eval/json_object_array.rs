@@ -8,6 +8,11 @@ impl Harness {
     ) -> Option<Value> {
         self.resolve_listener_capture_pending_value(target)
             .flatten()
+            .or_else(|| {
+                self.resolve_module_import_binding(target, env)
+                    .ok()
+                    .flatten()
+            })
             .or_else(|| env.get(target).cloned())
     }
 
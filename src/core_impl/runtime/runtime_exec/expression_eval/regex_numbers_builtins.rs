@@ -51,6 +51,13 @@ impl Harness {
                         )))
                     } else if let Some(source_text) = self.callable_source_text(&value) {
                         Ok(Value::String(source_text))
+                    } else if let Value::Date(date) = &value {
+                        let timestamp_ms = *date.borrow();
+                        Ok(Value::String(self.format_date_display_string(
+                            timestamp_ms,
+                            true,
+                            true,
+                        )))
                     } else {
                         Ok(Value::String(value.as_string()))
                     }
@@ -78,9 +85,14 @@ impl Harness {
                         .map(|value| self.eval_expr(value, env, event_param, event))
                         .transpose()?
                         .unwrap_or(Value::Undefined);
-                    let coerced = self
-                        .callable_source_text(&value)
-                        .unwrap_or_else(|| value.as_string());
+                    let coerced = match &value {
+                        Value::Date(date) => {
+                            self.format_date_display_string(*date.borrow(), true, true)
+                        }
+                        _ => self
+                            .callable_source_text(&value)
+                            .unwrap_or_else(|| value.as_string()),
+                    };
                     if *called_with_new {
                         Ok(Self::new_string_wrapper_value(coerced))
                     } else {
@@ -732,6 +732,7 @@ impl Harness {
                             }
                         }
                         Value::String(value) => Ok(Value::String(value)),
+                        Value::Date(date) => Ok(Self::date_time_value(*date.borrow())),
                         other => Ok(other),
                     }
                 }
@@ -740,6 +741,14 @@ impl Harness {
                     if let Some(text) = self.callable_source_text(&value) {
                         return Ok(Value::String(text));
                     }
+                    if let Value::Date(date) = &value {
+                        let timestamp_ms = *date.borrow();
+                        return Ok(Value::String(self.format_date_display_string(
+                            timestamp_ms,
+                            true,
+                            true,
+                        )));
+                    }
                     if let Value::Node(node) = &value {
                         if let Some(tag_name) = self.dom.tag_name(*node) {
                             if tag_name.eq_ignore_ascii_case("a")
@@ -903,7 +912,7 @@ impl Harness {
         )
     }
 
-    fn fetch_type_error_value(reason: &str) -> Value {
+    pub(crate) fn fetch_type_error_value(reason: &str) -> Value {
         Value::String(format!("TypeError: {reason}"))
     }
 
@@ -1125,7 +1134,19 @@ impl Harness {
             .map(|options| self.fetch_options_from_value(options))
             .transpose()?
             .unwrap_or_else(|| ("GET".to_string(), Vec::new()));
-        Ok(self.new_fetch_request_value(&input, &url, &method, &headers))
+        let request = self.new_fetch_request_value(&input, &url, &method, &headers);
+        if let (Value::Object(request_entries), Some(Value::Object(options))) =
+            (&request, args.get(1))
+        {
+            if let Some(body) = Self::object_get_entry(&options.borrow(), "body") {
+                Self::object_set_entry(
+                    &mut request_entries.borrow_mut(),
+                    INTERNAL_FETCH_REQUEST_BODY_KEY.to_string(),
+                    body,
+                );
+            }
+        }
+        Ok(request)
     }
 
     pub(crate) fn new_fetch_response_value(
@@ -1135,7 +1156,21 @@ impl Harness {
         status_text: &str,
         body: &str,
     ) -> Value {
-        let headers = self.new_headers_value_from_pairs(&[]);
+        let mock = FetchMockResponse::new(status)
+            .with_status_text(status_text)
+            .with_bytes(body.as_bytes());
+        self.new_fetch_response_value_from_mock(url, &mock)
+    }
+
+    pub(crate) fn new_fetch_response_value_from_mock(
+        &self,
+        request_url: &str,
+        mock: &FetchMockResponse,
+    ) -> Value {
+        let url = mock.url.as_deref().unwrap_or(request_url);
+        let status = mock.status;
+        let status_text = mock.status_text.as_str();
+        let headers = self.new_headers_value_from_pairs(&mock.headers);
         Self::new_object_value(vec![
             (
                 INTERNAL_FETCH_RESPONSE_OBJECT_KEY.to_string(),
@@ -1143,7 +1178,11 @@ impl Harness {
             ),
             (
                 INTERNAL_FETCH_RESPONSE_BODY_KEY.to_string(),
-                Value::String(body.to_string()),
+                Value::ArrayBuffer(Rc::new(RefCell::new(ArrayBufferValue {
+                    bytes: mock.body.clone(),
+                    max_byte_length: None,
+                    detached: false,
+                }))),
             ),
             (
                 INTERNAL_FETCH_RESPONSE_STATUS_KEY.to_string(),
@@ -1164,6 +1203,11 @@ impl Harness {
                 Value::String(status_text.to_string()),
             ),
             ("url".to_string(), Value::String(url.to_string())),
+            ("redirected".to_string(), Value::Bool(mock.redirected)),
+            (
+                "type".to_string(),
+                Value::String(mock.response_type.clone()),
+            ),
             ("headers".to_string(), headers),
             ("text".to_string(), Self::new_builtin_placeholder_function()),
             ("json".to_string(), Self::new_builtin_placeholder_function()),
@@ -1179,6 +1223,41 @@ impl Harness {
         ])
     }
 
+    // The clone gets its own headers and body so later writes stay on one side.
+    fn clone_fetch_response_value(
+        &self,
+        response_object: &Rc<RefCell<ObjectValue>>,
+    ) -> Result<Value> {
+        let mut cloned = response_object.borrow().clone();
+        let body = Self::fetch_response_body_bytes(&cloned);
+        Self::object_set_entry(
+            &mut cloned,
+            INTERNAL_FETCH_RESPONSE_BODY_KEY.to_string(),
+            Value::ArrayBuffer(Rc::new(RefCell::new(ArrayBufferValue {
+                bytes: body,
+                max_byte_length: None,
+                detached: false,
+            }))),
+        );
+        if let Some(headers) = Self::object_get_entry(&cloned, "headers") {
+            let pairs = self.headers_pairs_from_value(&headers)?;
+            Self::object_set_entry(
+                &mut cloned,
+                "headers".to_string(),
+                self.new_headers_value_from_pairs(&pairs),
+            );
+        }
+        Ok(Value::Object(Rc::new(RefCell::new(cloned))))
+    }
+
+    pub(crate) fn fetch_response_body_bytes(entries: &[(String, Value)]) -> Vec<u8> {
+        match Self::object_get_entry(entries, INTERNAL_FETCH_RESPONSE_BODY_KEY) {
+            Some(Value::ArrayBuffer(buffer)) => buffer.borrow().bytes.clone(),
+            Some(value) => value.as_string().into_bytes(),
+            None => Vec::new(),
+        }
+    }
+
     pub(crate) fn fetch_response_property_from_entries(
         &self,
         entries: &[(String, Value)],
@@ -1188,8 +1267,10 @@ impl Harness {
             return None;
         }
         match key {
-            "ok" | "status" | "statusText" | "url" | "headers" | "text" | "json" | "blob"
-            | "arrayBuffer" | "clone" => Self::object_get_entry(entries, key),
+            "ok" | "status" | "statusText" | "url" | "redirected" | "type" | "headers" | "text"
+            | "json" | "blob" | "arrayBuffer" | "bytes" | "clone" => {
+                Self::object_get_entry(entries, key)
+            }
             _ => None,
         }
     }
@@ -1252,25 +1333,77 @@ impl Harness {
                 Err(_) => return self.fetch_rejected_promise("Invalid URL"),
             };
 
+        let (mut method, mut headers, mut body) = ("GET".to_string(), Vec::new(), None);
+        let mut signal = None;
+        if let Value::Object(entries) = request_value {
+            let entries = entries.borrow();
+            if Self::is_fetch_request_object(&entries) {
+                if let Some(Value::String(request_method)) =
+                    Self::object_get_entry(&entries, INTERNAL_FETCH_REQUEST_METHOD_KEY)
+                {
+                    method = request_method;
+                }
+                if let Some(request_headers) = Self::object_get_entry(&entries, "headers") {
+                    headers = self.headers_pairs_from_value(&request_headers)?;
+                }
+                body = Self::object_get_entry(&entries, INTERNAL_FETCH_REQUEST_BODY_KEY)
+                    .and_then(|value| self.fetch_request_body_bytes(&value));
+            }
+        }
         if let Some(options) = options_value {
-            if self.fetch_options_from_value(options).is_err() {
+            let Ok((init_method, init_headers)) = self.fetch_options_from_value(options) else {
                 return self.fetch_rejected_promise("RequestInit is invalid");
+            };
+            if let Value::Object(entries) = options {
+                let entries = entries.borrow();
+                if Self::object_get_entry(&entries, "method")
+                    .is_some_and(|value| !matches!(value, Value::Undefined))
+                {
+                    method = init_method;
+                }
+                if Self::object_get_entry(&entries, "headers").is_some() {
+                    headers = init_headers;
+                }
+                if let Some(init_body) = Self::object_get_entry(&entries, "body") {
+                    body = self.fetch_request_body_bytes(&init_body);
+                }
+                signal = Self::object_get_entry(&entries, "signal")
+                    .and_then(|value| Self::abort_signal_from_value(&value));
             }
         }
+        if let Some(signal) = signal
+            .as_ref()
+            .filter(|signal| Self::abort_signal_is_aborted(signal))
+        {
+            let promise = self.new_pending_promise();
+            self.promise_reject(&promise, Self::abort_signal_reason(signal));
+            return Ok(Value::Promise(promise));
+        }
 
         self.platform_mocks.fetch_calls.push(input_key.clone());
-        let mock = self
-            .platform_mocks
-            .fetch_mocks
-            .get(&input_key)
-            .cloned()
-            .or_else(|| self.platform_mocks.fetch_mocks.get(&request_url).cloned());
+        let request = FetchRequest {
+            destination: FetchDestination::Fetch,
+            method,
+            url: request_url.clone(),
+            headers,
+            body,
+        };
+        if self.platform_mocks.manual_fetch_mode {
+            let method = request.method.clone();
+            self.platform_mocks.fetch_requests.push(request);
+            return Ok(self.defer_fetch_response(method, request_url, None, signal));
+        }
+        let mock = self.resolve_fetch_mock(&input_key, &request);
+        let method = request.method.clone();
+        self.platform_mocks.fetch_requests.push(request);
         let Some(mock) = mock else {
             return self.fetch_rejected_promise("Failed to fetch");
         };
+        if mock.latency_ms > 0 {
+            return Ok(self.defer_fetch_response(method, request_url, Some(mock), signal));
+        }
 
-        let response =
-            self.new_fetch_response_value(&request_url, mock.status, &mock.status_text, &mock.body);
+        let response = self.new_fetch_response_value_from_mock(&request_url, &mock);
         let promise = self.new_pending_promise();
         self.promise_resolve(&promise, response)?;
         Ok(Value::Promise(promise))
@@ -1291,27 +1424,30 @@ impl Harness {
         member: &str,
         args: &[Value],
     ) -> Result<Option<Value>> {
-        let (is_response, body, status, status_text, url) = {
+        let (is_response, body, content_type) = {
             let entries = response_object.borrow();
+            let content_type = match Self::object_get_entry(&entries, "headers") {
+                Some(Value::Object(headers)) => {
+                    let headers = headers.borrow();
+                    match Self::object_get_entry(&headers, INTERNAL_HEADERS_ENTRIES_KEY) {
+                        Some(Value::Object(header_entries)) => {
+                            Self::object_get_entry(&header_entries.borrow(), "content-type")
+                                .map(|value| value.as_string())
+                        }
+                        _ => None,
+                    }
+                }
+                _ => None,
+            };
             (
                 Self::is_fetch_response_object(&entries),
-                Self::object_get_entry(&entries, INTERNAL_FETCH_RESPONSE_BODY_KEY),
-                Self::object_get_entry(&entries, INTERNAL_FETCH_RESPONSE_STATUS_KEY),
-                Self::object_get_entry(&entries, INTERNAL_FETCH_RESPONSE_STATUS_TEXT_KEY),
-                Self::object_get_entry(&entries, INTERNAL_FETCH_RESPONSE_URL_KEY),
+                Self::fetch_response_body_bytes(&entries),
+                content_type.unwrap_or_default(),
             )
         };
         if !is_response {
             return Ok(None);
         }
-        let body = body.map(|value| value.as_string()).unwrap_or_default();
-        let status = status
-            .map(|value| Self::value_to_i64(&value))
-            .unwrap_or(200);
-        let status_text = status_text
-            .map(|value| value.as_string())
-            .unwrap_or_default();
-        let url = url.map(|value| value.as_string()).unwrap_or_default();
 
         match member {
             "text" => {
@@ -1321,7 +1457,10 @@ impl Harness {
                     ));
                 }
                 let promise = self.new_pending_promise();
-                self.promise_resolve(&promise, Value::String(body))?;
+                self.promise_resolve(
+                    &promise,
+                    Value::String(String::from_utf8_lossy(&body).into_owned()),
+                )?;
                 Ok(Some(Value::Promise(promise)))
             }
             "json" => {
@@ -1331,7 +1470,7 @@ impl Harness {
                     ));
                 }
                 let promise = self.new_pending_promise();
-                match Self::parse_json_text(&body) {
+                match Self::parse_json_text(&String::from_utf8_lossy(&body)) {
                     Ok(value) => {
                         self.promise_resolve(&promise, value)?;
                     }
@@ -1355,7 +1494,7 @@ impl Harness {
                 let promise = self.new_pending_promise();
                 self.promise_resolve(
                     &promise,
-                    Self::new_blob_value(body.into_bytes(), String::new()),
+                    Self::new_blob_value(body, Self::normalize_blob_type(&content_type)),
                 )?;
                 Ok(Some(Value::Promise(promise)))
             }
@@ -1369,25 +1508,30 @@ impl Harness {
                 self.promise_resolve(
                     &promise,
                     Value::ArrayBuffer(Rc::new(RefCell::new(ArrayBufferValue {
-                        bytes: body.into_bytes(),
+                        bytes: body,
                         max_byte_length: None,
                         detached: false,
                     }))),
                 )?;
                 Ok(Some(Value::Promise(promise)))
             }
+            "bytes" => {
+                if !args.is_empty() {
+                    return Err(Error::ScriptRuntime(
+                        "Response.bytes does not take arguments".into(),
+                    ));
+                }
+                let promise = self.new_pending_promise();
+                self.promise_resolve(&promise, Self::new_uint8_typed_array_from_bytes(&body))?;
+                Ok(Some(Value::Promise(promise)))
+            }
             "clone" => {
                 if !args.is_empty() {
                     return Err(Error::ScriptRuntime(
                         "Response.clone does not take arguments".into(),
                     ));
                 }
-                Ok(Some(self.new_fetch_response_value(
-                    &url,
-                    status,
-                    &status_text,
-                    &body,
-                )))
+                Ok(Some(self.clone_fetch_response_value(response_object)?))
             }
             _ => Ok(None),
         }
@@ -49,6 +49,9 @@ impl Harness {
             Value::Null | Value::Undefined => Err(Error::ScriptRuntime(
                 "cannot convert null or undefined to BigInt".into(),
             )),
+            Value::Date(value) if *value.borrow() == INVALID_DATE_TIME_MS => Err(
+                Error::ScriptRuntime("cannot convert NaN to a BigInt".into()),
+            ),
             Value::Date(value) => Ok(JsBigInt::from(*value.borrow())),
             Value::Array(values) => {
                 let rendered = Value::Array(values.clone()).as_string();
@@ -79,6 +79,7 @@ impl Harness {
             | Value::PromiseCapability(_)
             | Value::Function(_) => Ok(None),
             Value::RegExp(_) => Ok(Some("{}".to_string())),
+            Value::Date(v) if *v.borrow() == INVALID_DATE_TIME_MS => Ok(Some("null".to_string())),
             Value::Date(v) => Ok(Some(format!(
                 "\"{}\"",
                 Self::json_escape_string(&Self::format_iso_8601_utc(*v.borrow()))
@@ -305,6 +305,20 @@ impl Harness {
             };
         }
 
+        if let Value::Date(date) = &value {
+            let member = match method {
+                NumberInstanceMethod::ToString => Some("toString"),
+                NumberInstanceMethod::ToLocaleString => Some("toLocaleString"),
+                NumberInstanceMethod::ValueOf => Some("valueOf"),
+                _ => None,
+            };
+            if let Some(member) = member {
+                if let Some(result) = self.eval_date_member_call(date, member, &args_value)? {
+                    return Ok(result);
+                }
+            }
+        }
+
         if matches!(method, NumberInstanceMethod::ToString)
             && let Value::Object(entries) = &value
         {
@@ -1,9 +1,17 @@
 use super::*;
 
+mod abort_signal_members;
 mod aria_dom_property_resolvers;
 mod binary_equality_ops;
 mod cache_storage_members;
 mod collection_member_calls;
 mod cookie_store_members;
+mod css_colors;
+mod custom_element_members;
+mod date_member_calls;
+mod event_interface_members;
+mod layout_observer_members;
+mod mutation_observer_members;
 mod node_selection_input_validity;
+mod style_cascade;
 mod value_object_helpers;
@@ -0,0 +1,381 @@
+use super::*;
+
+impl Harness {
+    pub(crate) fn new_abort_controller_constructor_value() -> Value {
+        Self::new_object_value(vec![(
+            INTERNAL_CALLABLE_KIND_KEY.to_string(),
+            Value::String("abort_controller_constructor".to_string()),
+        )])
+    }
+
+    pub(crate) fn new_abort_signal_constructor_value() -> Value {
+        let mut entries = vec![(
+            INTERNAL_CALLABLE_KIND_KEY.to_string(),
+            Value::String("abort_signal_constructor".to_string()),
+        )];
+        for method in ["abort", "any", "timeout"] {
+            entries.push((
+                method.to_string(),
+                Self::new_object_value(vec![
+                    (
+                        INTERNAL_CALLABLE_KIND_KEY.to_string(),
+                        Value::String("abort_signal_static_method".to_string()),
+                    ),
+                    (
+                        INTERNAL_STATIC_METHOD_NAME_KEY.to_string(),
+                        Value::String(method.to_string()),
+                    ),
+                ]),
+            ));
+        }
+        Self::new_object_value(entries)
+    }
+
+    pub(crate) fn new_dom_exception_constructor_value() -> Value {
+        Self::new_object_value(vec![(
+            INTERNAL_CALLABLE_KIND_KEY.to_string(),
+            Value::String("dom_exception_constructor".to_string()),
+        )])
+    }
+
+    pub(crate) fn new_dom_exception_value(name: &str, message: &str) -> Value {
+        let code = match name {
+            "IndexSizeError" => 1,
+            "NotFoundError" => 8,
+            "NotSupportedError" => 9,
+            "InvalidStateError" => 11,
+            "SyntaxError" => 12,
+            "InvalidAccessError" => 15,
+            "SecurityError" => 18,
+            "NetworkError" => 19,
+            "AbortError" => 20,
+            "TimeoutError" => 23,
+            "DataCloneError" => 25,
+            _ => 0,
+        };
+        Self::new_object_value(vec![
+            (
+                INTERNAL_DOM_EXCEPTION_OBJECT_KEY.to_string(),
+                Value::Bool(true),
+            ),
+            ("name".to_string(), Value::String(name.to_string())),
+            ("message".to_string(), Value::String(message.to_string())),
+            ("code".to_string(), Value::Number(code)),
+        ])
+    }
+
+    pub(crate) fn new_dom_exception_from_constructor_args(args: &[Value]) -> Value {
+        let message = match args.first() {
+            None | Some(Value::Undefined) => String::new(),
+            Some(value) => value.as_string(),
+        };
+        let name = match args.get(1) {
+            None | Some(Value::Undefined) => "Error".to_string(),
+            Some(value) => value.as_string(),
+        };
+        Self::new_dom_exception_value(&name, &message)
+    }
+
+    pub(crate) fn new_abort_controller_value() -> Value {
+        Self::new_object_value(vec![
+            (
+                INTERNAL_ABORT_CONTROLLER_OBJECT_KEY.to_string(),
+                Value::Bool(true),
+            ),
+            ("signal".to_string(), Self::new_abort_signal_value()),
+            (
+                "abort".to_string(),
+                Self::new_builtin_placeholder_function(),
+            ),
+        ])
+    }
+
+    pub(crate) fn new_abort_signal_value() -> Value {
+        Self::new_object_value(vec![
+            (
+                INTERNAL_ABORT_SIGNAL_OBJECT_KEY.to_string(),
+                Value::Bool(true),
+            ),
+            (
+                INTERNAL_EVENT_TARGET_OBJECT_KEY.to_string(),
+                Value::Bool(true),
+            ),
+            ("aborted".to_string(), Value::Bool(false)),
+            ("reason".to_string(), Value::Undefined),
+            ("onabort".to_string(), Value::Null),
+            (
+                "throwIfAborted".to_string(),
+                Self::new_builtin_placeholder_function(),
+            ),
+            (
+                "addEventListener".to_string(),
+                Self::new_builtin_placeholder_function(),
+            ),
+            (
+                "removeEventListener".to_string(),
+                Self::new_builtin_placeholder_function(),
+            ),
+            (
+                "dispatchEvent".to_string(),
+                Self::new_builtin_placeholder_function(),
+            ),
+        ])
+    }
+
+    pub(crate) fn is_abort_controller_object(entries: &[(String, Value)]) -> bool {
+        matches!(
+            Self::object_get_entry(entries, INTERNAL_ABORT_CONTROLLER_OBJECT_KEY),
+            Some(Value::Bool(true))
+        )
+    }
+
+    pub(crate) fn is_abort_signal_object(entries: &[(String, Value)]) -> bool {
+        matches!(
+            Self::object_get_entry(entries, INTERNAL_ABORT_SIGNAL_OBJECT_KEY),
+            Some(Value::Bool(true))
+        )
+    }
+
+    pub(crate) fn is_dom_exception_object(entries: &[(String, Value)]) -> bool {
+        matches!(
+            Self::object_get_entry(entries, INTERNAL_DOM_EXCEPTION_OBJECT_KEY),
+            Some(Value::Bool(true))
+        )
+    }
+
+    pub(crate) fn abort_signal_from_value(value: &Value) -> Option<Rc<RefCell<ObjectValue>>> {
+        let Value::Object(object) = value else {
+            return None;
+        };
+        Self::is_abort_signal_object(&object.borrow()).then(|| object.clone())
+    }
+
+    pub(crate) fn abort_signal_is_aborted(signal: &Rc<RefCell<ObjectValue>>) -> bool {
+        Self::object_get_entry(&signal.borrow(), "aborted").is_some_and(|value| value.truthy())
+    }
+
+    pub(crate) fn abort_signal_reason(signal: &Rc<RefCell<ObjectValue>>) -> Value {
+        Self::object_get_entry(&signal.borrow(), "reason").unwrap_or(Value::Undefined)
+    }
+
+    pub(crate) fn eval_abort_signal_static_method(
+        &mut self,
+        method: &str,
+        args: &[Value],
+    ) -> Result<Value> {
+        match method {
+            "abort" => {
+                let signal = Self::new_abort_signal_value();
+                if let Value::Object(object) = &signal {
+                    let reason = Self::abort_reason_or_default(args.first());
+                    Self::mark_abort_signal_aborted(object, reason);
+                }
+                Ok(signal)
+            }
+            "timeout" => {
+                let delay_ms = args
+                    .first()
+                    .map(Self::coerce_number_for_global)
+                    .filter(|value| value.is_finite() && *value >= 0.0)
+                    .ok_or_else(|| {
+                        Error::ScriptRuntime(
+                            "TypeError: AbortSignal.timeout requires a non-negative delay".into(),
+                        )
+                    })? as i64;
+                let signal = Self::new_abort_signal_value();
+                let due_at = self.scheduler.now_ms.saturating_add(delay_ms);
+                let id = self.scheduler.allocate_internal_task_id();
+                let order = self.scheduler.allocate_task_order();
+                self.scheduler.task_queue.push(ScheduledTask {
+                    id,
+                    due_at,
+                    order,
+                    kind: ScheduledTaskKind::AbortSignalTimeout,
+                    interval_ms: None,
+                    callback: TimerCallback::Reference(String::new()),
+                    callback_args: vec![signal.clone()],
+                    env: ScriptEnv::default(),
+                });
+                self.trace_timer_line(format!(
+                    "[timer] schedule abort signal timeout id={} due_at={} delay_ms={}",
+                    id, due_at, delay_ms
+                ));
+                Ok(signal)
+            }
+            "any" => {
+                let sources = args
+                    .first()
+                    .map(|value| self.array_like_values_from_value(value))
+                    .transpose()?
+                    .unwrap_or_default()
+                    .iter()
+                    .map(|value| {
+                        Self::abort_signal_from_value(value).ok_or_else(|| {
+                            Error::ScriptRuntime(
+                                "TypeError: AbortSignal.any requires a sequence of AbortSignal"
+                                    .into(),
+                            )
+                        })
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                let signal = Self::new_abort_signal_value();
+                let Value::Object(object) = &signal else {
+                    return Ok(signal);
+                };
+                if let Some(aborted) = sources
+                    .iter()
+                    .find(|source| Self::abort_signal_is_aborted(source))
+                {
+                    Self::mark_abort_signal_aborted(object, Self::abort_signal_reason(aborted));
+                    return Ok(signal);
+                }
+                for source in &sources {
+                    let mut entries = source.borrow_mut();
+                    match Self::object_get_entry(&entries, INTERNAL_ABORT_SIGNAL_DEPENDENTS_KEY) {
+                        Some(Value::Array(dependents)) => {
+                            dependents.borrow_mut().push(signal.clone());
+                        }
+                        _ => Self::object_set_entry(
+                            &mut entries,
+                            INTERNAL_ABORT_SIGNAL_DEPENDENTS_KEY.to_string(),
+                            Self::new_array_value(vec![signal.clone()]),
+                        ),
+                    }
+                }
+                Ok(signal)
+            }
+            _ => Err(Error::ScriptRuntime(format!(
+                "AbortSignal.{method} is not a function"
+            ))),
+        }
+    }
+
+    pub(crate) fn eval_abort_member_call(
+        &mut self,
+        object: &Rc<RefCell<ObjectValue>>,
+        member: &str,
+        args: &[Value],
+    ) -> Result<Option<Value>> {
+        let (is_controller, is_signal) = {
+            let entries = object.borrow();
+            (
+                Self::is_abort_controller_object(&entries),
+                Self::is_abort_signal_object(&entries),
+            )
+        };
+        match member {
+            "abort" if is_controller => {
+                let signal = Self::object_get_entry(&object.borrow(), "signal")
+                    .and_then(|value| Self::abort_signal_from_value(&value));
+                if let Some(signal) = signal {
+                    let reason = Self::abort_reason_or_default(args.first());
+                    self.abort_signal(&signal, reason)?;
+                }
+                Ok(Some(Value::Undefined))
+            }
+            "throwIfAborted" if is_signal => {
+                if Self::abort_signal_is_aborted(object) {
+                    return Err(Error::ScriptThrown(ThrownValue::new(
+                        Self::abort_signal_reason(object),
+                    )));
+                }
+                Ok(Some(Value::Undefined))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    fn abort_reason_or_default(reason: Option<&Value>) -> Value {
+        match reason {
+            None | Some(Value::Undefined) => {
+                Self::new_dom_exception_value("AbortError", "signal is aborted without reason")
+            }
+            Some(reason) => reason.clone(),
+        }
+    }
+
+    fn mark_abort_signal_aborted(signal: &Rc<RefCell<ObjectValue>>, reason: Value) {
+        let mut entries = signal.borrow_mut();
+        Self::object_set_entry(&mut entries, "aborted".to_string(), Value::Bool(true));
+        Self::object_set_entry(&mut entries, "reason".to_string(), reason);
+    }
+
+    fn abort_signal_dependents(signal: &Rc<RefCell<ObjectValue>>) -> Vec<Rc<RefCell<ObjectValue>>> {
+        match Self::object_get_entry(&signal.borrow(), INTERNAL_ABORT_SIGNAL_DEPENDENTS_KEY) {
+            Some(Value::Array(dependents)) => dependents
+                .borrow()
+                .iter()
+                .filter_map(Self::abort_signal_from_value)
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    pub(crate) fn abort_signal(
+        &mut self,
+        signal: &Rc<RefCell<ObjectValue>>,
+        reason: Value,
+    ) -> Result<()> {
+        if Self::abort_signal_is_aborted(signal) {
+            return Ok(());
+        }
+        // Every dependent is marked aborted before the first abort event fires.
+        Self::mark_abort_signal_aborted(signal, reason.clone());
+        let mut aborted = vec![signal.clone()];
+        let mut index = 0;
+        while index < aborted.len() {
+            for dependent in Self::abort_signal_dependents(&aborted[index]) {
+                if !Self::abort_signal_is_aborted(&dependent) {
+                    Self::mark_abort_signal_aborted(&dependent, reason.clone());
+                    aborted.push(dependent);
+                }
+            }
+            index += 1;
+        }
+        for signal in &aborted {
+            self.run_abort_steps(signal, &reason)?;
+        }
+        Ok(())
+    }
+
+    fn run_abort_steps(&mut self, signal: &Rc<RefCell<ObjectValue>>, reason: &Value) -> Result<()> {
+        self.listeners.remove_signal_listeners(signal);
+
+        let aborted_fetch_ids = self
+            .platform_mocks
+            .pending_fetches
+            .iter()
+            .filter(|pending| {
+                pending
+                    .signal
+                    .as_ref()
+                    .is_some_and(|pending_signal| Rc::ptr_eq(pending_signal, signal))
+            })
+            .map(|pending| pending.info.id)
+            .collect::<Vec<_>>();
+        for id in aborted_fetch_ids {
+            if let Some(pending) = self.take_pending_fetch(id) {
+                self.settle_pending_fetch(pending, Err(reason.clone()))?;
+            }
+        }
+
+        self.dispatch_event_target(signal.clone(), Value::String("abort".to_string()))?;
+        Ok(())
+    }
+
+    pub(crate) fn run_abort_signal_timeout(&mut self, task: &ScheduledTask) -> Result<()> {
+        let Some(signal) = task
+            .callback_args
+            .first()
+            .and_then(Self::abort_signal_from_value)
+        else {
+            return Ok(());
+        };
+        self.trace_timer_line(format!(
+            "[timer] run abort signal timeout id={} now_ms={}",
+            task.id, self.scheduler.now_ms
+        ));
+        let reason = Self::new_dom_exception_value("TimeoutError", "signal timed out");
+        self.run_in_task_context(|this| this.abort_signal(&signal, reason.clone()))
+    }
+}
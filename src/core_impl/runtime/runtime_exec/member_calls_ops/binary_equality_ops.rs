@@ -466,6 +466,9 @@ impl Harness {
         }
 
         if let Value::Node(node) = left {
+            if self.is_custom_element_instance_of(*node, right) {
+                return Ok(true);
+            }
             if self.is_named_constructor_value(right, "Element") {
                 return Ok(self.dom.element(*node).is_some());
             }
@@ -582,6 +585,52 @@ impl Harness {
                 matches!(left, Value::Object(left_obj) if Self::string_wrapper_value_from_object(&left_obj.borrow()).is_some()),
             );
         }
+        if Self::callable_kind_from_value(right).is_some() {
+            if let Some(interface) = Self::event_interface_from_value(right) {
+                if Self::is_event_instance_of_interface(left, interface) {
+                    return Ok(true);
+                }
+            }
+        }
+        match Self::callable_kind_from_value(right) {
+            Some("abort_controller_constructor") => {
+                return Ok(
+                    matches!(left, Value::Object(left_obj) if Self::is_abort_controller_object(&left_obj.borrow())),
+                );
+            }
+            Some("abort_signal_constructor") => {
+                return Ok(
+                    matches!(left, Value::Object(left_obj) if Self::is_abort_signal_object(&left_obj.borrow())),
+                );
+            }
+            Some("intersection_observer_constructor") => {
+                return Ok(self.is_layout_observer_of_kind(left, LayoutObserverKind::Intersection));
+            }
+            Some("resize_observer_constructor") => {
+                return Ok(self.is_layout_observer_of_kind(left, LayoutObserverKind::Resize));
+            }
+            Some("intersection_observer_entry_constructor") => {
+                return Ok(Self::is_layout_observer_entry_of_kind(
+                    left,
+                    LayoutObserverKind::Intersection,
+                ));
+            }
+            Some("resize_observer_entry_constructor") => {
+                return Ok(Self::is_layout_observer_entry_of_kind(
+                    left,
+                    LayoutObserverKind::Resize,
+                ));
+            }
+            Some("mutation_observer_constructor") => {
+                return Ok(Self::mutation_observer_id(left).is_some());
+            }
+            Some("dom_exception_constructor") => {
+                return Ok(
+                    matches!(left, Value::Object(left_obj) if Self::is_dom_exception_object(&left_obj.borrow())),
+                );
+            }
+            _ => {}
+        }
         if matches!(
             Self::callable_kind_from_value(right),
             Some("bound_function")
@@ -910,9 +959,18 @@ impl Harness {
     }
 
     fn to_primitive_for_addition(&mut self, value: &Value) -> Result<Value> {
-        if Self::is_primitive_value(value) || matches!(value, Value::Date(_)) {
+        if Self::is_primitive_value(value) {
             return Ok(value.clone());
         }
+        // Dates default to the string hint.
+        if let Value::Date(date) = value {
+            let timestamp_ms = *date.borrow();
+            return Ok(Value::String(self.format_date_display_string(
+                timestamp_ms,
+                true,
+                true,
+            )));
+        }
 
         if let Value::Object(entries) = value {
             let entries = entries.borrow();
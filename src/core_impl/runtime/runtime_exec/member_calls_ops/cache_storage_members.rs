@@ -41,9 +41,8 @@ impl Harness {
             ));
         }
 
-        let response_body = Self::object_get_entry(&entries, INTERNAL_FETCH_RESPONSE_BODY_KEY)
-            .map(|value| value.as_string())
-            .unwrap_or_default();
+        let response_body =
+            String::from_utf8_lossy(&Self::fetch_response_body_bytes(&entries)).into_owned();
         let response_status = Self::object_get_entry(&entries, INTERNAL_FETCH_RESPONSE_STATUS_KEY)
             .map(|value| Self::value_to_i64(&value))
             .unwrap_or(200);
@@ -0,0 +1,302 @@
+use super::*;
+
+// CSS Color 4 named colors.
+const CSS_NAMED_COLORS: &[(&str, [u8; 3])] = &[
+    ("aliceblue", [240, 248, 255]),
+    ("antiquewhite", [250, 235, 215]),
+    ("aqua", [0, 255, 255]),
+    ("aquamarine", [127, 255, 212]),
+    ("azure", [240, 255, 255]),
+    ("beige", [245, 245, 220]),
+    ("bisque", [255, 228, 196]),
+    ("black", [0, 0, 0]),
+    ("blanchedalmond", [255, 235, 205]),
+    ("blue", [0, 0, 255]),
+    ("blueviolet", [138, 43, 226]),
+    ("brown", [165, 42, 42]),
+    ("burlywood", [222, 184, 135]),
+    ("cadetblue", [95, 158, 160]),
+    ("chartreuse", [127, 255, 0]),
+    ("chocolate", [210, 105, 30]),
+    ("coral", [255, 127, 80]),
+    ("cornflowerblue", [100, 149, 237]),
+    ("cornsilk", [255, 248, 220]),
+    ("crimson", [220, 20, 60]),
+    ("cyan", [0, 255, 255]),
+    ("darkblue", [0, 0, 139]),
+    ("darkcyan", [0, 139, 139]),
+    ("darkgoldenrod", [184, 134, 11]),
+    ("darkgray", [169, 169, 169]),
+    ("darkgreen", [0, 100, 0]),
+    ("darkgrey", [169, 169, 169]),
+    ("darkkhaki", [189, 183, 107]),
+    ("darkmagenta", [139, 0, 139]),
+    ("darkolivegreen", [85, 107, 47]),
+    ("darkorange", [255, 140, 0]),
+    ("darkorchid", [153, 50, 204]),
+    ("darkred", [139, 0, 0]),
+    ("darksalmon", [233, 150, 122]),
+    ("darkseagreen", [143, 188, 143]),
+    ("darkslateblue", [72, 61, 139]),
+    ("darkslategray", [47, 79, 79]),
+    ("darkslategrey", [47, 79, 79]),
+    ("darkturquoise", [0, 206, 209]),
+    ("darkviolet", [148, 0, 211]),
+    ("deeppink", [255, 20, 147]),
+    ("deepskyblue", [0, 191, 255]),
+    ("dimgray", [105, 105, 105]),
+    ("dimgrey", [105, 105, 105]),
+    ("dodgerblue", [30, 144, 255]),
+    ("firebrick", [178, 34, 34]),
+    ("floralwhite", [255, 250, 240]),
+    ("forestgreen", [34, 139, 34]),
+    ("fuchsia", [255, 0, 255]),
+    ("gainsboro", [220, 220, 220]),
+    ("ghostwhite", [248, 248, 255]),
+    ("gold", [255, 215, 0]),
+    ("goldenrod", [218, 165, 32]),
+    ("gray", [128, 128, 128]),
+    ("green", [0, 128, 0]),
+    ("greenyellow", [173, 255, 47]),
+    ("grey", [128, 128, 128]),
+    ("honeydew", [240, 255, 240]),
+    ("hotpink", [255, 105, 180]),
+    ("indianred", [205, 92, 92]),
+    ("indigo", [75, 0, 130]),
+    ("ivory", [255, 255, 240]),
+    ("khaki", [240, 230, 140]),
+    ("lavender", [230, 230, 250]),
+    ("lavenderblush", [255, 240, 245]),
+    ("lawngreen", [124, 252, 0]),
+    ("lemonchiffon", [255, 250, 205]),
+    ("lightblue", [173, 216, 230]),
+    ("lightcoral", [240, 128, 128]),
+    ("lightcyan", [224, 255, 255]),
+    ("lightgoldenrodyellow", [250, 250, 210]),
+    ("lightgray", [211, 211, 211]),
+    ("lightgreen", [144, 238, 144]),
+    ("lightgrey", [211, 211, 211]),
+    ("lightpink", [255, 182, 193]),
+    ("lightsalmon", [255, 160, 122]),
+    ("lightseagreen", [32, 178, 170]),
+    ("lightskyblue", [135, 206, 250]),
+    ("lightslategray", [119, 136, 153]),
+    ("lightslategrey", [119, 136, 153]),
+    ("lightsteelblue", [176, 196, 222]),
+    ("lightyellow", [255, 255, 224]),
+    ("lime", [0, 255, 0]),
+    ("limegreen", [50, 205, 50]),
+    ("linen", [250, 240, 230]),
+    ("magenta", [255, 0, 255]),
+    ("maroon", [128, 0, 0]),
+    ("mediumaquamarine", [102, 205, 170]),
+    ("mediumblue", [0, 0, 205]),
+    ("mediumorchid", [186, 85, 211]),
+    ("mediumpurple", [147, 112, 219]),
+    ("mediumseagreen", [60, 179, 113]),
+    ("mediumslateblue", [123, 104, 238]),
+    ("mediumspringgreen", [0, 250, 154]),
+    ("mediumturquoise", [72, 209, 204]),
+    ("mediumvioletred", [199, 21, 133]),
+    ("midnightblue", [25, 25, 112]),
+    ("mintcream", [245, 255, 250]),
+    ("mistyrose", [255, 228, 225]),
+    ("moccasin", [255, 228, 181]),
+    ("navajowhite", [255, 222, 173]),
+    ("navy", [0, 0, 128]),
+    ("oldlace", [253, 245, 230]),
+    ("olive", [128, 128, 0]),
+    ("olivedrab", [107, 142, 35]),
+    ("orange", [255, 165, 0]),
+    ("orangered", [255, 69, 0]),
+    ("orchid", [218, 112, 214]),
+    ("palegoldenrod", [238, 232, 170]),
+    ("palegreen", [152, 251, 152]),
+    ("paleturquoise", [175, 238, 238]),
+    ("palevioletred", [219, 112, 147]),
+    ("papayawhip", [255, 239, 213]),
+    ("peachpuff", [255, 218, 185]),
+    ("peru", [205, 133, 63]),
+    ("pink", [255, 192, 203]),
+    ("plum", [221, 160, 221]),
+    ("powderblue", [176, 224, 230]),
+    ("purple", [128, 0, 128]),
+    ("rebeccapurple", [102, 51, 153]),
+    ("red", [255, 0, 0]),
+    ("rosybrown", [188, 143, 143]),
+    ("royalblue", [65, 105, 225]),
+    ("saddlebrown", [139, 69, 19]),
+    ("salmon", [250, 128, 114]),
+    ("sandybrown", [244, 164, 96]),
+    ("seagreen", [46, 139, 87]),
+    ("seashell", [255, 245, 238]),
+    ("sienna", [160, 82, 45]),
+    ("silver", [192, 192, 192]),
+    ("skyblue", [135, 206, 235]),
+    ("slateblue", [106, 90, 205]),
+    ("slategray", [112, 128, 144]),
+    ("slategrey", [112, 128, 144]),
+    ("snow", [255, 250, 250]),
+    ("springgreen", [0, 255, 127]),
+    ("steelblue", [70, 130, 180]),
+    ("tan", [210, 180, 140]),
+    ("teal", [0, 128, 128]),
+    ("thistle", [216, 191, 216]),
+    ("tomato", [255, 99, 71]),
+    ("turquoise", [64, 224, 208]),
+    ("violet", [238, 130, 238]),
+    ("wheat", [245, 222, 179]),
+    ("white", [255, 255, 255]),
+    ("whitesmoke", [245, 245, 245]),
+    ("yellow", [255, 255, 0]),
+    ("yellowgreen", [154, 205, 50]),
+];
+
+impl Harness {
+    pub(crate) fn css_property_is_color(property: &str) -> bool {
+        matches!(
+            property,
+            "color"
+                | "background-color"
+                | "border-color"
+                | "border-top-color"
+                | "border-right-color"
+                | "border-bottom-color"
+                | "border-left-color"
+                | "outline-color"
+                | "caret-color"
+                | "text-decoration-color"
+                | "column-rule-color"
+                | "flood-color"
+                | "lighting-color"
+                | "stop-color"
+        )
+    }
+
+    // The resolved form `getComputedStyle` reports: `rgb(r, g, b)`, or
+    // `rgba(r, g, b, a)` when not fully opaque. Values this engine cannot
+    // resolve (system colors, newer color spaces) are left as `None`.
+    pub(crate) fn resolve_css_color(value: &str) -> Option<String> {
+        let value = value.trim().to_ascii_lowercase();
+        let (rgb, alpha) = if value == "transparent" {
+            ([0.0; 3], 0.0)
+        } else if let Some(hex) = value.strip_prefix('#') {
+            Self::parse_css_hex_color(hex)?
+        } else if let Some((_, rgb)) = CSS_NAMED_COLORS.iter().find(|(name, _)| *name == value) {
+            (rgb.map(f64::from), 1.0)
+        } else {
+            Self::parse_css_color_function(&value)?
+        };
+        let [r, g, b] = rgb.map(|channel| channel.round().clamp(0.0, 255.0) as u8);
+        let alpha = (alpha.clamp(0.0, 1.0) * 1000.0).round() / 1000.0;
+        Some(if alpha == 1.0 {
+            format!("rgb({r}, {g}, {b})")
+        } else {
+            format!("rgba({r}, {g}, {b}, {alpha})")
+        })
+    }
+
+    fn parse_css_hex_color(hex: &str) -> Option<([f64; 3], f64)> {
+        if !hex.chars().all(|ch| ch.is_ascii_hexdigit()) {
+            return None;
+        }
+        let digits = match hex.len() {
+            3 | 4 => hex.chars().flat_map(|ch| [ch, ch]).collect::<String>(),
+            6 | 8 => hex.to_string(),
+            _ => return None,
+        };
+        let channel = |index: usize| {
+            u8::from_str_radix(&digits[index * 2..index * 2 + 2], 16)
+                .map(f64::from)
+                .unwrap_or(0.0)
+        };
+        let alpha = if digits.len() == 8 {
+            channel(3) / 255.0
+        } else {
+            1.0
+        };
+        Some(([channel(0), channel(1), channel(2)], alpha))
+    }
+
+    // `rgb()`/`rgba()`/`hsl()`/`hsla()` in both the comma and the space
+    // separated syntax.
+    fn parse_css_color_function(value: &str) -> Option<([f64; 3], f64)> {
+        let open = value.find('(')?;
+        let name = value[..open].trim();
+        let args = value[open + 1..].strip_suffix(')')?;
+        let (channels, alpha) = match args.split_once('/') {
+            Some((channels, alpha)) => (channels, Some(alpha.trim())),
+            None => (args, None),
+        };
+        let mut parts = if channels.contains(',') {
+            channels.split(',').map(str::trim).collect::<Vec<_>>()
+        } else {
+            channels.split_whitespace().collect::<Vec<_>>()
+        };
+        let alpha = match (alpha, parts.len()) {
+            (Some(alpha), 3) => alpha,
+            (None, 4) => parts.pop()?,
+            (None, 3) => "1",
+            _ => return None,
+        };
+        let alpha = Self::parse_css_number_or_percentage(alpha, 1.0)?;
+        let rgb = match name {
+            "rgb" | "rgba" => {
+                let mut rgb = [0.0; 3];
+                for (channel, part) in rgb.iter_mut().zip(&parts) {
+                    *channel = Self::parse_css_number_or_percentage(part, 255.0)?;
+                }
+                rgb
+            }
+            "hsl" | "hsla" => {
+                let hue = Self::parse_css_hue(parts[0])?;
+                let saturation = Self::parse_css_number_or_percentage(parts[1], 100.0)? / 100.0;
+                let lightness = Self::parse_css_number_or_percentage(parts[2], 100.0)? / 100.0;
+                Self::hsl_to_rgb(hue, saturation.clamp(0.0, 1.0), lightness.clamp(0.0, 1.0))
+            }
+            _ => return None,
+        };
+        Some((rgb, alpha))
+    }
+
+    // Percentages scale to `full`; plain numbers are taken as they are.
+    fn parse_css_number_or_percentage(value: &str, full: f64) -> Option<f64> {
+        let value = value.trim();
+        if value == "none" {
+            return Some(0.0);
+        }
+        let number = match value.strip_suffix('%') {
+            Some(percent) => percent.parse::<f64>().ok()? / 100.0 * full,
+            None => value.parse::<f64>().ok()?,
+        };
+        number.is_finite().then_some(number)
+    }
+
+    fn parse_css_hue(value: &str) -> Option<f64> {
+        let value = value.trim();
+        let (number, degrees_per_unit) = if let Some(number) = value.strip_suffix("deg") {
+            (number, 1.0)
+        } else if let Some(number) = value.strip_suffix("grad") {
+            (number, 0.9)
+        } else if let Some(number) = value.strip_suffix("rad") {
+            (number, 180.0 / std::f64::consts::PI)
+        } else if let Some(number) = value.strip_suffix("turn") {
+            (number, 360.0)
+        } else if value == "none" {
+            ("0", 1.0)
+        } else {
+            (value, 1.0)
+        };
+        let hue = number.parse::<f64>().ok()? * degrees_per_unit;
+        hue.is_finite().then(|| hue.rem_euclid(360.0))
+    }
+
+    fn hsl_to_rgb(hue: f64, saturation: f64, lightness: f64) -> [f64; 3] {
+        let channel = |offset: f64| {
+            let k = (offset + hue / 30.0) % 12.0;
+            let a = saturation * lightness.min(1.0 - lightness);
+            (lightness - a * (k - 3.0).min(9.0 - k).clamp(-1.0, 1.0)) * 255.0
+        };
+        [channel(0.0), channel(8.0), channel(4.0)]
+    }
+}
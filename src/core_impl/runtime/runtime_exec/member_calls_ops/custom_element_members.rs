@@ -0,0 +1,526 @@
+use super::*;
+
+impl Harness {
+    pub(crate) fn new_custom_element_registry_value() -> Value {
+        let method = |kind: &str| {
+            Self::new_object_value(vec![(
+                INTERNAL_CALLABLE_KIND_KEY.to_string(),
+                Value::String(kind.to_string()),
+            )])
+        };
+        Self::new_object_value(vec![
+            ("define".to_string(), method("custom_elements_define")),
+            ("get".to_string(), method("custom_elements_get")),
+            (
+                "whenDefined".to_string(),
+                method("custom_elements_when_defined"),
+            ),
+            ("upgrade".to_string(), method("custom_elements_upgrade")),
+        ])
+    }
+
+    pub(crate) fn eval_custom_elements_method(
+        &mut self,
+        kind: &str,
+        args: &[Value],
+    ) -> Result<Value> {
+        match kind {
+            "custom_elements_define" => self.define_custom_element(args),
+            "custom_elements_get" => {
+                let name = args.first().map(Value::as_string).unwrap_or_default();
+                Ok(self
+                    .custom_element_definition(&name)
+                    .map(|definition| definition.constructor.clone())
+                    .unwrap_or(Value::Undefined))
+            }
+            "custom_elements_when_defined" => {
+                let name = args.first().map(Value::as_string).unwrap_or_default();
+                self.custom_elements_when_defined(name)
+            }
+            _ => {
+                let Some(Value::Node(root)) = args.first() else {
+                    return Err(Error::ScriptRuntime(
+                        "TypeError: Failed to execute 'upgrade' on 'CustomElementRegistry': parameter 1 is not of type 'Node'.".into(),
+                    ));
+                };
+                let mut nodes = Vec::new();
+                self.dom.collect_elements_dfs(*root, &mut nodes);
+                for node in nodes {
+                    self.upgrade_custom_element(node)?;
+                }
+                Ok(Value::Undefined)
+            }
+        }
+    }
+
+    fn custom_element_definition(&self, name: &str) -> Option<&CustomElementDefinitionEntry> {
+        self.dom_runtime
+            .custom_elements
+            .definitions
+            .iter()
+            .find(|definition| definition.name == name)
+    }
+
+    // Name of the definition that applies to `node`: its local name for
+    // autonomous elements, or its `is` value for customized built-ins.
+    fn custom_element_definition_name(&self, node: NodeId) -> Option<String> {
+        let element = self.dom.element(node)?;
+        let local_name = element.tag_name.to_ascii_lowercase();
+        let applies = |name: &str| {
+            self.custom_element_definition(name)
+                .is_some_and(|definition| definition.local_name == local_name)
+        };
+        if applies(&local_name) {
+            return Some(local_name);
+        }
+        let is_value = element.attrs.get("is")?;
+        applies(is_value).then(|| is_value.clone())
+    }
+
+    fn custom_element_definition_for_node(
+        &self,
+        node: NodeId,
+    ) -> Option<&CustomElementDefinitionEntry> {
+        let instance = self.dom.custom_elements.get(&node)?;
+        self.custom_element_definition(&instance.name)
+    }
+
+    fn is_same_constructor(left: &Value, right: &Value) -> bool {
+        match (left, right) {
+            (Value::Function(left), Value::Function(right)) => Rc::ptr_eq(left, right),
+            (Value::Object(left), Value::Object(right)) => Rc::ptr_eq(left, right),
+            _ => false,
+        }
+    }
+
+    fn define_custom_element(&mut self, args: &[Value]) -> Result<Value> {
+        let name = args.first().map(Value::as_string).unwrap_or_default();
+        let constructor = args.get(1).cloned().unwrap_or(Value::Undefined);
+        if !self.is_callable_value(&constructor) {
+            return Err(Error::ScriptRuntime(
+                "TypeError: Failed to execute 'define' on 'CustomElementRegistry': The provided value is not a constructor.".into(),
+            ));
+        }
+        if !Dom::is_valid_custom_element_name(&name) {
+            return Err(Error::ScriptRuntime(format!(
+                "SyntaxError: Failed to execute 'define' on 'CustomElementRegistry': \"{name}\" is not a valid custom element name"
+            )));
+        }
+        let registry = &self.dom_runtime.custom_elements;
+        if registry
+            .definitions
+            .iter()
+            .any(|definition| definition.name == name)
+        {
+            return Err(Error::ScriptRuntime(format!(
+                "NotSupportedError: Failed to execute 'define' on 'CustomElementRegistry': the name \"{name}\" has already been used with this registry"
+            )));
+        }
+        if registry
+            .definitions
+            .iter()
+            .any(|definition| Self::is_same_constructor(&definition.constructor, &constructor))
+        {
+            return Err(Error::ScriptRuntime(
+                "NotSupportedError: Failed to execute 'define' on 'CustomElementRegistry': this constructor has already been used with this registry".into(),
+            ));
+        }
+        if registry.definition_running {
+            return Err(Error::ScriptRuntime(
+                "NotSupportedError: Failed to execute 'define' on 'CustomElementRegistry': a definition is already being processed".into(),
+            ));
+        }
+
+        let extends = match args.get(2) {
+            Some(Value::Object(options)) => Self::object_get_entry(&options.borrow(), "extends")
+                .filter(|value| !matches!(value, Value::Undefined))
+                .map(|value| value.as_string().to_ascii_lowercase()),
+            _ => None,
+        };
+        let local_name = match extends {
+            Some(extends) if Dom::is_valid_custom_element_name(&extends) => {
+                return Err(Error::ScriptRuntime(format!(
+                    "NotSupportedError: Failed to execute 'define' on 'CustomElementRegistry': \"{extends}\" is a valid custom element name"
+                )));
+            }
+            Some(extends) => extends,
+            None => name.clone(),
+        };
+
+        self.dom_runtime.custom_elements.definition_running = true;
+        let lifecycle = self.custom_element_lifecycle(&constructor);
+        self.dom_runtime.custom_elements.definition_running = false;
+        let (prototype, callbacks, observed_attributes) = lifecycle?;
+        let [connected, disconnected, adopted, attribute_changed] = callbacks;
+
+        self.dom_runtime
+            .custom_elements
+            .definitions
+            .push(CustomElementDefinitionEntry {
+                name: name.clone(),
+                local_name: local_name.clone(),
+                constructor: constructor.clone(),
+                prototype,
+                connected_callback: connected,
+                disconnected_callback: disconnected,
+                adopted_callback: adopted,
+                attribute_changed_callback: attribute_changed,
+                observed_attributes,
+            });
+        self.dom.custom_elements_defined = true;
+        for node in self.dom.inclusive_element_descendants(self.dom.root) {
+            if !self.dom.custom_elements.contains_key(&node)
+                && self.custom_element_definition_name(node).as_deref() == Some(name.as_str())
+            {
+                self.dom
+                    .pending_custom_element_reactions
+                    .push(CustomElementReaction::Upgrade(node));
+            }
+        }
+        self.run_custom_element_reactions()?;
+
+        if let Some(Value::Promise(promise)) = self
+            .dom_runtime
+            .custom_elements
+            .when_defined
+            .get(&name)
+            .cloned()
+        {
+            self.promise_resolve(&promise, constructor)?;
+        }
+        Ok(Value::Undefined)
+    }
+
+    // Reads the prototype, the lifecycle callbacks and `observedAttributes`
+    // once, when the element is defined.
+    fn custom_element_lifecycle(
+        &mut self,
+        constructor: &Value,
+    ) -> Result<(Value, [Option<Value>; 4], Vec<String>)> {
+        let prototype = self.object_property_from_value(constructor, "prototype")?;
+        if !matches!(prototype, Value::Object(_)) {
+            return Err(Error::ScriptRuntime(
+                "TypeError: Failed to execute 'define' on 'CustomElementRegistry': The prototype is not an object.".into(),
+            ));
+        }
+        let mut callbacks: [Option<Value>; 4] = Default::default();
+        for (slot, callback_name) in callbacks.iter_mut().zip([
+            "connectedCallback",
+            "disconnectedCallback",
+            "adoptedCallback",
+            "attributeChangedCallback",
+        ]) {
+            let callback = self.object_property_from_value(&prototype, callback_name)?;
+            if matches!(callback, Value::Undefined) {
+                continue;
+            }
+            if !self.is_callable_value(&callback) {
+                return Err(Error::ScriptRuntime(format!(
+                    "TypeError: Failed to execute 'define' on 'CustomElementRegistry': The '{callback_name}' property of the prototype is not a function."
+                )));
+            }
+            *slot = Some(callback);
+        }
+        let mut observed_attributes = Vec::new();
+        if callbacks[3].is_some() {
+            match self.object_property_from_value(constructor, "observedAttributes")? {
+                Value::Undefined => {}
+                Value::Array(values) => {
+                    observed_attributes = values.borrow().iter().map(Value::as_string).collect();
+                }
+                _ => {
+                    return Err(Error::ScriptRuntime(
+                        "TypeError: Failed to execute 'define' on 'CustomElementRegistry': observedAttributes is not iterable.".into(),
+                    ));
+                }
+            }
+        }
+        Ok((prototype, callbacks, observed_attributes))
+    }
+
+    fn custom_elements_when_defined(&mut self, name: String) -> Result<Value> {
+        if !Dom::is_valid_custom_element_name(&name) {
+            let promise = self.new_pending_promise();
+            self.promise_reject(
+                &promise,
+                Value::String(format!(
+                    "SyntaxError: Failed to execute 'whenDefined' on 'CustomElementRegistry': \"{name}\" is not a valid custom element name"
+                )),
+            );
+            return Ok(Value::Promise(promise));
+        }
+        if let Some(constructor) = self
+            .custom_element_definition(&name)
+            .map(|definition| definition.constructor.clone())
+        {
+            let promise = self.new_pending_promise();
+            self.promise_resolve(&promise, constructor)?;
+            return Ok(Value::Promise(promise));
+        }
+        if let Some(promise) = self.dom_runtime.custom_elements.when_defined.get(&name) {
+            return Ok(promise.clone());
+        }
+        let promise = Value::Promise(self.new_pending_promise());
+        self.dom_runtime
+            .custom_elements
+            .when_defined
+            .insert(name, promise.clone());
+        Ok(promise)
+    }
+
+    // Runs the definition's constructor with the existing element as `this`,
+    // then replays observed attributes and connection like a browser upgrade.
+    pub(crate) fn upgrade_custom_element(&mut self, node: NodeId) -> Result<()> {
+        if self.dom.custom_elements.contains_key(&node) {
+            return Ok(());
+        }
+        let Some(name) = self.custom_element_definition_name(node) else {
+            return Ok(());
+        };
+        let Some(definition) = self.custom_element_definition(&name).cloned() else {
+            return Ok(());
+        };
+        self.dom.mark_custom_element(node, &name);
+        let event = EventState::new("script", self.dom.root, self.scheduler.now_ms);
+        if let Err(err) = self.execute_constructor_value_with_this_and_env(
+            &definition.constructor,
+            &[],
+            &event,
+            None,
+            Some(Value::Node(node)),
+        ) {
+            self.dom.custom_elements.remove(&node);
+            return self.report_exception(err);
+        }
+
+        if let Some(callback) = &definition.attribute_changed_callback {
+            for attribute in definition.observed_attributes.iter().cloned() {
+                let Some(value) = self.dom.attr(node, &attribute) else {
+                    continue;
+                };
+                self.invoke_custom_element_callback(
+                    node,
+                    callback,
+                    &[
+                        Value::String(attribute),
+                        Value::Null,
+                        Value::String(value),
+                        Value::Null,
+                    ],
+                )?;
+            }
+        }
+        if let Some(callback) = &definition.connected_callback {
+            if self.dom.node_document(node).is_some() {
+                self.invoke_custom_element_callback(node, callback, &[])?;
+            }
+        }
+        Ok(())
+    }
+
+    pub(crate) fn upgrade_created_element(&mut self, node: NodeId) -> Result<()> {
+        if !self.dom.custom_elements_defined {
+            return Ok(());
+        }
+        self.upgrade_custom_element(node)
+    }
+
+    // `new MyElement()` for a defined constructor creates the element it will run on.
+    pub(crate) fn create_element_for_custom_constructor(
+        &mut self,
+        constructor: &Value,
+    ) -> Result<Option<Value>> {
+        let Some(definition) = self
+            .dom_runtime
+            .custom_elements
+            .definitions
+            .iter()
+            .find(|definition| Self::is_same_constructor(&definition.constructor, constructor))
+        else {
+            return Ok(None);
+        };
+        let name = definition.name.clone();
+        let node = self
+            .dom
+            .create_detached_element(definition.local_name.clone());
+        if definition.local_name != name {
+            self.dom.set_attr(node, "is", &name)?;
+        }
+        self.dom.mark_custom_element(node, &name);
+        Ok(Some(Value::Node(node)))
+    }
+
+    fn invoke_custom_element_callback(
+        &mut self,
+        node: NodeId,
+        callback: &Value,
+        args: &[Value],
+    ) -> Result<()> {
+        let event = EventState::new("script", self.dom.root, self.scheduler.now_ms);
+        match self.execute_callable_value_with_this_and_env(
+            callback,
+            args,
+            &event,
+            None,
+            Some(Value::Node(node)),
+        ) {
+            Ok(_) => Ok(()),
+            Err(err) => self.report_exception(err),
+        }
+    }
+
+    fn custom_element_document_value(&mut self, document: NodeId) -> Value {
+        if document == self.dom.root {
+            Value::Object(self.dom_runtime.document_object.clone())
+        } else {
+            self.parsed_document_value_from_root(document)
+        }
+    }
+
+    pub(crate) fn run_custom_element_reactions(&mut self) -> Result<()> {
+        loop {
+            let reactions = self.dom.take_pending_custom_element_reactions();
+            if reactions.is_empty() {
+                return Ok(());
+            }
+            for reaction in reactions {
+                let (node, callback, args) = match reaction {
+                    CustomElementReaction::Upgrade(node) => {
+                        self.upgrade_custom_element(node)?;
+                        continue;
+                    }
+                    CustomElementReaction::Connected(node) => (
+                        node,
+                        self.custom_element_definition_for_node(node)
+                            .and_then(|definition| definition.connected_callback.clone()),
+                        Vec::new(),
+                    ),
+                    CustomElementReaction::Disconnected(node) => (
+                        node,
+                        self.custom_element_definition_for_node(node)
+                            .and_then(|definition| definition.disconnected_callback.clone()),
+                        Vec::new(),
+                    ),
+                    CustomElementReaction::Adopted {
+                        node,
+                        old_document,
+                        new_document,
+                    } => (
+                        node,
+                        self.custom_element_definition_for_node(node)
+                            .and_then(|definition| definition.adopted_callback.clone()),
+                        vec![
+                            self.custom_element_document_value(old_document),
+                            self.custom_element_document_value(new_document),
+                        ],
+                    ),
+                    CustomElementReaction::AttributeChanged {
+                        node,
+                        name,
+                        old_value,
+                        new_value,
+                    } => (
+                        node,
+                        self.custom_element_definition_for_node(node)
+                            .filter(|definition| definition.observed_attributes.contains(&name))
+                            .and_then(|definition| definition.attribute_changed_callback.clone()),
+                        vec![
+                            Value::String(name),
+                            old_value.map(Value::String).unwrap_or(Value::Null),
+                            new_value.map(Value::String).unwrap_or(Value::Null),
+                            Value::Null,
+                        ],
+                    ),
+                };
+                if let Some(callback) = callback {
+                    self.invoke_custom_element_callback(node, &callback, &args)?;
+                }
+            }
+        }
+    }
+
+    // Prototype objects contributed by the definition's own classes, nearest
+    // first; the built-in element base is left to the DOM property tables.
+    fn custom_element_prototypes(&self, node: NodeId) -> Vec<Rc<RefCell<ObjectValue>>> {
+        let Some(definition) = self.custom_element_definition_for_node(node) else {
+            return Vec::new();
+        };
+        let mut prototypes = Vec::new();
+        if let Value::Object(prototype) = &definition.prototype {
+            prototypes.push(prototype.clone());
+        }
+        let mut cursor = match &definition.constructor {
+            Value::Function(function) => function.class_super_constructor.clone(),
+            _ => None,
+        };
+        while let Some(Value::Function(function)) = cursor {
+            if function.function_id == usize::MAX {
+                break;
+            }
+            prototypes.push(function.prototype_object.clone());
+            cursor = function.class_super_constructor.clone();
+        }
+        prototypes
+    }
+
+    pub(crate) fn is_custom_element_instance_of(&self, node: NodeId, constructor: &Value) -> bool {
+        let Some(definition) = self.custom_element_definition_for_node(node) else {
+            return false;
+        };
+        let mut cursor = Some(definition.constructor.clone());
+        while let Some(current) = cursor {
+            if Self::is_same_constructor(&current, constructor) {
+                return true;
+            }
+            cursor = match current {
+                Value::Function(function) => function.class_super_constructor.clone(),
+                _ => None,
+            };
+        }
+        false
+    }
+
+    pub(crate) fn custom_element_member(
+        &mut self,
+        node: NodeId,
+        key: &str,
+    ) -> Result<Option<Value>> {
+        if !self.dom.custom_elements.contains_key(&node) {
+            return Ok(None);
+        }
+        for prototype in self.custom_element_prototypes(node) {
+            let (getter, value) = {
+                let entries = prototype.borrow();
+                (
+                    Self::object_getter_from_entries(&entries, key),
+                    Self::object_get_entry(&entries, key),
+                )
+            };
+            if let Some(getter) = getter {
+                let event = EventState::new("script", self.dom.root, self.scheduler.now_ms);
+                return self
+                    .execute_callable_value_with_this_and_env(
+                        &getter,
+                        &[],
+                        &event,
+                        None,
+                        Some(Value::Node(node)),
+                    )
+                    .map(Some);
+            }
+            if value.is_some() {
+                return Ok(value);
+            }
+        }
+        Ok(None)
+    }
+
+    pub(crate) fn custom_element_setter(&self, node: NodeId, key: &str) -> Option<Value> {
+        if !self.dom.custom_elements.contains_key(&node) {
+            return None;
+        }
+        self.custom_element_prototypes(node)
+            .iter()
+            .find_map(|prototype| Self::object_setter_from_entries(&prototype.borrow(), key))
+    }
+}
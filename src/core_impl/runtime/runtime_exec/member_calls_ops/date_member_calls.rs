@@ -0,0 +1,274 @@
+use super::*;
+
+// Fields in `Date` constructor order: year, month, date, hours, minutes,
+// seconds, milliseconds.
+const DATE_FIELD_NAMES: [&str; 7] = [
+    "FullYear",
+    "Month",
+    "Date",
+    "Hours",
+    "Minutes",
+    "Seconds",
+    "Milliseconds",
+];
+
+// How many trailing fields each setter accepts, e.g. `setHours(h, m, s, ms)`.
+const DATE_SETTER_ARITY: [usize; 7] = [3, 2, 1, 4, 3, 2, 1];
+
+impl Harness {
+    pub(crate) fn eval_date_member_call(
+        &mut self,
+        value: &Rc<RefCell<i64>>,
+        member: &str,
+        evaluated_args: &[Value],
+    ) -> Result<Option<Value>> {
+        let timestamp_ms = *value.borrow();
+        let invalid = timestamp_ms == INVALID_DATE_TIME_MS;
+        let result = match member {
+            "getTime" | "valueOf" => {
+                if !evaluated_args.is_empty() {
+                    return Err(Error::ScriptRuntime(format!(
+                        "{member} does not take arguments"
+                    )));
+                }
+                Self::date_time_value(timestamp_ms)
+            }
+            "setTime" => {
+                if evaluated_args.len() != 1 {
+                    return Err(Error::ScriptRuntime(
+                        "setTime requires exactly one argument".into(),
+                    ));
+                }
+                let timestamp_ms =
+                    Self::time_clip_ms(Self::coerce_number_for_global(&evaluated_args[0]));
+                *value.borrow_mut() = timestamp_ms;
+                Self::date_time_value(timestamp_ms)
+            }
+            "toISOString" if invalid => {
+                return Err(Error::ScriptRuntime(
+                    "RangeError: Invalid time value".into(),
+                ));
+            }
+            "toJSON" if invalid => Value::Null,
+            "toISOString" | "toJSON" => Value::String(Self::format_iso_8601_utc(timestamp_ms)),
+            "toString" => Value::String(self.format_date_display_string(timestamp_ms, true, true)),
+            "toDateString" => {
+                Value::String(self.format_date_display_string(timestamp_ms, true, false))
+            }
+            "toTimeString" => {
+                Value::String(self.format_date_display_string(timestamp_ms, false, true))
+            }
+            "toUTCString" | "toGMTString" => {
+                Value::String(Self::format_date_utc_string(timestamp_ms))
+            }
+            "toLocaleString" | "toLocaleDateString" | "toLocaleTimeString" if invalid => {
+                Value::String("Invalid Date".to_string())
+            }
+            "toLocaleString" | "toLocaleDateString" | "toLocaleTimeString" => Value::String(
+                self.format_date_locale_string(timestamp_ms, member, evaluated_args)?,
+            ),
+            "getTimezoneOffset" => {
+                if !evaluated_args.is_empty() {
+                    return Err(Error::ScriptRuntime(
+                        "getTimezoneOffset does not take arguments".into(),
+                    ));
+                }
+                let offset_ms = -self.local_time_offset_ms(timestamp_ms);
+                if invalid {
+                    Value::Float(f64::NAN)
+                } else if offset_ms % 60_000 == 0 {
+                    Value::Number(offset_ms / 60_000)
+                } else {
+                    Value::Float(offset_ms as f64 / 60_000.0)
+                }
+            }
+            "getDay" | "getUTCDay" => {
+                if !evaluated_args.is_empty() {
+                    return Err(Error::ScriptRuntime(format!(
+                        "{member} does not take arguments"
+                    )));
+                }
+                let shifted_ms = if member == "getDay" {
+                    timestamp_ms.saturating_add(self.local_time_offset_ms(timestamp_ms))
+                } else {
+                    timestamp_ms
+                };
+                if invalid {
+                    Value::Float(f64::NAN)
+                } else {
+                    Value::Number(Self::weekday_from_timestamp_ms(shifted_ms) as i64)
+                }
+            }
+            _ => {
+                if let Some((utc, field)) = Self::date_accessor_field(member, "get") {
+                    if !evaluated_args.is_empty() {
+                        return Err(Error::ScriptRuntime(format!(
+                            "{member} does not take arguments"
+                        )));
+                    }
+                    if invalid {
+                        Value::Float(f64::NAN)
+                    } else {
+                        let fields = self.date_fields(timestamp_ms, utc);
+                        Value::Number(fields[field])
+                    }
+                } else if let Some((utc, field)) = Self::date_accessor_field(member, "set") {
+                    let args = evaluated_args
+                        .iter()
+                        .take(DATE_SETTER_ARITY[field])
+                        .map(Self::coerce_number_for_global)
+                        .collect::<Vec<_>>();
+                    // A missing or non-finite argument makes the Date invalid;
+                    // only `setFullYear` revives an invalid Date, from +0.
+                    let base = if !invalid {
+                        Some(self.date_fields(timestamp_ms, utc))
+                    } else if field == 0 {
+                        Some(self.date_fields(0, true))
+                    } else {
+                        None
+                    };
+                    let timestamp_ms = match base {
+                        Some(mut fields)
+                            if !args.is_empty() && args.iter().all(|arg| arg.is_finite()) =>
+                        {
+                            for (offset, arg) in args.iter().enumerate() {
+                                fields[field + offset] = arg.trunc() as i64;
+                            }
+                            let [year, month, day, hour, minute, second, millisecond] = fields;
+                            let composed = Self::utc_timestamp_ms_from_components(
+                                year,
+                                month,
+                                day,
+                                hour,
+                                minute,
+                                second,
+                                millisecond,
+                            );
+                            let timestamp_ms = if utc {
+                                composed
+                            } else {
+                                self.local_time_to_utc_ms(composed)
+                            };
+                            Self::time_clip_ms(timestamp_ms as f64)
+                        }
+                        _ => INVALID_DATE_TIME_MS,
+                    };
+                    *value.borrow_mut() = timestamp_ms;
+                    Self::date_time_value(timestamp_ms)
+                } else {
+                    return Ok(None);
+                }
+            }
+        };
+        Ok(Some(result))
+    }
+
+    pub(crate) fn eval_date_getter_on_variable(
+        &mut self,
+        env: &HashMap<String, Value>,
+        target: &str,
+        member: &str,
+    ) -> Result<Value> {
+        let date = self.resolve_date_from_env(env, target)?;
+        Ok(self
+            .eval_date_member_call(&date, member, &[])?
+            .unwrap_or(Value::Undefined))
+    }
+
+    // `getUTCHours` -> (true, 3), `setMonth` -> (false, 1).
+    fn date_accessor_field(member: &str, prefix: &str) -> Option<(bool, usize)> {
+        let rest = member.strip_prefix(prefix)?;
+        let (utc, name) = match rest.strip_prefix("UTC") {
+            Some(name) => (true, name),
+            None => (false, rest),
+        };
+        let field = DATE_FIELD_NAMES.iter().position(|field| *field == name)?;
+        Some((utc, field))
+    }
+
+    fn date_fields(&self, timestamp_ms: i64, utc: bool) -> [i64; 7] {
+        let (year, month, day, hour, minute, second, millisecond) = if utc {
+            Self::date_components_utc(timestamp_ms)
+        } else {
+            self.date_components_local(timestamp_ms)
+        };
+        [
+            year,
+            i64::from(month) - 1,
+            i64::from(day),
+            i64::from(hour),
+            i64::from(minute),
+            i64::from(second),
+            i64::from(millisecond),
+        ]
+    }
+
+    // Follows ECMA-402 ToDateTimeOptions: components are only defaulted when
+    // none of the required kind were given.
+    fn format_date_locale_string(
+        &mut self,
+        timestamp_ms: i64,
+        member: &str,
+        evaluated_args: &[Value],
+    ) -> Result<String> {
+        let requested_locales = match evaluated_args.first() {
+            None | Some(Value::Undefined) => Vec::new(),
+            Some(locales) => self.intl_collect_locales(locales)?,
+        };
+        let locale = Self::intl_select_locale_for_formatter(
+            IntlFormatterKind::DateTimeFormat,
+            &requested_locales,
+        );
+        let mut entries = match evaluated_args.get(1) {
+            None | Some(Value::Undefined) | Some(Value::Null) => Vec::new(),
+            Some(Value::Object(options)) => options
+                .borrow()
+                .iter()
+                .filter(|(key, _)| !key.starts_with('\0'))
+                .map(|(key, value)| (key.clone(), value.clone()))
+                .collect::<Vec<_>>(),
+            Some(_) => {
+                return Err(Error::ScriptRuntime(
+                    "TypeError: Intl.DateTimeFormat options must be an object".into(),
+                ));
+            }
+        };
+        let (needs_date, needs_time) = match member {
+            "toLocaleDateString" => (true, false),
+            "toLocaleTimeString" => (false, true),
+            _ => (true, true),
+        };
+        let has_option = |entries: &[(String, Value)], keys: &[&str]| {
+            entries.iter().any(|(key, value)| {
+                keys.contains(&key.as_str()) && !matches!(value, Value::Undefined)
+            })
+        };
+        let date_keys = ["weekday", "year", "month", "day"];
+        let time_keys = [
+            "dayPeriod",
+            "hour",
+            "minute",
+            "second",
+            "fractionalSecondDigits",
+        ];
+        let has_date = needs_date && has_option(&entries, &date_keys);
+        let has_time = needs_time && has_option(&entries, &time_keys);
+        let needs_defaults =
+            !has_date && !has_time && !has_option(&entries, &["dateStyle", "timeStyle"]);
+        if needs_defaults {
+            let mut defaults = Vec::new();
+            if needs_date {
+                defaults.extend(["year", "month", "day"]);
+            }
+            if needs_time {
+                defaults.extend(["hour", "minute", "second"]);
+            }
+            for key in defaults {
+                entries.push((key.to_string(), Value::String("numeric".to_string())));
+            }
+        }
+        let options = Self::new_object_value(entries);
+        let options = self.intl_date_time_options_from_value(&locale, Some(&options))?;
+        Ok(self.intl_format_date_time(timestamp_ms, &locale, &options))
+    }
+}
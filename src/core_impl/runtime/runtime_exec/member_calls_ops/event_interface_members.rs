@@ -0,0 +1,146 @@
+use super::*;
+
+impl Harness {
+    pub(crate) fn new_event_interface_constructor_value(interface: EventInterface) -> Value {
+        let prototype = Self::new_object_value(Vec::new());
+        let constructor = Self::new_object_value(vec![
+            (
+                INTERNAL_CALLABLE_KIND_KEY.to_string(),
+                Value::String("event_interface_constructor".to_string()),
+            ),
+            (
+                INTERNAL_EVENT_INTERFACE_KEY.to_string(),
+                Value::String(interface.name().to_string()),
+            ),
+            ("prototype".to_string(), prototype.clone()),
+        ]);
+        if let Value::Object(prototype_entries) = &prototype {
+            Self::object_set_entry(
+                &mut prototype_entries.borrow_mut(),
+                "constructor".to_string(),
+                constructor.clone(),
+            );
+        }
+        constructor
+    }
+
+    pub(crate) fn event_interface_constructor_bindings() -> Vec<(String, Value)> {
+        EventInterface::TABLE_DRIVEN
+            .into_iter()
+            .map(|interface| {
+                (
+                    interface.name().to_string(),
+                    Self::new_event_interface_constructor_value(interface),
+                )
+            })
+            .collect()
+    }
+
+    pub(crate) fn event_interface_from_value(value: &Value) -> Option<EventInterface> {
+        let Value::Object(object) = value else {
+            return None;
+        };
+        match Self::object_get_entry(&object.borrow(), INTERNAL_EVENT_INTERFACE_KEY) {
+            Some(Value::String(name)) => EventInterface::from_name(&name),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn event_interface_constructor_from_callable(
+        callable: &Value,
+    ) -> Result<EventInterface> {
+        Self::event_interface_from_value(callable).ok_or_else(|| {
+            Error::ScriptRuntime("event constructor has invalid internal state".into())
+        })
+    }
+
+    fn event_member_default(kind: EventMemberKind) -> Value {
+        match kind {
+            EventMemberKind::Bool => Value::Bool(false),
+            EventMemberKind::Long => Value::Number(0),
+            EventMemberKind::Double => Value::Float(0.0),
+            EventMemberKind::String => Value::String(String::new()),
+            EventMemberKind::NullableString | EventMemberKind::Object => Value::Null,
+            EventMemberKind::Sequence => Self::new_array_value(Vec::new()),
+        }
+    }
+
+    fn event_member_from_init(kind: EventMemberKind, value: Value) -> Value {
+        match (kind, value) {
+            (_, Value::Undefined) => Self::event_member_default(kind),
+            (EventMemberKind::Bool, value) => Value::Bool(value.truthy()),
+            (EventMemberKind::Long, value) => {
+                let number = Self::coerce_number_for_global(&value);
+                Value::Number(if number.is_finite() {
+                    number.trunc() as i64
+                } else {
+                    0
+                })
+            }
+            (EventMemberKind::Double, value) => {
+                Value::Float(Self::coerce_number_for_global(&value))
+            }
+            (EventMemberKind::String, value) => Value::String(value.as_string()),
+            (EventMemberKind::NullableString, Value::Null) => Value::Null,
+            (EventMemberKind::NullableString, value) => Value::String(value.as_string()),
+            (EventMemberKind::Sequence, value @ Value::Array(_)) => value,
+            (EventMemberKind::Sequence, _) => Self::new_array_value(Vec::new()),
+            (EventMemberKind::Object, value) => value,
+        }
+    }
+
+    // Members given in an init dictionary (or copied from a dispatched event
+    // object), coerced to their IDL kinds.
+    pub(crate) fn event_interface_members_from_init(
+        interface: EventInterface,
+        init: &(impl ObjectEntryLookup + ?Sized),
+    ) -> Vec<(String, Value)> {
+        interface
+            .inherited_members()
+            .into_iter()
+            .filter_map(|(name, kind)| {
+                Self::object_get_entry(init, name)
+                    .map(|value| (name.to_string(), Self::event_member_from_init(kind, value)))
+            })
+            .collect()
+    }
+
+    // Adds the interface tag plus every table-driven member. Explicit members
+    // win; otherwise a value already on the object is kept and missing ones get
+    // their IDL default.
+    pub(crate) fn push_event_interface_entries(
+        entries: &mut Vec<(String, Value)>,
+        interface: EventInterface,
+        members: &[(String, Value)],
+    ) {
+        Self::object_set_entry(
+            entries,
+            INTERNAL_EVENT_INTERFACE_KEY.to_string(),
+            Value::String(interface.name().to_string()),
+        );
+        for (name, kind) in interface.inherited_members() {
+            if let Some((_, value)) = members.iter().find(|(member, _)| member == name) {
+                Self::object_set_entry(entries, name.to_string(), value.clone());
+                continue;
+            }
+            if matches!(
+                Self::object_get_entry(entries, name),
+                None | Some(Value::Undefined)
+            ) {
+                Self::object_set_entry(entries, name.to_string(), Self::event_member_default(kind));
+            }
+        }
+    }
+
+    pub(crate) fn is_event_instance_of_interface(value: &Value, interface: EventInterface) -> bool {
+        let Value::Object(object) = value else {
+            return false;
+        };
+        if !Self::is_event_object(&object.borrow()) {
+            return false;
+        }
+        Self::event_interface_from_value(value)
+            .unwrap_or(EventInterface::Event)
+            .extends(interface)
+    }
+}
@@ -0,0 +1,600 @@
+use super::*;
+
+impl Harness {
+    pub(crate) fn new_intersection_observer_constructor_value() -> Value {
+        Self::new_object_value(vec![(
+            INTERNAL_CALLABLE_KIND_KEY.to_string(),
+            Value::String("intersection_observer_constructor".to_string()),
+        )])
+    }
+
+    pub(crate) fn new_resize_observer_constructor_value() -> Value {
+        Self::new_object_value(vec![(
+            INTERNAL_CALLABLE_KIND_KEY.to_string(),
+            Value::String("resize_observer_constructor".to_string()),
+        )])
+    }
+
+    pub(crate) fn new_intersection_observer_entry_constructor_value() -> Value {
+        Self::new_object_value(vec![(
+            INTERNAL_CALLABLE_KIND_KEY.to_string(),
+            Value::String("intersection_observer_entry_constructor".to_string()),
+        )])
+    }
+
+    pub(crate) fn new_resize_observer_entry_constructor_value() -> Value {
+        Self::new_object_value(vec![(
+            INTERNAL_CALLABLE_KIND_KEY.to_string(),
+            Value::String("resize_observer_entry_constructor".to_string()),
+        )])
+    }
+
+    fn new_layout_observer_method_value(kind: &str, id: usize) -> Value {
+        Self::new_object_value(vec![
+            (
+                INTERNAL_CALLABLE_KIND_KEY.to_string(),
+                Value::String(kind.to_string()),
+            ),
+            (
+                INTERNAL_LAYOUT_OBSERVER_ID_KEY.to_string(),
+                Value::Number(id as i64),
+            ),
+        ])
+    }
+
+    pub(crate) fn new_dom_rect_value(rect: DomRect) -> Value {
+        Self::new_object_value(vec![
+            ("x".to_string(), Self::number_value(rect.x)),
+            ("y".to_string(), Self::number_value(rect.y)),
+            ("width".to_string(), Self::number_value(rect.width)),
+            ("height".to_string(), Self::number_value(rect.height)),
+            ("top".to_string(), Self::number_value(rect.y)),
+            ("right".to_string(), Self::number_value(rect.right())),
+            ("bottom".to_string(), Self::number_value(rect.bottom())),
+            ("left".to_string(), Self::number_value(rect.x)),
+        ])
+    }
+
+    pub(crate) fn layout_observer_id(value: &Value) -> Option<usize> {
+        let Value::Object(object) = value else {
+            return None;
+        };
+        match Self::object_get_entry(&object.borrow(), INTERNAL_LAYOUT_OBSERVER_ID_KEY) {
+            Some(Value::Number(id)) => usize::try_from(id).ok(),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn is_layout_observer_of_kind(
+        &self,
+        value: &Value,
+        kind: LayoutObserverKind,
+    ) -> bool {
+        Self::layout_observer_id(value).is_some_and(|id| {
+            self.dom_runtime
+                .layout_observers
+                .observers
+                .iter()
+                .any(|entry| entry.id == id && entry.kind == kind)
+        })
+    }
+
+    pub(crate) fn is_layout_observer_entry_of_kind(
+        value: &Value,
+        kind: LayoutObserverKind,
+    ) -> bool {
+        let Value::Object(object) = value else {
+            return false;
+        };
+        let expected = match kind {
+            LayoutObserverKind::Intersection => "intersection",
+            LayoutObserverKind::Resize => "resize",
+        };
+        matches!(
+            Self::object_get_entry(&object.borrow(), INTERNAL_LAYOUT_OBSERVER_ENTRY_KEY),
+            Some(Value::String(entry_kind)) if entry_kind == expected
+        )
+    }
+
+    fn parse_root_margin(value: &str) -> Result<[String; 4]> {
+        let tokens = value.split_whitespace().collect::<Vec<_>>();
+        let valid = |token: &str| {
+            let numeric = token
+                .strip_suffix("px")
+                .or_else(|| token.strip_suffix('%'))
+                .unwrap_or(if token == "0" { "0" } else { "" });
+            numeric.parse::<f64>().is_ok_and(f64::is_finite)
+        };
+        if tokens.len() > 4 || !tokens.iter().all(|token| valid(token)) {
+            return Err(Error::ScriptRuntime(
+                "SyntaxError: rootMargin must be specified in pixels or percent".into(),
+            ));
+        }
+        let token = |index: usize| {
+            let token = tokens.get(index).copied().unwrap_or("0px");
+            if token == "0" {
+                "0px".to_string()
+            } else {
+                token.to_string()
+            }
+        };
+        Ok(match tokens.len() {
+            0 | 1 => [token(0), token(0), token(0), token(0)],
+            2 => [token(0), token(1), token(0), token(1)],
+            3 => [token(0), token(1), token(2), token(1)],
+            _ => [token(0), token(1), token(2), token(3)],
+        })
+    }
+
+    fn parse_intersection_thresholds(&self, value: Option<Value>) -> Result<Vec<f64>> {
+        let values = match value {
+            None => vec![Value::Number(0)],
+            Some(value @ Value::Array(_)) => self.array_like_values_from_value(&value)?,
+            Some(value) => vec![value],
+        };
+        let mut thresholds = values
+            .iter()
+            .map(Self::coerce_number_for_global)
+            .collect::<Vec<_>>();
+        if thresholds
+            .iter()
+            .any(|threshold| !(0.0..=1.0).contains(threshold))
+        {
+            return Err(Error::ScriptRuntime(
+                "RangeError: Threshold values must be numbers between 0 and 1".into(),
+            ));
+        }
+        if thresholds.is_empty() {
+            thresholds.push(0.0);
+        }
+        thresholds.sort_by(f64::total_cmp);
+        thresholds.dedup();
+        Ok(thresholds)
+    }
+
+    pub(crate) fn new_layout_observer_from_constructor_args(
+        &mut self,
+        kind: LayoutObserverKind,
+        args: &[Value],
+    ) -> Result<Value> {
+        let name = match kind {
+            LayoutObserverKind::Intersection => "IntersectionObserver",
+            LayoutObserverKind::Resize => "ResizeObserver",
+        };
+        let callback = args.first().cloned().unwrap_or(Value::Undefined);
+        if !self.is_callable_value(&callback) {
+            return Err(Error::ScriptRuntime(format!(
+                "TypeError: {name} callback must be a function"
+            )));
+        }
+
+        let mut root = None;
+        let mut root_margin = Self::parse_root_margin("0px")?;
+        let mut thresholds = Vec::new();
+        if kind == LayoutObserverKind::Intersection {
+            let option = |key: &str| match args.get(1) {
+                Some(Value::Object(object)) => Self::object_get_entry(&object.borrow(), key)
+                    .filter(|value| !matches!(value, Value::Undefined)),
+                _ => None,
+            };
+            root = match option("root") {
+                None | Some(Value::Null) => None,
+                Some(Value::Node(node)) => Some(node),
+                Some(_) => {
+                    return Err(Error::ScriptRuntime(
+                        "TypeError: IntersectionObserver root must be an Element or Document"
+                            .into(),
+                    ));
+                }
+            };
+            if let Some(margin) = option("rootMargin") {
+                root_margin = Self::parse_root_margin(&margin.as_string())?;
+            }
+            thresholds = self.parse_intersection_thresholds(option("threshold"))?;
+        }
+
+        let state = &mut self.dom_runtime.layout_observers;
+        state.next_id += 1;
+        let id = state.next_id;
+        let mut entries = vec![
+            (
+                INTERNAL_LAYOUT_OBSERVER_ID_KEY.to_string(),
+                Value::Number(id as i64),
+            ),
+            (
+                "observe".to_string(),
+                Self::new_layout_observer_method_value("layout_observer_observe", id),
+            ),
+            (
+                "unobserve".to_string(),
+                Self::new_layout_observer_method_value("layout_observer_unobserve", id),
+            ),
+            (
+                "disconnect".to_string(),
+                Self::new_layout_observer_method_value("layout_observer_disconnect", id),
+            ),
+        ];
+        if kind == LayoutObserverKind::Intersection {
+            entries.extend([
+                (
+                    "takeRecords".to_string(),
+                    Self::new_layout_observer_method_value("layout_observer_take_records", id),
+                ),
+                (
+                    "root".to_string(),
+                    root.map(Value::Node).unwrap_or(Value::Null),
+                ),
+                (
+                    "rootMargin".to_string(),
+                    Value::String(root_margin.join(" ")),
+                ),
+                (
+                    "thresholds".to_string(),
+                    Self::new_array_value(
+                        thresholds.iter().copied().map(Self::number_value).collect(),
+                    ),
+                ),
+            ]);
+        }
+        let object = Self::new_object_value(entries);
+        state.observers.push(LayoutObserverEntry {
+            id,
+            kind,
+            callback,
+            object: object.clone(),
+            root,
+            root_margin,
+            thresholds,
+            observations: Vec::new(),
+            records: Vec::new(),
+        });
+        Ok(object)
+    }
+
+    fn layout_observer_entry_mut(&mut self, id: usize) -> Option<&mut LayoutObserverEntry> {
+        self.dom_runtime
+            .layout_observers
+            .observers
+            .iter_mut()
+            .find(|entry| entry.id == id)
+    }
+
+    pub(crate) fn eval_layout_observer_method(
+        &mut self,
+        method: &str,
+        id: usize,
+        args: &[Value],
+    ) -> Result<Value> {
+        match method {
+            "layout_observer_observe" => {
+                let Some(Value::Node(target)) = args.first() else {
+                    return Err(Error::ScriptRuntime(
+                        "TypeError: observe target must be an Element".into(),
+                    ));
+                };
+                let target = *target;
+                if self.dom.element(target).is_none() {
+                    return Err(Error::ScriptRuntime(
+                        "TypeError: observe target must be an Element".into(),
+                    ));
+                }
+                let border_box = match args.get(1) {
+                    Some(Value::Object(object)) => Self::object_get_entry(&object.borrow(), "box")
+                        .is_some_and(|value| value.as_string() == "border-box"),
+                    _ => false,
+                };
+                let Some(entry) = self.layout_observer_entry_mut(id) else {
+                    return Ok(Value::Undefined);
+                };
+                if let Some(observation) = entry
+                    .observations
+                    .iter_mut()
+                    .find(|observation| observation.target == target)
+                {
+                    observation.border_box = border_box;
+                } else {
+                    entry.observations.push(LayoutObservation {
+                        target,
+                        previous_threshold_index: -1,
+                        previous_is_intersecting: false,
+                        border_box,
+                        last_size: (-1.0, -1.0),
+                    });
+                }
+                self.update_layout_observations();
+            }
+            "layout_observer_unobserve" => {
+                if let (Some(Value::Node(target)), Some(entry)) =
+                    (args.first(), self.layout_observer_entry_mut(id))
+                {
+                    entry
+                        .observations
+                        .retain(|observation| observation.target != *target);
+                }
+            }
+            "layout_observer_disconnect" => {
+                if let Some(entry) = self.layout_observer_entry_mut(id) {
+                    entry.observations.clear();
+                    entry.records.clear();
+                }
+            }
+            "layout_observer_take_records" => {
+                let records = self
+                    .layout_observer_entry_mut(id)
+                    .map(|entry| std::mem::take(&mut entry.records))
+                    .unwrap_or_default();
+                return Ok(Self::new_array_value(records));
+            }
+            _ => {}
+        }
+        Ok(Value::Undefined)
+    }
+
+    // The border box in document coordinates, or `None` while the element is
+    // not rendered.
+    pub(crate) fn element_layout_rect(&self, node: NodeId) -> Option<DomRect> {
+        self.is_element_rendered(node)
+            .then(|| self.dom.layout_rect(node))
+            .flatten()
+    }
+
+    fn element_layout_content_rect(&self, node: NodeId) -> Option<DomRect> {
+        self.is_element_rendered(node)
+            .then(|| self.dom.layout_content_rect(node))
+            .flatten()
+    }
+
+    // Border box in viewport coordinates; unrendered elements get an empty rect.
+    pub(crate) fn element_client_rect(&self, node: NodeId) -> DomRect {
+        self.element_layout_rect(node)
+            .unwrap_or_default()
+            .translated(
+                -(self.dom_runtime.document_scroll_x as f64),
+                -(self.dom_runtime.document_scroll_y as f64),
+            )
+    }
+
+    fn intersection_root_bounds(&self, entry: &LayoutObserverEntry) -> Option<DomRect> {
+        let bounds = match entry.root {
+            Some(root) if root != self.dom.root => {
+                self.element_layout_rect(root)?;
+                self.element_client_rect(root)
+            }
+            _ => {
+                let (width, height) = self.viewport_dimensions();
+                DomRect::new(0.0, 0.0, width, height)
+            }
+        };
+        let margin = |token: &str, basis: f64| {
+            if let Some(percent) = token.strip_suffix('%') {
+                percent.parse::<f64>().unwrap_or(0.0) * basis / 100.0
+            } else {
+                token
+                    .strip_suffix("px")
+                    .unwrap_or(token)
+                    .parse::<f64>()
+                    .unwrap_or(0.0)
+            }
+        };
+        let [top, right, bottom, left] = &entry.root_margin;
+        let top = margin(top, bounds.height);
+        let right = margin(right, bounds.width);
+        let bottom = margin(bottom, bounds.height);
+        let left = margin(left, bounds.width);
+        Some(DomRect::new(
+            bounds.x - left,
+            bounds.y - top,
+            bounds.width + left + right,
+            bounds.height + top + bottom,
+        ))
+    }
+
+    fn intersection_entry_value(
+        &self,
+        entry: &LayoutObserverEntry,
+        target: NodeId,
+    ) -> (Value, i64, bool) {
+        let root_bounds = self.intersection_root_bounds(entry);
+        let target_rect = self.element_client_rect(target);
+        let forced_ratio = self
+            .dom_runtime
+            .layout_observers
+            .forced_intersection_ratios
+            .get(&target)
+            .copied();
+
+        let (intersection_rect, ratio, is_intersecting) = if let Some(ratio) = forced_ratio {
+            let rect = DomRect::new(
+                target_rect.x,
+                target_rect.y,
+                target_rect.width,
+                target_rect.height * ratio,
+            );
+            (rect, ratio, ratio > 0.0)
+        } else {
+            let in_root = match entry.root {
+                Some(root) if root != self.dom.root => self.dom.is_descendant_of(target, root),
+                _ => true,
+            };
+            // Without harness-provided geometry a target never intersects.
+            let has_geometry = self.dom.layout_rects.contains_key(&target)
+                && self.element_layout_rect(target).is_some();
+            let intersection = root_bounds
+                .filter(|_| in_root && has_geometry)
+                .and_then(|bounds| target_rect.intersection(&bounds));
+            let target_area = target_rect.width * target_rect.height;
+            match intersection {
+                Some(rect) if target_area > 0.0 => {
+                    (rect, rect.width * rect.height / target_area, true)
+                }
+                Some(rect) => (rect, 1.0, true),
+                None => (DomRect::default(), 0.0, false),
+            }
+        };
+
+        let threshold_index = entry
+            .thresholds
+            .iter()
+            .position(|threshold| *threshold > ratio)
+            .unwrap_or(entry.thresholds.len()) as i64;
+        let value = Self::new_object_value(vec![
+            (
+                INTERNAL_LAYOUT_OBSERVER_ENTRY_KEY.to_string(),
+                Value::String("intersection".to_string()),
+            ),
+            ("target".to_string(), Value::Node(target)),
+            ("time".to_string(), Value::Number(self.scheduler.now_ms)),
+            (
+                "rootBounds".to_string(),
+                root_bounds
+                    .map(Self::new_dom_rect_value)
+                    .unwrap_or(Value::Null),
+            ),
+            (
+                "boundingClientRect".to_string(),
+                Self::new_dom_rect_value(target_rect),
+            ),
+            (
+                "intersectionRect".to_string(),
+                Self::new_dom_rect_value(intersection_rect),
+            ),
+            ("intersectionRatio".to_string(), Self::number_value(ratio)),
+            ("isIntersecting".to_string(), Value::Bool(is_intersecting)),
+            ("isVisible".to_string(), Value::Bool(false)),
+        ]);
+        (value, threshold_index, is_intersecting)
+    }
+
+    fn resize_entry_value(&self, target: NodeId) -> Value {
+        let border_box = self.element_layout_rect(target).unwrap_or_default();
+        let content_rect = self.element_layout_content_rect(target).unwrap_or_default();
+        let size = |inline: f64, block: f64| {
+            Self::new_array_value(vec![Self::new_object_value(vec![
+                ("inlineSize".to_string(), Self::number_value(inline)),
+                ("blockSize".to_string(), Self::number_value(block)),
+            ])])
+        };
+        Self::new_object_value(vec![
+            (
+                INTERNAL_LAYOUT_OBSERVER_ENTRY_KEY.to_string(),
+                Value::String("resize".to_string()),
+            ),
+            ("target".to_string(), Value::Node(target)),
+            (
+                "contentRect".to_string(),
+                Self::new_dom_rect_value(content_rect),
+            ),
+            (
+                "borderBoxSize".to_string(),
+                size(border_box.width, border_box.height),
+            ),
+            (
+                "contentBoxSize".to_string(),
+                size(content_rect.width, content_rect.height),
+            ),
+            (
+                "devicePixelContentBoxSize".to_string(),
+                size(content_rect.width, content_rect.height),
+            ),
+        ])
+    }
+
+    // Stands in for the rendering update: compares every observation with the
+    // current harness-driven geometry and queues one task for new entries.
+    pub(crate) fn update_layout_observations(&mut self) {
+        let mut observers = std::mem::take(&mut self.dom_runtime.layout_observers.observers);
+        let mut queued = false;
+        for entry in &mut observers {
+            let mut observations = std::mem::take(&mut entry.observations);
+            for observation in &mut observations {
+                match entry.kind {
+                    LayoutObserverKind::Intersection => {
+                        let (value, threshold_index, is_intersecting) =
+                            self.intersection_entry_value(entry, observation.target);
+                        if threshold_index != observation.previous_threshold_index
+                            || is_intersecting != observation.previous_is_intersecting
+                        {
+                            observation.previous_threshold_index = threshold_index;
+                            observation.previous_is_intersecting = is_intersecting;
+                            entry.records.push(value);
+                            queued = true;
+                        }
+                    }
+                    LayoutObserverKind::Resize => {
+                        let size = if observation.border_box {
+                            self.element_layout_rect(observation.target)
+                                .map(|rect| (rect.width, rect.height))
+                        } else {
+                            self.element_layout_content_rect(observation.target)
+                                .map(|rect| (rect.width, rect.height))
+                        }
+                        .unwrap_or_default();
+                        if size != observation.last_size {
+                            observation.last_size = size;
+                            entry
+                                .records
+                                .push(self.resize_entry_value(observation.target));
+                            queued = true;
+                        }
+                    }
+                }
+            }
+            entry.observations = observations;
+        }
+        self.dom_runtime.layout_observers.observers = observers;
+
+        if queued && !self.dom_runtime.layout_observers.delivery_queued {
+            self.dom_runtime.layout_observers.delivery_queued = true;
+            let id = self.scheduler.allocate_internal_task_id();
+            let order = self.scheduler.allocate_task_order();
+            self.scheduler.task_queue.push(ScheduledTask {
+                id,
+                due_at: self.scheduler.now_ms,
+                order,
+                kind: ScheduledTaskKind::LayoutObservers,
+                interval_ms: None,
+                callback: TimerCallback::Reference(String::new()),
+                callback_args: Vec::new(),
+                env: ScriptEnv::default(),
+            });
+        }
+    }
+
+    pub(crate) fn run_layout_observer_task(&mut self, task: &ScheduledTask) -> Result<()> {
+        self.trace_timer_line(format!(
+            "[timer] run layout observers id={} now_ms={}",
+            task.id, self.scheduler.now_ms
+        ));
+        self.dom_runtime.layout_observers.delivery_queued = false;
+        let ids = self
+            .dom_runtime
+            .layout_observers
+            .observers
+            .iter()
+            .map(|entry| entry.id)
+            .collect::<Vec<_>>();
+        for id in ids {
+            let Some(entry) = self.layout_observer_entry_mut(id) else {
+                continue;
+            };
+            if entry.records.is_empty() {
+                continue;
+            }
+            let records = Self::new_array_value(std::mem::take(&mut entry.records));
+            let callback = entry.callback.clone();
+            let object = entry.object.clone();
+            self.run_in_task_context(|this| {
+                let event = EventState::new("timeout", this.dom.root, this.scheduler.now_ms);
+                this.execute_callable_value_with_this_and_env(
+                    &callback,
+                    &[records.clone(), object.clone()],
+                    &event,
+                    None,
+                    Some(object.clone()),
+                )
+                .map(|_| ())
+            })?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,256 @@
+use super::*;
+
+impl Harness {
+    pub(crate) fn new_mutation_observer_constructor_value() -> Value {
+        Self::new_object_value(vec![(
+            INTERNAL_CALLABLE_KIND_KEY.to_string(),
+            Value::String("mutation_observer_constructor".to_string()),
+        )])
+    }
+
+    fn new_mutation_observer_method_value(kind: &str, id: usize) -> Value {
+        Self::new_object_value(vec![
+            (
+                INTERNAL_CALLABLE_KIND_KEY.to_string(),
+                Value::String(kind.to_string()),
+            ),
+            (
+                INTERNAL_MUTATION_OBSERVER_ID_KEY.to_string(),
+                Value::Number(id as i64),
+            ),
+        ])
+    }
+
+    pub(crate) fn new_mutation_observer_from_constructor_args(
+        &mut self,
+        args: &[Value],
+    ) -> Result<Value> {
+        let callback = args.first().cloned().unwrap_or(Value::Undefined);
+        if !self.is_callable_value(&callback) {
+            return Err(Error::ScriptRuntime(
+                "TypeError: MutationObserver callback must be a function".into(),
+            ));
+        }
+        let state = &mut self.dom_runtime.mutation_observers;
+        state.next_id += 1;
+        let id = state.next_id;
+        let object = Self::new_object_value(vec![
+            (
+                INTERNAL_MUTATION_OBSERVER_ID_KEY.to_string(),
+                Value::Number(id as i64),
+            ),
+            (
+                "observe".to_string(),
+                Self::new_mutation_observer_method_value("mutation_observer_observe", id),
+            ),
+            (
+                "disconnect".to_string(),
+                Self::new_mutation_observer_method_value("mutation_observer_disconnect", id),
+            ),
+            (
+                "takeRecords".to_string(),
+                Self::new_mutation_observer_method_value("mutation_observer_take_records", id),
+            ),
+        ]);
+        state.observers.push(MutationObserverEntry {
+            id,
+            callback,
+            object: object.clone(),
+            records: Vec::new(),
+        });
+        Ok(object)
+    }
+
+    pub(crate) fn mutation_observer_id(value: &Value) -> Option<usize> {
+        let Value::Object(object) = value else {
+            return None;
+        };
+        match Self::object_get_entry(&object.borrow(), INTERNAL_MUTATION_OBSERVER_ID_KEY) {
+            Some(Value::Number(id)) => usize::try_from(id).ok(),
+            _ => None,
+        }
+    }
+
+    fn mutation_observer_options(init: &Value) -> Result<MutationObserverOptions> {
+        let entry = |name: &str| match init {
+            Value::Object(object) => Self::object_get_entry(&object.borrow(), name)
+                .filter(|value| !matches!(value, Value::Undefined)),
+            _ => None,
+        };
+        let flag = |name: &str| entry(name).map(|value| value.truthy());
+        let attribute_filter = match entry("attributeFilter") {
+            Some(Value::Array(values)) => Some(
+                values
+                    .borrow()
+                    .iter()
+                    .map(|value| value.as_string().to_ascii_lowercase())
+                    .collect::<Vec<_>>(),
+            ),
+            Some(_) => {
+                return Err(Error::ScriptRuntime(
+                    "TypeError: attributeFilter must be a sequence of strings".into(),
+                ));
+            }
+            None => None,
+        };
+        let attribute_old_value = flag("attributeOldValue");
+        let character_data_old_value = flag("characterDataOldValue");
+        let attributes = flag("attributes")
+            .unwrap_or(attribute_old_value.is_some() || attribute_filter.is_some());
+        let character_data = flag("characterData").unwrap_or(character_data_old_value.is_some());
+        let options = MutationObserverOptions {
+            child_list: flag("childList").unwrap_or(false),
+            attributes,
+            character_data,
+            subtree: flag("subtree").unwrap_or(false),
+            attribute_old_value: attribute_old_value.unwrap_or(false),
+            character_data_old_value: character_data_old_value.unwrap_or(false),
+            attribute_filter,
+        };
+
+        if !options.child_list && !options.attributes && !options.character_data {
+            return Err(Error::ScriptRuntime(
+                "TypeError: The options object must set at least one of 'attributes', 'characterData', or 'childList' to true.".into(),
+            ));
+        }
+        if !options.attributes
+            && (options.attribute_old_value || options.attribute_filter.is_some())
+        {
+            return Err(Error::ScriptRuntime(
+                "TypeError: The options object may only set 'attributeOldValue' or 'attributeFilter' when 'attributes' is true or not present.".into(),
+            ));
+        }
+        if !options.character_data && options.character_data_old_value {
+            return Err(Error::ScriptRuntime(
+                "TypeError: The options object may only set 'characterDataOldValue' when 'characterData' is true or not present.".into(),
+            ));
+        }
+        Ok(options)
+    }
+
+    pub(crate) fn eval_mutation_observer_observe(
+        &mut self,
+        id: usize,
+        args: &[Value],
+    ) -> Result<Value> {
+        let Some(Value::Node(target)) = args.first() else {
+            return Err(Error::ScriptRuntime(
+                "TypeError: MutationObserver.observe target must be a Node".into(),
+            ));
+        };
+        let options = Self::mutation_observer_options(args.get(1).unwrap_or(&Value::Undefined))?;
+        self.dom.observe_mutations(id, *target, options);
+        Ok(Value::Undefined)
+    }
+
+    pub(crate) fn eval_mutation_observer_disconnect(&mut self, id: usize) -> Value {
+        self.dom.disconnect_mutation_observer(id);
+        if let Some(entry) = self.mutation_observer_entry_mut(id) {
+            entry.records.clear();
+        }
+        Value::Undefined
+    }
+
+    pub(crate) fn eval_mutation_observer_take_records(&mut self, id: usize) -> Value {
+        self.queue_pending_mutation_records();
+        let records = self
+            .mutation_observer_entry_mut(id)
+            .map(|entry| std::mem::take(&mut entry.records))
+            .unwrap_or_default();
+        Self::new_array_value(records)
+    }
+
+    fn mutation_observer_entry_mut(&mut self, id: usize) -> Option<&mut MutationObserverEntry> {
+        self.dom_runtime
+            .mutation_observers
+            .observers
+            .iter_mut()
+            .find(|entry| entry.id == id)
+    }
+
+    fn mutation_record_value(record: MutationRecordData) -> Value {
+        let node = |node: Option<NodeId>| node.map(Value::Node).unwrap_or(Value::Null);
+        Self::new_object_value(vec![
+            (
+                "type".to_string(),
+                Value::String(record.kind.as_str().to_string()),
+            ),
+            ("target".to_string(), Value::Node(record.target)),
+            (
+                "addedNodes".to_string(),
+                Self::new_static_node_list_value(record.added_nodes),
+            ),
+            (
+                "removedNodes".to_string(),
+                Self::new_static_node_list_value(record.removed_nodes),
+            ),
+            ("previousSibling".to_string(), node(record.previous_sibling)),
+            ("nextSibling".to_string(), node(record.next_sibling)),
+            (
+                "attributeName".to_string(),
+                record
+                    .attribute_name
+                    .map(Value::String)
+                    .unwrap_or(Value::Null),
+            ),
+            ("attributeNamespace".to_string(), Value::Null),
+            (
+                "oldValue".to_string(),
+                record.old_value.map(Value::String).unwrap_or(Value::Null),
+            ),
+        ])
+    }
+
+    // Moves records the DOM queued since the last checkpoint onto their
+    // observers and schedules the single delivery microtask if needed.
+    pub(crate) fn queue_pending_mutation_records(&mut self) {
+        let pending = self.dom.take_pending_mutation_records();
+        if pending.is_empty() {
+            return;
+        }
+        for (id, record) in pending {
+            let record = Self::mutation_record_value(record);
+            if let Some(entry) = self.mutation_observer_entry_mut(id) {
+                entry.records.push(record);
+            }
+        }
+        if !self.dom_runtime.mutation_observers.delivery_queued {
+            self.dom_runtime.mutation_observers.delivery_queued = true;
+            self.scheduler
+                .microtask_queue
+                .push_back(ScheduledMicrotask::MutationObservers);
+        }
+    }
+
+    pub(crate) fn notify_mutation_observers(&mut self) -> Result<()> {
+        self.dom_runtime.mutation_observers.delivery_queued = false;
+        let ids = self
+            .dom_runtime
+            .mutation_observers
+            .observers
+            .iter()
+            .map(|entry| entry.id)
+            .collect::<Vec<_>>();
+        for id in ids {
+            self.queue_pending_mutation_records();
+            let Some(entry) = self.mutation_observer_entry_mut(id) else {
+                continue;
+            };
+            if entry.records.is_empty() {
+                continue;
+            }
+            let records = Self::new_array_value(std::mem::take(&mut entry.records));
+            let callback = entry.callback.clone();
+            let object = entry.object.clone();
+            let event = EventState::new("microtask", self.dom.root, self.scheduler.now_ms);
+            self.execute_callable_value_with_this_and_env(
+                &callback,
+                &[records, object.clone()],
+                &event,
+                None,
+                Some(object),
+            )?;
+        }
+        Ok(())
+    }
+}
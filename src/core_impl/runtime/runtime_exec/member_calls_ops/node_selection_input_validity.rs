@@ -199,29 +199,12 @@ impl Harness {
     }
 
     fn get_bounding_client_rect_value(&self, node: NodeId) -> Result<Value> {
-        let left = self
-            .dom
-            .offset_left(node)?
-            .saturating_sub(self.dom_runtime.document_scroll_x);
-        let top = self
-            .dom
-            .offset_top(node)?
-            .saturating_sub(self.dom_runtime.document_scroll_y);
-        let width = self.dom.offset_width(node)?;
-        let height = self.dom.offset_height(node)?;
-        let right = left.saturating_add(width);
-        let bottom = top.saturating_add(height);
-
-        Ok(Self::new_object_value(vec![
-            ("x".to_string(), Value::Number(left)),
-            ("y".to_string(), Value::Number(top)),
-            ("left".to_string(), Value::Number(left)),
-            ("top".to_string(), Value::Number(top)),
-            ("right".to_string(), Value::Number(right)),
-            ("bottom".to_string(), Value::Number(bottom)),
-            ("width".to_string(), Value::Number(width)),
-            ("height".to_string(), Value::Number(height)),
-        ]))
+        if self.dom.element(node).is_none() {
+            return Err(Error::ScriptRuntime(
+                "getBoundingClientRect target is not an element".into(),
+            ));
+        }
+        Ok(Self::new_dom_rect_value(self.element_client_rect(node)))
     }
 
     fn node_has_client_rects(&self, node: NodeId) -> bool {
@@ -871,6 +854,9 @@ impl Harness {
                 out
             }
             NodeType::Text(text) => escape_html_text_for_serialization(text),
+            NodeType::DocumentType(_)
+            | NodeType::Comment(_)
+            | NodeType::ProcessingInstruction(_) => self.dom.dump_node(node_id),
             NodeType::Element(element) => {
                 let mut out = String::new();
                 out.push('<');
@@ -1006,9 +992,12 @@ impl Harness {
             let mut appended_elements = 0i64;
             for node in &nodes {
                 match self.dom.nodes.get(node.0).map(|entry| &entry.node_type) {
-                    Some(NodeType::Document) | Some(NodeType::Text(_)) => {
+                    Some(NodeType::Document)
+                    | Some(NodeType::DocumentType(_))
+                    | Some(NodeType::Text(_)) => {
                         return Err(Self::hierarchy_request_error());
                     }
+                    Some(NodeType::Comment(_)) | Some(NodeType::ProcessingInstruction(_)) => {}
                     Some(NodeType::Element(element))
                         if !element.tag_name.eq_ignore_ascii_case("#document-fragment") =>
                     {
@@ -1131,9 +1120,12 @@ impl Harness {
                     .get(candidate.0)
                     .map(|entry| &entry.node_type)
                 {
-                    Some(NodeType::Document) | Some(NodeType::Text(_)) => {
+                    Some(NodeType::Document)
+                    | Some(NodeType::DocumentType(_))
+                    | Some(NodeType::Text(_)) => {
                         return Err(Self::hierarchy_request_error());
                     }
+                    Some(NodeType::Comment(_)) | Some(NodeType::ProcessingInstruction(_)) => {}
                     Some(NodeType::Element(element))
                         if !element.tag_name.eq_ignore_ascii_case("#document-fragment") =>
                     {
@@ -1541,6 +1533,7 @@ impl Harness {
                 {
                     self.dom.set_attr(node, "is", &is_value)?;
                 }
+                self.upgrade_created_element(node)?;
                 Ok(Some(Value::Node(node)))
             }
             "createElementNS" => {
@@ -1577,6 +1570,41 @@ impl Harness {
                 let node = self.dom.create_detached_text(text);
                 Ok(Some(Value::Node(node)))
             }
+            "createComment" => {
+                if evaluated_args.len() != 1 {
+                    return Err(Error::ScriptRuntime(
+                        "createComment requires exactly one argument".into(),
+                    ));
+                }
+                let data = evaluated_args[0].as_string();
+                let node = self.dom.create_detached_comment(data);
+                Ok(Some(Value::Node(node)))
+            }
+            "createProcessingInstruction" => {
+                if evaluated_args.len() != 2 {
+                    return Err(Error::ScriptRuntime(
+                        "createProcessingInstruction requires exactly two arguments".into(),
+                    ));
+                }
+                let target = evaluated_args[0].as_string();
+                let data = evaluated_args[1].as_string();
+                if !is_valid_create_attribute_name(&target) {
+                    return Err(Error::ScriptRuntime(
+                        "InvalidCharacterError: processing instruction target is not a valid XML name"
+                            .into(),
+                    ));
+                }
+                if data.contains("?>") {
+                    return Err(Error::ScriptRuntime(
+                        "InvalidCharacterError: processing instruction data cannot contain '?>'"
+                            .into(),
+                    ));
+                }
+                let node = self
+                    .dom
+                    .create_detached_processing_instruction(target, data);
+                Ok(Some(Value::Node(node)))
+            }
             "createAttribute" => {
                 if evaluated_args.len() != 1 {
                     return Err(Error::ScriptRuntime(
@@ -1652,20 +1680,23 @@ impl Harness {
                 }
                 let event_type = evaluated_args[0].as_string();
                 let capture = self.parse_listener_capture_arg(evaluated_args.get(2))?;
+                let signal = self.parse_listener_signal_arg(evaluated_args.get(2))?;
                 match &evaluated_args[1] {
+                    Value::Function(_)
+                        if signal.as_ref().is_some_and(Self::abort_signal_is_aborted) =>
+                    {
+                        Ok(Some(Value::Undefined))
+                    }
                     Value::Function(function) => {
                         self.listeners.add(
                             self.dom.root,
                             event_type,
-                            Listener {
+                            Self::new_function_listener(
+                                function,
                                 capture,
-                                is_event_handler_property: false,
-                                handler: function.handler.clone(),
-                                captured_env: function.captured_env.clone(),
-                                captured_pending_function_decls: function
-                                    .captured_pending_function_decls
-                                    .clone(),
-                            },
+                                evaluated_args.get(2),
+                                signal,
+                            ),
                         );
                         Ok(Some(Value::Undefined))
                     }
@@ -1690,6 +1721,7 @@ impl Harness {
                             &event_type,
                             capture,
                             &function.handler,
+                            Some(function.function_id),
                         );
                         Ok(Some(Value::Undefined))
                     }
@@ -1760,8 +1792,9 @@ impl Harness {
 
     fn selection_clamped_offset(&self, node: NodeId, offset: i64) -> i64 {
         let max = match &self.dom.nodes[node.0].node_type {
-            NodeType::Text(text) => text.chars().count() as i64,
-            NodeType::Document | NodeType::Element(_) => {
+            NodeType::Text(text) | NodeType::Comment(text) => text.chars().count() as i64,
+            NodeType::ProcessingInstruction(instruction) => instruction.data.chars().count() as i64,
+            NodeType::Document | NodeType::DocumentType(_) | NodeType::Element(_) => {
                 self.dom.nodes[node.0].children.len() as i64
             }
         };
@@ -1779,7 +1812,8 @@ impl Harness {
             let clamped = self.selection_clamped_offset(target, target_offset) as usize;
             let index = match &self.dom.nodes[target.0].node_type {
                 NodeType::Text(_) => prefix + clamped,
-                NodeType::Document | NodeType::Element(_) => {
+                NodeType::Comment(_) | NodeType::ProcessingInstruction(_) => prefix,
+                NodeType::Document | NodeType::DocumentType(_) | NodeType::Element(_) => {
                     let children = &self.dom.nodes[target.0].children;
                     let upto = clamped.min(children.len());
                     let mut out = prefix;
@@ -2413,12 +2447,17 @@ impl Harness {
                         self.selection_clamped_offset(start_container, start_offset) as usize;
                     let end = self.selection_clamped_offset(end_container, end_offset) as usize;
                     match &mut self.dom.nodes[start_container.0].node_type {
-                        NodeType::Text(text) => {
+                        NodeType::Text(text) | NodeType::Comment(text) => {
                             let start_byte = Self::char_index_to_byte(text, start);
                             let end_byte = Self::char_index_to_byte(text, end);
                             text.replace_range(start_byte..end_byte, "");
                         }
-                        NodeType::Document | NodeType::Element(_) => {
+                        NodeType::ProcessingInstruction(instruction) => {
+                            let start_byte = Self::char_index_to_byte(&instruction.data, start);
+                            let end_byte = Self::char_index_to_byte(&instruction.data, end);
+                            instruction.data.replace_range(start_byte..end_byte, "");
+                        }
+                        NodeType::Document | NodeType::DocumentType(_) | NodeType::Element(_) => {
                             if end > start {
                                 let targets =
                                     self.dom.nodes[start_container.0].children[start..end].to_vec();
@@ -2586,12 +2625,7 @@ impl Harness {
                     ));
                 }
                 let node = self.selection_boundary_node_from_value(&evaluated_args[0])?;
-                let end = match &self.dom.nodes[node.0].node_type {
-                    NodeType::Text(text) => text.chars().count() as i64,
-                    NodeType::Document | NodeType::Element(_) => {
-                        self.dom.nodes[node.0].children.len() as i64
-                    }
-                };
+                let end = self.selection_clamped_offset(node, i64::MAX);
                 let changed = self.selection_set_state(Some(node), 0, Some(node), end);
                 if changed {
                     let _ = self.dispatch_document_selectionchange()?;
@@ -2695,6 +2729,51 @@ impl Harness {
         }
     }
 
+    pub(crate) fn parse_listener_signal_arg(
+        &self,
+        value: Option<&Value>,
+    ) -> Result<Option<Rc<RefCell<ObjectValue>>>> {
+        let Some(Value::Object(entries)) = value else {
+            return Ok(None);
+        };
+        match Self::object_get_entry(&entries.borrow(), "signal") {
+            None | Some(Value::Undefined) => Ok(None),
+            Some(signal) => Self::abort_signal_from_value(&signal)
+                .map(Some)
+                .ok_or_else(|| {
+                    Error::ScriptRuntime(
+                        "TypeError: addEventListener options.signal must be an AbortSignal".into(),
+                    )
+                }),
+        }
+    }
+
+    pub(crate) fn new_function_listener(
+        function: &FunctionValue,
+        capture: bool,
+        options: Option<&Value>,
+        signal: Option<Rc<RefCell<ObjectValue>>>,
+    ) -> Listener {
+        let option_flag = |key: &str| match options {
+            Some(Value::Object(entries)) => {
+                Self::object_get_entry(&entries.borrow(), key).is_some_and(|value| value.truthy())
+            }
+            _ => false,
+        };
+        Listener {
+            capture,
+            is_event_handler_property: false,
+            handler: function.handler.clone(),
+            captured_env: function.captured_env.clone(),
+            captured_pending_function_decls: function.captured_pending_function_decls.clone(),
+            signal,
+            once: option_flag("once"),
+            passive: option_flag("passive"),
+            callback_id: Some(function.function_id),
+            removed: Rc::default(),
+        }
+    }
+
     pub(crate) fn eval_event_target_member_call(
         &mut self,
         object: &Rc<RefCell<ObjectValue>>,
@@ -2748,22 +2827,29 @@ impl Harness {
             )
         };
 
+        let signal = if capture_mode.is_some() {
+            self.parse_listener_signal_arg(evaluated_args.get(2))?
+        } else {
+            None
+        };
         let node = self.event_target_listener_node_id(object);
         let result = match normalized_member {
             "addEventListener" => match callback_value {
+                Value::Function(_)
+                    if signal.as_ref().is_some_and(Self::abort_signal_is_aborted) =>
+                {
+                    Value::Undefined
+                }
                 Value::Function(function) => {
                     self.listeners.add(
                         node,
                         event_type,
-                        Listener {
+                        Self::new_function_listener(
+                            &function,
                             capture,
-                            is_event_handler_property: false,
-                            handler: function.handler.clone(),
-                            captured_env: function.captured_env.clone(),
-                            captured_pending_function_decls: function
-                                .captured_pending_function_decls
-                                .clone(),
-                        },
+                            evaluated_args.get(2),
+                            signal,
+                        ),
                     );
                     Value::Undefined
                 }
@@ -2776,9 +2862,13 @@ impl Harness {
             },
             "removeEventListener" => match callback_value {
                 Value::Function(function) => {
-                    let _ = self
-                        .listeners
-                        .remove(node, &event_type, capture, &function.handler);
+                    let _ = self.listeners.remove(
+                        node,
+                        &event_type,
+                        capture,
+                        &function.handler,
+                        Some(function.function_id),
+                    );
                     Value::Undefined
                 }
                 Value::Null | Value::Undefined => Value::Undefined,
@@ -2810,20 +2900,23 @@ impl Harness {
                 }
                 let event_type = evaluated_args[0].as_string();
                 let capture = self.parse_listener_capture_arg(evaluated_args.get(2))?;
+                let signal = self.parse_listener_signal_arg(evaluated_args.get(2))?;
                 match &evaluated_args[1] {
+                    Value::Function(_)
+                        if signal.as_ref().is_some_and(Self::abort_signal_is_aborted) =>
+                    {
+                        Ok(Some(Value::Undefined))
+                    }
                     Value::Function(function) => {
                         self.listeners.add(
                             node,
                             event_type,
-                            Listener {
+                            Self::new_function_listener(
+                                function,
                                 capture,
-                                is_event_handler_property: false,
-                                handler: function.handler.clone(),
-                                captured_env: function.captured_env.clone(),
-                                captured_pending_function_decls: function
-                                    .captured_pending_function_decls
-                                    .clone(),
-                            },
+                                evaluated_args.get(2),
+                                signal,
+                            ),
                         );
                         Ok(Some(Value::Undefined))
                     }
@@ -2843,9 +2936,13 @@ impl Harness {
                 let capture = self.parse_listener_capture_arg(evaluated_args.get(2))?;
                 match &evaluated_args[1] {
                     Value::Function(function) => {
-                        let _ =
-                            self.listeners
-                                .remove(node, &event_type, capture, &function.handler);
+                        let _ = self.listeners.remove(
+                            node,
+                            &event_type,
+                            capture,
+                            &function.handler,
+                            Some(function.function_id),
+                        );
                         Ok(Some(Value::Undefined))
                     }
                     Value::Null | Value::Undefined => Ok(Some(Value::Undefined)),
@@ -4124,7 +4221,24 @@ impl Harness {
                         "checkVisibility supports at most one argument".into(),
                     ));
                 }
-                Ok(Some(Value::Bool(!self.dom.has_attr(node, "hidden")?)))
+                let option = |names: [&str; 2]| match evaluated_args.first() {
+                    Some(Value::Object(options)) => {
+                        let options = options.borrow();
+                        names.iter().any(|name| {
+                            Self::object_get_entry(&options, name)
+                                .is_some_and(|value| value.truthy())
+                        })
+                    }
+                    _ => false,
+                };
+                let visible = if option(["visibilityProperty", "checkVisibilityCSS"]) {
+                    self.is_element_visible(node)
+                } else {
+                    self.is_element_rendered(node)
+                };
+                let transparent = option(["opacityProperty", "checkOpacity"])
+                    && self.computed_style_property_value(node, None, "opacity")? == "0";
+                Ok(Some(Value::Bool(visible && !transparent)))
             }
             "checkValidity" | "reportValidity" => {
                 if !evaluated_args.is_empty() {
@@ -0,0 +1,810 @@
+use super::*;
+
+// The subset of the HTML rendering section's UA style sheet that decides
+// `display`, plus the few presentational defaults tests commonly read back.
+const USER_AGENT_STYLE_SHEET: &str = r#"
+html, body, address, article, aside, blockquote, center, dd, details, dialog, div, dl, dt,
+fieldset, figcaption, figure, footer, form, h1, h2, h3, h4, h5, h6, header, hgroup, hr,
+legend, main, menu, nav, ol, p, pre, search, section, ul { display: block; }
+li, summary { display: list-item; }
+table { display: table; }
+caption { display: table-caption; }
+colgroup { display: table-column-group; }
+col { display: table-column; }
+thead { display: table-header-group; }
+tbody { display: table-row-group; }
+tfoot { display: table-footer-group; }
+tr { display: table-row; }
+td, th { display: table-cell; }
+button, input, meter, progress, select, textarea { display: inline-block; }
+area, base, datalist, head, link, meta, noscript, param, rp, script, style, template, title,
+input[type="hidden"], dialog:not([open]), [hidden]:not([hidden="until-found"]) { display: none; }
+body { margin: 8px; }
+b, strong, th, h1, h2, h3, h4, h5, h6 { font-weight: 700; }
+i, em, cite, dfn, var, address { font-style: italic; }
+pre, listing, xmp, plaintext { white-space: pre; }
+textarea { white-space: pre-wrap; }
+"#;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum CascadeOrigin {
+    UserAgent,
+    Author,
+    Inline,
+}
+
+#[derive(Debug, Clone)]
+struct CascadedDeclaration {
+    value: String,
+    important: bool,
+    origin: CascadeOrigin,
+    specificity: (u32, u32, u32),
+    order: usize,
+}
+
+impl CascadedDeclaration {
+    fn precedence(&self) -> (bool, CascadeOrigin, (u32, u32, u32), usize) {
+        (self.important, self.origin, self.specificity, self.order)
+    }
+}
+
+// Var substitution nests at most this deep; deeper chains are treated as cycles.
+const MAX_CSS_VAR_DEPTH: usize = 32;
+
+impl Harness {
+    pub(crate) fn computed_style_property_value(
+        &self,
+        node: NodeId,
+        pseudo: Option<&str>,
+        property_name: &str,
+    ) -> Result<String> {
+        if self.dom.element(node).is_none() {
+            return Err(Error::ScriptRuntime(
+                "TypeError: getComputedStyle target must be an Element".into(),
+            ));
+        }
+        let property = property_name.trim();
+        let css_property = if property.starts_with("--") {
+            property.to_ascii_lowercase()
+        } else {
+            js_prop_to_css_name(property)
+        };
+        Ok(self.computed_css_value(node, pseudo, &css_property, 0))
+    }
+
+    // Whether the element generates a box: connected, not `display: none`
+    // itself or through an ancestor, and not inside the hidden part of a closed
+    // `<details>` or a `hidden="until-found"` subtree. Shadow trees are walked
+    // through their host.
+    pub(crate) fn is_element_rendered(&self, node: NodeId) -> bool {
+        if self.dom.element(node).is_none() {
+            return false;
+        }
+        let mut child = None;
+        let mut cursor = Some(node);
+        while let Some(current) = cursor {
+            if current == self.dom.root {
+                return true;
+            }
+            if self.dom.element(current).is_none() {
+                return false;
+            }
+            if let Some(host) = self.shadow_host_of_root(current) {
+                child = None;
+                cursor = Some(host);
+                continue;
+            }
+            if self.computed_css_value(current, None, "display", 0) == "none" {
+                return false;
+            }
+            let until_found = self
+                .dom
+                .attr(current, "hidden")
+                .is_some_and(|value| value.eq_ignore_ascii_case("until-found"));
+            if until_found {
+                return false;
+            }
+            if let Some(child) = child {
+                if self.is_closed_details_content(current, child) {
+                    return false;
+                }
+            }
+            child = Some(current);
+            cursor = self.dom.parent(current);
+        }
+        false
+    }
+
+    fn shadow_host_of_root(&self, root: NodeId) -> Option<NodeId> {
+        self.dom_runtime
+            .shadow_roots
+            .iter()
+            .find(|(_, record)| record.root == root)
+            .map(|(host, _)| *host)
+    }
+
+    pub(crate) fn is_element_visible(&self, node: NodeId) -> bool {
+        self.is_element_rendered(node)
+            && !matches!(
+                self.computed_css_value(node, None, "visibility", 0)
+                    .as_str(),
+                "hidden" | "collapse"
+            )
+    }
+
+    fn is_closed_details_content(&self, details: NodeId, child: NodeId) -> bool {
+        let is_details = self
+            .dom
+            .tag_name(details)
+            .is_some_and(|tag| tag.eq_ignore_ascii_case("details"));
+        if !is_details || self.dom.attr(details, "open").is_some() {
+            return false;
+        }
+        let summary = self.dom.nodes[details.0]
+            .children
+            .iter()
+            .copied()
+            .find(|candidate| {
+                self.dom
+                    .tag_name(*candidate)
+                    .is_some_and(|tag| tag.eq_ignore_ascii_case("summary"))
+            });
+        summary != Some(child)
+    }
+
+    // Colors resolve to `rgb()`/`rgba()`, with `currentcolor` taking the
+    // element's own color (the inherited one for `color` itself).
+    fn computed_css_value(
+        &self,
+        node: NodeId,
+        pseudo: Option<&str>,
+        property: &str,
+        depth: usize,
+    ) -> String {
+        let value = self.specified_css_value(node, pseudo, property, depth);
+        if !Self::css_property_is_color(property) {
+            return value;
+        }
+        if value.eq_ignore_ascii_case("currentcolor") {
+            return if property == "color" {
+                self.inherited_css_value(node, pseudo, property, depth)
+            } else {
+                self.computed_css_value(node, pseudo, "color", depth)
+            };
+        }
+        Self::resolve_css_color(&value).unwrap_or(value)
+    }
+
+    fn specified_css_value(
+        &self,
+        node: NodeId,
+        pseudo: Option<&str>,
+        property: &str,
+        depth: usize,
+    ) -> String {
+        let declared = self
+            .cascaded_css_declarations(node, pseudo)
+            .remove(property)
+            .map(|declaration| declaration.value);
+        let inherited = css_property_is_inherited(property);
+        let Some(value) = declared else {
+            return if inherited {
+                self.inherited_css_value(node, pseudo, property, depth)
+            } else {
+                css_initial_value(property).to_string()
+            };
+        };
+        match value.to_ascii_lowercase().as_str() {
+            "inherit" => return self.inherited_css_value(node, pseudo, property, depth),
+            "initial" => return css_initial_value(property).to_string(),
+            "unset" | "revert" | "revert-layer" if inherited => {
+                return self.inherited_css_value(node, pseudo, property, depth);
+            }
+            "unset" | "revert" | "revert-layer" => return css_initial_value(property).to_string(),
+            _ => {}
+        }
+        if !value.contains("var(") {
+            return value;
+        }
+        // A reference that cannot be substituted makes the declaration invalid
+        // at computed-value time, which behaves like `unset`.
+        match self.substitute_css_vars(node, pseudo, &value, depth) {
+            Some(value) => value,
+            None if inherited => self.inherited_css_value(node, pseudo, property, depth),
+            None => css_initial_value(property).to_string(),
+        }
+    }
+
+    // Pseudo-elements inherit from their originating element.
+    fn inherited_css_value(
+        &self,
+        node: NodeId,
+        pseudo: Option<&str>,
+        property: &str,
+        depth: usize,
+    ) -> String {
+        let parent = match pseudo {
+            Some(_) => Some(node),
+            None => self
+                .dom
+                .parent(node)
+                .filter(|parent| self.dom.element(*parent).is_some()),
+        };
+        match parent {
+            Some(parent) => self.computed_css_value(parent, None, property, depth),
+            None => css_initial_value(property).to_string(),
+        }
+    }
+
+    fn substitute_css_vars(
+        &self,
+        node: NodeId,
+        pseudo: Option<&str>,
+        value: &str,
+        depth: usize,
+    ) -> Option<String> {
+        if depth >= MAX_CSS_VAR_DEPTH {
+            return None;
+        }
+        let mut out = String::new();
+        let mut rest = value;
+        while let Some(start) = rest.find("var(") {
+            out.push_str(&rest[..start]);
+            let args_start = start + "var(".len();
+            let args_len = css_matching_paren(&rest[args_start..])?;
+            let args = &rest[args_start..args_start + args_len];
+            let (name, fallback) = match css_top_level_comma(args) {
+                Some(comma) => (args[..comma].trim(), Some(args[comma + 1..].trim())),
+                None => (args.trim(), None),
+            };
+            let name = name.to_ascii_lowercase();
+            let resolved = self.computed_css_value(node, pseudo, &name, depth + 1);
+            if !resolved.is_empty() {
+                out.push_str(&resolved);
+            } else {
+                let fallback = fallback?;
+                if fallback.contains("var(") {
+                    out.push_str(&self.substitute_css_vars(node, pseudo, fallback, depth + 1)?);
+                } else {
+                    out.push_str(fallback);
+                }
+            }
+            rest = &rest[args_start + args_len + 1..];
+        }
+        out.push_str(rest);
+        Some(out.trim().to_string())
+    }
+
+    // The winning declaration for every property set on `node`: importance,
+    // then origin, then specificity, then source order.
+    fn cascaded_css_declarations(
+        &self,
+        node: NodeId,
+        pseudo: Option<&str>,
+    ) -> HashMap<String, CascadedDeclaration> {
+        let mut cascaded = HashMap::new();
+        let mut order = 0usize;
+        let pseudo = pseudo.map(normalize_pseudo_element);
+        for rule in self.parsed_style_rules().iter() {
+            if !rule
+                .media
+                .iter()
+                .all(|condition| self.media_condition_matches(condition))
+            {
+                continue;
+            }
+            let Some(specificity) =
+                self.css_rule_match_specificity(node, pseudo.as_deref(), &rule.selectors)
+            else {
+                continue;
+            };
+            let origin = if rule.author {
+                CascadeOrigin::Author
+            } else {
+                CascadeOrigin::UserAgent
+            };
+            for (name, value) in &rule.declarations {
+                order += 1;
+                apply_cascaded_declaration(
+                    &mut cascaded,
+                    name.clone(),
+                    value.clone(),
+                    origin,
+                    specificity,
+                    order,
+                );
+            }
+        }
+        if pseudo.is_none() {
+            if let Some(style) = self.dom.attr(node, "style") {
+                for (name, value) in expand_css_declarations(&style) {
+                    order += 1;
+                    apply_cascaded_declaration(
+                        &mut cascaded,
+                        name,
+                        value,
+                        CascadeOrigin::Inline,
+                        (0, 0, 0),
+                        order,
+                    );
+                }
+            }
+        }
+        cascaded
+    }
+
+    // The highest specificity among the rule's selectors that match `node`.
+    fn css_rule_match_specificity(
+        &self,
+        node: NodeId,
+        pseudo: Option<&str>,
+        selectors: &[CachedStyleSelector],
+    ) -> Option<(u32, u32, u32)> {
+        selectors
+            .iter()
+            .filter(|selector| selector.pseudo.as_deref() == pseudo)
+            .filter(|selector| {
+                selector
+                    .chain
+                    .as_ref()
+                    .is_none_or(|chain| self.dom.matches_selector_chain(node, chain))
+            })
+            .map(|selector| selector.specificity)
+            .max()
+    }
+
+    // Re-parsing every sheet per query dominates style lookups, so the parsed
+    // rules are reused until a style element, linked sheet or adopted sheet
+    // changes what gets gathered.
+    fn parsed_style_rules(&self) -> Rc<Vec<CachedStyleRule>> {
+        let sources = self.author_style_sheet_sources();
+        let mut cache = self.dom_runtime.style_sheet_cache.borrow_mut();
+        if cache.rules.is_empty() || cache.author_sources != sources {
+            let mut rules = Vec::new();
+            let sheets = std::iter::once((false, USER_AGENT_STYLE_SHEET))
+                .chain(sources.iter().map(|source| (true, source.as_str())));
+            for (author, source) in sheets {
+                collect_css_style_rules(
+                    &strip_css_comments(source),
+                    author,
+                    &mut Vec::new(),
+                    &mut rules,
+                );
+            }
+            cache.rules = Rc::new(rules);
+            cache.author_sources = sources;
+        }
+        cache.rules.clone()
+    }
+
+    fn author_style_sheet_sources(&self) -> Vec<String> {
+        let mut elements = Vec::new();
+        self.dom.collect_elements_dfs(self.dom.root, &mut elements);
+        let mut sources = Vec::new();
+        for node in elements {
+            let source = match self.dom.tag_name(node) {
+                Some(tag) if tag.eq_ignore_ascii_case("style") => self.dom.text_content(node),
+                _ => match self.dom_runtime.linked_stylesheets.get(&node) {
+                    Some(source) => source.clone(),
+                    None => continue,
+                },
+            };
+            let in_template =
+                std::iter::successors(self.dom.parent(node), |parent| self.dom.parent(*parent))
+                    .any(|ancestor| {
+                        self.dom
+                            .tag_name(ancestor)
+                            .is_some_and(|tag| tag.eq_ignore_ascii_case("template"))
+                    });
+            let media_matches = self
+                .dom
+                .attr(node, "media")
+                .is_none_or(|media| self.media_condition_matches(&media));
+            if !in_template && media_matches {
+                sources.push(source);
+            }
+        }
+
+        // Adopted sheets come after the document's own sheets.
+        let adopted = Self::object_get_entry(
+            &self.dom_runtime.document_object.borrow(),
+            "adoptedStyleSheets",
+        );
+        if let Some(Value::Array(sheets)) = adopted {
+            for sheet in sheets.borrow().iter() {
+                let Value::Object(sheet) = sheet else {
+                    continue;
+                };
+                let rules =
+                    Self::object_get_entry(&sheet.borrow(), INTERNAL_CSS_STYLE_SHEET_RULES_KEY);
+                if let Some(Value::Array(rules)) = rules {
+                    sources.extend(rules.borrow().iter().map(Value::as_string));
+                }
+            }
+        }
+        sources
+    }
+}
+
+// Flattens a style sheet into rules, recording the conditions of the `@media`
+// blocks around each one. `@supports` and `@layer` blocks are kept; other
+// at-rules are dropped.
+fn collect_css_style_rules(
+    css: &str,
+    author: bool,
+    media: &mut Vec<String>,
+    out: &mut Vec<CachedStyleRule>,
+) {
+    for (prelude, body) in css_top_level_blocks(css) {
+        let Some(at_rule) = prelude.strip_prefix('@') else {
+            if let Some(rule) = parse_css_style_rule(prelude, body, author, media) {
+                out.push(rule);
+            }
+            continue;
+        };
+        let (name, condition) = at_rule
+            .split_once(|ch: char| ch.is_whitespace() || ch == '(')
+            .map(|(name, _)| (name, &at_rule[name.len()..]))
+            .unwrap_or((at_rule, ""));
+        match name.to_ascii_lowercase().as_str() {
+            "media" => {
+                media.push(condition.to_string());
+                collect_css_style_rules(body, author, media, out);
+                media.pop();
+            }
+            "supports" | "layer" => collect_css_style_rules(body, author, media, out),
+            _ => {}
+        }
+    }
+}
+
+// Selectors that fail to parse are dropped, as is the whole rule when its
+// selector list cannot be split.
+fn parse_css_style_rule(
+    selector_text: &str,
+    body: &str,
+    author: bool,
+    media: &[String],
+) -> Option<CachedStyleRule> {
+    let selectors = split_selector_groups(selector_text)
+        .ok()?
+        .iter()
+        .filter_map(|selector| {
+            let (base, pseudo) = split_selector_pseudo_element(selector);
+            let (chain, mut specificity) = if base.is_empty() {
+                (None, (0, 0, 0))
+            } else {
+                let chain = parse_selector_chain(base).ok()?;
+                let specificity = selector_specificity(&chain);
+                (Some(chain), specificity)
+            };
+            if pseudo.is_some() {
+                specificity.2 += 1;
+            }
+            Some(CachedStyleSelector {
+                chain,
+                pseudo,
+                specificity,
+            })
+        })
+        .collect();
+    Some(CachedStyleRule {
+        author,
+        media: media.to_vec(),
+        selectors,
+        declarations: expand_css_declarations(body),
+    })
+}
+
+fn apply_cascaded_declaration(
+    cascaded: &mut HashMap<String, CascadedDeclaration>,
+    name: String,
+    value: String,
+    origin: CascadeOrigin,
+    specificity: (u32, u32, u32),
+    order: usize,
+) {
+    let (value, important) = split_css_important(&value);
+    let declaration = CascadedDeclaration {
+        value,
+        important,
+        origin,
+        specificity,
+        order,
+    };
+    let wins = cascaded
+        .get(&name)
+        .is_none_or(|current| declaration.precedence() > current.precedence());
+    if wins {
+        cascaded.insert(name, declaration);
+    }
+}
+
+fn split_css_important(value: &str) -> (String, bool) {
+    let trimmed = value.trim();
+    if let Some(bang) = trimmed.rfind('!') {
+        if trimmed[bang + 1..].trim().eq_ignore_ascii_case("important") {
+            return (trimmed[..bang].trim().to_string(), true);
+        }
+    }
+    (trimmed.to_string(), false)
+}
+
+// Declarations in source order, with the box shorthands also written to
+// their longhands.
+fn expand_css_declarations(text: &str) -> Vec<(String, String)> {
+    let mut out = Vec::new();
+    for (name, value) in parse_style_declaration_list(text) {
+        let (bare_value, important) = split_css_important(&value);
+        let suffix = if important { " !important" } else { "" };
+        let sides = match name.as_str() {
+            "margin" | "padding" => Some([
+                format!("{name}-top"),
+                format!("{name}-right"),
+                format!("{name}-bottom"),
+                format!("{name}-left"),
+            ]),
+            _ => None,
+        };
+        if let Some(sides) = sides {
+            let parts = split_css_component_values(&bare_value);
+            let picked = match parts.as_slice() {
+                [all] => Some([all, all, all, all]),
+                [block, inline] => Some([block, inline, block, inline]),
+                [top, inline, bottom] => Some([top, inline, bottom, inline]),
+                [top, right, bottom, left] => Some([top, right, bottom, left]),
+                _ => None,
+            };
+            if let Some(picked) = picked {
+                for (side, part) in sides.into_iter().zip(picked) {
+                    out.push((side, format!("{part}{suffix}")));
+                }
+            }
+        }
+        if name == "overflow" {
+            let parts = split_css_component_values(&bare_value);
+            if let [x] | [x, _] = parts.as_slice() {
+                let y = parts.get(1).unwrap_or(x);
+                out.push(("overflow-x".to_string(), format!("{x}{suffix}")));
+                out.push(("overflow-y".to_string(), format!("{y}{suffix}")));
+            }
+        }
+        out.push((name, value));
+    }
+    out
+}
+
+fn split_css_component_values(value: &str) -> Vec<String> {
+    let mut parts = Vec::new();
+    let mut current = String::new();
+    let mut depth = 0usize;
+    for ch in value.chars() {
+        match ch {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            ch if ch.is_whitespace() && depth == 0 => {
+                if !current.is_empty() {
+                    parts.push(std::mem::take(&mut current));
+                }
+                continue;
+            }
+            _ => {}
+        }
+        current.push(ch);
+    }
+    if !current.is_empty() {
+        parts.push(current);
+    }
+    parts
+}
+
+fn strip_css_comments(css: &str) -> String {
+    let mut out = String::with_capacity(css.len());
+    let mut rest = css;
+    while let Some(start) = rest.find("/*") {
+        out.push_str(&rest[..start]);
+        match rest[start + 2..].find("*/") {
+            Some(end) => rest = &rest[start + 2 + end + 2..],
+            None => return out,
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+// (prelude, body) for every `{ ... }` block at the top level of `css`.
+// Statements ending in `;` before a block, like `@import`, are skipped.
+fn css_top_level_blocks(css: &str) -> Vec<(&str, &str)> {
+    let bytes = css.as_bytes();
+    let mut blocks = Vec::new();
+    let mut prelude_start = 0usize;
+    let mut cursor = 0usize;
+    let mut quote = None;
+    while cursor < bytes.len() {
+        let ch = bytes[cursor];
+        match (quote, ch) {
+            (Some(_), b'\\') => cursor += 1,
+            (Some(q), _) if q == ch => quote = None,
+            (Some(_), _) => {}
+            (None, b'"' | b'\'') => quote = Some(ch),
+            (None, b';') => prelude_start = cursor + 1,
+            (None, b'{') => {
+                let Some(body_len) = css_matching_brace(&css[cursor + 1..]) else {
+                    break;
+                };
+                let prelude = css[prelude_start..cursor].trim();
+                let body = &css[cursor + 1..cursor + 1 + body_len];
+                if !prelude.is_empty() {
+                    blocks.push((prelude, body));
+                }
+                cursor += body_len + 2;
+                prelude_start = cursor;
+                continue;
+            }
+            _ => {}
+        }
+        cursor += 1;
+    }
+    blocks
+}
+
+// Length of `text` up to the `}` that closes an already opened block.
+fn css_matching_brace(text: &str) -> Option<usize> {
+    css_matching_close(text, b'{', b'}')
+}
+
+fn css_matching_paren(text: &str) -> Option<usize> {
+    css_matching_close(text, b'(', b')')
+}
+
+fn css_matching_close(text: &str, open: u8, close: u8) -> Option<usize> {
+    let mut depth = 1usize;
+    let mut quote = None;
+    let bytes = text.as_bytes();
+    let mut index = 0usize;
+    while index < bytes.len() {
+        let ch = bytes[index];
+        match (quote, ch) {
+            (Some(_), b'\\') => index += 1,
+            (Some(q), _) if q == ch => quote = None,
+            (Some(_), _) => {}
+            (None, b'"' | b'\'') => quote = Some(ch),
+            (None, ch) if ch == open => depth += 1,
+            (None, ch) if ch == close => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(index);
+                }
+            }
+            _ => {}
+        }
+        index += 1;
+    }
+    None
+}
+
+fn css_top_level_comma(text: &str) -> Option<usize> {
+    let mut depth = 0usize;
+    for (index, ch) in text.char_indices() {
+        match ch {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => return Some(index),
+            _ => {}
+        }
+    }
+    None
+}
+
+// `::before` and the legacy `:before` both normalize to `before`.
+fn normalize_pseudo_element(pseudo: &str) -> String {
+    pseudo.trim().trim_start_matches(':').to_ascii_lowercase()
+}
+
+fn split_selector_pseudo_element(selector: &str) -> (&str, Option<String>) {
+    let selector = selector.trim();
+    if let Some(position) = selector.find("::") {
+        let pseudo = normalize_pseudo_element(&selector[position..]);
+        return (selector[..position].trim_end(), Some(pseudo));
+    }
+    for legacy in [":before", ":after", ":first-line", ":first-letter"] {
+        if selector.len() >= legacy.len()
+            && selector[selector.len() - legacy.len()..].eq_ignore_ascii_case(legacy)
+        {
+            let base = &selector[..selector.len() - legacy.len()];
+            return (base.trim_end(), Some(normalize_pseudo_element(legacy)));
+        }
+    }
+    (selector, None)
+}
+
+fn css_property_is_inherited(property: &str) -> bool {
+    property.starts_with("--")
+        || matches!(
+            property,
+            "border-collapse"
+                | "border-spacing"
+                | "caption-side"
+                | "caret-color"
+                | "color"
+                | "cursor"
+                | "direction"
+                | "empty-cells"
+                | "font"
+                | "font-family"
+                | "font-size"
+                | "font-style"
+                | "font-variant"
+                | "font-weight"
+                | "hyphens"
+                | "letter-spacing"
+                | "line-height"
+                | "list-style"
+                | "list-style-image"
+                | "list-style-position"
+                | "list-style-type"
+                | "orphans"
+                | "overflow-wrap"
+                | "pointer-events"
+                | "quotes"
+                | "tab-size"
+                | "text-align"
+                | "text-indent"
+                | "text-shadow"
+                | "text-transform"
+                | "visibility"
+                | "white-space"
+                | "widows"
+                | "word-break"
+                | "word-spacing"
+                | "writing-mode"
+        )
+}
+
+// Initial values as browsers report them from `getComputedStyle`; properties
+// outside this table compute to an empty string when nothing sets them.
+fn css_initial_value(property: &str) -> &'static str {
+    match property {
+        "display" => "inline",
+        "visibility" => "visible",
+        "position" => "static",
+        "float" | "clear" => "none",
+        "opacity" => "1",
+        "color" => "rgb(0, 0, 0)",
+        "background-color" => "rgba(0, 0, 0, 0)",
+        "background-image" | "transform" | "max-width" | "max-height" => "none",
+        "font-style" | "font-variant" | "line-height" | "letter-spacing" | "white-space"
+        | "word-break" | "justify-content" | "align-items" | "align-content" | "content" => {
+            "normal"
+        }
+        "font-weight" => "400",
+        "font-size" => "16px",
+        "text-align" => "start",
+        "text-transform" | "text-decoration-line" | "text-shadow" | "box-shadow" => "none",
+        "text-indent" | "word-spacing" => "0px",
+        "cursor" | "pointer-events" | "z-index" | "width" | "height" | "min-width"
+        | "min-height" | "top" | "right" | "bottom" | "left" | "user-select" => "auto",
+        "overflow" | "overflow-x" | "overflow-y" | "content-visibility" => "visible",
+        "box-sizing" => "content-box",
+        "margin-top" | "margin-right" | "margin-bottom" | "margin-left" | "padding-top"
+        | "padding-right" | "padding-bottom" | "padding-left" => "0px",
+        "border-top-style"
+        | "border-right-style"
+        | "border-bottom-style"
+        | "border-left-style"
+        | "outline-style" => "none",
+        "list-style-type" => "disc",
+        "list-style-position" => "outside",
+        "vertical-align" => "baseline",
+        "direction" => "ltr",
+        "flex-direction" => "row",
+        "flex-wrap" => "nowrap",
+        "flex-grow" => "0",
+        "flex-shrink" => "1",
+        "flex-basis" => "auto",
+        "order" => "0",
+        "writing-mode" => "horizontal-tb",
+        "border-collapse" => "separate",
+        _ => "",
+    }
+}
@@ -2173,6 +2173,10 @@ impl Harness {
                 INTERNAL_CALLABLE_KIND_KEY.to_string(),
                 Value::String("event_constructor".to_string()),
             ),
+            (
+                INTERNAL_EVENT_INTERFACE_KEY.to_string(),
+                Value::String("Event".to_string()),
+            ),
             ("prototype".to_string(), prototype.clone()),
         ]);
         if let Value::Object(prototype_entries) = &prototype {
@@ -2192,6 +2196,10 @@ impl Harness {
                 INTERNAL_CALLABLE_KIND_KEY.to_string(),
                 Value::String("custom_event_constructor".to_string()),
             ),
+            (
+                INTERNAL_EVENT_INTERFACE_KEY.to_string(),
+                Value::String("CustomEvent".to_string()),
+            ),
             ("prototype".to_string(), prototype.clone()),
         ]);
         if let Value::Object(prototype_entries) = &prototype {
@@ -2211,6 +2219,10 @@ impl Harness {
                 INTERNAL_CALLABLE_KIND_KEY.to_string(),
                 Value::String("mouse_event_constructor".to_string()),
             ),
+            (
+                INTERNAL_EVENT_INTERFACE_KEY.to_string(),
+                Value::String("MouseEvent".to_string()),
+            ),
             ("prototype".to_string(), prototype.clone()),
         ]);
         if let Value::Object(prototype_entries) = &prototype {
@@ -2230,6 +2242,10 @@ impl Harness {
                 INTERNAL_CALLABLE_KIND_KEY.to_string(),
                 Value::String("keyboard_event_constructor".to_string()),
             ),
+            (
+                INTERNAL_EVENT_INTERFACE_KEY.to_string(),
+                Value::String("KeyboardEvent".to_string()),
+            ),
             ("prototype".to_string(), prototype.clone()),
             ("DOM_KEY_LOCATION_STANDARD".to_string(), Value::Number(0x00)),
             ("DOM_KEY_LOCATION_LEFT".to_string(), Value::Number(0x01)),
@@ -2253,6 +2269,10 @@ impl Harness {
                 INTERNAL_CALLABLE_KIND_KEY.to_string(),
                 Value::String("wheel_event_constructor".to_string()),
             ),
+            (
+                INTERNAL_EVENT_INTERFACE_KEY.to_string(),
+                Value::String("WheelEvent".to_string()),
+            ),
             ("prototype".to_string(), prototype.clone()),
             ("DOM_DELTA_PIXEL".to_string(), Value::Number(0)),
             ("DOM_DELTA_LINE".to_string(), Value::Number(1)),
@@ -2275,6 +2295,10 @@ impl Harness {
                 INTERNAL_CALLABLE_KIND_KEY.to_string(),
                 Value::String("navigate_event_constructor".to_string()),
             ),
+            (
+                INTERNAL_EVENT_INTERFACE_KEY.to_string(),
+                Value::String("NavigateEvent".to_string()),
+            ),
             ("prototype".to_string(), prototype.clone()),
         ]);
         if let Value::Object(prototype_entries) = &prototype {
@@ -2294,6 +2318,10 @@ impl Harness {
                 INTERNAL_CALLABLE_KIND_KEY.to_string(),
                 Value::String("pointer_event_constructor".to_string()),
             ),
+            (
+                INTERNAL_EVENT_INTERFACE_KEY.to_string(),
+                Value::String("PointerEvent".to_string()),
+            ),
             ("prototype".to_string(), prototype.clone()),
         ]);
         if let Value::Object(prototype_entries) = &prototype {
@@ -2313,6 +2341,10 @@ impl Harness {
                 INTERNAL_CALLABLE_KIND_KEY.to_string(),
                 Value::String("error_event_constructor".to_string()),
             ),
+            (
+                INTERNAL_EVENT_INTERFACE_KEY.to_string(),
+                Value::String("ErrorEvent".to_string()),
+            ),
             ("prototype".to_string(), prototype.clone()),
         ]);
         if let Value::Object(prototype_entries) = &prototype {
@@ -2332,6 +2364,10 @@ impl Harness {
                 INTERNAL_CALLABLE_KIND_KEY.to_string(),
                 Value::String("hash_change_event_constructor".to_string()),
             ),
+            (
+                INTERNAL_EVENT_INTERFACE_KEY.to_string(),
+                Value::String("HashChangeEvent".to_string()),
+            ),
             ("prototype".to_string(), prototype.clone()),
         ]);
         if let Value::Object(prototype_entries) = &prototype {
@@ -2351,6 +2387,10 @@ impl Harness {
                 INTERNAL_CALLABLE_KIND_KEY.to_string(),
                 Value::String("before_unload_event_constructor".to_string()),
             ),
+            (
+                INTERNAL_EVENT_INTERFACE_KEY.to_string(),
+                Value::String("BeforeUnloadEvent".to_string()),
+            ),
             ("prototype".to_string(), prototype.clone()),
         ]);
         if let Value::Object(prototype_entries) = &prototype {
@@ -2525,6 +2565,13 @@ impl Harness {
         )])
     }
 
+    pub(crate) fn new_window_open_callable_value() -> Value {
+        Self::new_object_value(vec![(
+            INTERNAL_CALLABLE_KIND_KEY.to_string(),
+            Value::String("window_open_function".to_string()),
+        )])
+    }
+
     pub(crate) fn new_window_get_computed_style_callable_value() -> Value {
         Self::new_object_value(vec![(
             INTERNAL_CALLABLE_KIND_KEY.to_string(),
@@ -2539,6 +2586,49 @@ impl Harness {
         )])
     }
 
+    pub(crate) fn new_console_object_value() -> Value {
+        let methods = [
+            "assert",
+            "clear",
+            "count",
+            "countReset",
+            "debug",
+            "dir",
+            "error",
+            "group",
+            "groupCollapsed",
+            "groupEnd",
+            "info",
+            "log",
+            "table",
+            "time",
+            "timeEnd",
+            "timeLog",
+            "trace",
+            "warn",
+        ];
+        Self::new_object_value(
+            methods
+                .into_iter()
+                .map(|method| {
+                    (
+                        method.to_string(),
+                        Self::new_object_value(vec![
+                            (
+                                INTERNAL_CALLABLE_KIND_KEY.to_string(),
+                                Value::String("console_method".to_string()),
+                            ),
+                            (
+                                INTERNAL_STATIC_METHOD_NAME_KEY.to_string(),
+                                Value::String(method.to_string()),
+                            ),
+                        ]),
+                    )
+                })
+                .collect(),
+        )
+    }
+
     pub(crate) fn new_window_confirm_callable_value() -> Value {
         Self::new_object_value(vec![(
             INTERNAL_CALLABLE_KIND_KEY.to_string(),
@@ -3209,138 +3299,6 @@ impl Harness {
         }
     }
 
-    fn computed_style_rule_value_from_style_nodes(
-        &self,
-        node: NodeId,
-        pseudo: Option<&str>,
-        property_name: &str,
-    ) -> Option<String> {
-        let mut resolved = None;
-        for index in 0..self.dom.nodes.len() {
-            let node_id = NodeId(index);
-            let is_style_tag = self
-                .dom
-                .tag_name(node_id)
-                .is_some_and(|tag| tag.eq_ignore_ascii_case("style"));
-            if !is_style_tag {
-                continue;
-            }
-            let css_source = self.dom.text_content(node_id);
-            for (selector_text, declarations_text) in Self::parse_css_rule_blocks(&css_source) {
-                for selector in selector_text.split(',').map(str::trim) {
-                    if selector.is_empty() {
-                        continue;
-                    }
-                    let (base_selector, selector_pseudo) =
-                        Self::split_selector_and_pseudo(selector);
-
-                    let pseudo_matches = match (pseudo, selector_pseudo.as_deref()) {
-                        (None, None) => true,
-                        (Some(expected), Some(actual)) => actual.eq_ignore_ascii_case(expected),
-                        _ => false,
-                    };
-                    if !pseudo_matches {
-                        continue;
-                    }
-
-                    let selector_matches = if base_selector.is_empty() || base_selector == "*" {
-                        true
-                    } else {
-                        matches!(
-                            self.eval_matches_selector_value(node, base_selector),
-                            Ok(Value::Bool(true))
-                        )
-                    };
-                    if !selector_matches {
-                        continue;
-                    }
-
-                    for (name, value) in parse_style_declarations(Some(declarations_text)) {
-                        if name == property_name {
-                            resolved = Some(value);
-                        }
-                    }
-                }
-            }
-        }
-        resolved
-    }
-
-    fn split_selector_and_pseudo(selector: &str) -> (&str, Option<String>) {
-        let normalized = selector.trim();
-        let Some(pseudo_pos) = normalized.find("::") else {
-            return (normalized, None);
-        };
-        let base = normalized[..pseudo_pos].trim_end();
-        let pseudo = normalized[pseudo_pos..].trim();
-        (base, Some(pseudo.to_string()))
-    }
-
-    fn parse_css_rule_blocks(css_source: &str) -> Vec<(&str, &str)> {
-        let bytes = css_source.as_bytes();
-        let mut blocks = Vec::new();
-        let mut cursor = 0usize;
-        let mut selector_start = 0usize;
-        while cursor < bytes.len() {
-            if bytes[cursor] != b'{' {
-                cursor += 1;
-                continue;
-            }
-            let selector_end = cursor;
-            cursor += 1;
-            let declarations_start = cursor;
-            let mut depth = 1usize;
-            while cursor < bytes.len() && depth > 0 {
-                match bytes[cursor] {
-                    b'{' => depth += 1,
-                    b'}' => depth -= 1,
-                    _ => {}
-                }
-                cursor += 1;
-            }
-            if depth != 0 || cursor == 0 {
-                break;
-            }
-            let declarations_end = cursor.saturating_sub(1);
-            let selector = css_source[selector_start..selector_end].trim();
-            let declarations = css_source[declarations_start..declarations_end].trim();
-            if !selector.is_empty() && !declarations.is_empty() {
-                blocks.push((selector, declarations));
-            }
-            selector_start = cursor;
-        }
-        blocks
-    }
-
-    pub(crate) fn computed_style_property_value(
-        &self,
-        node: NodeId,
-        pseudo: Option<&str>,
-        property_name: &str,
-    ) -> Result<String> {
-        if self.dom.element(node).is_none() {
-            return Err(Error::ScriptRuntime(
-                "TypeError: getComputedStyle target must be an Element".into(),
-            ));
-        }
-        let css_property = js_prop_to_css_name(property_name.trim());
-
-        if pseudo.is_none() {
-            let inline = self.dom.style_get(node, &css_property)?;
-            if !inline.is_empty() {
-                return Ok(inline);
-            }
-        }
-
-        if let Some(from_rules) =
-            self.computed_style_rule_value_from_style_nodes(node, pseudo, &css_property)
-        {
-            return Ok(from_rules);
-        }
-
-        Ok(String::new())
-    }
-
     pub(crate) fn computed_style_object_property_from_entries(
         &self,
         entries: &[(String, Value)],
@@ -4551,6 +4509,7 @@ impl Harness {
                 "error_event_constructor" => "error_event_constructor",
                 "hash_change_event_constructor" => "hash_change_event_constructor",
                 "before_unload_event_constructor" => "before_unload_event_constructor",
+                "event_interface_constructor" => "event_interface_constructor",
                 "image_data_constructor" => "image_data_constructor",
                 "dom_parser_constructor" => "dom_parser_constructor",
                 "document_constructor" => "document_constructor",
@@ -4568,8 +4527,33 @@ impl Harness {
                 "window_resize_by_function" => "window_resize_by_function",
                 "window_resize_to_function" => "window_resize_to_function",
                 "window_post_message_function" => "window_post_message_function",
+                "window_open_function" => "window_open_function",
+                "window_proxy_close_function" => "window_proxy_close_function",
                 "window_get_computed_style_function" => "window_get_computed_style_function",
                 "window_alert_function" => "window_alert_function",
+                "console_method" => "console_method",
+                "abort_controller_constructor" => "abort_controller_constructor",
+                "abort_signal_constructor" => "abort_signal_constructor",
+                "abort_signal_static_method" => "abort_signal_static_method",
+                "dom_exception_constructor" => "dom_exception_constructor",
+                "intersection_observer_constructor" => "intersection_observer_constructor",
+                "intersection_observer_entry_constructor" => {
+                    "intersection_observer_entry_constructor"
+                }
+                "resize_observer_constructor" => "resize_observer_constructor",
+                "resize_observer_entry_constructor" => "resize_observer_entry_constructor",
+                "layout_observer_observe" => "layout_observer_observe",
+                "layout_observer_unobserve" => "layout_observer_unobserve",
+                "layout_observer_disconnect" => "layout_observer_disconnect",
+                "layout_observer_take_records" => "layout_observer_take_records",
+                "custom_elements_define" => "custom_elements_define",
+                "custom_elements_get" => "custom_elements_get",
+                "custom_elements_when_defined" => "custom_elements_when_defined",
+                "custom_elements_upgrade" => "custom_elements_upgrade",
+                "mutation_observer_constructor" => "mutation_observer_constructor",
+                "mutation_observer_observe" => "mutation_observer_observe",
+                "mutation_observer_disconnect" => "mutation_observer_disconnect",
+                "mutation_observer_take_records" => "mutation_observer_take_records",
                 "window_confirm_function" => "window_confirm_function",
                 "window_print_function" => "window_print_function",
                 "window_report_error_function" => "window_report_error_function",
@@ -4588,6 +4572,7 @@ impl Harness {
                 "text_encoder_stream_constructor" => "text_encoder_stream_constructor",
                 "text_decoder_stream_constructor" => "text_decoder_stream_constructor",
                 "text_encoder_get_encoding" => "text_encoder_get_encoding",
+                "module_namespace_get_export" => "module_namespace_get_export",
                 "text_encoder_encode" => "text_encoder_encode",
                 "text_encoder_encode_into" => "text_encoder_encode_into",
                 "text_decoder_get_encoding" => "text_decoder_get_encoding",
@@ -5130,6 +5115,9 @@ impl Harness {
     }
 
     fn object_property_from_node_value(&mut self, node: &NodeId, key: &str) -> Result<Value> {
+        if let Some(value) = self.custom_element_member(*node, key)? {
+            return Ok(value);
+        }
         let is_canvas = self
             .dom
             .tag_name(*node)
@@ -5194,6 +5182,10 @@ impl Harness {
             }
         }
 
+        if let Some(value) = self.character_data_or_doctype_property(*node, key) {
+            return Ok(value);
+        }
+
         match key {
             "nodeType" => Ok(Value::Number(self.node_type_number(*node))),
             "nodeName" => Ok(Value::String(self.node_name(*node))),
@@ -6246,6 +6238,20 @@ impl Harness {
                 .document_element()
                 .map(Value::Node)
                 .unwrap_or(Value::Null),
+            "doctype" => self.dom.doctype().map(Value::Node).unwrap_or(Value::Null),
+            "childNodes" => self.child_nodes_live_list_value(self.dom.root),
+            "firstChild" => self.dom.nodes[self.dom.root.0]
+                .children
+                .first()
+                .copied()
+                .map(Value::Node)
+                .unwrap_or(Value::Null),
+            "lastChild" => self.dom.nodes[self.dom.root.0]
+                .children
+                .last()
+                .copied()
+                .map(Value::Node)
+                .unwrap_or(Value::Null),
             "readyState" => Value::String(self.dom_runtime.document_ready_state.clone()),
             "cookie" => Value::String(self.document_cookie_string()),
             "hidden" => Value::Bool(self.dom_runtime.document_visibility_state == "hidden"),
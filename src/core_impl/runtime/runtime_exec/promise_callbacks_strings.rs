@@ -4,6 +4,8 @@ mod array_callback_helpers;
 mod callback_typed_array_methods;
 mod date_time_utils;
 mod dom_query_runtime_resolution;
+mod import_maps;
+mod module_graph;
 mod numeric_bitwise_coercion;
 mod promise_reactions;
 mod scheduler_script_runtime;
@@ -1,8 +1,30 @@
 use super::*;
 
+const DATE_WEEKDAY_NAMES: [&str; 7] = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
+const DATE_MONTH_NAMES: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
 impl Harness {
     pub(crate) fn parse_date_string_to_epoch_ms(src: &str) -> Option<i64> {
+        Self::parse_date_string_fields(src).map(|(timestamp_ms, _)| timestamp_ms)
+    }
+
+    // Date-time strings without an offset are local time; date-only forms and
+    // everything else are read as given.
+    pub(crate) fn parse_date_string_in_time_zone(&self, src: &str) -> Option<i64> {
+        let (timestamp_ms, is_wall_clock) = Self::parse_date_string_fields(src)?;
+        if is_wall_clock {
+            return Some(self.local_time_to_utc_ms(timestamp_ms));
+        }
+        Some(timestamp_ms)
+    }
+
+    fn parse_date_string_fields(src: &str) -> Option<(i64, bool)> {
         let src = src.trim();
+        if let Some(timestamp_ms) = Self::parse_date_display_string(src) {
+            return Some((timestamp_ms, false));
+        }
         if src.is_empty() {
             return None;
         }
@@ -52,6 +74,7 @@ impl Harness {
         let mut second = 0i64;
         let mut millisecond = 0i64;
         let mut offset_minutes = 0i64;
+        let mut is_wall_clock = false;
 
         if i < bytes.len() {
             if bytes[i] != b'T' && bytes[i] != b' ' {
@@ -117,6 +140,8 @@ impl Harness {
                     }
                     _ => return None,
                 }
+            } else {
+                is_wall_clock = true;
             }
         }
 
@@ -136,6 +161,61 @@ impl Harness {
             second,
             millisecond,
         );
+        Some((timestamp_ms - offset_minutes * 60_000, is_wall_clock))
+    }
+
+    // Reads the `toString()` and `toUTCString()` forms back, e.g.
+    // `Tue Mar 10 2026 14:05:00 GMT-0400 (Eastern Daylight Time)` or
+    // `Tue, 10 Mar 2026 18:05:00 GMT`.
+    fn parse_date_display_string(src: &str) -> Option<i64> {
+        let src = src.split_once(" (").map_or(src, |(head, _)| head);
+        let tokens = src
+            .split(|ch: char| ch.is_ascii_whitespace() || ch == ',')
+            .filter(|token| !token.is_empty())
+            .collect::<Vec<_>>();
+        let [weekday, first, second, year, time, zone] = tokens.as_slice() else {
+            return None;
+        };
+        if !DATE_WEEKDAY_NAMES.contains(weekday) {
+            return None;
+        }
+        let month_index = |name: &str| DATE_MONTH_NAMES.iter().position(|month| *month == name);
+        let (month, day) = match (month_index(first), month_index(second)) {
+            (Some(month), None) => (month, second.parse::<i64>().ok()?),
+            (None, Some(month)) => (month, first.parse::<i64>().ok()?),
+            _ => return None,
+        };
+        let year = year.parse::<i64>().ok()?;
+        let mut time_fields = time.split(':').map(|field| field.parse::<i64>().ok());
+        let hour = time_fields.next()??;
+        let minute = time_fields.next()??;
+        let second = time_fields.next().unwrap_or(Some(0))?;
+        let offset = zone
+            .strip_prefix("GMT")
+            .or_else(|| zone.strip_prefix("UTC"))?;
+        let offset_minutes = if offset.is_empty() {
+            0
+        } else {
+            let sign = match offset.as_bytes()[0] {
+                b'+' => 1,
+                b'-' => -1,
+                _ => return None,
+            };
+            let digits = &offset[1..];
+            if digits.len() != 4 || !digits.bytes().all(|byte| byte.is_ascii_digit()) {
+                return None;
+            }
+            sign * (digits[..2].parse::<i64>().ok()? * 60 + digits[2..].parse::<i64>().ok()?)
+        };
+        let timestamp_ms = Self::utc_timestamp_ms_from_components(
+            year,
+            month as i64,
+            day,
+            hour,
+            minute,
+            second,
+            0,
+        );
         Some(timestamp_ms - offset_minutes * 60_000)
     }
 
@@ -175,6 +255,86 @@ impl Harness {
         (year, month, day, hour, minute, second, millisecond)
     }
 
+    pub(crate) fn local_time_offset_ms(&self, timestamp_ms: i64) -> i64 {
+        Self::time_zone_offset_at(&self.scheduler.time_zone, timestamp_ms).seconds * 1_000
+    }
+
+    pub(crate) fn date_components_local(
+        &self,
+        timestamp_ms: i64,
+    ) -> (i64, u32, u32, u32, u32, u32, u32) {
+        Self::date_components_utc(
+            timestamp_ms.saturating_add(self.local_time_offset_ms(timestamp_ms)),
+        )
+    }
+
+    pub(crate) fn local_time_to_utc_ms(&self, local_ms: i64) -> i64 {
+        Self::time_zone_local_to_utc_ms(&self.scheduler.time_zone, local_ms)
+    }
+
+    pub(crate) fn weekday_from_timestamp_ms(timestamp_ms: i64) -> usize {
+        (timestamp_ms.div_euclid(86_400_000) + 4).rem_euclid(7) as usize
+    }
+
+    // `Date.prototype.toString` and friends: `Tue Mar 10 2026`,
+    // `14:05:00 GMT-0400 (Eastern Daylight Time)`.
+    pub(crate) fn format_date_display_string(
+        &self,
+        timestamp_ms: i64,
+        with_date: bool,
+        with_time: bool,
+    ) -> String {
+        if timestamp_ms == INVALID_DATE_TIME_MS {
+            return "Invalid Date".to_string();
+        }
+        let offset = Self::time_zone_offset_at(&self.scheduler.time_zone, timestamp_ms);
+        let local_ms = timestamp_ms.saturating_add(offset.seconds * 1_000);
+        let (year, month, day, hour, minute, second, _) = Self::date_components_utc(local_ms);
+        let mut parts = Vec::with_capacity(2);
+        if with_date {
+            let year = if year >= 0 {
+                format!("{year:04}")
+            } else {
+                format!("-{:04}", -(year as i128))
+            };
+            parts.push(format!(
+                "{} {} {day:02} {year}",
+                DATE_WEEKDAY_NAMES[Self::weekday_from_timestamp_ms(local_ms)],
+                DATE_MONTH_NAMES[(month - 1) as usize],
+            ));
+        }
+        if with_time {
+            let sign = if offset.seconds >= 0 { '+' } else { '-' };
+            let offset_minutes = offset.seconds.abs() / 60;
+            let zone_name = Self::time_zone_long_name(&self.scheduler.time_zone, &offset)
+                .map(str::to_string)
+                .unwrap_or_else(|| Self::format_time_zone_gmt_offset(offset.seconds, true));
+            parts.push(format!(
+                "{hour:02}:{minute:02}:{second:02} GMT{sign}{:02}{:02} ({zone_name})",
+                offset_minutes / 60,
+                offset_minutes % 60,
+            ));
+        }
+        parts.join(" ")
+    }
+
+    pub(crate) fn format_date_utc_string(timestamp_ms: i64) -> String {
+        if timestamp_ms == INVALID_DATE_TIME_MS {
+            return "Invalid Date".to_string();
+        }
+        let (year, month, day, hour, minute, second, _) = Self::date_components_utc(timestamp_ms);
+        let year = if year >= 0 {
+            format!("{year:04}")
+        } else {
+            format!("-{:04}", -(year as i128))
+        };
+        format!(
+            "{}, {day:02} {} {year} {hour:02}:{minute:02}:{second:02} GMT",
+            DATE_WEEKDAY_NAMES[Self::weekday_from_timestamp_ms(timestamp_ms)],
+            DATE_MONTH_NAMES[(month - 1) as usize],
+        )
+    }
+
     pub(crate) fn utc_timestamp_ms_from_components(
         year: i64,
         month_zero_based: i64,
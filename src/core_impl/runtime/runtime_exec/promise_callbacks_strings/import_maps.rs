@@ -0,0 +1,177 @@
+use super::*;
+
+impl Harness {
+    // Import maps are merged in document order; entries from an earlier map win.
+    pub(crate) fn register_import_map(&mut self, source: &str) -> Result<()> {
+        let base = self.document_url.clone();
+        let Value::Object(map) = Self::parse_json_text(source)? else {
+            return Err(Error::ScriptRuntime(
+                "TypeError: import map must be a JSON object".into(),
+            ));
+        };
+        let (imports, scopes) = {
+            let map = map.borrow();
+            (
+                Self::object_get_entry(&map, "imports"),
+                Self::object_get_entry(&map, "scopes"),
+            )
+        };
+        let imports = match imports {
+            Some(imports) => Self::parse_import_map_specifiers(&imports, &base)?,
+            None => Vec::new(),
+        };
+        let mut parsed_scopes = Vec::new();
+        match scopes {
+            Some(Value::Object(scopes)) => {
+                for (prefix, entries) in scopes.borrow().iter() {
+                    if prefix.starts_with('\0') {
+                        continue;
+                    }
+                    let Some(prefix) = Self::resolve_url_string(prefix, Some(&base)) else {
+                        continue;
+                    };
+                    parsed_scopes
+                        .push((prefix, Self::parse_import_map_specifiers(entries, &base)?));
+                }
+            }
+            Some(_) => {
+                return Err(Error::ScriptRuntime(
+                    "TypeError: import map \"scopes\" must be an object".into(),
+                ));
+            }
+            None => {}
+        }
+
+        let import_map = &mut self.script_runtime.import_map;
+        Self::merge_import_map_specifiers(&mut import_map.imports, imports);
+        for (prefix, entries) in parsed_scopes {
+            match import_map
+                .scopes
+                .iter_mut()
+                .find(|(existing, _)| *existing == prefix)
+            {
+                Some((_, existing)) => Self::merge_import_map_specifiers(existing, entries),
+                None => {
+                    let mut merged = Vec::new();
+                    Self::merge_import_map_specifiers(&mut merged, entries);
+                    import_map.scopes.push((prefix, merged));
+                }
+            }
+        }
+        import_map
+            .scopes
+            .sort_by(|(left, _), (right, _)| right.cmp(left));
+        Ok(())
+    }
+
+    fn parse_import_map_specifiers(value: &Value, base: &str) -> Result<Vec<(String, String)>> {
+        let Value::Object(entries) = value else {
+            return Err(Error::ScriptRuntime(
+                "TypeError: import map specifier map must be an object".into(),
+            ));
+        };
+        let mut out = Vec::new();
+        for (key, address) in entries.borrow().iter() {
+            if key.is_empty() || key.starts_with('\0') {
+                continue;
+            }
+            let Value::String(address) = address else {
+                continue;
+            };
+            let Some(address) = Self::url_like_module_specifier(address, base) else {
+                continue;
+            };
+            if key.ends_with('/') && !address.ends_with('/') {
+                continue;
+            }
+            let key = Self::url_like_module_specifier(key, base).unwrap_or_else(|| key.clone());
+            out.push((key, address));
+        }
+        Ok(out)
+    }
+
+    // Longest keys sort first, so the first prefix match is the most specific.
+    fn merge_import_map_specifiers(
+        existing: &mut Vec<(String, String)>,
+        entries: Vec<(String, String)>,
+    ) {
+        for (key, address) in entries {
+            if !existing
+                .iter()
+                .any(|(existing_key, _)| *existing_key == key)
+            {
+                existing.push((key, address));
+            }
+        }
+        existing.sort_by(|(left, _), (right, _)| right.cmp(left));
+    }
+
+    // Specifiers that are absolute URLs or start with `/`, `./` or `../`
+    // resolve as URLs; anything else is a bare specifier.
+    fn url_like_module_specifier(specifier: &str, base: &str) -> Option<String> {
+        if specifier.starts_with("data:") || specifier.starts_with("blob:") {
+            return Some(specifier.to_string());
+        }
+        if specifier.starts_with('/') || specifier.starts_with("./") || specifier.starts_with("../")
+        {
+            return Self::resolve_url_string(specifier, Some(base));
+        }
+        LocationParts::parse(specifier)?;
+        Self::resolve_url_string(specifier, None)
+    }
+
+    fn resolve_import_map_match(
+        specifier: &str,
+        entries: &[(String, String)],
+    ) -> Result<Option<String>> {
+        for (key, address) in entries {
+            if key == specifier {
+                return Ok(Some(address.clone()));
+            }
+            let Some(rest) = specifier
+                .strip_prefix(key.as_str())
+                .filter(|_| key.ends_with('/'))
+            else {
+                continue;
+            };
+            let resolved = Self::resolve_url_string(rest, Some(address))
+                .filter(|resolved| resolved.starts_with(address.as_str()))
+                .ok_or_else(|| {
+                    Error::ScriptRuntime(format!(
+                        "TypeError: import map entry \"{key}\" cannot resolve \"{specifier}\""
+                    ))
+                })?;
+            return Ok(Some(resolved));
+        }
+        Ok(None)
+    }
+
+    pub(crate) fn resolve_module_specifier_key(
+        &self,
+        specifier: &str,
+        referrer: &str,
+    ) -> Result<String> {
+        let specifier = specifier.trim();
+        let as_url = Self::url_like_module_specifier(specifier, referrer);
+        let normalized = as_url.as_deref().unwrap_or(specifier);
+        let import_map = &self.script_runtime.import_map;
+        for (prefix, entries) in &import_map.scopes {
+            let in_scope = prefix == referrer
+                || (prefix.ends_with('/') && referrer.starts_with(prefix.as_str()));
+            if !in_scope {
+                continue;
+            }
+            if let Some(resolved) = Self::resolve_import_map_match(normalized, entries)? {
+                return Ok(resolved);
+            }
+        }
+        if let Some(resolved) = Self::resolve_import_map_match(normalized, &import_map.imports)? {
+            return Ok(resolved);
+        }
+        as_url.ok_or_else(|| {
+            Error::ScriptRuntime(format!(
+                "TypeError: Failed to resolve module specifier \"{specifier}\". Relative references must start with either \"/\", \"./\", or \"../\"."
+            ))
+        })
+    }
+}
@@ -0,0 +1,463 @@
+use super::*;
+
+impl Harness {
+    pub(crate) fn parse_data_module_source(specifier: &str) -> Result<(String, String)> {
+        let Some(rest) = specifier.strip_prefix("data:") else {
+            return Err(Error::ScriptRuntime(format!(
+                "invalid data module specifier: {specifier}"
+            )));
+        };
+        let Some((meta, payload)) = rest.split_once(',') else {
+            return Err(Error::ScriptRuntime(format!(
+                "invalid data module specifier: {specifier}"
+            )));
+        };
+        let media_type = meta
+            .split(';')
+            .next()
+            .map(str::trim)
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+        let source = decode_uri_like(payload, true)?;
+        if meta
+            .split(';')
+            .skip(1)
+            .any(|part| part.trim().eq_ignore_ascii_case("base64"))
+        {
+            return Ok((media_type, Self::decode_base64_text(&source)?));
+        }
+        Ok((media_type, source))
+    }
+
+    pub(crate) fn decode_base64_text(payload: &str) -> Result<String> {
+        let bytes = decode_base64_to_binary_string(payload)?
+            .chars()
+            .map(|ch| ch as u8)
+            .collect::<Vec<_>>();
+        Ok(String::from_utf8_lossy(&bytes).into_owned())
+    }
+
+    pub(crate) fn fetch_module_source(
+        &mut self,
+        specifier: &str,
+        cache_key: &str,
+    ) -> Result<(String, String)> {
+        if cache_key.starts_with("data:") {
+            return Self::parse_data_module_source(cache_key);
+        }
+        let source = self
+            .load_resource_source(FetchDestination::Module, specifier, cache_key)
+            .ok_or_else(|| {
+                Error::ScriptRuntime(format!(
+                    "module source mock not found for import: {specifier}"
+                ))
+            })?;
+        Ok(("text/javascript".to_string(), source))
+    }
+
+    // Fetches and links the whole graph below `specifier` before any of it
+    // runs, then evaluates it dependencies first. Returns the module's key.
+    pub(crate) fn load_module(
+        &mut self,
+        specifier: &str,
+        attribute_type: Option<&str>,
+        referrer: &str,
+    ) -> Result<String> {
+        let cache_key = self.resolve_module_specifier_key(specifier, referrer)?;
+        self.link_module(specifier, &cache_key, attribute_type)?;
+        self.evaluate_module(&cache_key)?;
+        Ok(cache_key)
+    }
+
+    fn link_module(
+        &mut self,
+        specifier: &str,
+        cache_key: &str,
+        attribute_type: Option<&str>,
+    ) -> Result<()> {
+        if self.script_runtime.modules.contains_key(cache_key) {
+            return Ok(());
+        }
+        let (media_type, source) = self.fetch_module_source(specifier, cache_key)?;
+        self.link_module_source(cache_key, &media_type, &source, attribute_type)
+    }
+
+    pub(crate) fn link_module_source(
+        &mut self,
+        cache_key: &str,
+        media_type: &str,
+        source: &str,
+        attribute_type: Option<&str>,
+    ) -> Result<()> {
+        let is_json_module = attribute_type.is_some_and(|ty| ty == "json")
+            || media_type.contains("application/json");
+        if is_json_module {
+            let value = Self::parse_json_text(source)?;
+            let exports =
+                HashMap::from([("default".to_string(), ModuleExportBinding::Value(value))]);
+            self.script_runtime.modules.insert(
+                cache_key.to_string(),
+                ModuleRecord {
+                    status: ModuleStatus::Evaluated,
+                    body: Rc::default(),
+                    requested_modules: Vec::new(),
+                    exports: Rc::new(RefCell::new(exports)),
+                    scope: None,
+                    error: None,
+                },
+            );
+            return Ok(());
+        }
+
+        let stmts = parse_module_block_statements(source)?;
+        Self::ensure_no_illegal_top_level_return(&stmts)?;
+        let exports = Self::module_static_exports(&stmts);
+        let body = Rc::new(stmts);
+        self.script_runtime.modules.insert(
+            cache_key.to_string(),
+            ModuleRecord {
+                status: ModuleStatus::Linking,
+                body: body.clone(),
+                requested_modules: Vec::new(),
+                exports: Rc::new(RefCell::new(exports)),
+                scope: None,
+                error: None,
+            },
+        );
+        match self.link_requested_modules(cache_key, &body) {
+            Ok(requested_modules) => {
+                if let Some(record) = self.script_runtime.modules.get_mut(cache_key) {
+                    record.status = ModuleStatus::Linked;
+                    record.requested_modules = requested_modules;
+                }
+                Ok(())
+            }
+            Err(err) => {
+                self.script_runtime.modules.remove(cache_key);
+                Err(err)
+            }
+        }
+    }
+
+    fn module_static_exports(stmts: &[Stmt]) -> HashMap<String, ModuleExportBinding> {
+        let mut exports = HashMap::new();
+        for stmt in stmts {
+            match stmt {
+                Stmt::ExportDecl { bindings, .. } | Stmt::ExportNamed { bindings } => {
+                    for (local, exported) in bindings {
+                        exports.insert(exported.clone(), ModuleExportBinding::Local(local.clone()));
+                    }
+                }
+                Stmt::ExportDefaultExpr { .. } => {
+                    exports.insert(
+                        "default".to_string(),
+                        ModuleExportBinding::Value(Value::Undefined),
+                    );
+                }
+                _ => {}
+            }
+        }
+        exports
+    }
+
+    // A dependency that is still linking is part of a cycle; its export table
+    // is already complete, so the requested names can be checked against it.
+    fn link_requested_modules(&mut self, cache_key: &str, body: &[Stmt]) -> Result<Vec<String>> {
+        let mut requested_modules = Vec::new();
+        for stmt in body {
+            let Stmt::ImportDecl {
+                specifier,
+                default_binding,
+                named_bindings,
+                attribute_type,
+                ..
+            } = stmt
+            else {
+                continue;
+            };
+            let dependency = self.resolve_module_specifier_key(specifier, cache_key)?;
+            self.link_module(specifier, &dependency, attribute_type.as_deref())?;
+            let exports = self
+                .script_runtime
+                .modules
+                .get(&dependency)
+                .map(|record| record.exports.clone())
+                .unwrap_or_default();
+            let exports = exports.borrow();
+            let missing = default_binding
+                .as_ref()
+                .map(|_| "default")
+                .into_iter()
+                .chain(
+                    named_bindings
+                        .iter()
+                        .map(|binding| binding.imported.as_str()),
+                )
+                .find(|name| !exports.contains_key(*name));
+            if let Some(name) = missing {
+                return Err(Error::ScriptRuntime(format!(
+                    "module '{specifier}' does not provide an export named '{name}'"
+                )));
+            }
+            if !requested_modules.contains(&dependency) {
+                requested_modules.push(dependency);
+            }
+        }
+        Ok(requested_modules)
+    }
+
+    // Modules that are already evaluating are on the current cycle and are
+    // skipped; their bindings become readable once their bodies run. A module
+    // whose evaluation failed rethrows the same error to every later importer.
+    pub(crate) fn evaluate_module(&mut self, cache_key: &str) -> Result<()> {
+        let Some(record) = self.script_runtime.modules.get_mut(cache_key) else {
+            return Ok(());
+        };
+        match record.status {
+            ModuleStatus::Linked => {}
+            ModuleStatus::Errored => {
+                if let Some(err) = record.error.clone() {
+                    return Err(err);
+                }
+                return Ok(());
+            }
+            _ => return Ok(()),
+        }
+        record.status = ModuleStatus::Evaluating;
+        let requested_modules = record.requested_modules.clone();
+        let body = record.body.clone();
+        let exports = record.exports.clone();
+
+        let result = requested_modules
+            .iter()
+            .try_for_each(|dependency| self.evaluate_module(dependency))
+            .and_then(|_| {
+                let scope = self.script_runtime.module_scopes.len();
+                if let Some(record) = self.script_runtime.modules.get_mut(cache_key) {
+                    record.scope = Some(scope);
+                }
+                self.run_module_body(&body, cache_key, Some(exports))
+            });
+        if let Some(record) = self.script_runtime.modules.get_mut(cache_key) {
+            match &result {
+                Ok(()) => record.status = ModuleStatus::Evaluated,
+                Err(err) => {
+                    record.status = ModuleStatus::Errored;
+                    record.error = Some(err.clone());
+                }
+            }
+        }
+        result
+    }
+
+    // Runs a module body in its own environment: a copy of the global env in
+    // which the module's lexical declarations start out missing, with its own
+    // const table so imports marked read-only here stay writable in the
+    // module that exports them.
+    pub(crate) fn run_module_body(
+        &mut self,
+        stmts: &[Stmt],
+        referrer: &str,
+        exports: Option<Rc<RefCell<HashMap<String, ModuleExportBinding>>>>,
+    ) -> Result<()> {
+        let scope = self.script_runtime.module_scopes.len();
+        let mut env = self.script_runtime.env.to_map();
+        for name in Self::collect_direct_tdz_binding_names(stmts) {
+            env.remove(&name);
+        }
+        env.remove(INTERNAL_GLOBAL_SYNC_NAMES_KEY);
+        env.insert(INTERNAL_SCOPE_DEPTH_KEY.to_string(), Value::Number(1));
+        env.insert(
+            INTERNAL_MODULE_SCOPE_KEY.to_string(),
+            Value::Number(scope as i64),
+        );
+        if let Some(Value::Object(consts)) = env.get(INTERNAL_CONST_BINDINGS_KEY) {
+            let consts = consts.borrow().clone();
+            env.insert(
+                INTERNAL_CONST_BINDINGS_KEY.to_string(),
+                Value::Object(Rc::new(RefCell::new(consts))),
+            );
+        }
+        let module_env = Rc::new(RefCell::new(ScriptEnv::from_snapshot(&env)));
+        self.script_runtime.module_scopes.push(ModuleScope {
+            exports: exports.clone(),
+            env: module_env.clone(),
+            ..ModuleScope::default()
+        });
+        self.script_runtime
+            .module_referrer_stack
+            .push(referrer.to_string());
+        let has_exports = exports.is_some();
+        if let Some(exports) = exports {
+            self.script_runtime.module_export_stack.push(exports);
+        }
+        let result = stacker::grow(32 * 1024 * 1024, || {
+            let mut event = EventState::new("script", self.dom.root, self.scheduler.now_ms);
+            // The body's top-level frame shares the module env, so closures
+            // created there and importers read the same bindings.
+            self.script_runtime.pending_module_env = Some(module_env.clone());
+            self.run_in_task_context(|inner| {
+                inner
+                    .execute_stmts(stmts, &None, &mut event, &mut env)
+                    .map(|_| ())
+            })
+        });
+        self.script_runtime.pending_module_env = None;
+        if result.is_ok() {
+            *module_env.borrow_mut() = ScriptEnv::from_snapshot(&env);
+        }
+        if has_exports {
+            let _ = self.script_runtime.module_export_stack.pop();
+        }
+        let _ = self.script_runtime.module_referrer_stack.pop();
+        result
+    }
+
+    pub(crate) fn module_import_key(local: &str) -> String {
+        format!("{INTERNAL_MODULE_IMPORT_KEY_PREFIX}{local}")
+    }
+
+    // Binds `local` in a module's env to a named export of another module.
+    // The value stored under `local` is a snapshot for the paths that read the
+    // env directly; the key next to it makes identifier reads go through to
+    // the exporting module.
+    pub(crate) fn bind_module_import(
+        &mut self,
+        env: &mut HashMap<String, Value>,
+        local: &str,
+        value: Value,
+        binding: ModuleImportBinding,
+    ) {
+        env.insert(local.to_string(), value);
+        self.set_const_binding(env, local, true);
+        let Some(Value::Number(scope)) = env.get(INTERNAL_MODULE_SCOPE_KEY) else {
+            return;
+        };
+        let Some(scope) = self.script_runtime.module_scopes.get_mut(*scope as usize) else {
+            return;
+        };
+        scope.imports.insert(local.to_string(), binding);
+        env.insert(Self::module_import_key(local), Value::Bool(true));
+    }
+
+    // A nested scope that declares an imported name hides the import there.
+    // Returns the removed keys so the caller can restore them on scope exit.
+    pub(crate) fn shadow_module_imports<'a>(
+        names: impl IntoIterator<Item = &'a String>,
+        env: &mut HashMap<String, Value>,
+    ) -> Vec<(String, Value)> {
+        if !env.contains_key(INTERNAL_MODULE_SCOPE_KEY) {
+            return Vec::new();
+        }
+        names
+            .into_iter()
+            .filter_map(|name| {
+                let key = Self::module_import_key(name);
+                let marker = env.remove(&key)?;
+                Some((key, marker))
+            })
+            .collect()
+    }
+
+    // Reads an imported binding through to the exporting module so imports
+    // stay live. Returns `None` when `name` is not an import visible in `env`.
+    pub(crate) fn resolve_module_import_binding(
+        &self,
+        name: &str,
+        env: &HashMap<String, Value>,
+    ) -> Result<Option<Value>> {
+        if !self.is_module_import_binding(name, env) {
+            return Ok(None);
+        }
+        let Some(Value::Number(scope)) = env.get(INTERNAL_MODULE_SCOPE_KEY) else {
+            return Ok(None);
+        };
+        let Some(binding) = self
+            .script_runtime
+            .module_scopes
+            .get(*scope as usize)
+            .and_then(|scope| scope.imports.get(name))
+        else {
+            return Ok(None);
+        };
+        self.module_export_value(&binding.module, &binding.import_name)
+    }
+
+    pub(crate) fn is_module_import_binding(
+        &self,
+        name: &str,
+        env: &HashMap<String, Value>,
+    ) -> bool {
+        env.contains_key(&Self::module_import_key(name))
+    }
+
+    pub(crate) fn is_module_env(&self, env: &Rc<RefCell<ScriptEnv>>) -> bool {
+        self.script_runtime
+            .module_scopes
+            .iter()
+            .any(|scope| Rc::ptr_eq(&scope.env, env))
+    }
+
+    // Returns `None` when the module has no such export, and a ReferenceError
+    // while the exported binding is not initialized yet.
+    pub(crate) fn module_export_value(&self, module: &str, name: &str) -> Result<Option<Value>> {
+        let Some(record) = self.script_runtime.modules.get(module) else {
+            return Ok(None);
+        };
+        let Some(binding) = record.exports.borrow().get(name).cloned() else {
+            return Ok(None);
+        };
+        match binding {
+            ModuleExportBinding::Local(local) => record
+                .scope
+                .and_then(|scope| self.script_runtime.module_scopes.get(scope))
+                .and_then(|scope| scope.env.borrow().get(&local).cloned())
+                .map(Some)
+                .ok_or_else(|| {
+                    Error::ScriptRuntime(format!(
+                        "ReferenceError: Cannot access '{name}' before initialization"
+                    ))
+                }),
+            ModuleExportBinding::Value(value) => Ok(Some(value)),
+        }
+    }
+
+    // Namespace objects expose every export through a getter, so reads are
+    // always current.
+    pub(crate) fn module_namespace(&mut self, module: &str) -> Value {
+        if let Some(namespace) = self.script_runtime.module_namespace_cache.get(module) {
+            return namespace.clone();
+        }
+        let mut names = self
+            .script_runtime
+            .modules
+            .get(module)
+            .map(|record| record.exports.borrow().keys().cloned().collect::<Vec<_>>())
+            .unwrap_or_default();
+        names.sort();
+        let mut entries = Vec::new();
+        for name in names {
+            let getter = Self::new_object_value(vec![
+                (
+                    INTERNAL_CALLABLE_KIND_KEY.to_string(),
+                    Value::String("module_namespace_get_export".to_string()),
+                ),
+                (
+                    INTERNAL_MODULE_NAMESPACE_MODULE_KEY.to_string(),
+                    Value::String(module.to_string()),
+                ),
+                (
+                    INTERNAL_MODULE_NAMESPACE_EXPORT_KEY.to_string(),
+                    Value::String(name.clone()),
+                ),
+            ]);
+            entries.push((Self::object_getter_storage_key(&name), getter));
+            entries.push((name, Value::Undefined));
+        }
+        let namespace = Self::new_object_value(entries);
+        self.script_runtime
+            .module_namespace_cache
+            .insert(module.to_string(), namespace.clone());
+        namespace
+    }
+}
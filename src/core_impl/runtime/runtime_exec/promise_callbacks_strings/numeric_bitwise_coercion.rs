@@ -12,7 +12,7 @@ impl Harness {
                     f64::INFINITY
                 }
             }),
-            Value::Date(v) => *v.borrow() as f64,
+            Value::Date(v) => Self::date_time_value_f64(*v.borrow()),
             Value::Null => 0.0,
             Value::Undefined => f64::NAN,
             _ => value.as_string().parse::<f64>().unwrap_or(0.0),
@@ -40,7 +40,7 @@ impl Harness {
             Value::Null => 0.0,
             Value::Undefined => f64::NAN,
             Value::String(v) => Self::parse_js_number_from_string(v),
-            Value::Date(v) => *v.borrow() as f64,
+            Value::Date(v) => Self::date_time_value_f64(*v.borrow()),
             Value::Object(_)
             | Value::Promise(_)
             | Value::Map(_)
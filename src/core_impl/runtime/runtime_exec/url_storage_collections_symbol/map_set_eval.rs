@@ -467,10 +467,9 @@ impl Harness {
             }
             if Self::is_storage_object(&entries.borrow()) {
                 return match method {
-                    MapInstanceMethod::Clear => {
-                        Self::set_storage_pairs(&mut entries.borrow_mut(), &[]);
-                        Ok(Value::Undefined)
-                    }
+                    MapInstanceMethod::Clear => Ok(self
+                        .eval_storage_member_call(&entries, "clear", &[])?
+                        .unwrap_or(Value::Undefined)),
                     _ => Err(Error::ScriptRuntime(format!(
                         "variable '{}' is not a Map",
                         target
@@ -558,6 +557,28 @@ impl Harness {
             }
         }
 
+        if let Value::Object(entries) = target_value {
+            let member = match method {
+                MapInstanceMethod::Get => "get",
+                MapInstanceMethod::Has => "has",
+                MapInstanceMethod::Delete => "delete",
+                MapInstanceMethod::Clear => "clear",
+                MapInstanceMethod::ForEach => "forEach",
+                MapInstanceMethod::GetOrInsert => "getOrInsert",
+                MapInstanceMethod::GetOrInsertComputed => "getOrInsertComputed",
+            };
+            let callee = Self::object_get_entry(&entries.borrow(), member);
+            if let Some(callee) = callee.filter(|callee| self.is_callable_value(callee)) {
+                return self.execute_callable_value_with_this_and_env(
+                    &callee,
+                    &evaluated_args,
+                    event,
+                    Some(env),
+                    Some(target_value.clone()),
+                );
+            }
+        }
+
         let Value::Map(map) = target_value else {
             if method == MapInstanceMethod::Clear {
                 return self.eval_expr(
@@ -44,7 +44,20 @@ impl Harness {
         pairs
     }
 
-    pub(crate) fn set_storage_pairs(entries: &mut impl ObjectEntryMut, pairs: &[(String, String)]) {
+    // The item list may be shared with other pages' storage objects, so it is
+    // rewritten in place rather than replaced.
+    pub(crate) fn set_storage_pairs(
+        entries: &mut (impl ObjectEntryMut + ObjectEntryLookup),
+        pairs: &[(String, String)],
+    ) {
+        if let Some(Value::Array(list)) =
+            Self::object_get_entry(entries, INTERNAL_STORAGE_ENTRIES_KEY)
+        {
+            if let Value::Array(fresh) = Self::storage_pairs_to_value(pairs) {
+                list.borrow_mut().elements = std::mem::take(&mut fresh.borrow_mut().elements);
+            }
+            return;
+        }
         Self::object_set_entry(
             entries,
             INTERNAL_STORAGE_ENTRIES_KEY.to_string(),
@@ -52,6 +65,54 @@ impl Harness {
         );
     }
 
+    pub(crate) fn set_storage_item(
+        &mut self,
+        object: &Rc<RefCell<ObjectValue>>,
+        key: String,
+        value: String,
+    ) {
+        let old_value = {
+            let mut entries = object.borrow_mut();
+            let mut pairs = Self::storage_pairs_from_object_entries(&entries);
+            let old_value =
+                if let Some((_, stored)) = pairs.iter_mut().find(|(name, _)| name == &key) {
+                    Some(std::mem::replace(stored, value.clone()))
+                } else {
+                    pairs.push((key.clone(), value.clone()));
+                    None
+                };
+            Self::set_storage_pairs(&mut entries, &pairs);
+            old_value
+        };
+        if old_value.as_deref() != Some(value.as_str()) {
+            self.queue_storage_change(object, Some(key), old_value, Some(value));
+        }
+    }
+
+    // Only `localStorage` is observable from other pages; a page's own
+    // `sessionStorage` has no other same-tab documents to notify.
+    fn queue_storage_change(
+        &mut self,
+        object: &Rc<RefCell<ObjectValue>>,
+        key: Option<String>,
+        old_value: Option<String>,
+        new_value: Option<String>,
+    ) {
+        if !Rc::ptr_eq(object, &self.browser_apis.local_storage_object) {
+            return;
+        }
+        let source = self.browser_apis.browsing_context.id.clone();
+        let origin = self.current_location_parts().origin();
+        self.queue_browsing_context_request(BrowsingContextRequest::StorageChanged {
+            source,
+            origin,
+            url: self.document_url.clone(),
+            key,
+            old_value,
+            new_value,
+        });
+    }
+
     pub(crate) fn eval_storage_member_call(
         &mut self,
         object: &Rc<RefCell<ObjectValue>>,
@@ -81,16 +142,7 @@ impl Harness {
                 }
                 let key = args[0].as_string();
                 let value = args[1].as_string();
-                {
-                    let mut entries = object.borrow_mut();
-                    let mut pairs = Self::storage_pairs_from_object_entries(&entries);
-                    if let Some((_, stored)) = pairs.iter_mut().find(|(name, _)| name == &key) {
-                        *stored = value;
-                    } else {
-                        pairs.push((key, value));
-                    }
-                    Self::set_storage_pairs(&mut entries, &pairs);
-                }
+                self.set_storage_item(object, key, value);
                 Ok(Some(Value::Undefined))
             }
             "removeItem" => {
@@ -100,16 +152,28 @@ impl Harness {
                     ));
                 }
                 let key = args[0].as_string();
-                {
+                let removed = {
                     let mut entries = object.borrow_mut();
                     let mut pairs = Self::storage_pairs_from_object_entries(&entries);
-                    pairs.retain(|(name, _)| name != &key);
+                    let removed = pairs
+                        .iter()
+                        .position(|(name, _)| name == &key)
+                        .map(|index| pairs.remove(index).1);
                     Self::set_storage_pairs(&mut entries, &pairs);
+                    removed
+                };
+                if removed.is_some() {
+                    self.queue_storage_change(object, Some(key), removed, None);
                 }
                 Ok(Some(Value::Undefined))
             }
             "clear" => {
+                let had_items =
+                    !Self::storage_pairs_from_object_entries(&object.borrow()).is_empty();
                 Self::set_storage_pairs(&mut object.borrow_mut(), &[]);
+                if had_items {
+                    self.queue_storage_change(object, None, None, None);
+                }
                 Ok(Some(Value::Undefined))
             }
             "key" => {
@@ -7,4 +7,5 @@ mod document_replace_location_property;
 mod environment_global_init;
 mod navigation_history_management;
 mod object_type_property_setters;
+mod resource_loading;
 mod window_document_location_sync;
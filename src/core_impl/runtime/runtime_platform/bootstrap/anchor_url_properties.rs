@@ -20,7 +20,7 @@ impl Harness {
         }
     }
 
-    fn viewport_dimensions(&self) -> (f64, f64) {
+    pub(crate) fn viewport_dimensions(&self) -> (f64, f64) {
         let width = self
             .viewport_dimension_value("innerWidth")
             .unwrap_or(1024.0);
@@ -134,7 +134,7 @@ impl Harness {
         true
     }
 
-    fn media_condition_matches(&self, media: &str) -> bool {
+    pub(crate) fn media_condition_matches(&self, media: &str) -> bool {
         let media = media.trim();
         if media.is_empty() {
             return true;
@@ -151,6 +151,7 @@ impl Harness {
         let now_ms = self.scheduler.now_ms;
         self.browser_apis
             .cookies
+            .borrow_mut()
             .retain(|record| !Self::cookie_is_expired(record, now_ms));
     }
 
@@ -158,6 +159,7 @@ impl Harness {
         self.prune_expired_cookies();
         self.browser_apis
             .cookies
+            .borrow()
             .iter()
             .filter(|record| self.cookie_visible_to_document(record))
             .map(|record| format!("{}={}", record.name, record.value))
@@ -194,29 +196,23 @@ impl Harness {
                 && candidate.partitioned == record.partitioned
         };
 
+        let mut cookies = self.browser_apis.cookies.borrow_mut();
         if record
             .expires_ms
             .is_some_and(|expires| expires <= self.scheduler.now_ms)
         {
-            let before = self.browser_apis.cookies.len();
-            self.browser_apis
-                .cookies
-                .retain(|candidate| !key_matches(candidate));
-            return before != self.browser_apis.cookies.len();
+            let before = cookies.len();
+            cookies.retain(|candidate| !key_matches(candidate));
+            return before != cookies.len();
         }
 
-        if let Some(existing) = self
-            .browser_apis
-            .cookies
-            .iter_mut()
-            .find(|candidate| key_matches(candidate))
-        {
+        if let Some(existing) = cookies.iter_mut().find(|candidate| key_matches(candidate)) {
             let changed = *existing != record;
             *existing = record;
             return changed;
         }
 
-        self.browser_apis.cookies.push(record);
+        cookies.push(record);
         true
     }
 
@@ -262,6 +258,7 @@ impl Harness {
         self.prune_expired_cookies();
         self.browser_apis
             .cookies
+            .borrow()
             .iter()
             .filter(|record| self.cookie_visible_to_document(record))
             .filter(|record| self.cookie_matches_filter(record, name, path, domain, partitioned))
@@ -279,7 +276,7 @@ impl Harness {
         self.prune_expired_cookies();
         let mut deleted = Vec::new();
         let mut kept = Vec::new();
-        let existing = std::mem::take(&mut self.browser_apis.cookies);
+        let existing = std::mem::take(&mut *self.browser_apis.cookies.borrow_mut());
         for record in existing {
             let should_delete = self.cookie_visible_to_document(&record)
                 && self.cookie_matches_filter(&record, name, path, domain, partitioned);
@@ -289,7 +286,7 @@ impl Harness {
                 kept.push(record);
             }
         }
-        self.browser_apis.cookies = kept;
+        *self.browser_apis.cookies.borrow_mut() = kept;
         deleted
     }
 
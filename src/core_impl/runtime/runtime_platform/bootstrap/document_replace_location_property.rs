@@ -12,23 +12,25 @@ impl Harness {
     }
 
     pub(crate) fn replace_document_with_html(&mut self, html: &str) -> Result<()> {
-        let ParseOutput { mut dom, scripts } = parse_html(html)?;
-        if scripts
-            .iter()
-            .any(|script| script.code.contains("document.body"))
-        {
-            let _ = dom.ensure_document_body_element()?;
-        }
+        let ParseOutput { dom, scripts } = parse_html(html)?;
         self.dom = dom;
+        self.location_history.document_generation += 1;
         self.listeners = ListenerStore::default();
         self.dom_runtime.node_event_handler_props.clear();
         self.dom_runtime.node_expando_props.clear();
         self.dom_runtime.live_child_nodes_lists.clear();
         self.dom_runtime.live_children_lists.clear();
         self.dom_runtime.live_named_node_maps.clear();
+        self.dom_runtime.mutation_observers = MutationObserverState::default();
+        self.dom_runtime.layout_observers = LayoutObserverState::default();
+        self.dom_runtime.custom_elements = CustomElementRegistryState::default();
+        self.dom_runtime.current_script = None;
+        self.dom_runtime.linked_stylesheets.clear();
+        self.dom_runtime.style_sheet_cache = RefCell::default();
         self.script_runtime.env.clear();
         self.scheduler.task_queue.clear();
         self.scheduler.microtask_queue.clear();
+        self.platform_mocks.pending_fetches.clear();
         self.scheduler.running_timer_id = None;
         self.scheduler.running_timer_canceled = false;
         self.script_runtime.pending_function_decls.clear();
@@ -37,21 +39,23 @@ impl Harness {
         self.script_runtime.loop_label_stack.clear();
         self.script_runtime.module_export_stack.clear();
         self.script_runtime.module_referrer_stack.clear();
-        self.script_runtime.module_cache.clear();
+        self.script_runtime.modules.clear();
         self.script_runtime.module_namespace_cache.clear();
-        self.script_runtime.loading_modules.clear();
+        self.script_runtime.module_scopes.clear();
+        self.script_runtime.import_map = ImportMap::default();
         self.script_runtime.event_target_listener_nodes.clear();
         self.script_runtime.next_event_target_listener_slot = 0;
         self.dom.set_active_element(None);
         self.dom.set_active_pseudo_element(None);
+        self.dom.set_hover_element(None);
+        self.dom_runtime.pointer_capture_targets.clear();
+        self.dom_runtime.pointer_input = PointerInputState::default();
         self.dom_runtime.document_ready_state = "loading".to_string();
         self.dom_runtime.document_visibility_state = "visible".to_string();
         self.dom_runtime.document_scroll_x = 0;
         self.dom_runtime.document_scroll_y = 0;
         self.initialize_global_bindings();
-        for script in scripts {
-            self.compile_and_register_script(&script.code, script.is_module)?;
-        }
+        self.run_document_scripts(scripts)?;
         self.finalize_document_ready_state_with_dom_content_loaded()?;
         Ok(())
     }
@@ -2,18 +2,18 @@ use super::*;
 
 impl Harness {
     pub fn from_html(html: &str) -> Result<Self> {
-        Self::from_html_impl("about:blank", html, &[])
+        Self::from_html_impl("about:blank", html, &[], &[])
     }
 
     pub fn from_html_with_url(url: &str, html: &str) -> Result<Self> {
-        Self::from_html_impl(url, html, &[])
+        Self::from_html_impl(url, html, &[], &[])
     }
 
     pub fn from_html_with_local_storage(
         html: &str,
         initial_local_storage: &[(&str, &str)],
     ) -> Result<Self> {
-        Self::from_html_impl("about:blank", html, initial_local_storage)
+        Self::from_html_impl("about:blank", html, initial_local_storage, &[])
     }
 
     pub fn from_html_with_url_and_local_storage(
@@ -21,21 +21,91 @@ impl Harness {
         html: &str,
         initial_local_storage: &[(&str, &str)],
     ) -> Result<Self> {
-        Self::from_html_impl(url, html, initial_local_storage)
+        Self::from_html_impl(url, html, initial_local_storage, &[])
+    }
+
+    pub fn from_html_with_session_storage(
+        html: &str,
+        initial_session_storage: &[(&str, &str)],
+    ) -> Result<Self> {
+        Self::from_html_impl("about:blank", html, &[], initial_session_storage)
+    }
+
+    pub fn from_html_with_url_and_session_storage(
+        url: &str,
+        html: &str,
+        initial_session_storage: &[(&str, &str)],
+    ) -> Result<Self> {
+        Self::from_html_impl(url, html, &[], initial_session_storage)
+    }
+
+    pub fn from_html_with_resource_loader(
+        url: &str,
+        html: &str,
+        loader: impl ResourceLoader + 'static,
+    ) -> Result<Self> {
+        let platform_mocks = PlatformMockState {
+            resource_loader: Some(ResourceLoaderHandle(Rc::new(RefCell::new(loader)))),
+            ..PlatformMockState::default()
+        };
+        Self::from_html_impl_with_browser_apis(
+            url,
+            html,
+            &[],
+            &[],
+            BrowserApiState::default(),
+            platform_mocks,
+        )
+    }
+
+    pub fn from_file(path: impl AsRef<std::path::Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let entry = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .ok_or_else(|| Error::HtmlParse(format!("invalid HTML file path: {}", path.display())))?
+            .to_string();
+        let root = match path.parent() {
+            Some(parent) if !parent.as_os_str().is_empty() => parent.to_path_buf(),
+            _ => std::path::PathBuf::from("."),
+        };
+        Self::from_dir(root, &entry)
+    }
+
+    pub fn from_dir(root: impl Into<std::path::PathBuf>, entry: &str) -> Result<Self> {
+        let loader = DirectoryResourceLoader::new(root);
+        let path = loader.root().join(entry.trim_start_matches('/'));
+        let html = std::fs::read_to_string(&path)
+            .map_err(|err| Error::HtmlParse(format!("failed to read {}: {err}", path.display())))?;
+        let url = loader.url_for(entry);
+        Self::from_html_with_resource_loader(&url, &html, loader)
     }
 
     pub(crate) fn from_html_impl(
         url: &str,
         html: &str,
         initial_local_storage: &[(&str, &str)],
+        initial_session_storage: &[(&str, &str)],
     ) -> Result<Self> {
-        let ParseOutput { mut dom, scripts } = parse_html(html)?;
-        if scripts
-            .iter()
-            .any(|script| script.code.contains("document.body"))
-        {
-            let _ = dom.ensure_document_body_element()?;
-        }
+        Self::from_html_impl_with_browser_apis(
+            url,
+            html,
+            initial_local_storage,
+            initial_session_storage,
+            BrowserApiState::default(),
+            PlatformMockState::default(),
+        )
+    }
+
+    pub(crate) fn from_html_impl_with_browser_apis(
+        url: &str,
+        html: &str,
+        initial_local_storage: &[(&str, &str)],
+        initial_session_storage: &[(&str, &str)],
+        browser_apis: BrowserApiState,
+        platform_mocks: PlatformMockState,
+    ) -> Result<Self> {
+        let ParseOutput { dom, scripts } = parse_html(html)?;
         let normalized_url = Self::resolve_url_string(url, None).unwrap_or_else(|| url.to_string());
         let mut harness = Self {
             dom,
@@ -47,51 +117,107 @@ impl Harness {
             scheduler: SchedulerState::default(),
             promise_runtime: PromiseRuntimeState::default(),
             symbol_runtime: SymbolRuntimeState::default(),
-            browser_apis: BrowserApiState::default(),
+            browser_apis,
             rng_state: 0x9E37_79B9_7F4A_7C15,
-            platform_mocks: PlatformMockState::default(),
+            platform_mocks,
             trace_state: TraceState::default(),
         };
 
         harness.initialize_global_bindings();
-        harness.seed_initial_local_storage(initial_local_storage);
+        Self::seed_initial_storage(
+            &harness.browser_apis.local_storage_object,
+            initial_local_storage,
+        );
+        Self::seed_initial_storage(
+            &harness.browser_apis.session_storage_object,
+            initial_session_storage,
+        );
         harness.dom_runtime.document_ready_state = "loading".to_string();
 
-        for script in scripts {
-            harness.compile_and_register_script(&script.code, script.is_module)?;
-        }
+        harness.run_document_scripts(scripts)?;
         harness.finalize_document_ready_state_with_dom_content_loaded()?;
 
         Ok(harness)
     }
 
-    pub(crate) fn seed_initial_local_storage(&mut self, initial_local_storage: &[(&str, &str)]) {
-        if initial_local_storage.is_empty() {
+    pub(crate) fn seed_initial_storage(
+        object: &Rc<RefCell<ObjectValue>>,
+        initial_items: &[(&str, &str)],
+    ) {
+        if initial_items.is_empty() {
             return;
         }
 
-        let mut pairs = Vec::new();
-        for (key, value) in initial_local_storage {
+        let mut pairs = Self::storage_pairs_from_object_entries(&object.borrow());
+        for (key, value) in initial_items {
             if let Some((_, stored)) = pairs.iter_mut().find(|(name, _)| name == key) {
                 *stored = (*value).to_string();
             } else {
                 pairs.push(((*key).to_string(), (*value).to_string()));
             }
         }
-        Self::set_storage_pairs(
-            &mut self.browser_apis.local_storage_object.borrow_mut(),
-            &pairs,
+        Self::set_storage_pairs(&mut object.borrow_mut(), &pairs);
+    }
+
+    // Rebuilds a storage area in place, keeping its items, so every holder of
+    // the object sees the fresh built-in state.
+    pub(crate) fn reset_storage_object(object: &Rc<RefCell<ObjectValue>>) {
+        let items = {
+            let entries = object.borrow();
+            if Self::is_storage_object(&entries) {
+                Self::object_get_entry(&entries, INTERNAL_STORAGE_ENTRIES_KEY)
+            } else {
+                None
+            }
+        };
+        let mut entries = vec![(INTERNAL_STORAGE_OBJECT_KEY.to_string(), Value::Bool(true))];
+        match items {
+            Some(items @ Value::Array(_)) => {
+                entries.push((INTERNAL_STORAGE_ENTRIES_KEY.to_string(), items));
+            }
+            _ => Self::set_storage_pairs(&mut entries, &[]),
+        }
+        *object.borrow_mut() = entries.into();
+    }
+
+    pub(crate) fn new_storage_object_over(
+        items: &Rc<RefCell<ArrayValue>>,
+    ) -> Rc<RefCell<ObjectValue>> {
+        let entries = vec![
+            (INTERNAL_STORAGE_OBJECT_KEY.to_string(), Value::Bool(true)),
+            (
+                INTERNAL_STORAGE_ENTRIES_KEY.to_string(),
+                Value::Array(items.clone()),
+            ),
+        ];
+        Rc::new(RefCell::new(entries.into()))
+    }
+
+    pub(crate) fn storage_item_list(object: &Rc<RefCell<ObjectValue>>) -> Rc<RefCell<ArrayValue>> {
+        if let Some(Value::Array(items)) =
+            Self::object_get_entry(&object.borrow(), INTERNAL_STORAGE_ENTRIES_KEY)
+        {
+            return items;
+        }
+        let items = Rc::new(RefCell::new(ArrayValue::new(Vec::new())));
+        Self::object_set_entry(
+            &mut object.borrow_mut(),
+            INTERNAL_STORAGE_ENTRIES_KEY.to_string(),
+            Value::Array(items.clone()),
         );
+        items
     }
 
     pub(crate) fn with_script_env<R>(
         &mut self,
         f: impl FnOnce(&mut Self, &mut HashMap<String, Value>) -> Result<R>,
     ) -> Result<R> {
+        let session_storage = self.browser_apis.session_storage_object.clone();
         let mut env = self.script_runtime.env.share();
         match f(self, &mut env) {
             Ok(value) => {
                 self.script_runtime.env = env;
+                self.rebind_switched_session_storage(&session_storage);
                 Ok(value)
             }
             Err(err) => Err(err),
@@ -102,9 +228,11 @@ impl Harness {
         &mut self,
         f: impl FnOnce(&mut Self, &mut HashMap<String, Value>) -> Result<R>,
     ) -> Result<R> {
+        let session_storage = self.browser_apis.session_storage_object.clone();
         let mut env = self.script_runtime.env.share();
         let result = f(self, &mut env);
         self.script_runtime.env = env;
+        self.rebind_switched_session_storage(&session_storage);
         result
     }
 
@@ -124,18 +252,8 @@ impl Harness {
             .clear();
         self.sync_cookie_store_object();
         self.sync_cache_storage_object();
-        let local_storage_items = {
-            let entries = self.browser_apis.local_storage_object.borrow();
-            if Self::is_storage_object(&entries) {
-                Self::storage_pairs_from_object_entries(&entries)
-            } else {
-                Vec::new()
-            }
-        };
-        let mut local_storage_entries =
-            vec![(INTERNAL_STORAGE_OBJECT_KEY.to_string(), Value::Bool(true))];
-        Self::set_storage_pairs(&mut local_storage_entries, &local_storage_items);
-        *self.browser_apis.local_storage_object.borrow_mut() = local_storage_entries.into();
+        Self::reset_storage_object(&self.browser_apis.local_storage_object);
+        Self::reset_storage_object(&self.browser_apis.session_storage_object);
         let read_text = Self::new_builtin_placeholder_function();
         let write_text = Self::new_builtin_placeholder_function();
         let write = Self::new_clipboard_write_callable_value();
@@ -269,6 +387,7 @@ impl Harness {
         let hash_change_event_constructor = Self::new_hash_change_event_constructor_value();
         let error_event_constructor = Self::new_error_event_constructor_value();
         let before_unload_event_constructor = Self::new_before_unload_event_constructor_value();
+        let event_interface_constructors = Self::event_interface_constructor_bindings();
         let image_data_constructor = Self::new_image_data_constructor_value();
         let iterator_constructor = self.new_iterator_constructor_value();
         let cookie_store = self.cookie_store_global_value();
@@ -285,7 +404,19 @@ impl Harness {
         let resize_by_callable = Self::new_window_resize_by_callable_value();
         let resize_to_callable = Self::new_window_resize_to_callable_value();
         let post_message_callable = Self::new_window_post_message_callable_value();
+        let open_callable = Self::new_window_open_callable_value();
         let get_computed_style_callable = Self::new_window_get_computed_style_callable_value();
+        let console = Self::new_console_object_value();
+        let abort_controller_constructor = Self::new_abort_controller_constructor_value();
+        let abort_signal_constructor = Self::new_abort_signal_constructor_value();
+        let dom_exception_constructor = Self::new_dom_exception_constructor_value();
+        let mutation_observer_constructor = Self::new_mutation_observer_constructor_value();
+        let custom_elements = Self::new_custom_element_registry_value();
+        let intersection_observer_constructor = Self::new_intersection_observer_constructor_value();
+        let intersection_observer_entry_constructor =
+            Self::new_intersection_observer_entry_constructor_value();
+        let resize_observer_constructor = Self::new_resize_observer_constructor_value();
+        let resize_observer_entry_constructor = Self::new_resize_observer_entry_constructor_value();
         let alert_callable = Self::new_window_alert_callable_value();
         let confirm_callable = Self::new_window_confirm_callable_value();
         let prompt_callable = Self::new_window_prompt_callable_value();
@@ -473,6 +604,43 @@ impl Harness {
         );
         {
             let mut window_entries = self.dom_runtime.window_object.borrow_mut();
+            Self::object_set_entry(&mut window_entries, "console".to_string(), console.clone());
+            Self::object_set_entry(
+                &mut window_entries,
+                "AbortController".to_string(),
+                abort_controller_constructor.clone(),
+            );
+            Self::object_set_entry(
+                &mut window_entries,
+                "AbortSignal".to_string(),
+                abort_signal_constructor.clone(),
+            );
+            Self::object_set_entry(
+                &mut window_entries,
+                "DOMException".to_string(),
+                dom_exception_constructor.clone(),
+            );
+            Self::object_set_entry(
+                &mut window_entries,
+                "MutationObserver".to_string(),
+                mutation_observer_constructor.clone(),
+            );
+            Self::object_set_entry(
+                &mut window_entries,
+                "customElements".to_string(),
+                custom_elements.clone(),
+            );
+            for (name, constructor) in [
+                ("IntersectionObserver", &intersection_observer_constructor),
+                (
+                    "IntersectionObserverEntry",
+                    &intersection_observer_entry_constructor,
+                ),
+                ("ResizeObserver", &resize_observer_constructor),
+                ("ResizeObserverEntry", &resize_observer_entry_constructor),
+            ] {
+                Self::object_set_entry(&mut window_entries, name.to_string(), constructor.clone());
+            }
             Self::object_set_entry(
                 &mut window_entries,
                 "decodeURI".to_string(),
@@ -578,6 +746,14 @@ impl Harness {
                 "BeforeUnloadEvent".to_string(),
                 before_unload_event_constructor.clone(),
             );
+            for (name, constructor) in &event_interface_constructors {
+                Self::object_set_entry(&mut window_entries, name.clone(), constructor.clone());
+            }
+            Self::object_set_entry(
+                &mut window_entries,
+                "open".to_string(),
+                open_callable.clone(),
+            );
             Self::object_set_entry(
                 &mut window_entries,
                 "ImageData".to_string(),
@@ -706,6 +882,9 @@ impl Harness {
             "BeforeUnloadEvent".to_string(),
             before_unload_event_constructor,
         );
+        for (name, constructor) in event_interface_constructors {
+            self.script_runtime.env.insert(name, constructor);
+        }
         self.script_runtime
             .env
             .insert("ImageData".to_string(), image_data_constructor);
@@ -755,6 +934,38 @@ impl Harness {
         self.script_runtime
             .env
             .insert("getComputedStyle".to_string(), get_computed_style_callable);
+        self.script_runtime
+            .env
+            .insert("console".to_string(), console);
+        self.script_runtime
+            .env
+            .insert("AbortController".to_string(), abort_controller_constructor);
+        self.script_runtime
+            .env
+            .insert("AbortSignal".to_string(), abort_signal_constructor);
+        self.script_runtime
+            .env
+            .insert("DOMException".to_string(), dom_exception_constructor);
+        self.script_runtime.env.insert(
+            "MutationObserver".to_string(),
+            mutation_observer_constructor,
+        );
+        self.script_runtime
+            .env
+            .insert("customElements".to_string(), custom_elements);
+        for (name, constructor) in [
+            ("IntersectionObserver", intersection_observer_constructor),
+            (
+                "IntersectionObserverEntry",
+                intersection_observer_entry_constructor,
+            ),
+            ("ResizeObserver", resize_observer_constructor),
+            ("ResizeObserverEntry", resize_observer_entry_constructor),
+        ] {
+            self.script_runtime
+                .env
+                .insert(name.to_string(), constructor);
+        }
         self.script_runtime
             .env
             .insert("alert".to_string(), alert_callable);
@@ -767,6 +978,9 @@ impl Harness {
         self.script_runtime
             .env
             .insert("print".to_string(), print_callable);
+        self.script_runtime
+            .env
+            .insert("open".to_string(), open_callable);
         self.script_runtime
             .env
             .insert("reportError".to_string(), report_error_callable);
@@ -928,6 +1142,10 @@ impl Harness {
         self.script_runtime
             .env
             .insert("localStorage".to_string(), local_storage);
+        self.script_runtime.env.insert(
+            "sessionStorage".to_string(),
+            Value::Object(self.browser_apis.session_storage_object.clone()),
+        );
         self.script_runtime
             .env
             .insert("window".to_string(), window.clone());
@@ -950,5 +1168,6 @@ impl Harness {
         self.script_runtime
             .env
             .insert(INTERNAL_SCOPE_DEPTH_KEY.to_string(), Value::Number(0));
+        self.apply_browsing_context_window_entries();
     }
 }
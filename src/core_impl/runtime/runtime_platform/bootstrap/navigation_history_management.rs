@@ -205,7 +205,7 @@ impl Harness {
             event.current_target_value = Some(target_value);
             event.bubbles = false;
             event.cancelable = false;
-            event.hash_change_interface = true;
+            event.interface = Some(EventInterface::HashChangeEvent);
             event.hash_change_old_url = old_url.clone();
             event.hash_change_new_url = new_url.clone();
             event.event_phase = 2;
@@ -228,6 +228,7 @@ impl Harness {
         let from = self.document_url.clone();
         let to = self.try_resolve_location_target_url(next_url)?;
         self.document_url = to.clone();
+        self.switch_session_storage_origin(&from);
         match kind {
             LocationNavigationKind::Replace => {
                 self.history_replace_current_entry(&to, Value::Null);
@@ -386,6 +387,7 @@ impl Harness {
             }
         };
         self.document_url = entry.url.clone();
+        self.switch_session_storage_origin(&from);
         self.sync_location_object();
         self.sync_history_object();
         self.sync_navigation_object();
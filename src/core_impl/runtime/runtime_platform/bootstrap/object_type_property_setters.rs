@@ -249,14 +249,7 @@ impl Harness {
                 Ok(())
             }
             _ => {
-                let mut entries = storage_object.borrow_mut();
-                let mut pairs = Self::storage_pairs_from_object_entries(&entries);
-                if let Some((_, stored)) = pairs.iter_mut().find(|(name, _)| name == key) {
-                    *stored = value.as_string();
-                } else {
-                    pairs.push((key.to_string(), value.as_string()));
-                }
-                Self::set_storage_pairs(&mut entries, &pairs);
+                self.set_storage_item(storage_object, key.to_string(), value.as_string());
                 Ok(())
             }
         }
@@ -704,6 +697,11 @@ impl Harness {
                 handler: handler.clone(),
                 captured_env: function.captured_env.clone(),
                 captured_pending_function_decls: function.captured_pending_function_decls.clone(),
+                signal: None,
+                once: false,
+                passive: false,
+                callback_id: None,
+                removed: Rc::default(),
             };
 
             let replaced = previous_handler.as_ref().is_some_and(|previous| {
@@ -813,6 +811,11 @@ impl Harness {
                 handler: function.handler.clone(),
                 captured_env: function.captured_env.clone(),
                 captured_pending_function_decls: function.captured_pending_function_decls.clone(),
+                signal: None,
+                once: false,
+                passive: false,
+                callback_id: None,
+                removed: Rc::default(),
             };
             let replaced = previous_handler.as_ref().is_some_and(|previous| {
                 self.listeners.replace_event_handler_property(
@@ -856,6 +859,17 @@ impl Harness {
         key: &str,
         value: Value,
     ) -> Result<()> {
+        if let Some(setter) = self.custom_element_setter(node, key) {
+            let event = EventState::new("script", self.dom.root, self.scheduler.now_ms);
+            self.execute_callable_value_with_this_and_env(
+                &setter,
+                &[value],
+                &event,
+                None,
+                Some(Value::Node(node)),
+            )?;
+            return Ok(());
+        }
         if self.set_node_event_handler_property(node, key, value.clone())? {
             return Ok(());
         }
@@ -934,11 +948,10 @@ impl Harness {
             "textContent" | "innerText" | "text" => {
                 self.dom.set_text_content(node, &value.as_string())?
             }
-            "nodeValue" => {
-                if let NodeType::Text(text) = &mut self.dom.nodes[node.0].node_type {
-                    *text = value.as_string();
-                }
+            "nodeValue" | "data" if self.dom.character_data(node).is_some() => {
+                self.dom.set_character_data(node, value.as_string());
             }
+            "nodeValue" => {}
             "innerHTML" => {
                 let html = if matches!(value, Value::Null) {
                     String::new()
@@ -1972,11 +1985,11 @@ impl Harness {
 
         let assigning_before_unload_return_value = key == "returnValue"
             && event_param.as_ref().is_some_and(|param| param == target)
-            && (event.before_unload_interface
+            && (event.has_interface(EventInterface::BeforeUnloadEvent)
                 || event.event_type.eq_ignore_ascii_case("beforeunload"));
         if assigning_before_unload_return_value {
             let return_value = value.as_string();
-            event.before_unload_interface = true;
+            event.interface = Some(EventInterface::BeforeUnloadEvent);
             event.before_unload_return_value = return_value.clone();
             if event.cancelable && !return_value.is_empty() {
                 event.default_prevented = true;
@@ -0,0 +1,194 @@
+use super::*;
+
+impl Harness {
+    pub fn set_resource_loader(&mut self, loader: impl ResourceLoader + 'static) {
+        self.platform_mocks.resource_loader =
+            Some(ResourceLoaderHandle(Rc::new(RefCell::new(loader))));
+    }
+
+    pub fn clear_resource_loader(&mut self) {
+        self.platform_mocks.resource_loader = None;
+    }
+
+    // Asks the resource loader first and falls back to the fetch mocks when it
+    // passes. Only 2xx responses count as a loaded resource.
+    pub(crate) fn load_resource_source(
+        &mut self,
+        destination: FetchDestination,
+        input: &str,
+        url: &str,
+    ) -> Option<String> {
+        let url = url.trim();
+        if url.starts_with("data:") {
+            return Self::decode_data_url_text(url).ok();
+        }
+        if let Some(blob) = self.browser_apis.blob_url_objects.get(url) {
+            return Some(String::from_utf8_lossy(&blob.borrow().bytes).into_owned());
+        }
+        let request = FetchRequest::get(destination, url);
+        let outcome = match self.platform_mocks.resource_loader.clone() {
+            Some(loader) => loader.0.borrow_mut().load(&request),
+            None => FetchOutcome::Fallthrough,
+        };
+        let response = match outcome {
+            FetchOutcome::Response(response) => Some(response),
+            FetchOutcome::NetworkError => None,
+            FetchOutcome::Fallthrough => self.resolve_fetch_mock(input, &request),
+        }?;
+        (200..300)
+            .contains(&response.status)
+            .then(|| String::from_utf8_lossy(&response.body).into_owned())
+    }
+
+    fn decode_data_url_text(url: &str) -> Result<String> {
+        let rest = url.strip_prefix("data:").unwrap_or(url);
+        let Some((meta, payload)) = rest.split_once(',') else {
+            return Err(Error::ScriptRuntime(format!("invalid data URL: {url}")));
+        };
+        let is_base64 = meta
+            .split(';')
+            .skip(1)
+            .any(|part| part.trim().eq_ignore_ascii_case("base64"));
+        let text = decode_uri_like(payload, true)?;
+        if is_base64 {
+            return Self::decode_base64_text(&text);
+        }
+        Ok(text)
+    }
+
+    // Runs the scripts of a freshly parsed document the way a browser orders
+    // them: parser-blocking scripts, then async scripts, then deferred scripts
+    // and modules. External scripts get a `load` or `error` event.
+    pub(crate) fn run_document_scripts(&mut self, scripts: Vec<ScriptSource>) -> Result<()> {
+        self.register_document_import_maps()?;
+        let mut loaded = Vec::with_capacity(scripts.len());
+        for script in scripts {
+            let source = match script.src.as_deref() {
+                Some(src) => {
+                    let url = Self::resolve_url_string(src, Some(&self.document_url))
+                        .unwrap_or_else(|| src.to_string());
+                    if script.is_module && self.script_runtime.modules.contains_key(&url) {
+                        Some(String::new())
+                    } else {
+                        let destination = if script.is_module {
+                            FetchDestination::Module
+                        } else {
+                            FetchDestination::Script
+                        };
+                        self.load_resource_source(destination, src, &url)
+                    }
+                }
+                None => Some(script.code.clone()),
+            };
+            loaded.push((script, source));
+        }
+        if loaded.iter().any(|(_, source)| {
+            source
+                .as_ref()
+                .is_some_and(|code| code.contains("document.body"))
+        }) {
+            let _ = self.dom.ensure_document_body_element()?;
+        }
+        let stylesheets = self.load_linked_stylesheets();
+
+        for timing in [
+            ScriptTiming::ParserBlocking,
+            ScriptTiming::Async,
+            ScriptTiming::Defer,
+        ] {
+            for (script, source) in loaded.iter().filter(|(script, _)| script.timing == timing) {
+                self.execute_document_script(script, source.as_deref())?;
+            }
+        }
+
+        for (link, ok) in stylesheets {
+            self.dispatch_resource_event(link, if ok { "load" } else { "error" })?;
+        }
+        Ok(())
+    }
+
+    fn execute_document_script(
+        &mut self,
+        script: &ScriptSource,
+        source: Option<&str>,
+    ) -> Result<()> {
+        let Some(code) = source else {
+            return self.dispatch_resource_event(script.element, "error");
+        };
+        match script.src.as_deref() {
+            Some(src) if script.is_module => {
+                let url = Self::resolve_url_string(src, Some(&self.document_url))
+                    .unwrap_or_else(|| src.to_string());
+                if !self.script_runtime.modules.contains_key(&url) {
+                    self.link_module_source(&url, "text/javascript", code, None)?;
+                }
+                self.evaluate_module(&url)?;
+            }
+            _ => {
+                let previous = self.dom_runtime.current_script;
+                if !script.is_module {
+                    self.dom_runtime.current_script = Some(script.element);
+                }
+                let result = self.compile_and_register_script(code, script.is_module);
+                self.dom_runtime.current_script = previous;
+                result?;
+            }
+        }
+        if script.src.is_some() {
+            self.dispatch_resource_event(script.element, "load")?;
+        }
+        Ok(())
+    }
+
+    fn register_document_import_maps(&mut self) -> Result<()> {
+        for node in self.dom.query_selector_all("script").unwrap_or_default() {
+            let is_import_map = self
+                .dom
+                .attr(node, "type")
+                .is_some_and(|ty| ty.trim().eq_ignore_ascii_case("importmap"));
+            if is_import_map {
+                let source = self.dom.text_content(node);
+                self.register_import_map(&source)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn load_linked_stylesheets(&mut self) -> Vec<(NodeId, bool)> {
+        let mut links = Vec::new();
+        for node in self
+            .dom
+            .query_selector_all("link[href]")
+            .unwrap_or_default()
+        {
+            let is_stylesheet = self.dom.attr(node, "rel").is_some_and(|rel| {
+                let mut tokens = rel.split_ascii_whitespace();
+                let stylesheet = tokens
+                    .clone()
+                    .any(|token| token.eq_ignore_ascii_case("stylesheet"));
+                stylesheet && !tokens.any(|token| token.eq_ignore_ascii_case("alternate"))
+            });
+            if !is_stylesheet {
+                continue;
+            }
+            let href = self.dom.attr(node, "href").unwrap_or_default();
+            let url = Self::resolve_url_string(&href, Some(&self.document_url))
+                .unwrap_or_else(|| href.clone());
+            let source = self.load_resource_source(FetchDestination::Style, &href, &url);
+            links.push((node, source.is_some()));
+            if let Some(source) = source {
+                self.dom_runtime.linked_stylesheets.insert(node, source);
+            }
+        }
+        links
+    }
+
+    fn dispatch_resource_event(&mut self, target: NodeId, event_type: &str) -> Result<()> {
+        self.with_script_env(|this, env| {
+            this.dispatch_event_with_options(
+                target, event_type, env, true, false, false, None, None, None,
+            )
+            .map(|_| ())
+        })
+    }
+}
@@ -36,6 +36,8 @@ impl Harness {
             "createElement",
             "createElementNS",
             "createTextNode",
+            "createComment",
+            "createProcessingInstruction",
             "createAttribute",
             "createDocumentFragment",
             "createRange",
@@ -119,6 +121,14 @@ impl Harness {
                 "createTextNode".to_string(),
                 Self::new_builtin_placeholder_function(),
             ),
+            (
+                "createComment".to_string(),
+                Self::new_builtin_placeholder_function(),
+            ),
+            (
+                "createProcessingInstruction".to_string(),
+                Self::new_builtin_placeholder_function(),
+            ),
             (
                 "createAttribute".to_string(),
                 Self::new_builtin_placeholder_function(),
@@ -223,6 +233,7 @@ impl Harness {
             "navigator",
             "clientInformation",
             "localStorage",
+            "sessionStorage",
             "document",
             "origin",
             "isSecureContext",
@@ -513,6 +524,10 @@ impl Harness {
                 Value::Object(self.dom_runtime.document_object.clone()),
             ),
             ("localStorage".to_string(), local_storage.clone()),
+            (
+                "sessionStorage".to_string(),
+                Value::Object(self.browser_apis.session_storage_object.clone()),
+            ),
             (
                 "origin".to_string(),
                 Value::String(self.current_location_parts().origin()),
@@ -1,7 +1,17 @@
 use super::*;
 
 mod assertions_form_helpers;
+mod browsing_contexts;
 mod event_dispatch_focus_dialog;
+mod focus_navigation;
+mod form_submission;
+mod harness_snapshot;
+mod keyboard_typing;
+mod layout_geometry;
+mod locators;
+mod pointer_input;
+mod script_evaluation;
+mod storage_areas;
 mod timer_controls_execution;
 mod trace_mocks_input_primitives;
 mod user_actions_forms;
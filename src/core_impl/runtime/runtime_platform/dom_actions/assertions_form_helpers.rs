@@ -1,12 +1,13 @@
 use super::*;
 
 impl Harness {
-    pub fn assert_text(&self, selector: &str, expected: &str) -> Result<()> {
-        let target = self.select_one(selector)?;
+    pub fn assert_text(&self, selector: impl Into<Locator>, expected: &str) -> Result<()> {
+        let locator = selector.into();
+        let target = self.select_one(&locator)?;
         let actual = render_js_string_for_display(&self.dom.text_content(target));
         if actual != expected {
             return Err(Error::AssertionFailed {
-                selector: selector.to_string(),
+                selector: locator.to_string(),
                 expected: expected.to_string(),
                 actual,
                 dom_snippet: self.node_snippet(target),
@@ -15,12 +16,13 @@ impl Harness {
         Ok(())
     }
 
-    pub fn assert_value(&self, selector: &str, expected: &str) -> Result<()> {
-        let target = self.select_one(selector)?;
+    pub fn assert_value(&self, selector: impl Into<Locator>, expected: &str) -> Result<()> {
+        let locator = selector.into();
+        let target = self.select_one(&locator)?;
         let actual = self.dom.value(target)?;
         if actual != expected {
             return Err(Error::AssertionFailed {
-                selector: selector.to_string(),
+                selector: locator.to_string(),
                 expected: expected.to_string(),
                 actual,
                 dom_snippet: self.node_snippet(target),
@@ -29,12 +31,13 @@ impl Harness {
         Ok(())
     }
 
-    pub fn assert_checked(&self, selector: &str, expected: bool) -> Result<()> {
-        let target = self.select_one(selector)?;
+    pub fn assert_checked(&self, selector: impl Into<Locator>, expected: bool) -> Result<()> {
+        let locator = selector.into();
+        let target = self.select_one(&locator)?;
         let actual = self.dom.checked(target)?;
         if actual != expected {
             return Err(Error::AssertionFailed {
-                selector: selector.to_string(),
+                selector: locator.to_string(),
                 expected: expected.to_string(),
                 actual: actual.to_string(),
                 dom_snippet: self.node_snippet(target),
@@ -43,20 +46,46 @@ impl Harness {
         Ok(())
     }
 
-    pub fn assert_exists(&self, selector: &str) -> Result<()> {
-        let _ = self.select_one(selector)?;
+    pub fn assert_exists(&self, selector: impl Into<Locator>) -> Result<()> {
+        let _ = self.select_one(&selector.into())?;
         Ok(())
     }
 
-    pub fn dump_dom(&self, selector: &str) -> Result<String> {
-        let target = self.select_one(selector)?;
+    // Visible means the element is rendered (see `is_element_rendered`) and its
+    // computed `visibility` is not `hidden` or `collapse`.
+    pub fn is_visible(&self, selector: impl Into<Locator>) -> Result<bool> {
+        let target = self.select_one(&selector.into())?;
+        Ok(self.is_element_visible(target))
+    }
+
+    pub fn assert_visible(&self, selector: impl Into<Locator>) -> Result<()> {
+        let locator = selector.into();
+        let target = self.select_one(&locator)?;
+        if !self.is_element_visible(target) {
+            return Err(Error::AssertionFailed {
+                selector: locator.to_string(),
+                expected: "visible".to_string(),
+                actual: "hidden".to_string(),
+                dom_snippet: self.node_snippet(target),
+            });
+        }
+        Ok(())
+    }
+
+    pub fn dump_dom(&self, selector: impl Into<Locator>) -> Result<String> {
+        let target = self.select_one(&selector.into())?;
         Ok(render_js_string_for_display(&self.dom.dump_node(target)))
     }
 
-    pub(crate) fn select_one(&self, selector: &str) -> Result<NodeId> {
-        self.dom
-            .query_selector(selector)?
-            .ok_or_else(|| Error::SelectorNotFound(selector.to_string()))
+    pub(crate) fn select_one(&self, locator: &Locator) -> Result<NodeId> {
+        let found = match locator.css_selector() {
+            Some(selector) => self.dom.query_selector(selector)?,
+            None => self
+                .locate_all(locator)?
+                .get(locator.nth.unwrap_or(0))
+                .copied(),
+        };
+        found.ok_or_else(|| Error::SelectorNotFound(locator.to_string()))
     }
 
     pub(crate) fn node_snippet(&self, node_id: NodeId) -> String {
@@ -137,26 +166,29 @@ impl Harness {
                 continue;
             }
             let name = self.dom.attr(control, "name").unwrap_or_default();
-            let value = if self
-                .dom
-                .tag_name(control)
-                .is_some_and(|tag| tag.eq_ignore_ascii_case("input"))
-                && self
-                    .dom
-                    .attr(control, "type")
-                    .unwrap_or_else(|| "text".to_string())
-                    .eq_ignore_ascii_case("hidden")
-                && name == "_charset_"
-            {
-                "UTF-8".to_string()
-            } else {
-                self.form_data_control_value(control)?
-            };
+            let value = self.form_data_entry_value(control, &name)?;
             out.push((name, value));
         }
         Ok(out)
     }
 
+    pub(crate) fn form_data_entry_value(&self, control: NodeId, name: &str) -> Result<String> {
+        if self
+            .dom
+            .tag_name(control)
+            .is_some_and(|tag| tag.eq_ignore_ascii_case("input"))
+            && self
+                .dom
+                .attr(control, "type")
+                .unwrap_or_else(|| "text".to_string())
+                .eq_ignore_ascii_case("hidden")
+            && name == "_charset_"
+        {
+            return Ok("UTF-8".to_string());
+        }
+        self.form_data_control_value(control)
+    }
+
     pub(crate) fn is_successful_form_data_control(&self, control: NodeId) -> Result<bool> {
         if self.is_effectively_disabled(control) {
             return Ok(false);
@@ -0,0 +1,492 @@
+use super::*;
+
+impl Harness {
+    pub(crate) fn from_html_in_browsing_context(
+        url: &str,
+        html: &str,
+        context: BrowsingContextState,
+        shared_storage: &SharedOriginStorage,
+    ) -> Result<Self> {
+        let browser_apis = BrowserApiState {
+            local_storage_object: Self::new_storage_object_over(&shared_storage.local_storage),
+            cookies: shared_storage.cookies.clone(),
+            browsing_context: BrowsingContextState {
+                window_owned: true,
+                ..context
+            },
+            ..BrowserApiState::default()
+        };
+        Self::from_html_impl_with_browser_apis(
+            url,
+            html,
+            &[],
+            &[],
+            browser_apis,
+            PlatformMockState::default(),
+        )
+    }
+
+    pub(crate) fn apply_browsing_context_window_entries(&mut self) {
+        let opener = match self.browser_apis.browsing_context.opener.clone() {
+            Some(opener) => self.window_proxy_value(&opener),
+            None => Value::Null,
+        };
+        let name = self.browser_apis.browsing_context.name.clone();
+        {
+            let mut window = self.dom_runtime.window_object.borrow_mut();
+            Self::object_set_entry(&mut window, "opener".to_string(), opener.clone());
+            if !name.is_empty() {
+                Self::object_set_entry(&mut window, "name".to_string(), Value::String(name));
+            }
+        }
+        self.script_runtime.env.insert("opener".to_string(), opener);
+    }
+
+    pub(crate) fn window_proxy_value(&mut self, id: &str) -> Value {
+        if let Some((_, proxy)) = self
+            .browser_apis
+            .browsing_context
+            .proxies
+            .iter()
+            .find(|(proxy_id, _)| proxy_id == id)
+        {
+            return proxy.clone();
+        }
+        let context = Value::String(id.to_string());
+        let proxy = Self::new_object_value(vec![
+            (
+                INTERNAL_WINDOW_PROXY_CONTEXT_KEY.to_string(),
+                context.clone(),
+            ),
+            ("closed".to_string(), Value::Bool(false)),
+            (
+                "postMessage".to_string(),
+                Self::new_window_post_message_callable_value(),
+            ),
+            (
+                "close".to_string(),
+                Self::new_object_value(vec![
+                    (
+                        INTERNAL_CALLABLE_KIND_KEY.to_string(),
+                        Value::String("window_proxy_close_function".to_string()),
+                    ),
+                    (INTERNAL_WINDOW_PROXY_CONTEXT_KEY.to_string(), context),
+                ]),
+            ),
+        ]);
+        self.browser_apis
+            .browsing_context
+            .proxies
+            .push((id.to_string(), proxy.clone()));
+        proxy
+    }
+
+    pub(crate) fn window_proxy_context_id(value: &Value) -> Option<String> {
+        let Value::Object(object) = value else {
+            return None;
+        };
+        match Self::object_get_entry(&object.borrow(), INTERNAL_WINDOW_PROXY_CONTEXT_KEY) {
+            Some(Value::String(id)) => Some(id),
+            _ => None,
+        }
+    }
+
+    // Links and forms opening a new context imply `noopener` for `_blank`
+    // unless `rel=opener` asks otherwise.
+    pub(crate) fn rel_keeps_opener(&self, element: NodeId, target_name: &str) -> bool {
+        let rel = self
+            .dom
+            .attr(element, "rel")
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+        let rel_tokens = rel.split_ascii_whitespace().collect::<Vec<_>>();
+        !rel_tokens.contains(&"noopener")
+            && !rel_tokens.contains(&"noreferrer")
+            && (!target_name.eq_ignore_ascii_case("_blank") || rel_tokens.contains(&"opener"))
+    }
+
+    // Opens (or reuses, by name) an auxiliary browsing context. The owning
+    // `MockWindow` creates the page once the current action finishes.
+    pub(crate) fn open_browsing_context(
+        &mut self,
+        url: &str,
+        name: &str,
+        with_opener: bool,
+    ) -> Result<Value> {
+        let url = if url.trim().is_empty() {
+            "about:blank".to_string()
+        } else {
+            self.resolve_document_target_url(url)
+        };
+        let name = if name.eq_ignore_ascii_case("_blank") {
+            ""
+        } else {
+            name
+        };
+        let context = &mut self.browser_apis.browsing_context;
+        let existing = (!name.is_empty())
+            .then(|| {
+                context
+                    .named_children
+                    .iter()
+                    .find(|(child_name, _)| child_name == name)
+                    .map(|(_, id)| id.clone())
+            })
+            .flatten();
+        let id = existing.unwrap_or_else(|| {
+            let id = format!("{}.{}", context.id, context.next_child_id);
+            context.next_child_id += 1;
+            if !name.is_empty() {
+                context.named_children.push((name.to_string(), id.clone()));
+            }
+            id
+        });
+        let opener = with_opener.then(|| context.id.clone());
+        let html = self.location_history.location_mock_pages.get(&url).cloned();
+        self.queue_browsing_context_request(BrowsingContextRequest::Open {
+            id: id.clone(),
+            url,
+            html,
+            name: name.to_string(),
+            opener,
+        });
+        if with_opener {
+            Ok(self.window_proxy_value(&id))
+        } else {
+            Ok(Value::Null)
+        }
+    }
+
+    pub(crate) fn eval_window_open_call(&mut self, args: &[Value]) -> Result<Value> {
+        let url = match args.first() {
+            None | Some(Value::Undefined) => String::new(),
+            Some(value) => value.as_string(),
+        };
+        let target = match args.get(1) {
+            None | Some(Value::Undefined) => "_blank".to_string(),
+            Some(value) => value.as_string(),
+        };
+        let features = args.get(2).map(Value::as_string).unwrap_or_default();
+        let no_opener = features.split([',', ' ']).any(|feature| {
+            let (key, value) = feature.split_once('=').unwrap_or((feature, "yes"));
+            matches!(
+                key.trim().to_ascii_lowercase().as_str(),
+                "noopener" | "noreferrer"
+            ) && !matches!(
+                value.trim().to_ascii_lowercase().as_str(),
+                "0" | "no" | "false"
+            )
+        });
+
+        if matches!(
+            target.to_ascii_lowercase().as_str(),
+            "_self" | "_parent" | "_top" | "_unfencedtop"
+        ) {
+            if !url.trim().is_empty() {
+                self.navigate_location(&url, LocationNavigationKind::Assign)?;
+            }
+            return Ok(Value::Object(self.dom_runtime.window_object.clone()));
+        }
+        self.open_browsing_context(&url, &target, !no_opener)
+    }
+
+    pub(crate) fn queue_cross_context_post_message(
+        &mut self,
+        target: String,
+        target_origin: String,
+        data: &Value,
+    ) -> Result<()> {
+        let origin = self.current_location_parts().origin();
+        let target_origin = match target_origin.as_str() {
+            "*" => target_origin,
+            "/" => origin.clone(),
+            _ => LocationParts::parse(&target_origin)
+                .map(|parts| parts.origin())
+                .ok_or_else(|| {
+                    Error::ScriptRuntime(
+                        "SyntaxError: postMessage targetOrigin is not a valid URL".into(),
+                    )
+                })?,
+        };
+        let mut array_stack = Vec::new();
+        let mut object_stack = Vec::new();
+        let data = Self::structured_clone_value(data, &mut array_stack, &mut object_stack)?;
+        let source = self.browser_apis.browsing_context.id.clone();
+        self.queue_browsing_context_request(BrowsingContextRequest::PostMessage {
+            target,
+            source,
+            origin,
+            target_origin,
+            data,
+        });
+        Ok(())
+    }
+
+    pub(crate) fn request_browsing_context_close(&mut self, id: String) {
+        self.queue_browsing_context_request(BrowsingContextRequest::Close { id });
+    }
+
+    // A bare `Harness` has no window to hand requests to, so they are dropped.
+    pub(crate) fn queue_browsing_context_request(&mut self, request: BrowsingContextRequest) {
+        if self.browser_apis.browsing_context.window_owned {
+            self.browser_apis.browsing_context.requests.push(request);
+        }
+    }
+
+    pub(crate) fn take_browsing_context_requests(&mut self) -> Vec<BrowsingContextRequest> {
+        std::mem::take(&mut self.browser_apis.browsing_context.requests)
+    }
+
+    pub(crate) fn queue_cross_context_message(
+        &mut self,
+        source: &str,
+        origin: String,
+        data: Value,
+    ) {
+        let id = self.scheduler.allocate_internal_task_id();
+        let order = self.scheduler.allocate_task_order();
+        self.scheduler.task_queue.push(ScheduledTask {
+            id,
+            due_at: self.scheduler.now_ms,
+            order,
+            kind: ScheduledTaskKind::WindowMessage,
+            interval_ms: None,
+            callback: TimerCallback::Reference(String::new()),
+            callback_args: vec![
+                data,
+                Value::String(origin),
+                Value::String(source.to_string()),
+            ],
+            env: ScriptEnv::default(),
+        });
+        self.trace_timer_line(format!(
+            "[timer] schedule window message id={} source={} due_at={}",
+            id, source, self.scheduler.now_ms
+        ));
+    }
+
+    pub(crate) fn run_window_message_task(&mut self, task: ScheduledTask) -> Result<()> {
+        let mut args = task.callback_args.into_iter();
+        let (Some(data), Some(Value::String(origin)), Some(Value::String(source))) =
+            (args.next(), args.next(), args.next())
+        else {
+            return Ok(());
+        };
+        let source = self.window_proxy_value(&source);
+        self.dispatch_window_task_event("message", |event| {
+            event.message_data = Some(data);
+            event.message_origin = Some(origin);
+            event.message_source = Some(source);
+        })
+    }
+
+    // Fires a trusted, non-bubbling event at the window from a fresh task.
+    pub(crate) fn dispatch_window_task_event(
+        &mut self,
+        event_type: &str,
+        init: impl FnOnce(&mut EventState),
+    ) -> Result<()> {
+        let mut init = Some(init);
+        self.run_in_task_context(|this| {
+            this.with_script_env_always(|this, env| {
+                let target_object = this.dom_runtime.window_object.clone();
+                let target_node = this.event_target_listener_node_id(&target_object);
+                let target_value = Value::Object(target_object);
+                let mut event = EventState::new(event_type, target_node, this.scheduler.now_ms);
+                event.target_value = Some(target_value.clone());
+                event.current_target_value = Some(target_value);
+                event.bubbles = false;
+                event.cancelable = false;
+                if let Some(init) = init.take() {
+                    init(&mut event);
+                }
+                event.event_phase = 2;
+                event.current_target = target_node;
+                this.invoke_listeners(target_node, &mut event, env, true)?;
+                if !event.propagation_stopped {
+                    this.invoke_listeners(target_node, &mut event, env, false)?;
+                }
+                Ok(())
+            })
+        })
+    }
+
+    pub(crate) fn mark_browsing_context_closed(&mut self, id: &str) {
+        for (proxy_id, proxy) in &self.browser_apis.browsing_context.proxies {
+            if proxy_id != id {
+                continue;
+            }
+            if let Value::Object(proxy) = proxy {
+                Self::object_set_entry(
+                    &mut proxy.borrow_mut(),
+                    "closed".to_string(),
+                    Value::Bool(true),
+                );
+            }
+        }
+    }
+}
+
+impl MockWindow {
+    const MAX_BROWSING_CONTEXT_ROUNDS: usize = 64;
+
+    pub(crate) fn page_index_for_context(&self, id: &str) -> Option<usize> {
+        let id = self
+            .context_aliases
+            .iter()
+            .find(|(alias, _)| alias == id)
+            .map(|(_, target)| target.as_str())
+            .unwrap_or(id);
+        self.pages
+            .iter()
+            .position(|page| page.harness.browser_apis.browsing_context.id == id)
+    }
+
+    pub(crate) fn process_browsing_context_requests(&mut self) -> Result<()> {
+        for _ in 0..Self::MAX_BROWSING_CONTEXT_ROUNDS {
+            let requests = self
+                .pages
+                .iter_mut()
+                .flat_map(|page| page.harness.take_browsing_context_requests())
+                .collect::<Vec<_>>();
+            self.share_same_origin_storage();
+            if requests.is_empty() {
+                return Ok(());
+            }
+            let mut messaged = Vec::new();
+            for request in requests {
+                if let Some(target) = self.apply_browsing_context_request(request)? {
+                    if !messaged.contains(&target) {
+                        messaged.push(target);
+                    }
+                }
+            }
+            // Pages that were posted to spin their event loop so the queued
+            // message tasks run after whatever was already due there.
+            for target in messaged {
+                if let Some(index) = self.page_index_for_context(&target) {
+                    self.pages[index].harness.run_due_timers_internal()?;
+                }
+            }
+        }
+        Err(Error::ScriptRuntime(
+            "browsing context requests did not settle".into(),
+        ))
+    }
+
+    // Returns the context a message was queued on, if any.
+    fn apply_browsing_context_request(
+        &mut self,
+        request: BrowsingContextRequest,
+    ) -> Result<Option<String>> {
+        match request {
+            BrowsingContextRequest::Open {
+                id,
+                url,
+                html,
+                name,
+                opener,
+            } => {
+                let existing = self.page_index_for_context(&id).or_else(|| {
+                    (!name.is_empty())
+                        .then(|| {
+                            self.pages.iter().position(|page| {
+                                page.harness.browser_apis.browsing_context.name == name
+                            })
+                        })
+                        .flatten()
+                });
+                let context = match existing {
+                    Some(index) => {
+                        let current = &self.pages[index].harness.browser_apis.browsing_context;
+                        if current.id != id {
+                            self.context_aliases.push((id, current.id.clone()));
+                        }
+                        BrowsingContextState {
+                            id: current.id.clone(),
+                            name: current.name.clone(),
+                            opener: current.opener.clone(),
+                            script_opened: current.script_opened,
+                            ..BrowsingContextState::default()
+                        }
+                    }
+                    None => BrowsingContextState {
+                        id,
+                        name,
+                        opener,
+                        script_opened: true,
+                        ..BrowsingContextState::default()
+                    },
+                };
+                let shared_storage = self.shared_storage_for_url(&url);
+                let harness = Harness::from_html_in_browsing_context(
+                    &url,
+                    html.as_deref().unwrap_or_default(),
+                    context,
+                    &shared_storage,
+                )?;
+                match existing {
+                    Some(index) => self.pages[index] = MockPage { harness },
+                    None => self.pages.push(MockPage { harness }),
+                }
+            }
+            BrowsingContextRequest::PostMessage {
+                target,
+                source,
+                origin,
+                target_origin,
+                data,
+            } => {
+                let Some(index) = self.page_index_for_context(&target) else {
+                    return Ok(None);
+                };
+                let harness = &mut self.pages[index].harness;
+                if target_origin != "*"
+                    && target_origin != harness.current_location_parts().origin()
+                {
+                    return Ok(None);
+                }
+                harness.queue_cross_context_message(&source, origin, data);
+                return Ok(Some(harness.browser_apis.browsing_context.id.clone()));
+            }
+            BrowsingContextRequest::Close { id } => {
+                let Some(index) = self.page_index_for_context(&id) else {
+                    return Ok(None);
+                };
+                let removed = self.pages.remove(index);
+                let removed_id = removed.harness.browser_apis.browsing_context.id.clone();
+                for page in &mut self.pages {
+                    page.harness.mark_browsing_context_closed(&removed_id);
+                    for (alias, target) in &self.context_aliases {
+                        if *target == removed_id {
+                            page.harness.mark_browsing_context_closed(alias);
+                        }
+                    }
+                }
+                if self.current == index {
+                    self.current = removed
+                        .harness
+                        .browser_apis
+                        .browsing_context
+                        .opener
+                        .as_deref()
+                        .and_then(|opener| self.page_index_for_context(opener))
+                        .unwrap_or(0);
+                } else if self.current > index {
+                    self.current -= 1;
+                }
+            }
+            BrowsingContextRequest::StorageChanged {
+                source,
+                origin,
+                url,
+                key,
+                old_value,
+                new_value,
+            } => {
+                self.dispatch_storage_change(&source, &origin, &url, key, old_value, new_value)?;
+            }
+        }
+        Ok(None)
+    }
+}
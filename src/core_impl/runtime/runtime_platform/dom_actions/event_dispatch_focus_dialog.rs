@@ -99,6 +99,18 @@ impl Harness {
         self.dispatch_prepared_event_with_env(event, env)
     }
 
+    pub(crate) fn dispatch_event_with_members(
+        &mut self,
+        target: NodeId,
+        event_type: &str,
+        env: &mut HashMap<String, Value>,
+        members: Vec<(String, Value)>,
+    ) -> Result<EventState> {
+        let mut event = EventState::new(event_type, target, self.scheduler.now_ms);
+        event.interface_members = members;
+        self.dispatch_prepared_event_with_env(event, env)
+    }
+
     pub(crate) fn dispatch_prepared_event_with_env(
         &mut self,
         mut event: EventState,
@@ -198,13 +210,18 @@ impl Harness {
             return Ok(());
         }
 
-        if let Some(current) = self.dom.active_element() {
-            self.blur_node_with_env(current, env)?;
+        let previous = self.dom.active_element();
+        if let Some(current) = previous {
+            self.blur_node_with_related_target_with_env(current, Some(node), env)?;
         }
 
         self.dom.set_active_element(Some(node));
-        self.dispatch_event_with_env(node, "focusin", env, true)?;
-        self.dispatch_event_with_env(node, "focus", env, true)?;
+        let related_target = vec![(
+            "relatedTarget".to_string(),
+            previous.map(Value::Node).unwrap_or(Value::Null),
+        )];
+        self.dispatch_event_with_members(node, "focusin", env, related_target.clone())?;
+        self.dispatch_event_with_members(node, "focus", env, related_target)?;
         Ok(())
     }
 
@@ -216,13 +233,26 @@ impl Harness {
         &mut self,
         node: NodeId,
         env: &mut HashMap<String, Value>,
+    ) -> Result<()> {
+        self.blur_node_with_related_target_with_env(node, None, env)
+    }
+
+    fn blur_node_with_related_target_with_env(
+        &mut self,
+        node: NodeId,
+        related_target: Option<NodeId>,
+        env: &mut HashMap<String, Value>,
     ) -> Result<()> {
         if self.dom.active_element() != Some(node) {
             return Ok(());
         }
 
-        self.dispatch_event_with_env(node, "focusout", env, true)?;
-        self.dispatch_event_with_env(node, "blur", env, true)?;
+        let related_target = vec![(
+            "relatedTarget".to_string(),
+            related_target.map(Value::Node).unwrap_or(Value::Null),
+        )];
+        self.dispatch_event_with_members(node, "focusout", env, related_target.clone())?;
+        self.dispatch_event_with_members(node, "blur", env, related_target)?;
         self.dom.set_active_element(None);
         Ok(())
     }
@@ -319,6 +349,9 @@ impl Harness {
         env: &mut HashMap<String, Value>,
         position_changed: bool,
     ) -> Result<()> {
+        if position_changed {
+            self.update_layout_observations();
+        }
         let _ = self.dispatch_document_scroll_with_env(env)?;
         if position_changed {
             let _ = self.dispatch_document_scrollend_with_env(env)?;
@@ -371,11 +404,13 @@ impl Harness {
     pub(crate) fn show_dialog_with_env(
         &mut self,
         dialog: NodeId,
-        _modal: bool,
+        modal: bool,
         env: &mut HashMap<String, Value>,
     ) -> Result<()> {
         self.ensure_dialog_target(dialog, "show/showModal")?;
-        let _ = self.transition_dialog_open_state_with_env(dialog, true, false, env)?;
+        if self.transition_dialog_open_state_with_env(dialog, true, false, env)? && modal {
+            self.dom_runtime.modal_dialogs.push(dialog);
+        }
         Ok(())
     }
 
@@ -452,6 +487,9 @@ impl Harness {
             self.dom.set_attr(dialog, "open", "true")?;
         } else {
             self.dom.remove_attr(dialog, "open")?;
+            self.dom_runtime
+                .modal_dialogs
+                .retain(|modal| *modal != dialog);
         }
 
         let _ = self.dispatch_event_with_options(
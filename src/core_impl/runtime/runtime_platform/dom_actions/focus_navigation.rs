@@ -0,0 +1,232 @@
+use super::*;
+
+impl Harness {
+    pub fn press_tab(&mut self) -> Result<()> {
+        self.press_tab_key(false)
+    }
+
+    pub fn press_shift_tab(&mut self) -> Result<()> {
+        self.press_tab_key(true)
+    }
+
+    pub fn focus_order(&self) -> Vec<String> {
+        self.sequential_focus_order()
+            .into_iter()
+            .map(|node| self.trace_node_label(node))
+            .collect()
+    }
+
+    fn press_tab_key(&mut self, shift_key: bool) -> Result<()> {
+        let init = KeyboardEventInit {
+            shift_key,
+            ..Self::keyboard_init_for_key("Tab")
+        };
+        stacker::grow(32 * 1024 * 1024, || {
+            self.with_script_env_always(|this, env| this.press_key_with_env(&init, env))
+        })
+    }
+
+    pub(crate) fn sequential_focus_order(&self) -> Vec<NodeId> {
+        let scope = self.topmost_modal_dialog().unwrap_or(self.dom.root);
+        let mut candidates = Vec::new();
+        self.collect_sequential_focus_candidates(scope, None, &mut candidates);
+
+        let mut positive = Vec::new();
+        let mut in_order = Vec::new();
+        for node in candidates {
+            let Some(tab_index) = self.sequential_tab_index(node) else {
+                continue;
+            };
+            if tab_index < 0
+                || self.is_effectively_disabled(node)
+                || !self.is_shadow_including_connected(node)
+            {
+                continue;
+            }
+            if tab_index > 0 {
+                positive.push((tab_index, node));
+            } else {
+                in_order.push(node);
+            }
+        }
+        positive.sort_by_key(|(tab_index, _)| *tab_index);
+        positive
+            .into_iter()
+            .map(|(_, node)| node)
+            .chain(in_order)
+            .collect()
+    }
+
+    // Shadow trees hang off detached fragments, so step from their root to the host.
+    fn is_shadow_including_connected(&self, node: NodeId) -> bool {
+        let mut cursor = node;
+        loop {
+            if self.dom.is_connected(cursor) {
+                return true;
+            }
+            let mut root = cursor;
+            while let Some(parent) = self.dom.parent(root) {
+                root = parent;
+            }
+            let Some(host) = self
+                .dom_runtime
+                .shadow_roots
+                .iter()
+                .find(|(_, record)| record.root == root)
+                .map(|(host, _)| *host)
+            else {
+                return false;
+            };
+            cursor = host;
+        }
+    }
+
+    pub(crate) fn topmost_modal_dialog(&self) -> Option<NodeId> {
+        self.dom_runtime
+            .modal_dialogs
+            .iter()
+            .rev()
+            .copied()
+            .find(|dialog| {
+                self.dom.is_connected(*dialog)
+                    && self.dom.has_attr(*dialog, "open").unwrap_or(false)
+            })
+    }
+
+    fn collect_sequential_focus_candidates(
+        &self,
+        node: NodeId,
+        shadow_host: Option<NodeId>,
+        out: &mut Vec<NodeId>,
+    ) {
+        for child in self.composed_focus_children(node, shadow_host) {
+            if self.hides_sequential_focus_subtree(child) {
+                continue;
+            }
+            out.push(child);
+            let host = if self.dom_runtime.shadow_roots.contains_key(&child) {
+                Some(child)
+            } else {
+                shadow_host
+            };
+            self.collect_sequential_focus_candidates(child, host, out);
+        }
+    }
+
+    fn composed_focus_children(&self, node: NodeId, shadow_host: Option<NodeId>) -> Vec<NodeId> {
+        if let Some(record) = self.dom_runtime.shadow_roots.get(&node) {
+            return self.dom.child_elements(record.root);
+        }
+        let tag = self.dom.tag_name(node).map(str::to_ascii_lowercase);
+        match tag.as_deref() {
+            Some("slot") if shadow_host.is_some() => {
+                let host = shadow_host.unwrap_or(node);
+                let name = self.dom.attr(node, "name").unwrap_or_default();
+                let assigned = self
+                    .dom
+                    .child_elements(host)
+                    .into_iter()
+                    .filter(|child| self.dom.attr(*child, "slot").unwrap_or_default() == name)
+                    .collect::<Vec<_>>();
+                if assigned.is_empty() {
+                    self.dom.child_elements(node)
+                } else {
+                    assigned
+                }
+            }
+            Some("details") if !self.dom.has_attr(node, "open").unwrap_or(false) => self
+                .dom
+                .child_elements(node)
+                .into_iter()
+                .find(|child| {
+                    self.dom
+                        .tag_name(*child)
+                        .is_some_and(|tag| tag.eq_ignore_ascii_case("summary"))
+                })
+                .into_iter()
+                .collect(),
+            _ => self.dom.child_elements(node),
+        }
+    }
+
+    fn hides_sequential_focus_subtree(&self, node: NodeId) -> bool {
+        let Some(element) = self.dom.element(node) else {
+            return true;
+        };
+        if element.attrs.contains_key("inert")
+            || element
+                .attrs
+                .get("hidden")
+                .is_some_and(|value| value.eq_ignore_ascii_case("until-found"))
+        {
+            return true;
+        }
+        let computed = |property| {
+            self.computed_style_property_value(node, None, property)
+                .unwrap_or_default()
+        };
+        computed("display") == "none"
+            || matches!(computed("visibility").as_str(), "hidden" | "collapse")
+    }
+
+    pub(crate) fn sequential_tab_index(&self, node: NodeId) -> Option<i64> {
+        if let Some(raw) = self.dom.attr(node, "tabindex") {
+            if let Ok(tab_index) = raw.trim().parse::<i64>() {
+                return Some(tab_index);
+            }
+        }
+        let tag = self.dom.tag_name(node)?.to_ascii_lowercase();
+        let focusable = match tag.as_str() {
+            "a" | "area" => self.dom.attr(node, "href").is_some(),
+            "button" | "select" | "textarea" | "iframe" => true,
+            "input" => self.normalized_input_type(node) != "hidden",
+            "summary" => self.dom.parent(node).is_some_and(|details| {
+                self.dom
+                    .tag_name(details)
+                    .is_some_and(|tag| tag.eq_ignore_ascii_case("details"))
+                    && self.dom.child_elements(details).into_iter().find(|child| {
+                        self.dom
+                            .tag_name(*child)
+                            .is_some_and(|tag| tag.eq_ignore_ascii_case("summary"))
+                    }) == Some(node)
+            }),
+            _ => self.paste_contenteditable_host(node) == Some(node),
+        };
+        focusable.then_some(0)
+    }
+
+    pub(crate) fn move_sequential_focus_with_env(
+        &mut self,
+        forward: bool,
+        env: &mut HashMap<String, Value>,
+    ) -> Result<()> {
+        let order = self.sequential_focus_order();
+        let current = self
+            .dom
+            .active_element()
+            .and_then(|active| order.iter().position(|node| *node == active));
+        let next = match (current, forward) {
+            (Some(index), true) => order.get(index + 1).copied(),
+            (Some(index), false) => index
+                .checked_sub(1)
+                .and_then(|index| order.get(index))
+                .copied(),
+            (None, true) => order.first().copied(),
+            (None, false) => order.last().copied(),
+        };
+        match next {
+            Some(next) => {
+                self.focus_node_with_env(next, env)?;
+                if self.node_supports_text_selection(next) {
+                    let len = self.dom.value(next)?.chars().count();
+                    self.dom.set_selection_range(next, 0, len, "forward")?;
+                }
+                Ok(())
+            }
+            None => match self.dom.active_element() {
+                Some(active) => self.blur_node_with_env(active, env),
+                None => Ok(()),
+            },
+        }
+    }
+}
@@ -0,0 +1,281 @@
+use super::*;
+
+const FORM_SUBMISSION_MULTIPART_BOUNDARY: &str = "----BrowserTesterFormBoundary";
+
+enum FormSubmissionEntryValue {
+    Text(String),
+    File(MockFile),
+}
+
+impl Harness {
+    pub fn take_form_submissions(&mut self) -> Vec<FormSubmission> {
+        std::mem::take(&mut self.location_history.form_submissions)
+    }
+
+    fn form_submission_attr(
+        &self,
+        form: NodeId,
+        submitter: Option<NodeId>,
+        submitter_attr: &str,
+        form_attr: &str,
+    ) -> Option<String> {
+        submitter
+            .and_then(|node| self.dom.attr(node, submitter_attr))
+            .or_else(|| self.dom.attr(form, form_attr))
+    }
+
+    pub(crate) fn form_submission_method(&self, form: NodeId, submitter: Option<NodeId>) -> String {
+        let method = self
+            .form_submission_attr(form, submitter, "formmethod", "method")
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+        match method.as_str() {
+            "post" | "dialog" => method,
+            _ => "get".to_string(),
+        }
+    }
+
+    fn form_submission_enctype(&self, form: NodeId, submitter: Option<NodeId>) -> String {
+        let enctype = self
+            .form_submission_attr(form, submitter, "formenctype", "enctype")
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+        match enctype.as_str() {
+            "multipart/form-data" | "text/plain" => enctype,
+            _ => "application/x-www-form-urlencoded".to_string(),
+        }
+    }
+
+    fn form_submission_entries(
+        &self,
+        form: NodeId,
+        submitter: Option<NodeId>,
+    ) -> Result<Vec<(String, FormSubmissionEntryValue)>> {
+        let mut out = Vec::new();
+        for control in self.form_elements(form)? {
+            if Some(control) == submitter {
+                if self.is_effectively_disabled(control) {
+                    continue;
+                }
+                let name = self.dom.attr(control, "name").unwrap_or_default();
+                let is_image = self
+                    .dom
+                    .attr(control, "type")
+                    .is_some_and(|kind| kind.eq_ignore_ascii_case("image"));
+                if is_image {
+                    let prefix = if name.is_empty() {
+                        String::new()
+                    } else {
+                        format!("{name}.")
+                    };
+                    for axis in ["x", "y"] {
+                        out.push((
+                            format!("{prefix}{axis}"),
+                            FormSubmissionEntryValue::Text("0".to_string()),
+                        ));
+                    }
+                } else if !name.is_empty() {
+                    let value = self.dom.attr(control, "value").unwrap_or_default();
+                    out.push((name, FormSubmissionEntryValue::Text(value)));
+                }
+                continue;
+            }
+
+            let is_file_input = self.dom.element(control).is_some_and(is_file_input_element);
+            if is_file_input {
+                let name = self.dom.attr(control, "name").unwrap_or_default();
+                if name.is_empty() || self.is_effectively_disabled(control) {
+                    continue;
+                }
+                let files = self
+                    .dom
+                    .element(control)
+                    .map(|element| element.files.clone())
+                    .unwrap_or_default();
+                if files.is_empty() {
+                    let mut empty = MockFile::new("");
+                    empty.mime_type = "application/octet-stream".to_string();
+                    out.push((name, FormSubmissionEntryValue::File(empty)));
+                } else {
+                    for file in files {
+                        out.push((name.clone(), FormSubmissionEntryValue::File(file)));
+                    }
+                }
+                continue;
+            }
+
+            if !self.is_successful_form_data_control(control)? {
+                continue;
+            }
+            let name = self.dom.attr(control, "name").unwrap_or_default();
+            let value = self.form_data_entry_value(control, &name)?;
+            out.push((name, FormSubmissionEntryValue::Text(value)));
+        }
+        Ok(out)
+    }
+
+    fn normalize_form_submission_newlines(value: &str) -> String {
+        value
+            .replace("\r\n", "\n")
+            .replace('\r', "\n")
+            .replace('\n', "\r\n")
+    }
+
+    fn encode_form_submission_urlencoded(entries: &[(String, FormSubmissionEntryValue)]) -> String {
+        let pairs = entries
+            .iter()
+            .map(|(name, value)| {
+                let value = match value {
+                    FormSubmissionEntryValue::Text(text) => {
+                        Self::normalize_form_submission_newlines(text)
+                    }
+                    FormSubmissionEntryValue::File(file) => file.name.clone(),
+                };
+                (Self::normalize_form_submission_newlines(name), value)
+            })
+            .collect::<Vec<_>>();
+        serialize_url_search_params_pairs(&pairs)
+    }
+
+    fn encode_form_submission_text_plain(entries: &[(String, FormSubmissionEntryValue)]) -> String {
+        let mut out = String::new();
+        for (name, value) in entries {
+            let value = match value {
+                FormSubmissionEntryValue::Text(text) => text.as_str(),
+                FormSubmissionEntryValue::File(file) => file.name.as_str(),
+            };
+            out.push_str(&Self::normalize_form_submission_newlines(name));
+            out.push('=');
+            out.push_str(&Self::normalize_form_submission_newlines(value));
+            out.push_str("\r\n");
+        }
+        out
+    }
+
+    fn escape_multipart_header_value(value: &str) -> String {
+        value
+            .replace('\n', "%0A")
+            .replace('\r', "%0D")
+            .replace('"', "%22")
+    }
+
+    fn encode_form_submission_multipart(entries: &[(String, FormSubmissionEntryValue)]) -> Vec<u8> {
+        let mut out = Vec::new();
+        for (name, value) in entries {
+            let name = Self::escape_multipart_header_value(
+                &Self::normalize_form_submission_newlines(name),
+            );
+            out.extend_from_slice(
+                format!(
+                    "--{FORM_SUBMISSION_MULTIPART_BOUNDARY}\r\nContent-Disposition: form-data; name=\"{name}\""
+                )
+                .as_bytes(),
+            );
+            match value {
+                FormSubmissionEntryValue::Text(text) => {
+                    out.extend_from_slice(b"\r\n\r\n");
+                    out.extend_from_slice(
+                        Self::normalize_form_submission_newlines(text).as_bytes(),
+                    );
+                }
+                FormSubmissionEntryValue::File(file) => {
+                    let mime_type = if file.mime_type.is_empty() {
+                        "application/octet-stream"
+                    } else {
+                        file.mime_type.as_str()
+                    };
+                    out.extend_from_slice(
+                        format!(
+                            "; filename=\"{}\"\r\nContent-Type: {mime_type}\r\n\r\n",
+                            Self::escape_multipart_header_value(&file.name)
+                        )
+                        .as_bytes(),
+                    );
+                    out.extend_from_slice(&file.bytes);
+                }
+            }
+            out.extend_from_slice(b"\r\n");
+        }
+        out.extend_from_slice(format!("--{FORM_SUBMISSION_MULTIPART_BOUNDARY}--\r\n").as_bytes());
+        out
+    }
+
+    pub(crate) fn submit_form_entry_list(
+        &mut self,
+        form: NodeId,
+        submitter: Option<NodeId>,
+    ) -> Result<()> {
+        let method = self.form_submission_method(form, submitter);
+        if method == "dialog" {
+            return Ok(());
+        }
+
+        let action = self
+            .form_submission_attr(form, submitter, "formaction", "action")
+            .unwrap_or_default();
+        let action = if action.trim().is_empty() {
+            self.document_url.clone()
+        } else {
+            self.resolve_document_target_url(&action)
+        };
+        let Some(mut parts) = LocationParts::parse(&action) else {
+            return Ok(());
+        };
+        if parts.scheme.eq_ignore_ascii_case("javascript") {
+            return Ok(());
+        }
+
+        let enctype = self.form_submission_enctype(form, submitter);
+        let entries = self.form_submission_entries(form, submitter)?;
+        let (url, enctype, body) = if method == "get" {
+            let query = Self::encode_form_submission_urlencoded(&entries);
+            parts.search = if query.is_empty() {
+                String::new()
+            } else {
+                format!("?{query}")
+            };
+            (
+                parts.href(),
+                "application/x-www-form-urlencoded".to_string(),
+                Vec::new(),
+            )
+        } else {
+            let (enctype, body) = match enctype.as_str() {
+                "multipart/form-data" => (
+                    format!("{enctype}; boundary={FORM_SUBMISSION_MULTIPART_BOUNDARY}"),
+                    Self::encode_form_submission_multipart(&entries),
+                ),
+                "text/plain" => (
+                    enctype,
+                    Self::encode_form_submission_text_plain(&entries).into_bytes(),
+                ),
+                _ => (
+                    enctype,
+                    Self::encode_form_submission_urlencoded(&entries).into_bytes(),
+                ),
+            };
+            (action, enctype, body)
+        };
+
+        self.location_history.form_submissions.push(FormSubmission {
+            method: method.to_ascii_uppercase(),
+            url: url.clone(),
+            enctype,
+            body,
+            submitter: submitter.map(|node| self.trace_node_label(node)),
+        });
+
+        let target = self
+            .form_submission_attr(form, submitter, "formtarget", "target")
+            .unwrap_or_else(|| self.default_hyperlink_target());
+        if !matches!(
+            target.to_ascii_lowercase().as_str(),
+            "" | "_self" | "_parent" | "_top" | "_unfencedtop"
+        ) {
+            let with_opener = self.rel_keeps_opener(form, &target);
+            let _ = self.open_browsing_context(&url, &target, with_opener)?;
+            return Ok(());
+        }
+        self.navigate_location(&url, LocationNavigationKind::Assign)
+    }
+}
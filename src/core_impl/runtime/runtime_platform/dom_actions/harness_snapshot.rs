@@ -0,0 +1,907 @@
+use super::*;
+use std::any::Any;
+use std::cell::Cell;
+
+impl Harness {
+    pub fn snapshot(&self) -> HarnessSnapshot {
+        HarnessSnapshot {
+            harness: Box::new(self.deep_copy()),
+        }
+    }
+
+    pub fn restore(&mut self, snapshot: &HarnessSnapshot) {
+        *self = snapshot.harness.deep_copy();
+    }
+
+    // A fork stands alone, so no `MockWindow` collects its context requests.
+    pub fn fork(&self) -> Harness {
+        let mut fork = self.deep_copy();
+        fork.browser_apis.browsing_context.window_owned = false;
+        fork
+    }
+
+    fn deep_copy(&self) -> Harness {
+        stacker::grow(32 * 1024 * 1024, || SnapshotCopier::default().harness(self))
+    }
+}
+
+// Copies every piece of harness state while keeping aliasing intact: each shared
+// allocation reachable from the source is copied once and every reference to it
+// is pointed at that single copy.
+#[derive(Default)]
+struct SnapshotCopier {
+    copies: HashMap<usize, Box<dyn Any>>,
+}
+
+impl SnapshotCopier {
+    fn copied<T: ?Sized + 'static>(&self, key: usize) -> Option<Rc<T>> {
+        self.copies
+            .get(&key)
+            .and_then(|copy| copy.downcast_ref::<Rc<T>>())
+            .cloned()
+    }
+
+    // The copy is registered before its contents are walked, so cycles that come
+    // back to `source` resolve to the copy under construction.
+    fn cell<T: 'static>(
+        &mut self,
+        source: &Rc<RefCell<T>>,
+        empty: impl FnOnce() -> T,
+        copy: impl FnOnce(&mut Self, &T) -> T,
+    ) -> Rc<RefCell<T>> {
+        let key = Rc::as_ptr(source) as *const () as usize;
+        if let Some(existing) = self.copied::<RefCell<T>>(key) {
+            return existing;
+        }
+        let target = Rc::new(RefCell::new(empty()));
+        self.copies.insert(key, Box::new(target.clone()));
+        let contents = copy(self, &source.borrow());
+        *target.borrow_mut() = contents;
+        target
+    }
+
+    // Immutable allocations can only be reached again through a cell inside them;
+    // when that happened the copy made on the inner visit wins.
+    fn shared<T: 'static>(
+        &mut self,
+        source: &Rc<T>,
+        copy: impl FnOnce(&mut Self, &T) -> T,
+    ) -> Rc<T> {
+        let key = Rc::as_ptr(source) as *const () as usize;
+        if let Some(existing) = self.copied::<T>(key) {
+            return existing;
+        }
+        let target = Rc::new(copy(self, source));
+        if let Some(existing) = self.copied::<T>(key) {
+            return existing;
+        }
+        self.copies.insert(key, Box::new(target.clone()));
+        target
+    }
+
+    fn copied_object_key(&self, key: usize) -> Option<usize> {
+        self.copied::<RefCell<ObjectValue>>(key)
+            .map(|object| Rc::as_ptr(&object) as *const () as usize)
+    }
+
+    fn value(&mut self, value: &Value) -> Value {
+        stacker::maybe_grow(64 * 1024, 4 * 1024 * 1024, || self.value_impl(value))
+    }
+
+    fn value_impl(&mut self, value: &Value) -> Value {
+        match value {
+            Value::Array(array) => Value::Array(self.array(array)),
+            Value::Object(object) => Value::Object(self.object(object)),
+            Value::Promise(promise) => Value::Promise(self.promise(promise)),
+            Value::Map(map) => Value::Map(self.cell(
+                map,
+                || map.borrow().clone(),
+                |this, map| MapValue {
+                    entries: this.value_pairs(&map.entries),
+                    properties: this.object_value(&map.properties),
+                },
+            )),
+            Value::WeakMap(map) => Value::WeakMap(self.cell(
+                map,
+                || map.borrow().clone(),
+                |this, map| WeakMapValue {
+                    entries: this.value_pairs(&map.entries),
+                    properties: this.object_value(&map.properties),
+                },
+            )),
+            Value::Set(set) => Value::Set(self.cell(
+                set,
+                || set.borrow().clone(),
+                |this, set| SetValue {
+                    values: this.values(&set.values),
+                    properties: this.object_value(&set.properties),
+                },
+            )),
+            Value::WeakSet(set) => Value::WeakSet(self.cell(
+                set,
+                || set.borrow().clone(),
+                |this, set| WeakSetValue {
+                    values: this.values(&set.values),
+                    properties: this.object_value(&set.properties),
+                },
+            )),
+            Value::Blob(blob) => Value::Blob(self.blob(blob)),
+            Value::ArrayBuffer(buffer) => Value::ArrayBuffer(self.array_buffer(buffer)),
+            Value::TypedArray(array) => Value::TypedArray(self.cell(
+                array,
+                || array.borrow().clone(),
+                |this, array| TypedArrayValue {
+                    buffer: this.array_buffer(&array.buffer),
+                    ..array.clone()
+                },
+            )),
+            Value::PromiseCapability(capability) => {
+                Value::PromiseCapability(self.shared(capability, |this, capability| {
+                    PromiseCapabilityFunction {
+                        promise: this.promise(&capability.promise),
+                        reject: capability.reject,
+                        already_called: this.cell(
+                            &capability.already_called,
+                            bool::default,
+                            |_, called| *called,
+                        ),
+                    }
+                }))
+            }
+            Value::RegExp(regex) => Value::RegExp(self.cell(
+                regex,
+                || regex.borrow().clone(),
+                |this, regex| RegexValue {
+                    properties: this.object_value(&regex.properties),
+                    ..regex.clone()
+                },
+            )),
+            Value::Date(date) => Value::Date(self.cell(date, i64::default, |_, date| *date)),
+            Value::NodeList(list) => Value::NodeList(self.node_list(list)),
+            Value::FormData(entries) => {
+                Value::FormData(self.cell(entries, Vec::new, |_, entries| entries.clone()))
+            }
+            Value::Function(function) => Value::Function(self.function(function)),
+            // Symbols are immutable and compared by id, so copies can share them.
+            other => other.clone(),
+        }
+    }
+
+    fn values(&mut self, values: &[Value]) -> Vec<Value> {
+        values.iter().map(|value| self.value(value)).collect()
+    }
+
+    fn optional_value(&mut self, value: &Option<Value>) -> Option<Value> {
+        value.as_ref().map(|value| self.value(value))
+    }
+
+    fn optional_values(&mut self, values: &[Option<Value>]) -> Vec<Option<Value>> {
+        values
+            .iter()
+            .map(|value| self.optional_value(value))
+            .collect()
+    }
+
+    fn value_pairs(&mut self, entries: &[(Value, Value)]) -> Vec<(Value, Value)> {
+        entries
+            .iter()
+            .map(|(key, value)| (self.value(key), self.value(value)))
+            .collect()
+    }
+
+    fn module_exports(
+        &mut self,
+        exports: &Rc<RefCell<HashMap<String, ModuleExportBinding>>>,
+    ) -> Rc<RefCell<HashMap<String, ModuleExportBinding>>> {
+        self.cell(exports, HashMap::new, |this, exports| {
+            exports
+                .iter()
+                .map(|(name, binding)| {
+                    let binding = match binding {
+                        ModuleExportBinding::Local(local) => {
+                            ModuleExportBinding::Local(local.clone())
+                        }
+                        ModuleExportBinding::Value(value) => {
+                            ModuleExportBinding::Value(this.value(value))
+                        }
+                    };
+                    (name.clone(), binding)
+                })
+                .collect()
+        })
+    }
+
+    fn value_map<K: Clone + Eq + std::hash::Hash>(
+        &mut self,
+        map: &HashMap<K, Value>,
+    ) -> HashMap<K, Value> {
+        map.iter()
+            .map(|(key, value)| (key.clone(), self.value(value)))
+            .collect()
+    }
+
+    fn object_value(&mut self, object: &ObjectValue) -> ObjectValue {
+        ObjectValue {
+            entries: object
+                .entries
+                .iter()
+                .map(|(key, value)| (key.clone(), self.value(value)))
+                .collect(),
+            index_by_key: object.index_by_key.clone(),
+        }
+    }
+
+    fn object(&mut self, object: &Rc<RefCell<ObjectValue>>) -> Rc<RefCell<ObjectValue>> {
+        self.cell(object, ObjectValue::default, Self::object_value)
+    }
+
+    fn optional_object(
+        &mut self,
+        object: &Option<Rc<RefCell<ObjectValue>>>,
+    ) -> Option<Rc<RefCell<ObjectValue>>> {
+        object.as_ref().map(|object| self.object(object))
+    }
+
+    fn object_map<K: Clone + Eq + std::hash::Hash>(
+        &mut self,
+        map: &HashMap<K, Rc<RefCell<ObjectValue>>>,
+    ) -> HashMap<K, Rc<RefCell<ObjectValue>>> {
+        map.iter()
+            .map(|(key, object)| (key.clone(), self.object(object)))
+            .collect()
+    }
+
+    fn array(&mut self, array: &Rc<RefCell<ArrayValue>>) -> Rc<RefCell<ArrayValue>> {
+        self.cell(array, ArrayValue::default, |this, array| ArrayValue {
+            elements: this.values(&array.elements),
+            properties: this.object_value(&array.properties),
+        })
+    }
+
+    fn blob(&mut self, blob: &Rc<RefCell<BlobValue>>) -> Rc<RefCell<BlobValue>> {
+        self.cell(blob, || blob.borrow().clone(), |_, blob| blob.clone())
+    }
+
+    fn array_buffer(
+        &mut self,
+        buffer: &Rc<RefCell<ArrayBufferValue>>,
+    ) -> Rc<RefCell<ArrayBufferValue>> {
+        self.cell(
+            buffer,
+            || buffer.borrow().clone(),
+            |_, buffer| buffer.clone(),
+        )
+    }
+
+    fn node_list(&mut self, list: &Rc<RefCell<NodeListValue>>) -> Rc<RefCell<NodeListValue>> {
+        self.cell(list, || list.borrow().clone(), |_, list| list.clone())
+    }
+
+    fn env(&mut self, env: &ScriptEnv) -> ScriptEnv {
+        let key = Arc::as_ptr(&env.inner) as *const () as usize;
+        let copied = |this: &Self| {
+            this.copies
+                .get(&key)
+                .and_then(|copy| copy.downcast_ref::<ScriptEnv>())
+                .map(ScriptEnv::share)
+        };
+        if let Some(existing) = copied(self) {
+            return existing;
+        }
+        let mut copy = ScriptEnv::default();
+        *copy = self.value_map(&env.inner);
+        if let Some(existing) = copied(self) {
+            return existing;
+        }
+        self.copies.insert(key, Box::new(copy.share()));
+        copy
+    }
+
+    fn env_cell(&mut self, env: &Rc<RefCell<ScriptEnv>>) -> Rc<RefCell<ScriptEnv>> {
+        self.cell(env, ScriptEnv::default, Self::env)
+    }
+
+    fn function(&mut self, function: &Rc<FunctionValue>) -> Rc<FunctionValue> {
+        self.shared(function, |this, function| FunctionValue {
+            captured_env: this.env_cell(&function.captured_env),
+            prototype_object: this.object(&function.prototype_object),
+            class_super_constructor: this.optional_value(&function.class_super_constructor),
+            class_super_prototype: this.optional_value(&function.class_super_prototype),
+            ..function.clone()
+        })
+    }
+
+    fn promise(&mut self, promise: &Rc<RefCell<PromiseValue>>) -> Rc<RefCell<PromiseValue>> {
+        self.cell(
+            promise,
+            || promise.borrow().clone(),
+            |this, promise| PromiseValue {
+                id: promise.id,
+                state: match &promise.state {
+                    PromiseState::Pending => PromiseState::Pending,
+                    PromiseState::Fulfilled(value) => PromiseState::Fulfilled(this.value(value)),
+                    PromiseState::Rejected(value) => PromiseState::Rejected(this.value(value)),
+                },
+                reactions: promise
+                    .reactions
+                    .iter()
+                    .map(|reaction| PromiseReaction {
+                        kind: this.promise_reaction(&reaction.kind),
+                    })
+                    .collect(),
+            },
+        )
+    }
+
+    fn promise_settled(&mut self, settled: &PromiseSettledValue) -> PromiseSettledValue {
+        match settled {
+            PromiseSettledValue::Fulfilled(value) => {
+                PromiseSettledValue::Fulfilled(self.value(value))
+            }
+            PromiseSettledValue::Rejected(value) => {
+                PromiseSettledValue::Rejected(self.value(value))
+            }
+        }
+    }
+
+    fn promise_reaction(&mut self, kind: &PromiseReactionKind) -> PromiseReactionKind {
+        match kind {
+            PromiseReactionKind::Then {
+                on_fulfilled,
+                on_rejected,
+                result,
+            } => PromiseReactionKind::Then {
+                on_fulfilled: self.optional_value(on_fulfilled),
+                on_rejected: self.optional_value(on_rejected),
+                result: self.promise(result),
+            },
+            PromiseReactionKind::Finally { callback, result } => PromiseReactionKind::Finally {
+                callback: self.optional_value(callback),
+                result: self.promise(result),
+            },
+            PromiseReactionKind::FinallyContinuation { original, result } => {
+                PromiseReactionKind::FinallyContinuation {
+                    original: self.promise_settled(original),
+                    result: self.promise(result),
+                }
+            }
+            PromiseReactionKind::ResolveTo { target } => PromiseReactionKind::ResolveTo {
+                target: self.promise(target),
+            },
+            PromiseReactionKind::All { state, index } => PromiseReactionKind::All {
+                state: self.cell(
+                    state,
+                    || state.borrow().clone(),
+                    |this, state| PromiseAllState {
+                        result: this.promise(&state.result),
+                        remaining: state.remaining,
+                        values: this.optional_values(&state.values),
+                        settled: state.settled,
+                    },
+                ),
+                index: *index,
+            },
+            PromiseReactionKind::AllSettled { state, index } => PromiseReactionKind::AllSettled {
+                state: self.cell(
+                    state,
+                    || state.borrow().clone(),
+                    |this, state| PromiseAllSettledState {
+                        result: this.promise(&state.result),
+                        remaining: state.remaining,
+                        values: this.optional_values(&state.values),
+                    },
+                ),
+                index: *index,
+            },
+            PromiseReactionKind::Any { state, index } => PromiseReactionKind::Any {
+                state: self.cell(
+                    state,
+                    || state.borrow().clone(),
+                    |this, state| PromiseAnyState {
+                        result: this.promise(&state.result),
+                        remaining: state.remaining,
+                        reasons: this.optional_values(&state.reasons),
+                        settled: state.settled,
+                    },
+                ),
+                index: *index,
+            },
+            PromiseReactionKind::Race { state } => PromiseReactionKind::Race {
+                state: self.cell(
+                    state,
+                    || state.borrow().clone(),
+                    |this, state| PromiseRaceState {
+                        result: this.promise(&state.result),
+                        settled: state.settled,
+                    },
+                ),
+            },
+        }
+    }
+
+    fn listener(&mut self, listener: &Listener) -> Listener {
+        Listener {
+            captured_env: self.env_cell(&listener.captured_env),
+            signal: self.optional_object(&listener.signal),
+            removed: Rc::new(Cell::new(listener.removed.get())),
+            ..listener.clone()
+        }
+    }
+
+    fn harness(&mut self, source: &Harness) -> Harness {
+        let mut harness = Harness {
+            dom: source.dom.clone(),
+            listeners: ListenerStore {
+                map: source
+                    .listeners
+                    .map
+                    .iter()
+                    .map(|(node, events)| {
+                        let events = events
+                            .iter()
+                            .map(|(event, listeners)| {
+                                let listeners =
+                                    listeners.iter().map(|l| self.listener(l)).collect();
+                                (event.clone(), listeners)
+                            })
+                            .collect();
+                        (*node, events)
+                    })
+                    .collect(),
+            },
+            dom_runtime: self.dom_runtime(&source.dom_runtime),
+            script_runtime: self.script_runtime(&source.script_runtime),
+            document_url: source.document_url.clone(),
+            location_history: self.location_history(&source.location_history),
+            scheduler: self.scheduler(&source.scheduler),
+            promise_runtime: PromiseRuntimeState {
+                next_promise_id: source.promise_runtime.next_promise_id,
+            },
+            symbol_runtime: SymbolRuntimeState {
+                next_symbol_id: source.symbol_runtime.next_symbol_id,
+                symbol_registry: source.symbol_runtime.symbol_registry.clone(),
+                symbols_by_id: source.symbol_runtime.symbols_by_id.clone(),
+                well_known_symbols: source.symbol_runtime.well_known_symbols.clone(),
+            },
+            browser_apis: self.browser_apis(&source.browser_apis),
+            rng_state: source.rng_state,
+            platform_mocks: self.platform_mocks(&source.platform_mocks),
+            trace_state: TraceState {
+                logs: source.trace_state.logs.clone(),
+                ..source.trace_state
+            },
+        };
+
+        // These tables are keyed by object address, so they follow the objects
+        // to their copies; entries for objects nothing references any more drop out.
+        harness.script_runtime.event_target_listener_nodes = source
+            .script_runtime
+            .event_target_listener_nodes
+            .iter()
+            .filter_map(|(key, node)| Some((self.copied_object_key(*key)?, *node)))
+            .collect();
+        harness.script_runtime.private_instance_slots =
+            std::mem::take(&mut harness.script_runtime.private_instance_slots)
+                .into_iter()
+                .filter_map(|(key, slots)| Some((self.copied_object_key(key)?, slots)))
+                .collect();
+        harness
+    }
+
+    fn dom_runtime(&mut self, source: &DomRuntimeState) -> DomRuntimeState {
+        DomRuntimeState {
+            window_object: self.object(&source.window_object),
+            document_object: self.object(&source.document_object),
+            location_object: self.object(&source.location_object),
+            selection_object: self.object(&source.selection_object),
+            document_ready_state: source.document_ready_state.clone(),
+            document_visibility_state: source.document_visibility_state.clone(),
+            document_scroll_x: source.document_scroll_x,
+            document_scroll_y: source.document_scroll_y,
+            node_event_handler_props: source.node_event_handler_props.clone(),
+            node_expando_props: self.value_map(&source.node_expando_props),
+            live_child_nodes_lists: source
+                .live_child_nodes_lists
+                .iter()
+                .map(|(node, list)| (*node, self.node_list(list)))
+                .collect(),
+            live_children_lists: source
+                .live_children_lists
+                .iter()
+                .map(|(node, list)| (*node, self.node_list(list)))
+                .collect(),
+            live_named_node_maps: self.object_map(&source.live_named_node_maps),
+            node_animations: source
+                .node_animations
+                .iter()
+                .map(|record| NodeAnimationRecord {
+                    target: record.target,
+                    animation: self.object(&record.animation),
+                })
+                .collect(),
+            pointer_capture_targets: source.pointer_capture_targets.clone(),
+            pointer_input: source.pointer_input.clone(),
+            shadow_roots: source.shadow_roots.clone(),
+            dialog_return_values: source.dialog_return_values.clone(),
+            modal_dialogs: source.modal_dialogs.clone(),
+            click_in_progress: source.click_in_progress.clone(),
+            mutation_observers: MutationObserverState {
+                next_id: source.mutation_observers.next_id,
+                observers: source
+                    .mutation_observers
+                    .observers
+                    .iter()
+                    .map(|entry| MutationObserverEntry {
+                        id: entry.id,
+                        callback: self.value(&entry.callback),
+                        object: self.value(&entry.object),
+                        records: self.values(&entry.records),
+                    })
+                    .collect(),
+                delivery_queued: source.mutation_observers.delivery_queued,
+            },
+            layout_observers: LayoutObserverState {
+                next_id: source.layout_observers.next_id,
+                observers: source
+                    .layout_observers
+                    .observers
+                    .iter()
+                    .map(|entry| LayoutObserverEntry {
+                        callback: self.value(&entry.callback),
+                        object: self.value(&entry.object),
+                        records: self.values(&entry.records),
+                        ..entry.clone()
+                    })
+                    .collect(),
+                forced_intersection_ratios: source
+                    .layout_observers
+                    .forced_intersection_ratios
+                    .clone(),
+                delivery_queued: source.layout_observers.delivery_queued,
+            },
+            custom_elements: CustomElementRegistryState {
+                definitions: source
+                    .custom_elements
+                    .definitions
+                    .iter()
+                    .map(|entry| CustomElementDefinitionEntry {
+                        constructor: self.value(&entry.constructor),
+                        prototype: self.value(&entry.prototype),
+                        connected_callback: entry
+                            .connected_callback
+                            .as_ref()
+                            .map(|callback| self.value(callback)),
+                        disconnected_callback: entry
+                            .disconnected_callback
+                            .as_ref()
+                            .map(|callback| self.value(callback)),
+                        adopted_callback: entry
+                            .adopted_callback
+                            .as_ref()
+                            .map(|callback| self.value(callback)),
+                        attribute_changed_callback: entry
+                            .attribute_changed_callback
+                            .as_ref()
+                            .map(|callback| self.value(callback)),
+                        ..entry.clone()
+                    })
+                    .collect(),
+                when_defined: source
+                    .custom_elements
+                    .when_defined
+                    .iter()
+                    .map(|(name, promise)| (name.clone(), self.value(promise)))
+                    .collect(),
+                definition_running: source.custom_elements.definition_running,
+            },
+            current_script: source.current_script,
+            linked_stylesheets: source.linked_stylesheets.clone(),
+            style_sheet_cache: RefCell::default(),
+        }
+    }
+
+    fn script_runtime(&mut self, source: &ScriptRuntimeState) -> ScriptRuntimeState {
+        ScriptRuntimeState {
+            env: self.env(&source.env),
+            pending_function_decls: source.pending_function_decls.clone(),
+            listener_capture_env_stack: source
+                .listener_capture_env_stack
+                .iter()
+                .map(|frame| ListenerCaptureFrame {
+                    shared_env: frame.shared_env.as_ref().map(|env| self.env_cell(env)),
+                    pending_env_updates: frame
+                        .pending_env_updates
+                        .iter()
+                        .map(|(name, value)| (name.clone(), self.optional_value(value)))
+                        .collect(),
+                    inherit_outer_pending: frame.inherit_outer_pending,
+                })
+                .collect(),
+            generator_yield_stack: source
+                .generator_yield_stack
+                .iter()
+                .map(|values| self.cell(values, Vec::new, |this, values| this.values(values)))
+                .collect(),
+            pending_loop_labels: source.pending_loop_labels.clone(),
+            loop_label_stack: source.loop_label_stack.clone(),
+            tdz_scope_stack: source.tdz_scope_stack.clone(),
+            module_export_stack: source
+                .module_export_stack
+                .iter()
+                .map(|exports| self.module_exports(exports))
+                .collect(),
+            module_referrer_stack: source.module_referrer_stack.clone(),
+            modules: source
+                .modules
+                .iter()
+                .map(|(url, record)| {
+                    let record = ModuleRecord {
+                        status: record.status,
+                        body: record.body.clone(),
+                        requested_modules: record.requested_modules.clone(),
+                        exports: self.module_exports(&record.exports),
+                        scope: record.scope,
+                        error: record.error.clone(),
+                    };
+                    (url.clone(), record)
+                })
+                .collect(),
+            module_namespace_cache: self.value_map(&source.module_namespace_cache),
+            module_scopes: source
+                .module_scopes
+                .iter()
+                .map(|scope| ModuleScope {
+                    imports: scope
+                        .imports
+                        .iter()
+                        .map(|(local, binding)| (local.clone(), binding.clone()))
+                        .collect(),
+                    exports: scope
+                        .exports
+                        .as_ref()
+                        .map(|exports| self.module_exports(exports)),
+                    env: self.env_cell(&scope.env),
+                })
+                .collect(),
+            pending_module_env: source
+                .pending_module_env
+                .as_ref()
+                .map(|env| self.env_cell(env)),
+            import_map: source.import_map.clone(),
+            next_function_id: source.next_function_id,
+            next_private_slot_id: source.next_private_slot_id,
+            function_private_bindings: source.function_private_bindings.clone(),
+            function_public_properties: source
+                .function_public_properties
+                .iter()
+                .map(|(id, properties)| (*id, self.object_value(properties)))
+                .collect(),
+            function_registry: source
+                .function_registry
+                .iter()
+                .map(|(id, function)| (*id, self.function(function)))
+                .collect(),
+            constructor_instance_initializers: source
+                .constructor_instance_initializers
+                .iter()
+                .map(|(id, initializers)| {
+                    let initializers = initializers
+                        .iter()
+                        .map(|initializer| match initializer {
+                            ConstructorInstanceInitializerRuntime::Private(private) => {
+                                ConstructorInstanceInitializerRuntime::Private(
+                                    PrivateInitializerRuntime {
+                                        value: self.optional_value(&private.value),
+                                        setter_value: self.optional_value(&private.setter_value),
+                                        ..private.clone()
+                                    },
+                                )
+                            }
+                            public => public.clone(),
+                        })
+                        .collect();
+                    (*id, initializers)
+                })
+                .collect(),
+            constructor_call_stack: source.constructor_call_stack.clone(),
+            constructor_instance_initialized_stack: source
+                .constructor_instance_initialized_stack
+                .clone(),
+            private_binding_stack: source.private_binding_stack.clone(),
+            private_instance_slots: source
+                .private_instance_slots
+                .iter()
+                .map(|(key, slots)| (*key, self.value_map(slots)))
+                .collect(),
+            private_static_slots: source
+                .private_static_slots
+                .iter()
+                .map(|(key, slots)| (*key, self.value_map(slots)))
+                .collect(),
+            event_target_listener_nodes: HashMap::new(),
+            next_event_target_listener_slot: source.next_event_target_listener_slot,
+            builtin_constructor_prototypes: self.object_map(&source.builtin_constructor_prototypes),
+            string_constructor_prototype: self
+                .optional_object(&source.string_constructor_prototype),
+            symbol_constructor_prototype: self
+                .optional_object(&source.symbol_constructor_prototype),
+            typed_array_constructor_prototypes: self
+                .object_map(&source.typed_array_constructor_prototypes),
+            constructor_static_methods: self.value_map(&source.constructor_static_methods),
+        }
+    }
+
+    fn location_history(&mut self, source: &LocationHistoryState) -> LocationHistoryState {
+        LocationHistoryState {
+            history_object: self.object(&source.history_object),
+            navigation_object: self.object(&source.navigation_object),
+            history_entries: source
+                .history_entries
+                .iter()
+                .map(|entry| HistoryEntry {
+                    key: entry.key.clone(),
+                    url: entry.url.clone(),
+                    state: self.value(&entry.state),
+                })
+                .collect(),
+            history_index: source.history_index,
+            next_history_entry_key: source.next_history_entry_key,
+            history_scroll_restoration: source.history_scroll_restoration.clone(),
+            location_mock_pages: source.location_mock_pages.clone(),
+            location_navigations: source.location_navigations.clone(),
+            location_reload_count: source.location_reload_count,
+            form_submissions: source.form_submissions.clone(),
+            document_generation: source.document_generation,
+        }
+    }
+
+    fn scheduler(&mut self, source: &SchedulerState) -> SchedulerState {
+        SchedulerState {
+            task_queue: source
+                .task_queue
+                .iter()
+                .map(|task| ScheduledTask {
+                    callback_args: self.values(&task.callback_args),
+                    env: self.env(&task.env),
+                    ..task.clone()
+                })
+                .collect(),
+            microtask_queue: source
+                .microtask_queue
+                .iter()
+                .map(|microtask| match microtask {
+                    ScheduledMicrotask::Script { handler, env } => ScheduledMicrotask::Script {
+                        handler: handler.clone(),
+                        env: self.env(env),
+                    },
+                    ScheduledMicrotask::Callable { callback } => ScheduledMicrotask::Callable {
+                        callback: self.value(callback),
+                    },
+                    ScheduledMicrotask::Promise { reaction, settled } => {
+                        ScheduledMicrotask::Promise {
+                            reaction: self.promise_reaction(reaction),
+                            settled: self.promise_settled(settled),
+                        }
+                    }
+                    ScheduledMicrotask::MutationObservers => ScheduledMicrotask::MutationObservers,
+                })
+                .collect(),
+            now_ms: source.now_ms,
+            time_zone: source.time_zone.clone(),
+            timer_step_limit: source.timer_step_limit,
+            next_timer_id: source.next_timer_id,
+            next_internal_task_id: source.next_internal_task_id,
+            next_task_order: source.next_task_order,
+            task_depth: source.task_depth,
+            running_timer_id: source.running_timer_id,
+            running_timer_canceled: source.running_timer_canceled,
+        }
+    }
+
+    fn browser_apis(&mut self, source: &BrowserApiState) -> BrowserApiState {
+        BrowserApiState {
+            next_url_object_id: source.next_url_object_id,
+            url_objects: self.object_map(&source.url_objects),
+            url_constructor_properties: self.object(&source.url_constructor_properties),
+            local_storage_object: self.object(&source.local_storage_object),
+            session_storage_object: self.object(&source.session_storage_object),
+            session_storage_by_origin: self.object_map(&source.session_storage_by_origin),
+            cookie_store_object: self.object(&source.cookie_store_object),
+            cache_storage_object: self.object(&source.cache_storage_object),
+            caches_by_name: self.object_map(&source.caches_by_name),
+            cache_names_in_order: source.cache_names_in_order.clone(),
+            cache_entries_by_name: source.cache_entries_by_name.clone(),
+            window_closed: source.window_closed,
+            window_screen_x: source.window_screen_x,
+            window_screen_y: source.window_screen_y,
+            cookies: Rc::new(RefCell::new(source.cookies.borrow().clone())),
+            cookie_store_change_listeners: self.values(&source.cookie_store_change_listeners),
+            next_blob_url_id: source.next_blob_url_id,
+            blob_url_objects: source
+                .blob_url_objects
+                .iter()
+                .map(|(url, blob)| (url.clone(), self.blob(blob)))
+                .collect(),
+            downloads: source.downloads.clone(),
+            clipboard_writes: source.clipboard_writes.clone(),
+            browsing_context: self.browsing_context(&source.browsing_context),
+        }
+    }
+
+    fn browsing_context(&mut self, source: &BrowsingContextState) -> BrowsingContextState {
+        BrowsingContextState {
+            id: source.id.clone(),
+            name: source.name.clone(),
+            opener: source.opener.clone(),
+            script_opened: source.script_opened,
+            window_owned: source.window_owned,
+            next_child_id: source.next_child_id,
+            named_children: source.named_children.clone(),
+            proxies: source
+                .proxies
+                .iter()
+                .map(|(id, proxy)| (id.clone(), self.value(proxy)))
+                .collect(),
+            requests: source
+                .requests
+                .iter()
+                .map(|request| match request {
+                    BrowsingContextRequest::PostMessage {
+                        target,
+                        source,
+                        origin,
+                        target_origin,
+                        data,
+                    } => BrowsingContextRequest::PostMessage {
+                        target: target.clone(),
+                        source: source.clone(),
+                        origin: origin.clone(),
+                        target_origin: target_origin.clone(),
+                        data: self.value(data),
+                    },
+                    other => other.clone(),
+                })
+                .collect(),
+        }
+    }
+
+    fn platform_mocks(&mut self, source: &PlatformMockState) -> PlatformMockState {
+        PlatformMockState {
+            clipboard_text: source.clipboard_text.clone(),
+            clipboard_read_error: source.clipboard_read_error.clone(),
+            clipboard_write_error: source.clipboard_write_error.clone(),
+            fetch_mocks: source.fetch_mocks.clone(),
+            fetch_calls: source.fetch_calls.clone(),
+            fetch_mock_rules: source.fetch_mock_rules.clone(),
+            fetch_requests: source.fetch_requests.clone(),
+            fetch_handler: source.fetch_handler.clone(),
+            resource_loader: source.resource_loader.clone(),
+            manual_fetch_mode: source.manual_fetch_mode,
+            pending_fetches: source
+                .pending_fetches
+                .iter()
+                .map(|pending| PendingFetchState {
+                    info: pending.info.clone(),
+                    promise: self.promise(&pending.promise),
+                    response: pending.response.clone(),
+                    signal: self.optional_object(&pending.signal),
+                })
+                .collect(),
+            match_media_mocks: source.match_media_mocks.clone(),
+            match_media_calls: source.match_media_calls.clone(),
+            default_match_media_matches: source.default_match_media_matches,
+            alert_messages: source.alert_messages.clone(),
+            console_messages: source.console_messages.clone(),
+            console_group_depth: source.console_group_depth,
+            console_timers: source.console_timers.clone(),
+            console_counts: source.console_counts.clone(),
+            fail_on_console_error: source.fail_on_console_error,
+            pending_console_error: source.pending_console_error.clone(),
+            print_call_count: source.print_call_count,
+            confirm_responses: source.confirm_responses.clone(),
+            default_confirm_response: source.default_confirm_response,
+            prompt_responses: source.prompt_responses.clone(),
+            default_prompt_response: source.default_prompt_response.clone(),
+        }
+    }
+}
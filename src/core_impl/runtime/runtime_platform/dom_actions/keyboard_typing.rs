@@ -0,0 +1,551 @@
+use super::*;
+
+impl Harness {
+    pub fn type_keys(&mut self, selector: impl Into<Locator>, keys: &str) -> Result<()> {
+        let target = self.select_one(&selector.into())?;
+        let keys = Self::parse_key_sequence(keys)?;
+        stacker::grow(32 * 1024 * 1024, || {
+            self.with_script_env_always(|this, env| {
+                this.focus_for_typing_with_env(target, env)?;
+                for init in &keys {
+                    this.press_key_with_env(init, env)?;
+                }
+                Ok(())
+            })
+        })
+    }
+
+    pub fn press_key(
+        &mut self,
+        selector: impl Into<Locator>,
+        init: KeyboardEventInit,
+    ) -> Result<()> {
+        let target = self.select_one(&selector.into())?;
+        stacker::grow(32 * 1024 * 1024, || {
+            self.with_script_env_always(|this, env| {
+                this.focus_for_typing_with_env(target, env)?;
+                this.press_key_with_env(&init, env)
+            })
+        })
+    }
+
+    pub(crate) fn parse_key_sequence(keys: &str) -> Result<Vec<KeyboardEventInit>> {
+        let mut out = Vec::new();
+        let mut chars = keys.chars().peekable();
+        while let Some(ch) = chars.next() {
+            if ch != '{' {
+                out.push(Self::keyboard_init_for_key(&ch.to_string()));
+                continue;
+            }
+            if chars.peek() == Some(&'{') {
+                chars.next();
+                out.push(Self::keyboard_init_for_key("{"));
+                continue;
+            }
+            let mut name = String::new();
+            loop {
+                match chars.next() {
+                    Some('}') => break,
+                    Some(ch) => name.push(ch),
+                    None => {
+                        return Err(Error::ScriptRuntime(format!(
+                            "unterminated key name in key sequence: {{{name}"
+                        )));
+                    }
+                }
+            }
+            out.push(Self::keyboard_init_for_chord(&name)?);
+        }
+        Ok(out)
+    }
+
+    fn keyboard_init_for_chord(chord: &str) -> Result<KeyboardEventInit> {
+        let mut parts = chord.split('+').collect::<Vec<_>>();
+        // `{+}` and `{Shift++}` name the plus key itself.
+        if chord.ends_with("++") || chord == "+" {
+            parts.truncate(parts.len().saturating_sub(2));
+            parts.push("+");
+        }
+        let Some((key, modifiers)) = parts.split_last() else {
+            return Err(Error::ScriptRuntime(
+                "empty key name in key sequence".into(),
+            ));
+        };
+        let key = match *key {
+            "" => {
+                return Err(Error::ScriptRuntime(
+                    "empty key name in key sequence".into(),
+                ));
+            }
+            "Space" => " ",
+            "Esc" => "Escape",
+            "Del" => "Delete",
+            other => other,
+        };
+        let mut init = Self::keyboard_init_for_key(key);
+        for modifier in modifiers {
+            match modifier.to_ascii_lowercase().as_str() {
+                "shift" => init.shift_key = true,
+                "control" | "ctrl" => init.ctrl_key = true,
+                "alt" | "option" => init.alt_key = true,
+                "meta" | "cmd" | "command" => init.meta_key = true,
+                _ => {
+                    return Err(Error::ScriptRuntime(format!(
+                        "unknown modifier in key sequence: {modifier}"
+                    )));
+                }
+            }
+        }
+        if init.shift_key && init.key.len() == 1 {
+            init.key = init.key.to_ascii_uppercase();
+        }
+        Ok(init)
+    }
+
+    pub(crate) fn keyboard_init_for_key(key: &str) -> KeyboardEventInit {
+        const SHIFTED_DIGITS: &str = ")!@#$%^&*(";
+        let mut shift_key = false;
+        let code = match key.chars().collect::<Vec<_>>().as_slice() {
+            [ch] if ch.is_ascii_alphabetic() => {
+                shift_key = ch.is_ascii_uppercase();
+                format!("Key{}", ch.to_ascii_uppercase())
+            }
+            [ch] if ch.is_ascii_digit() => format!("Digit{ch}"),
+            [ch] if SHIFTED_DIGITS.contains(*ch) => {
+                shift_key = true;
+                format!("Digit{}", SHIFTED_DIGITS.find(*ch).unwrap_or_default())
+            }
+            [ch] => {
+                let (code, shifted) = match ch {
+                    ' ' => ("Space", false),
+                    '-' => ("Minus", false),
+                    '_' => ("Minus", true),
+                    '=' => ("Equal", false),
+                    '+' => ("Equal", true),
+                    '[' => ("BracketLeft", false),
+                    '{' => ("BracketLeft", true),
+                    ']' => ("BracketRight", false),
+                    '}' => ("BracketRight", true),
+                    '\\' => ("Backslash", false),
+                    '|' => ("Backslash", true),
+                    ';' => ("Semicolon", false),
+                    ':' => ("Semicolon", true),
+                    '\'' => ("Quote", false),
+                    '"' => ("Quote", true),
+                    ',' => ("Comma", false),
+                    '<' => ("Comma", true),
+                    '.' => ("Period", false),
+                    '>' => ("Period", true),
+                    '/' => ("Slash", false),
+                    '?' => ("Slash", true),
+                    '`' => ("Backquote", false),
+                    '~' => ("Backquote", true),
+                    '\n' => ("Enter", false),
+                    '\t' => ("Tab", false),
+                    _ => ("", false),
+                };
+                shift_key = shifted;
+                code.to_string()
+            }
+            _ => match key {
+                "Shift" | "Control" | "Alt" | "Meta" => format!("{key}Left"),
+                _ => key.to_string(),
+            },
+        };
+        let key = match key {
+            "\n" => "Enter",
+            "\t" => "Tab",
+            other => other,
+        };
+        KeyboardEventInit {
+            key: key.to_string(),
+            code: (!code.is_empty()).then_some(code),
+            shift_key,
+            ..KeyboardEventInit::default()
+        }
+    }
+
+    fn focus_for_typing_with_env(
+        &mut self,
+        target: NodeId,
+        env: &mut HashMap<String, Value>,
+    ) -> Result<()> {
+        if self.dom.active_element() == Some(target) {
+            return Ok(());
+        }
+        self.focus_node_with_env(target, env)?;
+        if self.dom.active_element() == Some(target) && self.node_supports_text_selection(target) {
+            let len = self.dom.value(target)?.chars().count();
+            self.dom.set_selection_range(target, len, len, "none")?;
+        }
+        Ok(())
+    }
+
+    fn keyboard_event_target(&self) -> NodeId {
+        self.dom
+            .active_element()
+            .or_else(|| self.dom.body())
+            .unwrap_or(self.dom.root)
+    }
+
+    pub(crate) fn press_key_with_env(
+        &mut self,
+        init: &KeyboardEventInit,
+        env: &mut HashMap<String, Value>,
+    ) -> Result<()> {
+        let target = self.keyboard_event_target();
+        let keydown = self.dispatch_keyboard_event_with_env(target, "keydown", init, env)?;
+        let mut default_allowed = !keydown.default_prevented;
+        let produces_character =
+            init.key.chars().count() == 1 && !init.ctrl_key && !init.meta_key && !init.alt_key;
+        if default_allowed && (produces_character || init.key == "Enter") {
+            let keypress = self.dispatch_keyboard_event_with_env(target, "keypress", init, env)?;
+            default_allowed = !keypress.default_prevented;
+        }
+        if default_allowed {
+            self.run_key_default_action_with_env(target, init, produces_character, env)?;
+        }
+
+        let keyup_target = self.keyboard_event_target();
+        let keyup = self.dispatch_keyboard_event_with_env(keyup_target, "keyup", init, env)?;
+        if init.key == " "
+            && !keydown.default_prevented
+            && !keyup.default_prevented
+            && keyup_target == target
+            && self.is_space_activated(target)
+        {
+            self.click_node_with_env(target, env)?;
+        }
+        Ok(())
+    }
+
+    fn dispatch_keyboard_event_with_env(
+        &mut self,
+        target: NodeId,
+        event_type: &str,
+        init: &KeyboardEventInit,
+        env: &mut HashMap<String, Value>,
+    ) -> Result<EventState> {
+        let mut event = EventState::new(event_type, target, self.scheduler.now_ms);
+        event.key = Some(init.key.clone());
+        event.code = Some(init.code.clone().unwrap_or_default());
+        event.location = init.location;
+        event.ctrl_key = init.ctrl_key;
+        event.meta_key = init.meta_key;
+        event.shift_key = init.shift_key;
+        event.alt_key = init.alt_key;
+        event.repeat = init.repeat;
+        event.is_composing = init.is_composing;
+        self.dispatch_prepared_event_with_env(event, env)
+    }
+
+    pub(crate) fn dispatch_input_event_with_env(
+        &mut self,
+        target: NodeId,
+        event_type: &str,
+        input_type: &str,
+        data: Option<&str>,
+        env: &mut HashMap<String, Value>,
+    ) -> Result<EventState> {
+        let mut event = EventState::new(event_type, target, self.scheduler.now_ms);
+        event.cancelable = event_type == "beforeinput";
+        event.input_type = Some(input_type.to_string());
+        event.input_data = data.map(str::to_string);
+        self.dispatch_prepared_event_with_env(event, env)
+    }
+
+    fn is_space_activated(&self, target: NodeId) -> bool {
+        match self.dom.tag_name(target).map(str::to_ascii_lowercase) {
+            Some(tag) if tag == "button" || tag == "summary" => true,
+            Some(tag) if tag == "input" => matches!(
+                self.normalized_input_type(target).as_str(),
+                "button" | "checkbox" | "radio" | "reset" | "submit" | "image"
+            ),
+            _ => false,
+        }
+    }
+
+    fn is_keyboard_text_field(&self, target: NodeId) -> bool {
+        match self.dom.tag_name(target).map(str::to_ascii_lowercase) {
+            Some(tag) if tag == "textarea" => true,
+            Some(tag) if tag == "input" => !matches!(
+                self.normalized_input_type(target).as_str(),
+                "button"
+                    | "checkbox"
+                    | "color"
+                    | "file"
+                    | "hidden"
+                    | "image"
+                    | "radio"
+                    | "range"
+                    | "reset"
+                    | "submit"
+            ),
+            _ => false,
+        }
+    }
+
+    fn run_key_default_action_with_env(
+        &mut self,
+        target: NodeId,
+        init: &KeyboardEventInit,
+        produces_character: bool,
+        env: &mut HashMap<String, Value>,
+    ) -> Result<()> {
+        let text_field = self.is_keyboard_text_field(target);
+        let editable =
+            text_field && !self.is_effectively_disabled(target) && !self.dom.readonly(target);
+        let is_textarea = self
+            .dom
+            .tag_name(target)
+            .is_some_and(|tag| tag.eq_ignore_ascii_case("textarea"));
+        match init.key.as_str() {
+            "Tab" if !init.ctrl_key && !init.meta_key && !init.alt_key => {
+                self.move_sequential_focus_with_env(!init.shift_key, env)
+            }
+            "Enter" if is_textarea => {
+                if editable {
+                    self.apply_keyboard_edit_with_env(target, "insertLineBreak", "\n", env)?;
+                }
+                Ok(())
+            }
+            "Enter" => self.activate_on_enter_with_env(target, env),
+            "Backspace" if editable => {
+                self.apply_keyboard_edit_with_env(target, "deleteContentBackward", "", env)
+            }
+            "Delete" if editable => {
+                self.apply_keyboard_edit_with_env(target, "deleteContentForward", "", env)
+            }
+            "ArrowLeft" | "ArrowRight" | "ArrowUp" | "ArrowDown" | "Home" | "End"
+                if self.node_supports_text_selection(target) =>
+            {
+                self.move_keyboard_caret(target, &init.key, init.shift_key)
+            }
+            "a" | "A"
+                if (init.ctrl_key || init.meta_key)
+                    && self.node_supports_text_selection(target) =>
+            {
+                let len = self.dom.value(target)?.chars().count();
+                self.dom.set_selection_range(target, 0, len, "none")
+            }
+            key if produces_character && editable => {
+                self.apply_keyboard_edit_with_env(target, "insertText", key, env)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn activate_on_enter_with_env(
+        &mut self,
+        target: NodeId,
+        env: &mut HashMap<String, Value>,
+    ) -> Result<()> {
+        if self.is_effectively_disabled(target) {
+            return Ok(());
+        }
+        let Some(tag) = self.dom.tag_name(target).map(str::to_ascii_lowercase) else {
+            return Ok(());
+        };
+        let activates = match tag.as_str() {
+            "a" | "area" => self.dom.attr(target, "href").is_some(),
+            "button" | "summary" => true,
+            "input" => matches!(
+                self.normalized_input_type(target).as_str(),
+                "button" | "reset" | "submit" | "image"
+            ),
+            _ => false,
+        };
+        if activates {
+            return self.click_node_with_env(target, env);
+        }
+        if tag != "input" {
+            return Ok(());
+        }
+
+        // Implicit submission: the form's default button is clicked when there is one,
+        // otherwise a form with a single text field submits directly.
+        let Some(form) = self.resolve_form_for_submit(target) else {
+            return Ok(());
+        };
+        let controls = self.form_elements(form)?;
+        if let Some(default_button) = controls
+            .iter()
+            .copied()
+            .find(|control| is_submit_control(&self.dom, *control))
+        {
+            if !self.is_effectively_disabled(default_button) {
+                self.click_node_with_env(default_button, env)?;
+            }
+            return Ok(());
+        }
+        let blocking_fields = controls
+            .iter()
+            .filter(|control| {
+                self.dom
+                    .tag_name(**control)
+                    .is_some_and(|tag| tag.eq_ignore_ascii_case("input"))
+                    && matches!(
+                        self.normalized_input_type(**control).as_str(),
+                        "text"
+                            | "search"
+                            | "url"
+                            | "tel"
+                            | "email"
+                            | "password"
+                            | "date"
+                            | "month"
+                            | "week"
+                            | "time"
+                            | "datetime-local"
+                            | "number"
+                    )
+            })
+            .count();
+        if blocking_fields <= 1 {
+            self.request_form_submit_with_env(form, None, env)?;
+        }
+        Ok(())
+    }
+
+    fn keyboard_selection(&self, target: NodeId, len: usize) -> Result<(usize, usize)> {
+        if !self.node_supports_text_selection(target) {
+            return Ok((len, len));
+        }
+        let start = self.dom.selection_start(target)?.min(len);
+        let end = self.dom.selection_end(target)?.min(len);
+        Ok((start.min(end), end.max(start)))
+    }
+
+    fn apply_keyboard_edit_with_env(
+        &mut self,
+        target: NodeId,
+        input_type: &str,
+        text: &str,
+        env: &mut HashMap<String, Value>,
+    ) -> Result<()> {
+        let chars = self.dom.value(target)?.chars().collect::<Vec<_>>();
+        let len = chars.len();
+        let (mut start, mut end) = self.keyboard_selection(target, len)?;
+        let mut inserted = text.to_string();
+        match input_type {
+            "deleteContentBackward" if start == end => {
+                if start == 0 {
+                    return Ok(());
+                }
+                start -= 1;
+            }
+            "deleteContentForward" if start == end => {
+                if end == len {
+                    return Ok(());
+                }
+                end += 1;
+            }
+            _ => {}
+        }
+        if !inserted.is_empty() {
+            let max_length = self
+                .dom
+                .attr(target, "maxlength")
+                .and_then(|raw| Self::parse_non_negative_int(&raw));
+            if let Some(max_length) = max_length {
+                let remaining = usize::try_from(max_length)
+                    .unwrap_or(usize::MAX)
+                    .saturating_sub(len - (end - start));
+                if remaining == 0 {
+                    return Ok(());
+                }
+                inserted = inserted.chars().take(remaining).collect();
+            }
+        }
+        let data = (input_type == "insertText").then(|| inserted.clone());
+
+        let before = self.dispatch_input_event_with_env(
+            target,
+            "beforeinput",
+            input_type,
+            data.as_deref(),
+            env,
+        )?;
+        if before.default_prevented {
+            return Ok(());
+        }
+
+        let mut next = chars[..start].iter().collect::<String>();
+        next.push_str(&inserted);
+        next.extend(&chars[end..]);
+        self.dom.set_value(target, &next)?;
+        if self.node_supports_text_selection(target) {
+            let caret = start + inserted.chars().count();
+            self.dom.set_selection_range(target, caret, caret, "none")?;
+        }
+        self.dispatch_input_event_with_env(target, "input", input_type, data.as_deref(), env)?;
+        Ok(())
+    }
+
+    fn move_keyboard_caret(&mut self, target: NodeId, key: &str, extend: bool) -> Result<()> {
+        let chars = self.dom.value(target)?.chars().collect::<Vec<_>>();
+        let len = chars.len();
+        let (start, end) = self.keyboard_selection(target, len)?;
+        let backward = self.dom.selection_direction(target)? == "backward";
+        let (anchor, focus) = if backward { (end, start) } else { (start, end) };
+        let multiline = self
+            .dom
+            .tag_name(target)
+            .is_some_and(|tag| tag.eq_ignore_ascii_case("textarea"));
+        let line_start = |pos: usize| {
+            chars[..pos]
+                .iter()
+                .rposition(|ch| *ch == '\n')
+                .map_or(0, |index| index + 1)
+        };
+        let line_end = |pos: usize| {
+            chars[pos..]
+                .iter()
+                .position(|ch| *ch == '\n')
+                .map_or(len, |index| pos + index)
+        };
+
+        let next = match key {
+            "ArrowLeft" if !extend && start != end => start,
+            "ArrowRight" if !extend && start != end => end,
+            "ArrowLeft" => focus.saturating_sub(1),
+            "ArrowRight" => (focus + 1).min(len),
+            "Home" if multiline => line_start(focus),
+            "End" if multiline => line_end(focus),
+            "Home" => 0,
+            "End" => len,
+            "ArrowUp" if multiline => {
+                let current = line_start(focus);
+                if current == 0 {
+                    0
+                } else {
+                    let previous = line_start(current - 1);
+                    (previous + (focus - current)).min(current - 1)
+                }
+            }
+            "ArrowDown" if multiline => {
+                let current_end = line_end(focus);
+                if current_end == len {
+                    len
+                } else {
+                    let next_start = current_end + 1;
+                    (next_start + (focus - line_start(focus))).min(line_end(next_start))
+                }
+            }
+            _ => return Ok(()),
+        };
+
+        if !extend {
+            return self.dom.set_selection_range(target, next, next, "none");
+        }
+        if next < anchor {
+            self.dom
+                .set_selection_range(target, next, anchor, "backward")
+        } else if next > anchor {
+            self.dom
+                .set_selection_range(target, anchor, next, "forward")
+        } else {
+            self.dom.set_selection_range(target, next, next, "none")
+        }
+    }
+}
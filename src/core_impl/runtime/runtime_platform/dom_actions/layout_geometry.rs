@@ -0,0 +1,35 @@
+use super::*;
+
+impl Harness {
+    pub fn set_element_rect(&mut self, selector: impl Into<Locator>, rect: DomRect) -> Result<()> {
+        let target = self.select_one(&selector.into())?;
+        self.dom.set_layout_rect(target, rect)?;
+        self.dom_runtime
+            .layout_observers
+            .forced_intersection_ratios
+            .remove(&target);
+        self.update_layout_observations();
+        Ok(())
+    }
+
+    pub fn set_intersecting(&mut self, selector: impl Into<Locator>, ratio: f64) -> Result<()> {
+        if !(0.0..=1.0).contains(&ratio) {
+            return Err(Error::ScriptRuntime(format!(
+                "set_intersecting ratio must be between 0 and 1: {ratio}"
+            )));
+        }
+        let target = self.select_one(&selector.into())?;
+        self.dom_runtime
+            .layout_observers
+            .forced_intersection_ratios
+            .insert(target, ratio);
+        self.update_layout_observations();
+        Ok(())
+    }
+
+    pub fn scroll_to(&mut self, x: i64, y: i64) -> Result<()> {
+        let position_changed =
+            self.apply_document_scroll_operation("scrollTo", &[Value::Number(x), Value::Number(y)]);
+        self.dispatch_document_scroll_sequence(position_changed)
+    }
+}
@@ -0,0 +1,417 @@
+use super::*;
+
+impl Harness {
+    pub fn get_by_role(&self, role: &str) -> Locator {
+        Locator::role(role)
+    }
+
+    pub fn get_by_label(&self, text: &str) -> Locator {
+        Locator::label(text)
+    }
+
+    pub fn get_by_text(&self, text: &str) -> Locator {
+        Locator::text(text)
+    }
+
+    pub fn get_by_placeholder(&self, text: &str) -> Locator {
+        Locator::placeholder(text)
+    }
+
+    pub fn get_by_test_id(&self, test_id: &str) -> Locator {
+        Locator::test_id(test_id)
+    }
+
+    pub fn count(&self, selector: impl Into<Locator>) -> Result<usize> {
+        let locator = selector.into();
+        let count = match locator.css_selector() {
+            Some(selector) => self.dom.query_selector_all(selector)?.len(),
+            None => {
+                let matches = self.locate_all(&locator)?.len();
+                match locator.nth {
+                    Some(index) => usize::from(index < matches),
+                    None => matches,
+                }
+            }
+        };
+        Ok(count)
+    }
+
+    pub fn accessible_name(&self, selector: impl Into<Locator>) -> Result<String> {
+        let target = self.select_one(&selector.into())?;
+        Ok(self.accessible_name_for_node(target))
+    }
+
+    pub(crate) fn locate_all(&self, locator: &Locator) -> Result<Vec<NodeId>> {
+        if let LocatorQuery::Css(selector) = &locator.query {
+            return self.dom.query_selector_all(selector);
+        }
+        let exact = locator.exact;
+        let elements = self.dom.all_element_nodes();
+        let found = match &locator.query {
+            LocatorQuery::Css(_) => Vec::new(),
+            LocatorQuery::Role { role, name } => elements
+                .into_iter()
+                .filter(|node| self.accessible_role_for_node(*node) == *role)
+                .filter(|node| !self.is_hidden_from_accessibility(*node))
+                .filter(|node| {
+                    name.as_ref().is_none_or(|name| {
+                        Self::locator_text_matches(
+                            &self.accessible_name_for_node(*node),
+                            name,
+                            exact,
+                        )
+                    })
+                })
+                .collect(),
+            LocatorQuery::Label(text) => elements
+                .into_iter()
+                .filter(|node| {
+                    self.label_texts_for_node(*node)
+                        .iter()
+                        .any(|label| Self::locator_text_matches(label, text, exact))
+                })
+                .collect(),
+            LocatorQuery::Text(text) => {
+                let matches_text = |node: NodeId| {
+                    !self.is_hidden_from_accessibility(node)
+                        && Self::locator_text_matches(
+                            &self.locator_text_for_node(node),
+                            text,
+                            exact,
+                        )
+                };
+                elements
+                    .into_iter()
+                    .filter(|node| matches_text(*node))
+                    .filter(|node| !self.dom.child_elements(*node).into_iter().any(matches_text))
+                    .collect()
+            }
+            LocatorQuery::Placeholder(text) => elements
+                .into_iter()
+                .filter(|node| {
+                    self.dom
+                        .attr(*node, "placeholder")
+                        .is_some_and(|placeholder| {
+                            Self::locator_text_matches(&placeholder, text, exact)
+                        })
+                })
+                .collect(),
+            LocatorQuery::TestId(test_id) => elements
+                .into_iter()
+                .filter(|node| self.dom.attr(*node, "data-testid").as_ref() == Some(test_id))
+                .collect(),
+        };
+        Ok(found)
+    }
+
+    fn locator_text_matches(actual: &str, expected: &str, exact: bool) -> bool {
+        let actual = Self::normalize_locator_text(actual);
+        let expected = Self::normalize_locator_text(expected);
+        if exact {
+            actual == expected
+        } else {
+            actual.to_lowercase().contains(&expected.to_lowercase())
+        }
+    }
+
+    fn normalize_locator_text(text: &str) -> String {
+        text.split_whitespace().collect::<Vec<_>>().join(" ")
+    }
+
+    fn locator_text_for_node(&self, node: NodeId) -> String {
+        if self
+            .dom
+            .tag_name(node)
+            .is_some_and(|tag| tag.eq_ignore_ascii_case("input"))
+        {
+            let input_type = self
+                .dom
+                .attr(node, "type")
+                .unwrap_or_default()
+                .to_ascii_lowercase();
+            if matches!(input_type.as_str(), "button" | "submit" | "reset") {
+                return self.dom.attr(node, "value").unwrap_or_default();
+            }
+        }
+        self.dom.text_content(node)
+    }
+
+    fn label_texts_for_node(&self, node: NodeId) -> Vec<String> {
+        let mut texts = Vec::new();
+        if let Some(ids) = self.dom.attr(node, "aria-labelledby") {
+            let mut visited = HashSet::from([node]);
+            let name = ids
+                .split_whitespace()
+                .filter_map(|id_ref| self.dom.by_id(id_ref))
+                .map(|label| self.compute_accessible_name(label, true, false, &mut visited))
+                .collect::<Vec<_>>()
+                .join(" ");
+            texts.push(name);
+        }
+        if let Some(label) = self.dom.attr(node, "aria-label") {
+            texts.push(label);
+        }
+        for label in self.labels_for_control_node(node) {
+            texts.push(self.dom.text_content(label));
+        }
+        texts
+    }
+
+    pub(crate) fn accessible_role_for_node(&self, node: NodeId) -> String {
+        self.resolved_role_for_node(node)
+            .split_whitespace()
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase()
+    }
+
+    // Unrendered and invisible elements are hidden the same way the cascade
+    // decides it for layout; `aria-hidden` additionally hides whole subtrees.
+    pub(crate) fn is_hidden_from_accessibility(&self, node: NodeId) -> bool {
+        if !self.is_element_visible(node) {
+            return true;
+        }
+        let mut cursor = Some(node);
+        while let Some(current) = cursor {
+            let Some(element) = self.dom.element(current) else {
+                break;
+            };
+            if element
+                .attrs
+                .get("aria-hidden")
+                .is_some_and(|value| value.trim().eq_ignore_ascii_case("true"))
+            {
+                return true;
+            }
+            cursor = self.dom.parent(current);
+        }
+        false
+    }
+
+    pub(crate) fn accessible_name_for_node(&self, node: NodeId) -> String {
+        let mut visited = HashSet::new();
+        Self::normalize_locator_text(&self.compute_accessible_name(
+            node,
+            false,
+            false,
+            &mut visited,
+        ))
+    }
+
+    fn compute_accessible_name(
+        &self,
+        node: NodeId,
+        in_labelledby: bool,
+        in_content: bool,
+        visited: &mut HashSet<NodeId>,
+    ) -> String {
+        if let NodeType::Text(text) = &self.dom.nodes[node.0].node_type {
+            return text.clone();
+        }
+        let Some(tag) = self.dom.tag_name(node).map(str::to_ascii_lowercase) else {
+            return String::new();
+        };
+        if !visited.insert(node) {
+            return String::new();
+        }
+        if in_content && self.is_hidden_from_accessibility(node) {
+            return String::new();
+        }
+
+        if !in_labelledby {
+            if let Some(ids) = self.dom.attr(node, "aria-labelledby") {
+                let name = ids
+                    .split_whitespace()
+                    .filter_map(|id_ref| self.dom.by_id(id_ref))
+                    .map(|label| self.compute_accessible_name(label, true, false, visited))
+                    .map(|part| Self::normalize_locator_text(&part))
+                    .filter(|part| !part.is_empty())
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                if !name.is_empty() {
+                    return name;
+                }
+            }
+        }
+
+        let role = self.accessible_role_for_node(node);
+        if in_content {
+            match role.as_str() {
+                "textbox" | "searchbox" | "spinbutton" | "slider" => {
+                    return self.dom.value(node).unwrap_or_default();
+                }
+                "combobox" | "listbox" if tag == "select" => {
+                    return self
+                        .select_selected_option_nodes(node)
+                        .into_iter()
+                        .map(|option| self.dom.text_content(option))
+                        .collect::<Vec<_>>()
+                        .join(" ");
+                }
+                _ => {}
+            }
+        }
+
+        if let Some(label) = self.dom.attr(node, "aria-label") {
+            if !label.trim().is_empty() {
+                return label;
+            }
+        }
+
+        if let Some(name) = self.native_accessible_name(node, &tag, visited) {
+            return name;
+        }
+
+        let name_from_content = in_content
+            || in_labelledby
+            || matches!(
+                role.as_str(),
+                "button"
+                    | "cell"
+                    | "checkbox"
+                    | "columnheader"
+                    | "gridcell"
+                    | "heading"
+                    | "link"
+                    | "menuitem"
+                    | "menuitemcheckbox"
+                    | "menuitemradio"
+                    | "option"
+                    | "radio"
+                    | "row"
+                    | "rowheader"
+                    | "switch"
+                    | "tab"
+                    | "tooltip"
+                    | "treeitem"
+            )
+            || matches!(tag.as_str(), "summary" | "label" | "legend" | "caption");
+        if name_from_content {
+            let content = self.accessible_name_from_content(node, visited);
+            if !content.trim().is_empty() {
+                return content;
+            }
+        }
+
+        if let Some(title) = self.dom.attr(node, "title") {
+            if !title.trim().is_empty() {
+                return title;
+            }
+        }
+        if matches!(tag.as_str(), "input" | "textarea") {
+            if let Some(placeholder) = self.dom.attr(node, "placeholder") {
+                return placeholder;
+            }
+        }
+        String::new()
+    }
+
+    fn native_accessible_name(
+        &self,
+        node: NodeId,
+        tag: &str,
+        visited: &mut HashSet<NodeId>,
+    ) -> Option<String> {
+        if tag == "input" {
+            let input_type = self
+                .dom
+                .attr(node, "type")
+                .unwrap_or_default()
+                .to_ascii_lowercase();
+            match input_type.as_str() {
+                "button" | "submit" | "reset" => {
+                    if let Some(value) = self.dom.attr(node, "value") {
+                        return Some(value);
+                    }
+                    return match input_type.as_str() {
+                        "submit" => Some("Submit".to_string()),
+                        "reset" => Some("Reset".to_string()),
+                        _ => None,
+                    };
+                }
+                "image" => {
+                    return self
+                        .dom
+                        .attr(node, "alt")
+                        .or_else(|| self.dom.attr(node, "value"))
+                        .or_else(|| Some("Submit".to_string()));
+                }
+                _ => {}
+            }
+        }
+
+        if self.is_labelable_control(node) {
+            let name = self
+                .labels_for_control_node(node)
+                .into_iter()
+                .map(|label| self.accessible_name_from_content(label, visited))
+                .map(|part| Self::normalize_locator_text(&part))
+                .filter(|part| !part.is_empty())
+                .collect::<Vec<_>>()
+                .join(" ");
+            if !name.is_empty() {
+                return Some(name);
+            }
+        }
+
+        let caption_tag = match tag {
+            "img" | "area" => return self.dom.attr(node, "alt"),
+            "fieldset" => "legend",
+            "table" => "caption",
+            "figure" => "figcaption",
+            _ => return None,
+        };
+        let caption = self.dom.child_elements(node).into_iter().find(|child| {
+            self.dom
+                .tag_name(*child)
+                .is_some_and(|tag| tag.eq_ignore_ascii_case(caption_tag))
+        })?;
+        let name = self.accessible_name_from_content(caption, visited);
+        (!name.trim().is_empty()).then_some(name)
+    }
+
+    fn accessible_name_from_content(&self, node: NodeId, visited: &mut HashSet<NodeId>) -> String {
+        let mut out = String::new();
+        for child in self.dom.nodes[node.0].children.clone() {
+            let part = self.compute_accessible_name(child, false, true, visited);
+            let inline = match self.dom.tag_name(child) {
+                None => true,
+                Some(tag) => matches!(
+                    tag.to_ascii_lowercase().as_str(),
+                    "a" | "abbr"
+                        | "b"
+                        | "bdi"
+                        | "bdo"
+                        | "cite"
+                        | "code"
+                        | "data"
+                        | "dfn"
+                        | "em"
+                        | "i"
+                        | "kbd"
+                        | "label"
+                        | "mark"
+                        | "q"
+                        | "s"
+                        | "samp"
+                        | "small"
+                        | "span"
+                        | "strong"
+                        | "sub"
+                        | "sup"
+                        | "time"
+                        | "u"
+                        | "var"
+                ),
+            };
+            if inline {
+                out.push_str(&part);
+            } else {
+                out.push(' ');
+                out.push_str(&part);
+                out.push(' ');
+            }
+        }
+        out
+    }
+}
@@ -0,0 +1,493 @@
+use super::*;
+
+// The harness mouse is always the primary `mouse` pointer.
+const MOUSE_POINTER_ID: i64 = 1;
+// Presses on the same element closer together than this (on the fake clock)
+// raise `detail`, and the second one produces `dblclick`.
+const MULTI_CLICK_INTERVAL_MS: i64 = 500;
+
+#[derive(Debug, Clone, Copy, Default)]
+struct MouseEventFields {
+    button: i64,
+    buttons: i64,
+    detail: i64,
+    related_target: Option<NodeId>,
+}
+
+impl Harness {
+    pub fn hover(&mut self, selector: impl Into<Locator>) -> Result<()> {
+        let target = self.select_one(&selector.into())?;
+        stacker::grow(32 * 1024 * 1024, || {
+            self.with_script_env_always(|this, env| this.hover_node_with_env(target, env))
+        })
+    }
+
+    // Moves the pointer off the page, leaving every hovered element.
+    pub fn unhover(&mut self) -> Result<()> {
+        stacker::grow(32 * 1024 * 1024, || {
+            self.with_script_env_always(|this, env| this.update_hover_with_env(None, env))
+        })
+    }
+
+    pub fn mouse_move(&mut self, x: f64, y: f64) -> Result<()> {
+        stacker::grow(32 * 1024 * 1024, || {
+            self.with_script_env_always(|this, env| {
+                let target = this.hit_test_client_point(x, y);
+                this.move_pointer_with_env(target, x, y, env)
+            })
+        })
+    }
+
+    pub fn mouse_down(&mut self, button: MouseButton) -> Result<()> {
+        stacker::grow(32 * 1024 * 1024, || {
+            self.with_script_env_always(|this, env| this.press_pointer_with_env(button, env))
+        })
+    }
+
+    pub fn mouse_up(&mut self, button: MouseButton) -> Result<()> {
+        stacker::grow(32 * 1024 * 1024, || {
+            self.with_script_env_always(|this, env| this.release_pointer_with_env(button, env))
+        })
+    }
+
+    pub fn double_click(&mut self, selector: impl Into<Locator>) -> Result<()> {
+        let target = self.select_one(&selector.into())?;
+        stacker::grow(32 * 1024 * 1024, || {
+            self.with_script_env_always(|this, env| {
+                this.hover_node_with_env(target, env)?;
+                for _ in 0..2 {
+                    this.press_pointer_with_env(MouseButton::Left, env)?;
+                    this.release_pointer_with_env(MouseButton::Left, env)?;
+                }
+                Ok(())
+            })
+        })
+    }
+
+    pub fn right_click(&mut self, selector: impl Into<Locator>) -> Result<()> {
+        let target = self.select_one(&selector.into())?;
+        stacker::grow(32 * 1024 * 1024, || {
+            self.with_script_env_always(|this, env| {
+                this.hover_node_with_env(target, env)?;
+                this.press_pointer_with_env(MouseButton::Right, env)?;
+                this.release_pointer_with_env(MouseButton::Right, env)
+            })
+        })
+    }
+
+    fn hover_node_with_env(
+        &mut self,
+        target: NodeId,
+        env: &mut HashMap<String, Value>,
+    ) -> Result<()> {
+        let rect = self.element_client_rect(target);
+        let x = rect.x + rect.width / 2.0;
+        let y = rect.y + rect.height / 2.0;
+        self.move_pointer_with_env(target, x, y, env)
+    }
+
+    // Topmost rendered element whose box contains the viewport point; later
+    // elements in tree order paint above earlier ones. The raw box is checked
+    // first so the cascade only runs for elements under the point.
+    fn hit_test_client_point(&self, x: f64, y: f64) -> NodeId {
+        let scroll_x = self.dom_runtime.document_scroll_x as f64;
+        let scroll_y = self.dom_runtime.document_scroll_y as f64;
+        self.dom
+            .all_element_nodes()
+            .into_iter()
+            .rev()
+            .find(|node| {
+                self.dom
+                    .layout_rect(*node)
+                    .is_some_and(|rect| rect.translated(-scroll_x, -scroll_y).contains_point(x, y))
+                    && self.is_element_visible(*node)
+            })
+            .or_else(|| self.dom.body())
+            .or_else(|| self.dom.document_element())
+            .unwrap_or(self.dom.root)
+    }
+
+    fn pointer_capture_target(&self) -> Option<NodeId> {
+        self.dom_runtime
+            .pointer_capture_targets
+            .get(&MOUSE_POINTER_ID)
+            .copied()
+            .filter(|node| self.dom.is_connected(*node))
+    }
+
+    // Where pointer events go: the capture target, else the hovered element.
+    fn pointer_event_target(&self) -> NodeId {
+        let state = &self.dom_runtime.pointer_input;
+        self.pointer_capture_target()
+            .or_else(|| {
+                self.dom
+                    .hover_element()
+                    .filter(|node| self.dom.is_connected(*node))
+            })
+            .unwrap_or_else(|| self.hit_test_client_point(state.client_x, state.client_y))
+    }
+
+    fn move_pointer_with_env(
+        &mut self,
+        hit: NodeId,
+        x: f64,
+        y: f64,
+        env: &mut HashMap<String, Value>,
+    ) -> Result<()> {
+        self.dom_runtime.pointer_input.client_x = x;
+        self.dom_runtime.pointer_input.client_y = y;
+        self.process_pending_pointer_capture_with_env(env)?;
+        let target = self.pointer_capture_target().unwrap_or(hit);
+        self.update_hover_with_env(Some(target), env)?;
+        let buttons = self.dom_runtime.pointer_input.buttons;
+        let fields = MouseEventFields {
+            button: -1,
+            buttons,
+            ..MouseEventFields::default()
+        };
+        self.dispatch_pointer_input_event_with_env(target, "pointermove", fields, env)?;
+        if !self.dom_runtime.pointer_input.suppress_mouse_events {
+            let fields = MouseEventFields {
+                button: 0,
+                ..fields
+            };
+            self.dispatch_pointer_input_event_with_env(target, "mousemove", fields, env)?;
+        }
+        Ok(())
+    }
+
+    // Fires the boundary events for moving from the hovered element to `next`:
+    // out/leave on what the pointer left, then over/enter on what it entered.
+    // A removed hovered element hands hover to its nearest connected ancestor.
+    // Canceling `pointerdown` does not suppress the mouse boundary events.
+    fn update_hover_with_env(
+        &mut self,
+        next: Option<NodeId>,
+        env: &mut HashMap<String, Value>,
+    ) -> Result<()> {
+        let previous = self
+            .dom_runtime
+            .pointer_input
+            .hover_chain
+            .iter()
+            .copied()
+            .find(|node| self.dom.is_connected(*node));
+        let next_chain = next.map(|node| self.hover_chain(node)).unwrap_or_default();
+        self.dom_runtime.pointer_input.hover_chain = next_chain.clone();
+        if previous == next {
+            self.dom.set_hover_element(next);
+            return Ok(());
+        }
+        let previous_chain = previous
+            .map(|node| self.hover_chain(node))
+            .unwrap_or_default();
+        let buttons = self.dom_runtime.pointer_input.buttons;
+        let kinds = [("pointer", -1), ("mouse", 0)];
+
+        if let Some(previous) = previous {
+            for (prefix, button) in &kinds {
+                let fields = MouseEventFields {
+                    button: *button,
+                    buttons,
+                    detail: 0,
+                    related_target: next,
+                };
+                let out = format!("{prefix}out");
+                self.dispatch_pointer_input_event_with_env(previous, &out, fields, env)?;
+                let leave = format!("{prefix}leave");
+                for node in previous_chain
+                    .iter()
+                    .filter(|node| !next_chain.contains(node))
+                {
+                    self.dispatch_pointer_input_event_with_env(*node, &leave, fields, env)?;
+                }
+            }
+        }
+
+        self.dom.set_hover_element(next);
+        if let Some(next) = next {
+            for (prefix, button) in &kinds {
+                let fields = MouseEventFields {
+                    button: *button,
+                    buttons,
+                    detail: 0,
+                    related_target: previous,
+                };
+                let over = format!("{prefix}over");
+                self.dispatch_pointer_input_event_with_env(next, &over, fields, env)?;
+                let enter = format!("{prefix}enter");
+                for node in next_chain
+                    .iter()
+                    .rev()
+                    .filter(|node| !previous_chain.contains(node))
+                {
+                    self.dispatch_pointer_input_event_with_env(*node, &enter, fields, env)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    // The element and its element ancestors, innermost first.
+    fn hover_chain(&self, node: NodeId) -> Vec<NodeId> {
+        let mut chain = Vec::new();
+        let mut cursor = Some(node);
+        while let Some(current) = cursor {
+            if self.dom.element(current).is_some() {
+                chain.push(current);
+            }
+            cursor = self.dom.parent(current);
+        }
+        chain
+    }
+
+    fn press_pointer_with_env(
+        &mut self,
+        button: MouseButton,
+        env: &mut HashMap<String, Value>,
+    ) -> Result<()> {
+        self.process_pending_pointer_capture_with_env(env)?;
+        let target = self.pointer_event_target();
+        let now = self.scheduler.now_ms;
+        let state = &mut self.dom_runtime.pointer_input;
+        state.click_count = match state.last_press {
+            Some((node, pressed, at))
+                if node == target
+                    && pressed == button.button()
+                    && now - at <= MULTI_CLICK_INTERVAL_MS =>
+            {
+                state.click_count + 1
+            }
+            _ => 1,
+        };
+        state.last_press = Some((target, button.button(), now));
+        state.buttons |= button.buttons_mask();
+        state.press_target = Some(target);
+        let fields = MouseEventFields {
+            button: button.button(),
+            buttons: state.buttons,
+            detail: state.click_count,
+            related_target: None,
+        };
+
+        let pointerdown = self.dispatch_pointer_input_event_with_env(
+            target,
+            "pointerdown",
+            MouseEventFields {
+                detail: 0,
+                ..fields
+            },
+            env,
+        )?;
+        // A canceled `pointerdown` suppresses mouse events until the release.
+        self.dom_runtime.pointer_input.suppress_mouse_events = pointerdown.default_prevented;
+        if button == MouseButton::Left {
+            self.dom.set_active_pseudo_element(Some(target));
+        }
+
+        let mut focus_allowed = true;
+        if !pointerdown.default_prevented && !self.is_effectively_disabled(target) {
+            let mousedown =
+                self.dispatch_pointer_input_event_with_env(target, "mousedown", fields, env)?;
+            focus_allowed = !mousedown.default_prevented;
+        }
+        if focus_allowed {
+            self.focus_for_pointer_press_with_env(target, env)?;
+        }
+        Ok(())
+    }
+
+    fn release_pointer_with_env(
+        &mut self,
+        button: MouseButton,
+        env: &mut HashMap<String, Value>,
+    ) -> Result<()> {
+        self.process_pending_pointer_capture_with_env(env)?;
+        let target = self.pointer_event_target();
+        let state = &mut self.dom_runtime.pointer_input;
+        state.buttons &= !button.buttons_mask();
+        let released = state.buttons == 0;
+        let press_target = if released {
+            state.press_target.take()
+        } else {
+            state.press_target
+        };
+        let suppress_mouse = state.suppress_mouse_events;
+        let fields = MouseEventFields {
+            button: button.button(),
+            buttons: state.buttons,
+            detail: state.click_count,
+            related_target: None,
+        };
+
+        self.dispatch_pointer_input_event_with_env(
+            target,
+            "pointerup",
+            MouseEventFields {
+                detail: 0,
+                ..fields
+            },
+            env,
+        )?;
+        if !suppress_mouse && !self.is_effectively_disabled(target) {
+            self.dispatch_pointer_input_event_with_env(target, "mouseup", fields, env)?;
+        }
+        if released {
+            // Capture is released implicitly once no button is pressed.
+            self.dom_runtime.pointer_input.suppress_mouse_events = false;
+            self.dom_runtime
+                .pointer_capture_targets
+                .remove(&MOUSE_POINTER_ID);
+            self.process_pending_pointer_capture_with_env(env)?;
+        }
+        if button == MouseButton::Left {
+            self.dom.set_active_pseudo_element(None);
+        }
+
+        // `click` and friends go to the nearest element containing both the
+        // press and the release target.
+        let Some(click_target) =
+            press_target.and_then(|pressed| self.common_inclusive_ancestor(pressed, target))
+        else {
+            return Ok(());
+        };
+        match button {
+            MouseButton::Left => {
+                let members = self.mouse_event_members(fields);
+                self.click_node_with_members_with_env(click_target, members, env)?;
+                if fields.detail == 2 && !self.is_effectively_disabled(click_target) {
+                    self.dispatch_pointer_input_event_with_env(
+                        click_target,
+                        "dblclick",
+                        fields,
+                        env,
+                    )?;
+                }
+            }
+            MouseButton::Middle | MouseButton::Right => {
+                if self.is_effectively_disabled(click_target) {
+                    return Ok(());
+                }
+                self.dispatch_pointer_input_event_with_env(click_target, "auxclick", fields, env)?;
+                if button == MouseButton::Right {
+                    self.dispatch_pointer_input_event_with_env(
+                        click_target,
+                        "contextmenu",
+                        fields,
+                        env,
+                    )?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn common_inclusive_ancestor(&self, first: NodeId, second: NodeId) -> Option<NodeId> {
+        let mut cursor = Some(first);
+        while let Some(node) = cursor {
+            if node == second || self.dom.is_descendant_of(second, node) {
+                return Some(node);
+            }
+            cursor = self.dom.parent(node);
+        }
+        None
+    }
+
+    // A press focuses the nearest focusable ancestor, or blurs when there is none.
+    fn focus_for_pointer_press_with_env(
+        &mut self,
+        target: NodeId,
+        env: &mut HashMap<String, Value>,
+    ) -> Result<()> {
+        let mut cursor = Some(target);
+        while let Some(node) = cursor {
+            if self.sequential_tab_index(node).is_some() {
+                if self.is_effectively_disabled(node) {
+                    return Ok(());
+                }
+                return self.focus_node_with_env(node, env);
+            }
+            cursor = self.dom.parent(node);
+        }
+        if let Some(active) = self.dom.active_element() {
+            self.blur_node_with_env(active, env)?;
+        }
+        Ok(())
+    }
+
+    // Fires `lostpointercapture` / `gotpointercapture` when a
+    // `setPointerCapture` / `releasePointerCapture` call changed the target.
+    fn process_pending_pointer_capture_with_env(
+        &mut self,
+        env: &mut HashMap<String, Value>,
+    ) -> Result<()> {
+        let pending = self.pointer_capture_target();
+        let notified = self.dom_runtime.pointer_input.notified_capture_target;
+        if pending == notified {
+            return Ok(());
+        }
+        self.dom_runtime.pointer_input.notified_capture_target = pending;
+        let buttons = self.dom_runtime.pointer_input.buttons;
+        let fields = MouseEventFields {
+            button: -1,
+            buttons,
+            ..MouseEventFields::default()
+        };
+        if let Some(previous) = notified.filter(|node| self.dom.is_connected(*node)) {
+            self.dispatch_pointer_input_event_with_env(
+                previous,
+                "lostpointercapture",
+                fields,
+                env,
+            )?;
+        }
+        if let Some(pending) = pending {
+            self.dispatch_pointer_input_event_with_env(pending, "gotpointercapture", fields, env)?;
+        }
+        Ok(())
+    }
+
+    fn mouse_event_members(&self, fields: MouseEventFields) -> Vec<(String, Value)> {
+        let state = &self.dom_runtime.pointer_input;
+        vec![
+            ("screenX".to_string(), Value::Float(state.client_x)),
+            ("screenY".to_string(), Value::Float(state.client_y)),
+            ("clientX".to_string(), Value::Float(state.client_x)),
+            ("clientY".to_string(), Value::Float(state.client_y)),
+            ("button".to_string(), Value::Number(fields.button)),
+            ("buttons".to_string(), Value::Number(fields.buttons)),
+            ("detail".to_string(), Value::Number(fields.detail)),
+            (
+                "relatedTarget".to_string(),
+                fields
+                    .related_target
+                    .map(Value::Node)
+                    .unwrap_or(Value::Null),
+            ),
+        ]
+    }
+
+    fn dispatch_pointer_input_event_with_env(
+        &mut self,
+        target: NodeId,
+        event_type: &str,
+        fields: MouseEventFields,
+        env: &mut HashMap<String, Value>,
+    ) -> Result<EventState> {
+        let mut event = EventState::new(event_type, target, self.scheduler.now_ms);
+        let boundary = matches!(
+            event_type,
+            "pointerenter" | "pointerleave" | "mouseenter" | "mouseleave"
+        );
+        event.bubbles = !boundary;
+        event.cancelable = !boundary && !event_type.ends_with("pointercapture");
+        if event_type.starts_with("pointer") || event_type.ends_with("pointercapture") {
+            event.pointer_id = MOUSE_POINTER_ID;
+            event.pointer_type = "mouse".to_string();
+            event.pointer_is_primary = true;
+            event.pointer_pressure = if fields.buttons == 0 { 0.0 } else { 0.5 };
+        }
+        event.interface_members = self.mouse_event_members(fields);
+        self.dispatch_prepared_event_with_env(event, env)
+    }
+}
@@ -0,0 +1,173 @@
+use super::*;
+
+impl Harness {
+    pub fn eval(&mut self, src: &str) -> Result<JsValue> {
+        let value = self.eval_script_completion_value(src)?;
+        Ok(self.js_value_from_value(&value))
+    }
+
+    pub fn eval_async(&mut self, src: &str) -> Result<JsValue> {
+        let value = self.eval_script_completion_value(src)?;
+        let value = self.settle_eval_completion_value(value)?;
+        Ok(self.js_value_from_value(&value))
+    }
+
+    pub fn node_handle(&self, selector: impl Into<Locator>) -> Result<NodeHandle> {
+        Ok(NodeHandle(self.select_one(&selector.into())?))
+    }
+
+    pub(crate) fn eval_script_completion_value(&mut self, src: &str) -> Result<Value> {
+        stacker::grow(32 * 1024 * 1024, || -> Result<Value> {
+            let mut stmts = parse_block_statements(src)?;
+            Self::ensure_no_illegal_top_level_return(&stmts)?;
+            // The completion value of the harness-side script is the value of its
+            // trailing expression statement, which we surface through the return slot.
+            if matches!(stmts.last(), Some(Stmt::Expr(_))) {
+                if let Some(Stmt::Expr(expr)) = stmts.pop() {
+                    stmts.push(Stmt::Return { value: Some(expr) });
+                }
+            }
+            self.with_script_env(|this, env| {
+                let mut event = EventState::new("script", this.dom.root, this.scheduler.now_ms);
+                this.run_in_task_context(|inner| {
+                    inner.execute_stmts(&stmts, &None, &mut event, env)?;
+                    Ok(env.remove(INTERNAL_RETURN_SLOT).unwrap_or(Value::Undefined))
+                })
+            })
+        })
+    }
+
+    fn settle_eval_completion_value(&mut self, value: Value) -> Result<Value> {
+        let Value::Promise(promise) = value else {
+            return Ok(value);
+        };
+        loop {
+            let settled = match &promise.borrow().state {
+                PromiseState::Pending => None,
+                PromiseState::Fulfilled(value) => Some(Ok(value.clone())),
+                PromiseState::Rejected(reason) => Some(Err(reason.clone())),
+            };
+            match settled {
+                Some(Ok(value)) => return Ok(value),
+                Some(Err(reason)) => return Err(Error::ScriptThrown(ThrownValue::new(reason))),
+                None => {
+                    if self.has_pending_microtasks() {
+                        self.run_microtask_queue()?;
+                        continue;
+                    }
+                    if self.run_due_timers_internal()? == 0 {
+                        return Err(Error::ScriptRuntime(
+                            "eval_async: promise is still pending after draining microtasks and due timers"
+                                .into(),
+                        ));
+                    }
+                }
+            }
+        }
+    }
+
+    pub(crate) fn js_value_from_value(&self, value: &Value) -> JsValue {
+        let mut visiting = HashSet::new();
+        self.js_value_from_value_inner(value, &mut visiting)
+    }
+
+    fn js_value_from_value_inner(&self, value: &Value, visiting: &mut HashSet<usize>) -> JsValue {
+        match value {
+            Value::Undefined => JsValue::Undefined,
+            Value::Null => JsValue::Null,
+            Value::Bool(value) => JsValue::Bool(*value),
+            Value::Number(value) => JsValue::Number(*value as f64),
+            Value::Float(value) => JsValue::Number(*value),
+            Value::BigInt(value) => JsValue::BigInt(value.to_string()),
+            Value::String(value) => JsValue::String(value.clone()),
+            Value::Symbol(symbol) => JsValue::Symbol(symbol.description.clone()),
+            Value::Date(value) => JsValue::Date(*value.borrow()),
+            Value::Node(node) => JsValue::Node(NodeHandle(*node)),
+            Value::NodeList(nodes) => JsValue::NodeList(
+                nodes
+                    .borrow()
+                    .nodes
+                    .iter()
+                    .copied()
+                    .map(NodeHandle)
+                    .collect(),
+            ),
+            Value::Array(values) => {
+                let ptr = Rc::as_ptr(values) as usize;
+                if !visiting.insert(ptr) {
+                    return JsValue::Circular;
+                }
+                let out = values
+                    .borrow()
+                    .iter()
+                    .map(|item| self.js_value_from_value_inner(item, visiting))
+                    .collect();
+                visiting.remove(&ptr);
+                JsValue::Array(out)
+            }
+            Value::Map(map) => {
+                let ptr = Rc::as_ptr(map) as usize;
+                if !visiting.insert(ptr) {
+                    return JsValue::Circular;
+                }
+                let out = map
+                    .borrow()
+                    .entries
+                    .iter()
+                    .map(|(key, value)| {
+                        (
+                            self.js_value_from_value_inner(key, visiting),
+                            self.js_value_from_value_inner(value, visiting),
+                        )
+                    })
+                    .collect();
+                visiting.remove(&ptr);
+                JsValue::Map(out)
+            }
+            Value::Set(set) => {
+                let ptr = Rc::as_ptr(set) as usize;
+                if !visiting.insert(ptr) {
+                    return JsValue::Circular;
+                }
+                let out = set
+                    .borrow()
+                    .values
+                    .iter()
+                    .map(|item| self.js_value_from_value_inner(item, visiting))
+                    .collect();
+                visiting.remove(&ptr);
+                JsValue::Set(out)
+            }
+            Value::Object(entries) => {
+                if self.is_callable_value(value) {
+                    return JsValue::Function;
+                }
+                let ptr = Rc::as_ptr(entries) as usize;
+                if !visiting.insert(ptr) {
+                    return JsValue::Circular;
+                }
+                let out = {
+                    let entries = entries.borrow();
+                    if let Some(text) = Self::string_wrapper_value_from_object(&entries) {
+                        visiting.remove(&ptr);
+                        return JsValue::String(text);
+                    }
+                    entries
+                        .iter()
+                        .filter(|(key, _)| {
+                            !Self::is_internal_object_key(key)
+                                && !Self::is_non_enumerable_object_key(&*entries, key)
+                        })
+                        .map(|(key, value)| {
+                            (key.clone(), self.js_value_from_value_inner(value, visiting))
+                        })
+                        .collect()
+                };
+                visiting.remove(&ptr);
+                JsValue::Object(out)
+            }
+            other if self.is_callable_value(other) => JsValue::Function,
+            other => JsValue::Opaque(other.as_string()),
+        }
+    }
+}
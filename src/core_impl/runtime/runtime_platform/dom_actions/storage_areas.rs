@@ -0,0 +1,195 @@
+use super::*;
+
+impl Harness {
+    pub fn local_storage_items(&self) -> Vec<(String, String)> {
+        Self::storage_pairs_from_object_entries(&self.browser_apis.local_storage_object.borrow())
+    }
+
+    pub fn session_storage_items(&self) -> Vec<(String, String)> {
+        Self::storage_pairs_from_object_entries(&self.browser_apis.session_storage_object.borrow())
+    }
+
+    pub(crate) fn shared_origin_storage(&self) -> SharedOriginStorage {
+        SharedOriginStorage {
+            origin: self.current_location_parts().origin(),
+            local_storage: Self::storage_item_list(&self.browser_apis.local_storage_object),
+            cookies: self.browser_apis.cookies.clone(),
+        }
+    }
+
+    pub(crate) fn uses_shared_origin_storage(&self, shared: &SharedOriginStorage) -> bool {
+        Rc::ptr_eq(
+            &Self::storage_item_list(&self.browser_apis.local_storage_object),
+            &shared.local_storage,
+        ) && Rc::ptr_eq(&self.browser_apis.cookies, &shared.cookies)
+    }
+
+    // Switches to another origin's storage after a cross-origin navigation.
+    pub(crate) fn adopt_shared_origin_storage(&mut self, shared: &SharedOriginStorage) {
+        self.browser_apis.local_storage_object =
+            Self::new_storage_object_over(&shared.local_storage);
+        self.browser_apis.cookies = shared.cookies.clone();
+        let local_storage = Value::Object(self.browser_apis.local_storage_object.clone());
+        Self::object_set_entry(
+            &mut self.dom_runtime.window_object.borrow_mut(),
+            "localStorage".to_string(),
+            local_storage.clone(),
+        );
+        self.script_runtime
+            .env
+            .insert("localStorage".to_string(), local_storage);
+    }
+
+    // A page keeps one session storage area per origin: leaving an origin
+    // parks its area, and coming back picks it up again.
+    pub(crate) fn switch_session_storage_origin(&mut self, from_url: &str) {
+        let from = LocationParts::parse(from_url)
+            .map(|parts| parts.origin())
+            .unwrap_or_else(|| "null".to_string());
+        let to = self.current_location_parts().origin();
+        if from == to {
+            return;
+        }
+        let apis = &mut self.browser_apis;
+        let next = apis
+            .session_storage_by_origin
+            .remove(&to)
+            .unwrap_or_else(|| {
+                Self::new_storage_object_over(&Rc::new(RefCell::new(ArrayValue::new(Vec::new()))))
+            });
+        let previous = std::mem::replace(&mut apis.session_storage_object, next);
+        if from != "null" {
+            apis.session_storage_by_origin.insert(from, previous);
+        }
+        let session_storage = Value::Object(self.browser_apis.session_storage_object.clone());
+        Self::object_set_entry(
+            &mut self.dom_runtime.window_object.borrow_mut(),
+            "sessionStorage".to_string(),
+            session_storage.clone(),
+        );
+        self.script_runtime
+            .env
+            .insert("sessionStorage".to_string(), session_storage);
+    }
+
+    // A script's env is written back when it finishes, which would restore the
+    // area a cross-origin navigation inside it switched away from.
+    pub(crate) fn rebind_switched_session_storage(&mut self, before: &Rc<RefCell<ObjectValue>>) {
+        if Rc::ptr_eq(before, &self.browser_apis.session_storage_object) {
+            return;
+        }
+        self.script_runtime.env.insert(
+            "sessionStorage".to_string(),
+            Value::Object(self.browser_apis.session_storage_object.clone()),
+        );
+    }
+
+    pub(crate) fn deliver_storage_event(
+        &mut self,
+        key: Option<String>,
+        old_value: Option<String>,
+        new_value: Option<String>,
+        url: String,
+    ) -> Result<()> {
+        let optional = |value: Option<String>| value.map(Value::String).unwrap_or(Value::Null);
+        let members = vec![
+            ("key".to_string(), optional(key)),
+            ("oldValue".to_string(), optional(old_value)),
+            ("newValue".to_string(), optional(new_value)),
+            ("url".to_string(), Value::String(url)),
+            (
+                "storageArea".to_string(),
+                Value::Object(self.browser_apis.local_storage_object.clone()),
+            ),
+        ];
+        self.dispatch_window_task_event("storage", |event| {
+            event.interface_members = members;
+        })
+    }
+}
+
+impl MockWindow {
+    // Opaque origins never share storage, so they get a fresh, unrecorded area.
+    pub(crate) fn shared_storage_for_url(&mut self, url: &str) -> SharedOriginStorage {
+        let origin = LocationParts::parse(url)
+            .map(|parts| parts.origin())
+            .unwrap_or_else(|| "null".to_string());
+        if let Some(shared) = self
+            .shared_storage
+            .iter()
+            .find(|shared| shared.origin == origin)
+        {
+            return shared.clone();
+        }
+        let shared = SharedOriginStorage {
+            origin,
+            local_storage: Rc::new(RefCell::new(ArrayValue::new(Vec::new()))),
+            cookies: Rc::new(RefCell::new(Vec::new())),
+        };
+        if shared.origin != "null" {
+            self.shared_storage.push(shared.clone());
+        }
+        shared
+    }
+
+    // Pages that navigated to another origin pick up that origin's storage.
+    pub(crate) fn share_same_origin_storage(&mut self) {
+        for page in &mut self.pages {
+            let own = page.harness.shared_origin_storage();
+            if own.origin == "null" {
+                continue;
+            }
+            if let Some(shared) = self
+                .shared_storage
+                .iter()
+                .find(|shared| shared.origin == own.origin)
+            {
+                if !page.harness.uses_shared_origin_storage(shared) {
+                    page.harness.adopt_shared_origin_storage(shared);
+                }
+                continue;
+            }
+            let held_by_other_origin = self
+                .shared_storage
+                .iter()
+                .any(|shared| page.harness.uses_shared_origin_storage(shared));
+            let shared = if held_by_other_origin {
+                SharedOriginStorage {
+                    local_storage: Rc::new(RefCell::new(ArrayValue::new(Vec::new()))),
+                    cookies: Rc::new(RefCell::new(Vec::new())),
+                    ..own
+                }
+            } else {
+                own
+            };
+            page.harness.adopt_shared_origin_storage(&shared);
+            self.shared_storage.push(shared);
+        }
+    }
+
+    pub(crate) fn dispatch_storage_change(
+        &mut self,
+        source: &str,
+        origin: &str,
+        url: &str,
+        key: Option<String>,
+        old_value: Option<String>,
+        new_value: Option<String>,
+    ) -> Result<()> {
+        for page in &mut self.pages {
+            let harness = &mut page.harness;
+            if harness.browser_apis.browsing_context.id == source
+                || harness.current_location_parts().origin() != origin
+            {
+                continue;
+            }
+            harness.deliver_storage_event(
+                key.clone(),
+                old_value.clone(),
+                new_value.clone(),
+                url.to_string(),
+            )?;
+        }
+        Ok(())
+    }
+}
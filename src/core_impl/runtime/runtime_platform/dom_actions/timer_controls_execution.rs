@@ -5,20 +5,37 @@ impl Harness {
         self.scheduler.now_ms
     }
 
+    // Sets the IANA time zone used for local `Date` fields and as the default
+    // `Intl.DateTimeFormat` zone. Harnesses start in UTC.
+    pub fn set_time_zone(&mut self, time_zone: &str) -> Result<()> {
+        let normalized = Self::intl_normalize_time_zone(time_zone).ok_or_else(|| {
+            Error::ScriptRuntime(format!("RangeError: invalid time zone: {time_zone}"))
+        })?;
+        self.scheduler.time_zone = normalized;
+        Ok(())
+    }
+
+    pub fn time_zone(&self) -> &str {
+        &self.scheduler.time_zone
+    }
+
     pub fn clear_timer(&mut self, timer_id: i64) -> bool {
         let existed = self.scheduler.running_timer_id == Some(timer_id)
             || self
                 .scheduler
                 .task_queue
                 .iter()
-                .any(|task| task.id == timer_id);
+                .any(|task| task.id == timer_id && !Self::is_internal_scheduler_task(task));
         self.clear_timeout(timer_id);
         existed
     }
 
     pub fn clear_all_timers(&mut self) -> usize {
-        let cleared = self.scheduler.task_queue.len();
-        self.scheduler.task_queue.clear();
+        let before = self.scheduler.task_queue.len();
+        self.scheduler
+            .task_queue
+            .retain(Self::is_internal_scheduler_task);
+        let cleared = before - self.scheduler.task_queue.len();
         if self.scheduler.running_timer_id.is_some() {
             self.scheduler.running_timer_canceled = true;
         }
@@ -31,6 +48,7 @@ impl Harness {
             .scheduler
             .task_queue
             .iter()
+            .filter(|task| !Self::is_internal_scheduler_task(task))
             .map(|task| PendingTimer {
                 id: task.id,
                 due_at: task.due_at,
@@ -201,7 +219,33 @@ impl Harness {
         stacker::grow(32 * 1024 * 1024, || self.execute_timer_task_impl(task))
     }
 
+    pub(crate) fn is_internal_scheduler_task(task: &ScheduledTask) -> bool {
+        matches!(
+            task.kind,
+            ScheduledTaskKind::FetchResponse
+                | ScheduledTaskKind::AbortSignalTimeout
+                | ScheduledTaskKind::LayoutObservers
+                | ScheduledTaskKind::WindowMessage
+        )
+    }
+
     pub(crate) fn execute_timer_task_impl(&mut self, mut task: ScheduledTask) -> Result<()> {
+        match task.kind {
+            ScheduledTaskKind::FetchResponse => {
+                let Some(mut pending) = self.take_pending_fetch(task.id) else {
+                    return Ok(());
+                };
+                let Some(response) = pending.response.take() else {
+                    return Ok(());
+                };
+                return self.settle_pending_fetch(pending, Ok(response));
+            }
+            ScheduledTaskKind::AbortSignalTimeout => return self.run_abort_signal_timeout(&task),
+            ScheduledTaskKind::LayoutObservers => return self.run_layout_observer_task(&task),
+            ScheduledTaskKind::WindowMessage => return self.run_window_message_task(task),
+            _ => {}
+        }
+
         let interval_desc = task
             .interval_ms
             .map(|value| value.to_string())
@@ -54,6 +54,10 @@ impl Harness {
         self.trace_state.timers = enabled;
     }
 
+    pub fn set_trace_console(&mut self, enabled: bool) {
+        self.trace_state.console = enabled;
+    }
+
     pub fn set_trace_log_limit(&mut self, max_entries: usize) -> Result<()> {
         if max_entries == 0 {
             return Err(Error::ScriptRuntime(
@@ -78,25 +82,23 @@ impl Harness {
     pub fn set_fetch_mock(&mut self, url: &str, body: &str) {
         self.platform_mocks.fetch_mocks.insert(
             url.to_string(),
-            FetchMockResponse {
-                status: 200,
-                status_text: "OK".to_string(),
-                body: body.to_string(),
-            },
+            FetchMockResponse::new(200).with_bytes(body.as_bytes()),
         );
     }
 
     pub fn set_fetch_mock_response(&mut self, url: &str, status: i64, body: &str) {
         self.platform_mocks.fetch_mocks.insert(
             url.to_string(),
-            FetchMockResponse {
-                status,
-                status_text: Self::default_fetch_status_text(status),
-                body: body.to_string(),
-            },
+            FetchMockResponse::new(status).with_bytes(body.as_bytes()),
         );
     }
 
+    pub fn add_fetch_mock(&mut self, matcher: FetchMatcher, response: FetchMockResponse) {
+        self.platform_mocks
+            .fetch_mock_rules
+            .push(FetchMockRule { matcher, response });
+    }
+
     pub fn set_clipboard_text(&mut self, text: &str) {
         self.platform_mocks.clipboard_text = text.to_string();
     }
@@ -147,12 +149,55 @@ impl Harness {
 
     pub fn clear_fetch_mocks(&mut self) {
         self.platform_mocks.fetch_mocks.clear();
+        self.platform_mocks.fetch_mock_rules.clear();
     }
 
     pub fn take_fetch_calls(&mut self) -> Vec<String> {
         std::mem::take(&mut self.platform_mocks.fetch_calls)
     }
 
+    pub fn take_fetch_requests(&mut self) -> Vec<RecordedRequest> {
+        std::mem::take(&mut self.platform_mocks.fetch_requests)
+    }
+
+    pub fn set_fetch_handler(
+        &mut self,
+        handler: impl FnMut(&FetchRequest) -> FetchOutcome + 'static,
+    ) {
+        self.platform_mocks.fetch_handler = Some(FetchHandler(Rc::new(RefCell::new(handler))));
+    }
+
+    pub fn clear_fetch_handler(&mut self) {
+        self.platform_mocks.fetch_handler = None;
+    }
+
+    pub fn set_manual_fetch_mode(&mut self, enabled: bool) {
+        self.platform_mocks.manual_fetch_mode = enabled;
+    }
+
+    pub fn pending_fetches(&self) -> Vec<PendingFetch> {
+        self.platform_mocks
+            .pending_fetches
+            .iter()
+            .map(|pending| pending.info.clone())
+            .collect()
+    }
+
+    pub fn resolve_pending_fetch(&mut self, id: i64, response: FetchMockResponse) -> Result<()> {
+        let pending = self
+            .take_pending_fetch(id)
+            .ok_or_else(|| Error::ScriptRuntime(format!("no pending fetch with id {id}")))?;
+        self.settle_pending_fetch(pending, Ok(response))
+    }
+
+    pub fn reject_pending_fetch(&mut self, id: i64) -> Result<()> {
+        let pending = self
+            .take_pending_fetch(id)
+            .ok_or_else(|| Error::ScriptRuntime(format!("no pending fetch with id {id}")))?;
+        let reason = Self::fetch_type_error_value("Failed to fetch");
+        self.settle_pending_fetch(pending, Err(reason))
+    }
+
     pub fn set_match_media_mock(&mut self, query: &str, matches: bool) {
         self.platform_mocks
             .match_media_mocks
@@ -193,6 +238,17 @@ impl Harness {
         std::mem::take(&mut self.platform_mocks.alert_messages)
     }
 
+    pub fn take_console_messages(&mut self) -> Vec<ConsoleMessage> {
+        std::mem::take(&mut self.platform_mocks.console_messages)
+    }
+
+    pub fn set_fail_on_console_error(&mut self, enabled: bool) {
+        self.platform_mocks.fail_on_console_error = enabled;
+        if !enabled {
+            self.platform_mocks.pending_console_error = None;
+        }
+    }
+
     pub fn take_print_call_count(&mut self) -> usize {
         std::mem::take(&mut self.platform_mocks.print_call_count)
     }
@@ -347,18 +403,10 @@ impl Harness {
             return Ok(());
         }
 
-        let target_attr = self
+        let target_name = self
             .dom
             .attr(target, "target")
-            .unwrap_or_else(|| self.default_hyperlink_target())
-            .to_ascii_lowercase();
-        if !matches!(
-            target_attr.as_str(),
-            "" | "_self" | "_parent" | "_top" | "_unfencedtop"
-        ) {
-            return Ok(());
-        }
-
+            .unwrap_or_else(|| self.default_hyperlink_target());
         let href = self.resolve_anchor_href(target);
         if href
             .split_once(':')
@@ -369,6 +417,15 @@ impl Harness {
         if self.try_resolve_location_target_url(&href).is_err() {
             return Ok(());
         }
+
+        if !matches!(
+            target_name.to_ascii_lowercase().as_str(),
+            "" | "_self" | "_parent" | "_top" | "_unfencedtop"
+        ) {
+            let with_opener = self.rel_keeps_opener(target, &target_name);
+            let _ = self.open_browsing_context(&href, &target_name, with_opener)?;
+            return Ok(());
+        }
         self.navigate_location(&href, LocationNavigationKind::Assign)
     }
 
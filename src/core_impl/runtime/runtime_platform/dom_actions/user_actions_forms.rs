@@ -1,8 +1,10 @@
 use super::*;
 
 impl Harness {
-    pub fn type_text(&mut self, selector: &str, text: &str) -> Result<()> {
-        let target = self.select_one(selector)?;
+    pub fn type_text(&mut self, selector: impl Into<Locator>, text: &str) -> Result<()> {
+        let locator = selector.into();
+        let target = self.select_one(&locator)?;
+        let selector = locator.to_string();
         if self.is_effectively_disabled(target) {
             return Ok(());
         }
@@ -56,15 +58,24 @@ impl Harness {
             });
         }
 
+        let (edit_type, data) = if text.is_empty() {
+            ("deleteContentBackward", None)
+        } else {
+            ("insertText", Some(text))
+        };
         stacker::grow(32 * 1024 * 1024, || {
             self.dom.set_value(target, text)?;
-            self.dispatch_event(target, "input")?;
+            self.with_script_env(|this, env| {
+                this.dispatch_input_event_with_env(target, "input", edit_type, data, env)
+            })?;
             Ok(())
         })
     }
 
-    pub fn set_select_value(&mut self, selector: &str, value: &str) -> Result<()> {
-        let target = self.select_one(selector)?;
+    pub fn set_select_value(&mut self, selector: impl Into<Locator>, value: &str) -> Result<()> {
+        let locator = selector.into();
+        let target = self.select_one(&locator)?;
+        let selector = locator.to_string();
         if self.is_effectively_disabled(target) {
             return Ok(());
         }
@@ -97,10 +108,15 @@ impl Harness {
         })
     }
 
-    pub fn set_input_files(&mut self, selector: &str, files: &[MockFile]) -> Result<()> {
-        let target = self.select_one(selector)?;
+    pub fn set_input_files(
+        &mut self,
+        selector: impl Into<Locator>,
+        files: &[MockFile],
+    ) -> Result<()> {
+        let locator = selector.into();
+        let target = self.select_one(&locator)?;
+        let selector = locator.to_string();
         let files = files.to_vec();
-        let selector = selector.to_string();
         stacker::grow(32 * 1024 * 1024, || {
             self.with_script_env_always(|this, env| {
                 this.set_input_files_with_env(target, &selector, &files, env)
@@ -159,8 +175,10 @@ impl Harness {
         Ok(())
     }
 
-    pub fn set_checked(&mut self, selector: &str, checked: bool) -> Result<()> {
-        let target = self.select_one(selector)?;
+    pub fn set_checked(&mut self, selector: impl Into<Locator>, checked: bool) -> Result<()> {
+        let locator = selector.into();
+        let target = self.select_one(&locator)?;
+        let selector = locator.to_string();
         if self.is_effectively_disabled(target) {
             return Ok(());
         }
@@ -202,8 +220,8 @@ impl Harness {
         })
     }
 
-    pub fn click(&mut self, selector: &str) -> Result<()> {
-        let target = self.select_one(selector)?;
+    pub fn click(&mut self, selector: impl Into<Locator>) -> Result<()> {
+        let target = self.select_one(&selector.into())?;
         self.click_node(target)
     }
 
@@ -291,6 +309,16 @@ impl Harness {
         &mut self,
         target: NodeId,
         env: &mut HashMap<String, Value>,
+    ) -> Result<()> {
+        self.click_node_with_members_with_env(target, Vec::new(), env)
+    }
+
+    // `members` carries the `MouseEvent` fields of a pointer-driven click.
+    pub(crate) fn click_node_with_members_with_env(
+        &mut self,
+        target: NodeId,
+        members: Vec<(String, Value)>,
+        env: &mut HashMap<String, Value>,
     ) -> Result<()> {
         if self.is_effectively_disabled(target) {
             return Ok(());
@@ -298,7 +326,7 @@ impl Harness {
 
         self.dom.set_active_pseudo_element(Some(target));
         let result: Result<()> = (|| {
-            let click_outcome = self.dispatch_event_with_env(target, "click", env, true)?;
+            let click_outcome = self.dispatch_event_with_members(target, "click", env, members)?;
             if click_outcome.default_prevented {
                 return Ok(());
             }
@@ -339,7 +367,11 @@ impl Harness {
             }
 
             if is_submit_control(&self.dom, target) {
+                let generation = self.location_history.document_generation;
                 self.request_form_submit_with_env(target, Some(target), env)?;
+                if self.location_history.document_generation != generation {
+                    return Ok(());
+                }
             }
             if is_reset_control(&self.dom, target) {
                 self.reset_form_with_env(target, env)?;
@@ -497,32 +529,32 @@ impl Harness {
         self.with_script_env_always(|this, env| this.click_dom_method_with_env(target, env))
     }
 
-    pub fn focus(&mut self, selector: &str) -> Result<()> {
-        let target = self.select_one(selector)?;
+    pub fn focus(&mut self, selector: impl Into<Locator>) -> Result<()> {
+        let target = self.select_one(&selector.into())?;
         stacker::grow(32 * 1024 * 1024, || self.focus_node(target))
     }
 
-    pub fn blur(&mut self, selector: &str) -> Result<()> {
-        let target = self.select_one(selector)?;
+    pub fn blur(&mut self, selector: impl Into<Locator>) -> Result<()> {
+        let target = self.select_one(&selector.into())?;
         stacker::grow(32 * 1024 * 1024, || self.blur_node(target))
     }
 
-    pub fn press_enter(&mut self, selector: &str) -> Result<()> {
-        let target = self.select_one(selector)?;
+    pub fn press_enter(&mut self, selector: impl Into<Locator>) -> Result<()> {
+        let target = self.select_one(&selector.into())?;
         stacker::grow(32 * 1024 * 1024, || {
             self.with_script_env_always(|this, env| this.press_enter_with_env(target, env))
         })
     }
 
-    pub fn copy(&mut self, selector: &str) -> Result<()> {
-        let target = self.select_one(selector)?;
+    pub fn copy(&mut self, selector: impl Into<Locator>) -> Result<()> {
+        let target = self.select_one(&selector.into())?;
         stacker::grow(32 * 1024 * 1024, || {
             self.with_script_env_always(|this, env| this.copy_node_with_env(target, env))
         })
     }
 
-    pub fn paste(&mut self, selector: &str) -> Result<()> {
-        let target = self.select_one(selector)?;
+    pub fn paste(&mut self, selector: impl Into<Locator>) -> Result<()> {
+        let target = self.select_one(&selector.into())?;
         stacker::grow(32 * 1024 * 1024, || {
             self.with_script_env_always(|this, env| this.paste_node_with_env(target, env))
         })
@@ -538,6 +570,7 @@ impl Harness {
         }
 
         self.focus_node_with_env(target, env)?;
+        let generation = self.location_history.document_generation;
         let keydown = self.dispatch_event_with_env(target, "keydown", env, true)?;
         if !keydown.default_prevented
             && self.dom.tag_name(target).is_some_and(|tag| {
@@ -547,6 +580,9 @@ impl Harness {
         {
             self.click_node_with_env(target, env)?;
         }
+        if self.location_history.document_generation != generation {
+            return Ok(());
+        }
         let _ = self.dispatch_event_with_env(target, "keyup", env, true)?;
         Ok(())
     }
@@ -605,7 +641,7 @@ impl Harness {
         Ok(())
     }
 
-    fn paste_contenteditable_host(&self, node: NodeId) -> Option<NodeId> {
+    pub(crate) fn paste_contenteditable_host(&self, node: NodeId) -> Option<NodeId> {
         let mut cursor = Some(node);
         while let Some(current) = cursor {
             let Some(raw) = self.dom.attr(current, "contenteditable") else {
@@ -668,8 +704,8 @@ impl Harness {
         Ok(())
     }
 
-    pub fn submit(&mut self, selector: &str) -> Result<()> {
-        let target = self.select_one(selector)?;
+    pub fn submit(&mut self, selector: impl Into<Locator>) -> Result<()> {
+        let target = self.select_one(&selector.into())?;
         stacker::grow(32 * 1024 * 1024, || {
             self.with_script_env(|this, env| this.request_form_submit_with_env(target, None, env))
         })
@@ -682,7 +718,8 @@ impl Harness {
     ) -> Result<()> {
         // form.submit() bypasses validation and submit event dispatch.
         if let Some(form_id) = self.resolve_submit_form_target(target) {
-            self.maybe_close_dialog_for_form_submit_with_env(form_id, env)?;
+            self.maybe_close_dialog_for_form_submit_with_env(form_id, None, env)?;
+            self.submit_form_entry_list(form_id, None)?;
         }
 
         Ok(())
@@ -738,9 +775,16 @@ impl Harness {
             return Ok(());
         }
 
-        let submit_outcome = self.dispatch_event_with_env(form_id, "submit", env, true)?;
+        let submitter_value = submitter.map(Value::Node).unwrap_or(Value::Null);
+        let submit_outcome = self.dispatch_event_with_members(
+            form_id,
+            "submit",
+            env,
+            vec![("submitter".to_string(), submitter_value)],
+        )?;
         if !submit_outcome.default_prevented {
-            self.maybe_close_dialog_for_form_submit_with_env(form_id, env)?;
+            self.maybe_close_dialog_for_form_submit_with_env(form_id, submitter, env)?;
+            self.submit_form_entry_list(form_id, submitter)?;
         }
         Ok(())
     }
@@ -748,12 +792,10 @@ impl Harness {
     pub(crate) fn maybe_close_dialog_for_form_submit_with_env(
         &mut self,
         form: NodeId,
+        submitter: Option<NodeId>,
         env: &mut HashMap<String, Value>,
     ) -> Result<()> {
-        let Some(method) = self.dom.attr(form, "method") else {
-            return Ok(());
-        };
-        if !method.eq_ignore_ascii_case("dialog") {
+        if self.form_submission_method(form, submitter) != "dialog" {
             return Ok(());
         }
         let Some(dialog) = self.dom.find_ancestor_by_tag(form, "dialog") else {
@@ -828,8 +870,9 @@ impl Harness {
         Ok(())
     }
 
-    pub fn dispatch(&mut self, selector: &str, event: &str) -> Result<()> {
-        if let Some(target_object) = self.resolve_dispatch_event_target_object(selector) {
+    pub fn dispatch(&mut self, selector: impl Into<Locator>, event: &str) -> Result<()> {
+        let locator = selector.into();
+        if let Some(target_object) = self.resolve_dispatch_event_target_object(&locator) {
             let event_payload = Value::String(event.to_string());
             return self.with_script_env(|this, env| {
                 stacker::grow(32 * 1024 * 1024, || {
@@ -842,7 +885,7 @@ impl Harness {
                 })
             });
         }
-        let target = self.resolve_dispatch_target(selector)?;
+        let target = self.resolve_dispatch_target(&locator)?;
         self.with_script_env(|this, env| {
             stacker::grow(32 * 1024 * 1024, || {
                 let _ = this.dispatch_event_with_env(target, event, env, false)?;
@@ -853,11 +896,12 @@ impl Harness {
 
     pub fn dispatch_keyboard(
         &mut self,
-        selector: &str,
+        selector: impl Into<Locator>,
         event: &str,
         init: KeyboardEventInit,
     ) -> Result<()> {
-        if let Some(target_object) = self.resolve_dispatch_event_target_object(selector) {
+        let locator = selector.into();
+        if let Some(target_object) = self.resolve_dispatch_event_target_object(&locator) {
             let event_payload = Self::new_object_value(vec![
                 (INTERNAL_EVENT_OBJECT_KEY.to_string(), Value::Bool(true)),
                 (
@@ -891,7 +935,7 @@ impl Harness {
                 })
             });
         }
-        let target = self.resolve_dispatch_target(selector)?;
+        let target = self.resolve_dispatch_target(&locator)?;
         self.with_script_env(move |this, env| {
             stacker::grow(32 * 1024 * 1024, || {
                 let mut dispatched =
@@ -915,9 +959,9 @@ impl Harness {
 
     fn resolve_dispatch_event_target_object(
         &self,
-        selector: &str,
+        locator: &Locator,
     ) -> Option<Rc<RefCell<ObjectValue>>> {
-        let selector = selector.trim();
+        let selector = locator.css_selector()?.trim();
         if !matches!(
             selector,
             "window" | "self" | "top" | "parent" | "frames" | "globalThis"
@@ -930,14 +974,11 @@ impl Harness {
         }
     }
 
-    fn resolve_dispatch_target(&self, selector: &str) -> Result<NodeId> {
-        let selector = selector.trim();
-        if matches!(selector, "document" | "window.document") {
-            return Ok(self.dom.root);
-        }
-        if selector == "window" {
+    fn resolve_dispatch_target(&self, locator: &Locator) -> Result<NodeId> {
+        let selector = locator.css_selector().map(str::trim);
+        if matches!(selector, Some("document" | "window.document" | "window")) {
             return Ok(self.dom.root);
         }
-        self.select_one(selector)
+        self.select_one(locator)
     }
 }
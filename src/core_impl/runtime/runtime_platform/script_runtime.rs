@@ -2,6 +2,7 @@ use super::*;
 
 mod callable_execution;
 mod catch_scope_analysis;
+mod console_messages;
 mod listener_trace;
 mod microtask_task_context;
 mod statement_execution;
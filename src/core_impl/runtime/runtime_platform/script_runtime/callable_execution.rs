@@ -1563,7 +1563,7 @@ impl Harness {
         }
     }
 
-    fn resolve_worker_script_source(&self, script_url: &str) -> Result<String> {
+    fn resolve_worker_script_source(&mut self, script_url: &str) -> Result<String> {
         let url = script_url.trim();
         if url.is_empty() {
             return Err(Error::ScriptRuntime(
@@ -1576,11 +1576,7 @@ impl Harness {
 
         let resolved = Self::resolve_url_string(url, Some(&self.document_url))
             .unwrap_or_else(|| url.to_string());
-        self.platform_mocks
-            .fetch_mocks
-            .get(&resolved)
-            .or_else(|| self.platform_mocks.fetch_mocks.get(url))
-            .map(|mock| mock.body.clone())
+        self.load_resource_source(FetchDestination::Worker, url, &resolved)
             .ok_or_else(|| {
                 Error::ScriptRuntime(format!("Worker script source not found: {script_url}"))
             })
@@ -2454,6 +2450,15 @@ impl Harness {
             entries.push(("colno".to_string(), Value::Number(error_colno)));
             entries.push(("error".to_string(), error_value));
         }
+        let interface =
+            EventInterface::from_name(constructor_name).unwrap_or(EventInterface::Event);
+        let members = match args.get(1) {
+            Some(Value::Object(options)) => {
+                Self::event_interface_members_from_init(interface, &options.borrow())
+            }
+            _ => Vec::new(),
+        };
+        Self::push_event_interface_entries(&mut entries, interface, &members);
         Ok(Self::new_object_value(entries))
     }
 
@@ -2645,6 +2650,10 @@ impl Harness {
                         Value::Object(prototype) => Value::Object(prototype),
                         _ => Value::Object(function.prototype_object.clone()),
                     };
+                let this_arg = match this_arg {
+                    Some(instance) => Some(instance),
+                    None => self.create_element_for_custom_constructor(constructor)?,
+                };
                 let instance = if let Some(instance) = this_arg {
                     if Self::is_primitive_value(&instance) {
                         return Err(Error::ScriptRuntime(
@@ -3251,6 +3260,21 @@ impl Harness {
                             false,
                             true,
                         ),
+                    "event_interface_constructor" => {
+                        let interface = Self::event_interface_constructor_from_callable(callable)?;
+                        self.new_event_object_from_constructor_args(
+                            interface.name(),
+                            args,
+                            false,
+                            false,
+                            false,
+                            false,
+                            false,
+                            false,
+                            false,
+                            false,
+                        )
+                    }
                     "image_data_constructor" => self.new_image_data_from_constructor_args(args),
                     "dom_parser_constructor" => {
                         if !args.is_empty() {
@@ -3286,10 +3310,26 @@ impl Harness {
                     }
                     "fetch_function" => self.eval_fetch_call_from_values(args),
                     "window_close_function" => {
+                        if let Some(id) = this_arg.as_ref().and_then(Self::window_proxy_context_id)
+                        {
+                            self.request_browsing_context_close(id);
+                            return Ok(Value::Undefined);
+                        }
                         self.browser_apis.window_closed = true;
                         self.sync_window_runtime_properties();
+                        if self.browser_apis.browsing_context.script_opened {
+                            let id = self.browser_apis.browsing_context.id.clone();
+                            self.request_browsing_context_close(id);
+                        }
                         Ok(Value::Undefined)
                     }
+                    "window_proxy_close_function" => {
+                        if let Some(id) = Self::window_proxy_context_id(callable) {
+                            self.request_browsing_context_close(id);
+                        }
+                        Ok(Value::Undefined)
+                    }
+                    "window_open_function" => self.eval_window_open_call(args),
                     "window_stop_function" => Ok(Value::Undefined),
                     "window_focus_function" => Ok(Value::Undefined),
                     "window_scroll_function" => {
@@ -3465,6 +3505,14 @@ impl Harness {
                         }
 
                         let sender_origin = self.current_location_parts().origin();
+                        if let Some(target) =
+                            this_arg.as_ref().and_then(Self::window_proxy_context_id)
+                        {
+                            let target_origin =
+                                self.window_post_message_target_origin_from_args(args, "/");
+                            self.queue_cross_context_post_message(target, target_origin, &args[0])?;
+                            return Ok(Value::Undefined);
+                        }
                         let target_origin =
                             self.window_post_message_target_origin_from_args(args, &sender_origin);
                         let target_window =
@@ -3525,6 +3573,71 @@ impl Harness {
                         self.platform_mocks.alert_messages.push(message);
                         Ok(Value::Undefined)
                     }
+                    "console_method" => {
+                        let method = Self::static_method_name(callable)?;
+                        self.eval_console_method(&method, args)
+                    }
+                    "abort_controller_constructor" => Ok(Self::new_abort_controller_value()),
+                    "abort_signal_constructor" => Err(Error::ScriptRuntime(
+                        "TypeError: Illegal constructor".into(),
+                    )),
+                    "abort_signal_static_method" => {
+                        let method = Self::static_method_name(callable)?;
+                        self.eval_abort_signal_static_method(&method, args)
+                    }
+                    "dom_exception_constructor" => {
+                        Ok(Self::new_dom_exception_from_constructor_args(args))
+                    }
+                    "intersection_observer_constructor" => self
+                        .new_layout_observer_from_constructor_args(
+                            LayoutObserverKind::Intersection,
+                            args,
+                        ),
+                    "resize_observer_constructor" => self
+                        .new_layout_observer_from_constructor_args(
+                            LayoutObserverKind::Resize,
+                            args,
+                        ),
+                    "intersection_observer_entry_constructor"
+                    | "resize_observer_entry_constructor" => Err(Error::ScriptRuntime(
+                        "TypeError: Illegal constructor".into(),
+                    )),
+                    "layout_observer_observe"
+                    | "layout_observer_unobserve"
+                    | "layout_observer_disconnect"
+                    | "layout_observer_take_records" => {
+                        let Some(id) = Self::layout_observer_id(callable) else {
+                            return Err(Error::ScriptRuntime(
+                                "TypeError: Illegal invocation".into(),
+                            ));
+                        };
+                        self.eval_layout_observer_method(kind, id, args)
+                    }
+                    "custom_elements_define"
+                    | "custom_elements_get"
+                    | "custom_elements_when_defined"
+                    | "custom_elements_upgrade" => self.eval_custom_elements_method(kind, args),
+                    "mutation_observer_constructor" => {
+                        self.new_mutation_observer_from_constructor_args(args)
+                    }
+                    "mutation_observer_observe"
+                    | "mutation_observer_disconnect"
+                    | "mutation_observer_take_records" => {
+                        let Some(id) = Self::mutation_observer_id(callable) else {
+                            return Err(Error::ScriptRuntime(
+                                "TypeError: Illegal invocation".into(),
+                            ));
+                        };
+                        match kind {
+                            "mutation_observer_observe" => {
+                                self.eval_mutation_observer_observe(id, args)
+                            }
+                            "mutation_observer_disconnect" => {
+                                Ok(self.eval_mutation_observer_disconnect(id))
+                            }
+                            _ => Ok(self.eval_mutation_observer_take_records(id)),
+                        }
+                    }
                     "window_confirm_function" => {
                         if args.len() > 1 {
                             return Err(Error::ScriptRuntime(
@@ -3713,6 +3826,20 @@ impl Harness {
                         Ok(instance)
                     }
                     "text_encoder_get_encoding" => Ok(Value::String("utf-8".to_string())),
+                    "module_namespace_get_export" => {
+                        let Value::Object(entries) = callable else {
+                            return Ok(Value::Undefined);
+                        };
+                        let entries = entries.borrow();
+                        let (Some(Value::String(module)), Some(Value::String(name))) = (
+                            Self::object_get_entry(&entries, INTERNAL_MODULE_NAMESPACE_MODULE_KEY),
+                            Self::object_get_entry(&entries, INTERNAL_MODULE_NAMESPACE_EXPORT_KEY),
+                        ) else {
+                            return Ok(Value::Undefined);
+                        };
+                        self.module_export_value(&module, &name)
+                            .map(|value| value.unwrap_or(Value::Undefined))
+                    }
                     "text_encoder_encode" => {
                         let input = args.first().map(Value::as_string).unwrap_or_default();
                         Ok(Self::new_uint8_typed_array_from_bytes(input.as_bytes()))
@@ -5090,6 +5217,7 @@ impl Harness {
                         captured_env_before_call.clone()
                     };
                     call_env.remove(INTERNAL_RETURN_SLOT);
+                    Self::shadow_module_imports(&function.local_bindings, &mut call_env);
                     let scope_depth = Self::env_scope_depth(&call_env);
                     call_env.insert(
                         INTERNAL_SCOPE_DEPTH_KEY.to_string(),
@@ -5329,6 +5457,7 @@ impl Harness {
                             || function.local_bindings.contains(name)
                             || name == "this"
                             || name == "arguments"
+                            || this.is_module_import_binding(name, &body_env)
                         {
                             continue;
                         }
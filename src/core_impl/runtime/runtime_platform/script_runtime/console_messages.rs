@@ -0,0 +1,299 @@
+use super::*;
+
+impl Harness {
+    pub(crate) fn eval_console_method(&mut self, method: &str, args: &[Value]) -> Result<Value> {
+        match method {
+            "log" | "dir" | "table" => self.push_console_message(ConsoleLevel::Log, "", args),
+            "debug" => self.push_console_message(ConsoleLevel::Debug, "", args),
+            "info" => self.push_console_message(ConsoleLevel::Info, "", args),
+            "warn" => self.push_console_message(ConsoleLevel::Warn, "", args),
+            "error" => self.push_console_message(ConsoleLevel::Error, "", args),
+            "trace" => self.push_console_message(ConsoleLevel::Debug, "Trace", args),
+            "assert" => {
+                if args.first().is_some_and(Value::truthy) {
+                    return Ok(Value::Undefined);
+                }
+                let rest = args.get(1..).unwrap_or_default();
+                self.push_console_message(ConsoleLevel::Error, "Assertion failed", rest);
+            }
+            "group" | "groupCollapsed" => {
+                if !args.is_empty() {
+                    self.push_console_message(ConsoleLevel::Log, "", args);
+                }
+                self.platform_mocks.console_group_depth += 1;
+            }
+            "groupEnd" => {
+                self.platform_mocks.console_group_depth =
+                    self.platform_mocks.console_group_depth.saturating_sub(1);
+            }
+            "count" => {
+                let label = Self::console_label(args);
+                let count = self
+                    .platform_mocks
+                    .console_counts
+                    .entry(label.clone())
+                    .or_insert(0);
+                *count += 1;
+                let line = Value::String(format!("{label}: {count}"));
+                self.push_console_message(ConsoleLevel::Info, "", &[line]);
+            }
+            "countReset" => {
+                let label = Self::console_label(args);
+                if self.platform_mocks.console_counts.remove(&label).is_none() {
+                    let line = Value::String(format!("Count for '{label}' does not exist"));
+                    self.push_console_message(ConsoleLevel::Warn, "", &[line]);
+                }
+            }
+            "time" => {
+                let label = Self::console_label(args);
+                if self.platform_mocks.console_timers.contains_key(&label) {
+                    let line = Value::String(format!("Timer '{label}' already exists"));
+                    self.push_console_message(ConsoleLevel::Warn, "", &[line]);
+                } else {
+                    self.platform_mocks
+                        .console_timers
+                        .insert(label, self.scheduler.now_ms);
+                }
+            }
+            "timeEnd" | "timeLog" => {
+                let label = Self::console_label(args);
+                let started = if method == "timeEnd" {
+                    self.platform_mocks.console_timers.remove(&label)
+                } else {
+                    self.platform_mocks.console_timers.get(&label).copied()
+                };
+                let Some(started) = started else {
+                    let line = Value::String(format!("Timer '{label}' does not exist"));
+                    self.push_console_message(ConsoleLevel::Warn, "", &[line]);
+                    return Ok(Value::Undefined);
+                };
+                let elapsed = self.scheduler.now_ms - started;
+                let mut line = vec![Value::String(format!("{label}: {elapsed}ms"))];
+                if method == "timeLog" {
+                    line.extend(args.iter().skip(1).cloned());
+                }
+                self.push_console_message(ConsoleLevel::Info, "", &line);
+            }
+            "clear" => {}
+            _ => {
+                return Err(Error::ScriptRuntime(format!(
+                    "console.{method} is not a function"
+                )));
+            }
+        }
+        Ok(Value::Undefined)
+    }
+
+    fn console_label(args: &[Value]) -> String {
+        match args.first() {
+            None | Some(Value::Undefined) => "default".to_string(),
+            Some(value) => value.as_string(),
+        }
+    }
+
+    fn push_console_message(&mut self, level: ConsoleLevel, prefix: &str, args: &[Value]) {
+        let body = self.render_console_args(args);
+        let mut args_rendered = "  ".repeat(self.platform_mocks.console_group_depth);
+        match (prefix.is_empty(), body.is_empty()) {
+            (true, _) => args_rendered.push_str(&body),
+            (false, true) => args_rendered.push_str(prefix),
+            (false, false) => {
+                args_rendered.push_str(prefix);
+                args_rendered.push_str(": ");
+                args_rendered.push_str(&body);
+            }
+        }
+
+        let level_label = match level {
+            ConsoleLevel::Debug => "debug",
+            ConsoleLevel::Log => "log",
+            ConsoleLevel::Info => "info",
+            ConsoleLevel::Warn => "warn",
+            ConsoleLevel::Error => "error",
+        };
+        if self.trace_state.console {
+            self.trace_line(format!("[console] {level_label} {args_rendered}"));
+        }
+        if level == ConsoleLevel::Error
+            && self.platform_mocks.fail_on_console_error
+            && self.platform_mocks.pending_console_error.is_none()
+        {
+            self.platform_mocks.pending_console_error = Some(args_rendered.clone());
+        }
+
+        let args = args
+            .iter()
+            .map(|value| self.js_value_from_value(value))
+            .collect();
+        self.platform_mocks.console_messages.push(ConsoleMessage {
+            level,
+            args_rendered,
+            args,
+            timestamp_ms: self.scheduler.now_ms,
+        });
+    }
+
+    // Logs an exception a callback threw instead of unwinding its caller, as a
+    // browser does for errors it reports.
+    pub(crate) fn report_exception(&mut self, err: Error) -> Result<()> {
+        let value = Self::error_to_catch_value(err)?;
+        let message = format!("Uncaught {}", self.render_console_value(&value));
+        self.push_console_message(ConsoleLevel::Error, "", &[Value::String(message)]);
+        Ok(())
+    }
+
+    pub(crate) fn take_pending_console_error(&mut self) -> Result<()> {
+        match self.platform_mocks.pending_console_error.take() {
+            Some(message) => Err(Error::ScriptRuntime(format!(
+                "console.error was called: {message}"
+            ))),
+            None => Ok(()),
+        }
+    }
+
+    fn render_console_args(&self, args: &[Value]) -> String {
+        let mut parts = Vec::new();
+        let mut rest = args;
+        if let Some(Value::String(format)) = args.first() {
+            if format.contains('%') {
+                let (formatted, consumed) = self.apply_console_format(format, &args[1..]);
+                parts.push(formatted);
+                rest = &args[1 + consumed..];
+            } else {
+                parts.push(format.clone());
+                rest = &args[1..];
+            }
+        }
+        parts.extend(rest.iter().map(|value| self.render_console_value(value)));
+        parts.join(" ")
+    }
+
+    fn apply_console_format(&self, format: &str, args: &[Value]) -> (String, usize) {
+        let mut out = String::new();
+        let mut consumed = 0usize;
+        let mut chars = format.chars().peekable();
+        while let Some(ch) = chars.next() {
+            if ch != '%' {
+                out.push(ch);
+                continue;
+            }
+            let Some(&spec) = chars.peek() else {
+                out.push(ch);
+                break;
+            };
+            if spec == '%' {
+                chars.next();
+                out.push('%');
+                continue;
+            }
+            if !matches!(spec, 's' | 'd' | 'i' | 'f' | 'o' | 'O' | 'c') {
+                out.push(ch);
+                continue;
+            }
+            chars.next();
+            let Some(arg) = args.get(consumed) else {
+                out.push('%');
+                out.push(spec);
+                continue;
+            };
+            consumed += 1;
+            match spec {
+                's' => match arg {
+                    Value::String(text) => out.push_str(text),
+                    other => out.push_str(&self.render_console_value(other)),
+                },
+                'd' | 'i' => {
+                    let number = Self::coerce_number_for_global(arg);
+                    out.push_str(&Self::format_number_default(number.trunc()));
+                }
+                'f' => {
+                    let number = Self::coerce_number_for_global(arg);
+                    out.push_str(&Self::format_number_default(number));
+                }
+                'o' | 'O' => out.push_str(&self.render_console_value(arg)),
+                _ => {}
+            }
+        }
+        (out, consumed)
+    }
+
+    fn render_console_value(&self, value: &Value) -> String {
+        match value {
+            Value::String(text) => text.clone(),
+            other => self.render_console_js_value(&self.js_value_from_value(other), false),
+        }
+    }
+
+    fn render_console_js_value(&self, value: &JsValue, nested: bool) -> String {
+        match value {
+            JsValue::Undefined => "undefined".to_string(),
+            JsValue::Null => "null".to_string(),
+            JsValue::Bool(value) => value.to_string(),
+            JsValue::Number(value) => Self::format_number_default(*value),
+            JsValue::BigInt(value) => format!("{value}n"),
+            JsValue::String(text) if nested => format!("'{text}'"),
+            JsValue::String(text) => text.clone(),
+            JsValue::Symbol(description) => {
+                format!("Symbol({})", description.as_deref().unwrap_or_default())
+            }
+            JsValue::Array(items) => format!(
+                "[{}]",
+                items
+                    .iter()
+                    .map(|item| self.render_console_js_value(item, true))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            JsValue::Object(entries) => format!(
+                "{{{}}}",
+                entries
+                    .iter()
+                    .map(|(key, value)| format!(
+                        "{key}: {}",
+                        self.render_console_js_value(value, true)
+                    ))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            JsValue::Date(timestamp_ms) if *timestamp_ms == INVALID_DATE_TIME_MS => {
+                "Invalid Date".to_string()
+            }
+            JsValue::Date(timestamp_ms) => Self::format_iso_8601_utc(*timestamp_ms),
+            JsValue::Map(entries) => format!(
+                "Map({}) {{{}}}",
+                entries.len(),
+                entries
+                    .iter()
+                    .map(|(key, value)| format!(
+                        "{} => {}",
+                        self.render_console_js_value(key, true),
+                        self.render_console_js_value(value, true)
+                    ))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            JsValue::Set(items) => format!(
+                "Set({}) {{{}}}",
+                items.len(),
+                items
+                    .iter()
+                    .map(|item| self.render_console_js_value(item, true))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            JsValue::Node(node) => format!("<{}>", self.trace_node_label(node.0)),
+            JsValue::NodeList(nodes) => format!(
+                "NodeList({}) [{}]",
+                nodes.len(),
+                nodes
+                    .iter()
+                    .map(|node| format!("<{}>", self.trace_node_label(node.0)))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            JsValue::Function => "[Function]".to_string(),
+            JsValue::Circular => "[Circular]".to_string(),
+            JsValue::Opaque(text) => text.clone(),
+        }
+    }
+}
@@ -8,8 +8,17 @@ impl Harness {
         env: &mut HashMap<String, Value>,
         capture: bool,
     ) -> Result<()> {
+        // The list is snapshotted: listeners added during dispatch wait for the next
+        // event, while removed ones are skipped via their shared `removed` flag.
         let listeners = self.listeners.get(node_id, &event.event_type, capture);
         for listener in listeners {
+            if listener.removed.get() {
+                continue;
+            }
+            if listener.once {
+                self.listeners
+                    .remove_instance(node_id, &event.event_type, &listener);
+            }
             let mut listener_env = env.clone();
             let captured_env_snapshot = listener.captured_env.borrow().to_map();
             let captured_keys = captured_env_snapshot
@@ -43,7 +52,13 @@ impl Harness {
             }
             let pending_scope_start =
                 self.push_pending_function_decl_scopes(&listener.captured_pending_function_decls);
+            let default_prevented_before = event.default_prevented;
+            event.in_passive_listener = listener.passive;
             let call_result = self.execute_handler(&listener.handler, event, &mut listener_env);
+            event.in_passive_listener = false;
+            if listener.passive {
+                event.default_prevented = default_prevented_before;
+            }
             self.restore_pending_function_decl_scopes(pending_scope_start);
             {
                 let mut captured_env = listener.captured_env.borrow_mut();
@@ -110,6 +125,14 @@ impl Harness {
         ));
     }
 
+    pub(crate) fn trace_passive_prevent_default(&mut self, event: &EventState) {
+        let target_label = self.trace_node_label(event.target);
+        self.trace_event_line(format!(
+            "[event] warning: preventDefault ignored in passive {} listener target={}",
+            event.event_type, target_label
+        ));
+    }
+
     pub(crate) fn trace_event_line(&mut self, line: String) {
         if self.trace_state.enabled && self.trace_state.events {
             self.trace_line(line);
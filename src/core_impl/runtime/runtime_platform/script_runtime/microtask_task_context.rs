@@ -2,6 +2,7 @@ use super::*;
 
 impl Harness {
     pub(crate) fn queue_microtask(&mut self, handler: ScriptHandler, env: &HashMap<String, Value>) {
+        self.queue_pending_mutation_records();
         self.scheduler
             .microtask_queue
             .push_back(ScheduledMicrotask::Script {
@@ -15,12 +16,14 @@ impl Harness {
         reaction: PromiseReactionKind,
         settled: PromiseSettledValue,
     ) {
+        self.queue_pending_mutation_records();
         self.scheduler
             .microtask_queue
             .push_back(ScheduledMicrotask::Promise { reaction, settled });
     }
 
     pub(crate) fn queue_callable_microtask(&mut self, callback: Value) {
+        self.queue_pending_mutation_records();
         self.scheduler
             .microtask_queue
             .push_back(ScheduledMicrotask::Callable { callback });
@@ -30,6 +33,7 @@ impl Harness {
         self.with_task_depth(|this| {
             let mut steps = 0usize;
             loop {
+                this.queue_pending_mutation_records();
                 let Some(task) = this.scheduler.microtask_queue.pop_front() else {
                     return Ok(steps);
                 };
@@ -52,11 +56,18 @@ impl Harness {
                     ScheduledMicrotask::Promise { reaction, settled } => {
                         this.run_promise_reaction_task(reaction, settled)?;
                     }
+                    ScheduledMicrotask::MutationObservers => {
+                        this.notify_mutation_observers()?;
+                    }
                 }
             }
         })
     }
 
+    pub(crate) fn has_pending_microtasks(&self) -> bool {
+        !self.scheduler.microtask_queue.is_empty() || !self.dom.pending_mutation_records.is_empty()
+    }
+
     fn with_task_depth<T>(&mut self, run: impl FnOnce(&mut Self) -> Result<T>) -> Result<T> {
         self.scheduler.task_depth += 1;
         let run_result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| run(self)));
@@ -77,6 +88,10 @@ impl Harness {
             Ok(value) => {
                 if should_flush_microtasks {
                     self.run_microtask_queue()?;
+                    self.take_pending_console_error()?;
+                    // DOM, style and viewport changes made by the task are
+                    // picked up here, where a browser would update the rendering.
+                    self.update_layout_observations();
                 }
                 Ok(value)
             }
@@ -146,39 +161,39 @@ impl Harness {
         } else {
             Value::Undefined
         };
-        let error_value = if event.error_event_interface {
+        let error_value = if event.has_interface(EventInterface::ErrorEvent) {
             event.error_event_error.clone()
         } else if event.event_type.eq_ignore_ascii_case("error") {
             event.detail.as_ref().cloned().unwrap_or(Value::Undefined)
         } else {
             Value::Undefined
         };
-        let error_message = if event.error_event_interface {
+        let error_message = if event.has_interface(EventInterface::ErrorEvent) {
             Value::String(event.error_event_message.clone())
         } else {
             Value::Undefined
         };
-        let error_filename = if event.error_event_interface {
+        let error_filename = if event.has_interface(EventInterface::ErrorEvent) {
             Value::String(event.error_event_filename.clone())
         } else {
             Value::Undefined
         };
-        let error_lineno = if event.error_event_interface {
+        let error_lineno = if event.has_interface(EventInterface::ErrorEvent) {
             Value::Number(event.error_event_lineno)
         } else {
             Value::Undefined
         };
-        let error_colno = if event.error_event_interface {
+        let error_colno = if event.has_interface(EventInterface::ErrorEvent) {
             Value::Number(event.error_event_colno)
         } else {
             Value::Undefined
         };
-        let hash_change_old_url = if event.hash_change_interface {
+        let hash_change_old_url = if event.has_interface(EventInterface::HashChangeEvent) {
             Value::String(event.hash_change_old_url.clone())
         } else {
             Value::Undefined
         };
-        let hash_change_new_url = if event.hash_change_interface {
+        let hash_change_new_url = if event.has_interface(EventInterface::HashChangeEvent) {
             Value::String(event.hash_change_new_url.clone())
         } else {
             Value::Undefined
@@ -269,7 +284,9 @@ impl Harness {
             ),
         ];
 
-        if event.before_unload_interface || event.event_type.eq_ignore_ascii_case("beforeunload") {
+        if event.has_interface(EventInterface::BeforeUnloadEvent)
+            || event.event_type.eq_ignore_ascii_case("beforeunload")
+        {
             entries.push((
                 INTERNAL_BEFORE_UNLOAD_EVENT_OBJECT_KEY.to_string(),
                 Value::Bool(true),
@@ -280,14 +297,14 @@ impl Harness {
             ));
         }
 
-        if event.error_event_interface {
+        if event.has_interface(EventInterface::ErrorEvent) {
             entries.push((
                 INTERNAL_ERROR_EVENT_OBJECT_KEY.to_string(),
                 Value::Bool(true),
             ));
         }
 
-        if event.hash_change_interface {
+        if event.has_interface(EventInterface::HashChangeEvent) {
             entries.push((
                 INTERNAL_HASH_CHANGE_EVENT_OBJECT_KEY.to_string(),
                 Value::Bool(true),
@@ -322,6 +339,18 @@ impl Harness {
             ));
         }
 
+        if let Some(input_type) = &event.input_type {
+            entries.push(("inputType".to_string(), Value::String(input_type.clone())));
+            entries.push((
+                "data".to_string(),
+                event
+                    .input_data
+                    .as_ref()
+                    .map(|value| Value::String(value.clone()))
+                    .unwrap_or(Value::Null),
+            ));
+        }
+
         if event.event_type.eq_ignore_ascii_case("wheel") {
             entries.push((
                 INTERNAL_WHEEL_EVENT_OBJECT_KEY.to_string(),
@@ -464,6 +493,11 @@ impl Harness {
             ));
         }
 
+        Self::push_event_interface_entries(
+            &mut entries,
+            event.interface(),
+            &event.interface_members,
+        );
         Self::new_object_value(entries)
     }
 
@@ -490,10 +524,10 @@ impl Harness {
         }
 
         if Self::is_before_unload_event_object(&entries)
-            || event.before_unload_interface
+            || event.has_interface(EventInterface::BeforeUnloadEvent)
             || event.event_type.eq_ignore_ascii_case("beforeunload")
         {
-            event.before_unload_interface = true;
+            event.interface = Some(EventInterface::BeforeUnloadEvent);
             event.before_unload_return_value = Self::object_get_entry(&entries, "returnValue")
                 .map(|value| value.as_string())
                 .unwrap_or_default();
@@ -685,6 +719,10 @@ impl Harness {
                 return;
             }
         }
+        // A module's bindings must not leak into whichever scope called into it.
+        if self.is_module_env(shared_env) {
+            return;
+        }
         if let Some(frame) = self.script_runtime.listener_capture_env_stack.last_mut() {
             frame.pending_env_updates.insert(name, value);
         }
@@ -195,8 +195,24 @@ impl Harness {
         handler: &ScriptHandler,
         env: &HashMap<String, Value>,
     ) {
+        // A bare identifier callback is bound to the function it names right now, so
+        // add/remove match on function identity like the member-call path does.
+        let function = handler
+            .listener_callback_reference()
+            .and_then(|name| match env.get(name) {
+                Some(Value::Function(function)) => Some(function.clone()),
+                _ => None,
+            });
         match op {
             ListenerRegistrationOp::Add => {
+                if let Some(function) = function {
+                    self.listeners.add(
+                        node,
+                        event_type.to_string(),
+                        Self::new_function_listener(&function, capture, None, None),
+                    );
+                    return;
+                }
                 let captured_env = self.ensure_listener_capture_env();
                 *captured_env.borrow_mut() = ScriptEnv::from_snapshot(env);
                 self.listeners.add(
@@ -211,11 +227,27 @@ impl Harness {
                             .script_runtime
                             .pending_function_decls
                             .clone(),
+                        signal: None,
+                        once: false,
+                        passive: false,
+                        callback_id: None,
+                        removed: Rc::default(),
                     },
                 );
             }
             ListenerRegistrationOp::Remove => {
-                let _ = self.listeners.remove(node, event_type, capture, handler);
+                let _ = match function {
+                    Some(function) => self.listeners.remove(
+                        node,
+                        event_type,
+                        capture,
+                        &function.handler,
+                        Some(function.function_id),
+                    ),
+                    None => self
+                        .listeners
+                        .remove(node, event_type, capture, handler, None),
+                };
             }
         }
     }
@@ -410,7 +442,7 @@ impl Harness {
         }
     }
 
-    fn collect_direct_tdz_binding_names(stmts: &[Stmt]) -> HashSet<String> {
+    pub(crate) fn collect_direct_tdz_binding_names(stmts: &[Stmt]) -> HashSet<String> {
         let mut out = HashSet::new();
         for stmt in stmts {
             out.extend(Self::direct_tdz_binding_names(stmt));
@@ -701,7 +733,7 @@ impl Harness {
     fn collect_direct_block_lexical_bindings(
         &self,
         stmts: &[Stmt],
-        env: &HashMap<String, Value>,
+        env: &mut HashMap<String, Value>,
     ) -> Vec<(String, Option<Value>, bool)> {
         let mut seen = HashSet::new();
         let mut previous = Vec::new();
@@ -800,6 +832,13 @@ impl Harness {
                 }
             }
         }
+        let names = previous
+            .iter()
+            .map(|(name, _, _)| name.clone())
+            .collect::<Vec<_>>();
+        for (key, marker) in Self::shadow_module_imports(&names, env) {
+            previous.push((key, Some(marker), false));
+        }
         previous
     }
 
@@ -942,6 +981,23 @@ impl Harness {
         Ok((value.as_string(), None, false, false, None))
     }
 
+    fn apply_event_interface_payload_fields(
+        event: &mut EventState,
+        event_payload_object: Option<&Rc<RefCell<ObjectValue>>>,
+    ) {
+        let Some(event_payload_object) = event_payload_object else {
+            return;
+        };
+        let Some(interface) =
+            Self::event_interface_from_value(&Value::Object(event_payload_object.clone()))
+        else {
+            return;
+        };
+        event.interface = Some(interface);
+        event.interface_members =
+            Self::event_interface_members_from_init(interface, &event_payload_object.borrow());
+    }
+
     fn apply_keyboard_event_payload_fields(
         event: &mut EventState,
         event_payload_object: Option<&Rc<RefCell<ObjectValue>>>,
@@ -1108,7 +1164,7 @@ impl Harness {
             return;
         }
 
-        event.before_unload_interface = true;
+        event.interface = Some(EventInterface::BeforeUnloadEvent);
         event.before_unload_return_value = Self::object_get_entry(&entries, "returnValue")
             .map(|value| value.as_string())
             .unwrap_or_default();
@@ -1129,7 +1185,7 @@ impl Harness {
             return;
         }
 
-        event.hash_change_interface = true;
+        event.interface = Some(EventInterface::HashChangeEvent);
         event.hash_change_old_url = Self::object_get_entry(&entries, "oldURL")
             .map(|value| value.as_string())
             .unwrap_or_default();
@@ -1150,7 +1206,7 @@ impl Harness {
             return;
         }
 
-        event.error_event_interface = true;
+        event.interface = Some(EventInterface::ErrorEvent);
         event.error_event_message = Self::object_get_entry(&entries, "message")
             .map(|value| value.as_string())
             .unwrap_or_default();
@@ -1210,6 +1266,7 @@ impl Harness {
         event.detail = detail;
         event.bubbles = bubbles;
         event.cancelable = cancelable;
+        Self::apply_event_interface_payload_fields(&mut event, event_payload_object.as_ref());
         Self::apply_keyboard_event_payload_fields(&mut event, event_payload_object.as_ref());
         Self::apply_wheel_event_payload_fields(&mut event, event_payload_object.as_ref());
         Self::apply_pointer_event_payload_fields(&mut event, event_payload_object.as_ref());
@@ -1259,6 +1316,7 @@ impl Harness {
         event.detail = detail;
         event.bubbles = bubbles;
         event.cancelable = cancelable;
+        Self::apply_event_interface_payload_fields(&mut event, event_payload_object.as_ref());
         Self::apply_keyboard_event_payload_fields(&mut event, event_payload_object.as_ref());
         Self::apply_wheel_event_payload_fields(&mut event, event_payload_object.as_ref());
         Self::apply_pointer_event_payload_fields(&mut event, event_payload_object.as_ref());
@@ -1769,7 +1827,7 @@ impl Harness {
                 Some(Ok(value)) => return Ok(value),
                 Some(Err(reason)) => return Err(Error::ScriptThrown(ThrownValue::new(reason))),
                 None => {
-                    if !self.scheduler.microtask_queue.is_empty() {
+                    if self.has_pending_microtasks() {
                         self.run_microtask_queue()?;
                         continue;
                     }
@@ -2076,34 +2134,45 @@ impl Harness {
             };
 
             let referrer = self.current_module_referrer();
-            let exports =
-                self.load_module_exports(specifier, attribute_type.as_deref(), &referrer)?;
+            let module = self.load_module(specifier, attribute_type.as_deref(), &referrer)?;
 
+            let mut bindings = Vec::new();
             if let Some(local) = default_binding {
-                let value = exports.get("default").cloned().unwrap_or(Value::Undefined);
-                env.insert(local.clone(), value);
-                self.set_const_binding(env, local, true);
+                bindings.push((local, Some("default")));
             }
-
             if let Some(local) = namespace_binding {
-                let mut entries = exports
-                    .iter()
-                    .map(|(name, value)| (name.clone(), value.clone()))
-                    .collect::<Vec<_>>();
-                entries.sort_by(|(a, _), (b, _)| a.cmp(b));
-                env.insert(local.clone(), Self::new_object_value(entries));
-                self.set_const_binding(env, local, true);
+                bindings.push((local, None));
             }
-
             for binding in named_bindings {
-                let value = exports.get(&binding.imported).cloned().ok_or_else(|| {
-                    Error::ScriptRuntime(format!(
-                        "module '{}' does not provide an export named '{}'",
-                        specifier, binding.imported
-                    ))
-                })?;
-                env.insert(binding.local.clone(), value);
-                self.set_const_binding(env, &binding.local, true);
+                bindings.push((&binding.local, Some(binding.imported.as_str())));
+            }
+            for (local, import_name) in bindings {
+                let Some(import_name) = import_name else {
+                    let namespace = self.module_namespace(&module);
+                    env.insert(local.clone(), namespace);
+                    self.set_const_binding(env, local, true);
+                    continue;
+                };
+                // An export that is not initialized yet sits on an import cycle;
+                // reads go through to the exporter once it is.
+                let value = match self.module_export_value(&module, import_name) {
+                    Ok(Some(value)) => value,
+                    Ok(None) => {
+                        return Err(Error::ScriptRuntime(format!(
+                            "module '{specifier}' does not provide an export named '{import_name}'"
+                        )));
+                    }
+                    Err(_) => Value::Undefined,
+                };
+                self.bind_module_import(
+                    env,
+                    local,
+                    value,
+                    ModuleImportBinding {
+                        module: module.clone(),
+                        import_name: import_name.to_string(),
+                    },
+                );
             }
         }
         Ok(())
@@ -2141,6 +2210,7 @@ impl Harness {
         self.script_runtime
             .listener_capture_env_stack
             .push(ListenerCaptureFrame {
+                shared_env: self.script_runtime.pending_module_env.take(),
                 inherit_outer_pending,
                 ..ListenerCaptureFrame::default()
             });
@@ -2154,11 +2224,24 @@ impl Harness {
             let mut initialized_var_bindings = HashSet::new();
             let flow_result = (|| -> Result<ExecFlow> {
                 for stmt in stmts {
+                    // Custom element reactions queued by the previous statement run before the next one.
+                    self.run_custom_element_reactions()?;
                     self.apply_pending_listener_capture_env_updates(env);
                     self.sync_top_level_env_from_runtime(env);
                     self.sync_listener_capture_env_if_shared(env);
+                    // Exported declarations run in this scope so their closures share it.
+                    let stmt = match stmt {
+                        Stmt::ExportDecl {
+                            declaration,
+                            bindings,
+                        } => {
+                            self.register_module_named_exports(bindings);
+                            declaration.as_ref()
+                        }
+                        stmt => stmt,
+                    };
                     match stmt {
-                        Stmt::ImportDecl { .. } => {}
+                        Stmt::ImportDecl { .. } | Stmt::ExportDecl { .. } => {}
                         Stmt::VarDecl { name, expr, kind } => {
                             if matches!(kind, VarDeclKind::Var) && matches!(expr, Expr::Undefined) {
                                 if !env.contains_key(name) {
@@ -2730,24 +2813,6 @@ impl Harness {
                             self.set_const_binding(env, name, false);
                             self.mark_tdz_initialized(&mut pending_tdz_bindings, name);
                         }
-                        Stmt::ExportDecl {
-                            declaration,
-                            bindings,
-                        } => {
-                            match self.execute_stmts(
-                                std::slice::from_ref(declaration.as_ref()),
-                                event_param,
-                                event,
-                                env,
-                            )? {
-                                ExecFlow::Continue => {}
-                                flow => return Ok(flow),
-                            }
-                            for local in Self::direct_tdz_binding_names(declaration) {
-                                self.mark_tdz_initialized(&mut pending_tdz_bindings, &local);
-                            }
-                            self.register_module_named_exports(bindings);
-                        }
                         Stmt::ExportNamed { bindings } => {
                             self.register_module_named_exports(bindings);
                         }
@@ -3246,13 +3311,15 @@ impl Harness {
                                         };
                                         if key == "returnValue"
                                             && is_event_param_target
-                                            && (event.before_unload_interface
+                                            && (event
+                                                .has_interface(EventInterface::BeforeUnloadEvent)
                                                 || event
                                                     .event_type
                                                     .eq_ignore_ascii_case("beforeunload"))
                                         {
                                             let return_value = assignment_value.as_string();
-                                            event.before_unload_interface = true;
+                                            event.interface =
+                                                Some(EventInterface::BeforeUnloadEvent);
                                             event.before_unload_return_value = return_value.clone();
                                             if event.cancelable && !return_value.is_empty() {
                                                 event.default_prevented = true;
@@ -3313,7 +3380,8 @@ impl Harness {
                                 )?,
                                 DomProp::ValueAsDate => {
                                     let timestamp_ms = match value {
-                                        Value::Date(timestamp) => Some(*timestamp.borrow()),
+                                        Value::Date(timestamp) => Some(*timestamp.borrow())
+                                            .filter(|ms| *ms != INVALID_DATE_TIME_MS),
                                         Value::Null | Value::Undefined => None,
                                         _ => None,
                                     };
@@ -5331,7 +5399,9 @@ impl Harness {
                                 if param == event_var {
                                     match method {
                                         EventMethod::PreventDefault => {
-                                            if event.cancelable {
+                                            if event.in_passive_listener {
+                                                self.trace_passive_prevent_default(event);
+                                            } else if event.cancelable {
                                                 event.default_prevented = true;
                                             }
                                         }
@@ -5393,6 +5463,7 @@ impl Harness {
                     }
                 }
 
+                self.run_custom_element_reactions()?;
                 Ok(ExecFlow::Continue)
             })();
 
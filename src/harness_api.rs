@@ -1,4 +1,5 @@
 use super::*;
+use std::fmt;
 
 #[derive(Debug)]
 pub struct Harness {
@@ -17,10 +18,18 @@ pub struct Harness {
     pub(crate) trace_state: TraceState,
 }
 
+#[derive(Debug)]
+pub struct HarnessSnapshot {
+    pub(crate) harness: Box<Harness>,
+}
+
 #[derive(Debug)]
 pub struct MockWindow {
     pub(crate) pages: Vec<MockPage>,
     pub(crate) current: usize,
+    pub(crate) next_page_id: usize,
+    pub(crate) context_aliases: Vec<(String, String)>,
+    pub(crate) shared_storage: Vec<SharedOriginStorage>,
 }
 
 #[derive(Debug)]
@@ -41,6 +50,158 @@ pub struct KeyboardEventInit {
     pub is_composing: bool,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MouseButton {
+    #[default]
+    Left,
+    Middle,
+    Right,
+}
+
+impl MouseButton {
+    // `MouseEvent.button`.
+    pub(crate) fn button(self) -> i64 {
+        match self {
+            Self::Left => 0,
+            Self::Middle => 1,
+            Self::Right => 2,
+        }
+    }
+
+    // Bit in `MouseEvent.buttons`.
+    pub(crate) fn buttons_mask(self) -> i64 {
+        match self {
+            Self::Left => 1,
+            Self::Middle => 4,
+            Self::Right => 2,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Locator {
+    pub(crate) query: LocatorQuery,
+    pub(crate) exact: bool,
+    pub(crate) nth: Option<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum LocatorQuery {
+    Css(String),
+    Role { role: String, name: Option<String> },
+    Label(String),
+    Text(String),
+    Placeholder(String),
+    TestId(String),
+}
+
+impl Locator {
+    fn from_query(query: LocatorQuery) -> Self {
+        Self {
+            query,
+            exact: false,
+            nth: None,
+        }
+    }
+
+    pub fn css(selector: &str) -> Self {
+        Self::from_query(LocatorQuery::Css(selector.to_string()))
+    }
+
+    pub fn role(role: &str) -> Self {
+        Self::from_query(LocatorQuery::Role {
+            role: role.trim().to_ascii_lowercase(),
+            name: None,
+        })
+    }
+
+    pub fn label(text: &str) -> Self {
+        Self::from_query(LocatorQuery::Label(text.to_string()))
+    }
+
+    pub fn text(text: &str) -> Self {
+        Self::from_query(LocatorQuery::Text(text.to_string()))
+    }
+
+    pub fn placeholder(text: &str) -> Self {
+        Self::from_query(LocatorQuery::Placeholder(text.to_string()))
+    }
+
+    pub fn test_id(test_id: &str) -> Self {
+        Self::from_query(LocatorQuery::TestId(test_id.to_string()))
+    }
+
+    pub fn with_name(mut self, name: &str) -> Self {
+        if let LocatorQuery::Role { name: slot, .. } = &mut self.query {
+            *slot = Some(name.to_string());
+        }
+        self
+    }
+
+    pub fn exact(mut self, exact: bool) -> Self {
+        self.exact = exact;
+        self
+    }
+
+    pub fn nth(mut self, index: usize) -> Self {
+        self.nth = Some(index);
+        self
+    }
+
+    pub(crate) fn css_selector(&self) -> Option<&str> {
+        match &self.query {
+            LocatorQuery::Css(selector) if self.nth.is_none() => Some(selector),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Locator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let quote = |value: &str| format!("{value:?}") + if self.exact { "s" } else { "i" };
+        match &self.query {
+            LocatorQuery::Css(selector) => write!(f, "{selector}")?,
+            LocatorQuery::Role { role, name: None } => write!(f, "role={role}")?,
+            LocatorQuery::Role {
+                role,
+                name: Some(name),
+            } => write!(f, "role={role}[name={}]", quote(name))?,
+            LocatorQuery::Label(text) => write!(f, "label={}", quote(text))?,
+            LocatorQuery::Text(text) => write!(f, "text={}", quote(text))?,
+            LocatorQuery::Placeholder(text) => write!(f, "placeholder={}", quote(text))?,
+            LocatorQuery::TestId(test_id) => write!(f, "test-id={test_id:?}")?,
+        }
+        if let Some(index) = self.nth {
+            write!(f, " >> nth={index}")?;
+        }
+        Ok(())
+    }
+}
+
+impl From<&str> for Locator {
+    fn from(selector: &str) -> Self {
+        Self::css(selector)
+    }
+}
+
+impl From<&String> for Locator {
+    fn from(selector: &String) -> Self {
+        Self::css(selector)
+    }
+}
+
+impl From<String> for Locator {
+    fn from(selector: String) -> Self {
+        Self::from_query(LocatorQuery::Css(selector))
+    }
+}
+
+impl From<&Locator> for Locator {
+    fn from(locator: &Locator) -> Self {
+        locator.clone()
+    }
+}
+
 impl MockWindow {
     pub(crate) fn with_current_harness_mut<R>(
         &mut self,
@@ -50,31 +211,55 @@ impl MockWindow {
             .pages
             .get_mut(self.current)
             .ok_or_else(|| Error::ScriptRuntime("window has no pages".into()))?;
-        f(&mut page.harness)
+        let result = f(&mut page.harness);
+        self.process_browsing_context_requests()?;
+        result
     }
 
     pub fn new() -> Self {
         Self {
             pages: Vec::new(),
             current: 0,
+            next_page_id: 1,
+            context_aliases: Vec::new(),
+            shared_storage: Vec::new(),
         }
     }
 
     pub fn open_page(&mut self, url: &str, html: &str) -> Result<usize> {
-        let harness = Harness::from_html_with_url(url, html)?;
-        if let Some(index) = self
+        let existing = self
             .pages
             .iter()
-            .position(|page| page.harness.document_url == url)
-        {
+            .position(|page| page.harness.document_url == url);
+        let id = match existing {
+            Some(index) => self.pages[index]
+                .harness
+                .browser_apis
+                .browsing_context
+                .id
+                .clone(),
+            None => {
+                let id = format!("page-{}", self.next_page_id);
+                self.next_page_id += 1;
+                id
+            }
+        };
+        let context = BrowsingContextState {
+            id,
+            ..BrowsingContextState::default()
+        };
+        let shared_storage = self.shared_storage_for_url(url);
+        let harness = Harness::from_html_in_browsing_context(url, html, context, &shared_storage)?;
+        if let Some(index) = existing {
             self.pages[index] = MockPage { harness };
             self.current = index;
-            Ok(index)
         } else {
             self.pages.push(MockPage { harness });
             self.current = self.pages.len() - 1;
-            Ok(self.current)
         }
+        let index = self.current;
+        self.process_browsing_context_requests()?;
+        Ok(index)
     }
 
     pub fn page_count(&self) -> usize {
@@ -129,75 +314,123 @@ impl MockWindow {
         self.with_current_harness_mut(f)
     }
 
-    pub fn type_text(&mut self, selector: &str, text: &str) -> Result<()> {
+    pub fn type_text(&mut self, selector: impl Into<Locator>, text: &str) -> Result<()> {
         self.with_current_harness_mut(|page| page.type_text(selector, text))
     }
 
-    pub fn set_select_value(&mut self, selector: &str, value: &str) -> Result<()> {
+    pub fn type_keys(&mut self, selector: impl Into<Locator>, keys: &str) -> Result<()> {
+        self.with_current_harness_mut(|page| page.type_keys(selector, keys))
+    }
+
+    pub fn set_select_value(&mut self, selector: impl Into<Locator>, value: &str) -> Result<()> {
         self.with_current_harness_mut(|page| page.set_select_value(selector, value))
     }
 
-    pub fn set_input_files(&mut self, selector: &str, files: &[MockFile]) -> Result<()> {
+    pub fn set_input_files(
+        &mut self,
+        selector: impl Into<Locator>,
+        files: &[MockFile],
+    ) -> Result<()> {
         self.with_current_harness_mut(|page| page.set_input_files(selector, files))
     }
 
-    pub fn set_checked(&mut self, selector: &str, checked: bool) -> Result<()> {
+    pub fn set_checked(&mut self, selector: impl Into<Locator>, checked: bool) -> Result<()> {
         self.with_current_harness_mut(|page| page.set_checked(selector, checked))
     }
 
-    pub fn click(&mut self, selector: &str) -> Result<()> {
+    pub fn click(&mut self, selector: impl Into<Locator>) -> Result<()> {
         self.with_current_harness_mut(|page| page.click(selector))
     }
 
-    pub fn press_enter(&mut self, selector: &str) -> Result<()> {
+    pub fn double_click(&mut self, selector: impl Into<Locator>) -> Result<()> {
+        self.with_current_harness_mut(|page| page.double_click(selector))
+    }
+
+    pub fn right_click(&mut self, selector: impl Into<Locator>) -> Result<()> {
+        self.with_current_harness_mut(|page| page.right_click(selector))
+    }
+
+    pub fn hover(&mut self, selector: impl Into<Locator>) -> Result<()> {
+        self.with_current_harness_mut(|page| page.hover(selector))
+    }
+
+    pub fn unhover(&mut self) -> Result<()> {
+        self.with_current_harness_mut(|page| page.unhover())
+    }
+
+    pub fn mouse_move(&mut self, x: f64, y: f64) -> Result<()> {
+        self.with_current_harness_mut(|page| page.mouse_move(x, y))
+    }
+
+    pub fn mouse_down(&mut self, button: MouseButton) -> Result<()> {
+        self.with_current_harness_mut(|page| page.mouse_down(button))
+    }
+
+    pub fn mouse_up(&mut self, button: MouseButton) -> Result<()> {
+        self.with_current_harness_mut(|page| page.mouse_up(button))
+    }
+
+    pub fn press_enter(&mut self, selector: impl Into<Locator>) -> Result<()> {
         self.with_current_harness_mut(|page| page.press_enter(selector))
     }
 
-    pub fn copy(&mut self, selector: &str) -> Result<()> {
+    pub fn copy(&mut self, selector: impl Into<Locator>) -> Result<()> {
         self.with_current_harness_mut(|page| page.copy(selector))
     }
 
-    pub fn paste(&mut self, selector: &str) -> Result<()> {
+    pub fn paste(&mut self, selector: impl Into<Locator>) -> Result<()> {
         self.with_current_harness_mut(|page| page.paste(selector))
     }
 
-    pub fn submit(&mut self, selector: &str) -> Result<()> {
+    pub fn submit(&mut self, selector: impl Into<Locator>) -> Result<()> {
         self.with_current_harness_mut(|page| page.submit(selector))
     }
 
-    pub fn dispatch(&mut self, selector: &str, event: &str) -> Result<()> {
+    pub fn dispatch(&mut self, selector: impl Into<Locator>, event: &str) -> Result<()> {
         self.with_current_harness_mut(|page| page.dispatch(selector, event))
     }
 
     pub fn dispatch_keyboard(
         &mut self,
-        selector: &str,
+        selector: impl Into<Locator>,
         event: &str,
         init: KeyboardEventInit,
     ) -> Result<()> {
         self.with_current_harness_mut(move |page| page.dispatch_keyboard(selector, event, init))
     }
 
-    pub fn assert_text(&mut self, selector: &str, expected: &str) -> Result<()> {
+    pub fn assert_text(&mut self, selector: impl Into<Locator>, expected: &str) -> Result<()> {
         self.with_current_harness_mut(|page| page.assert_text(selector, expected))
     }
 
-    pub fn assert_value(&mut self, selector: &str, expected: &str) -> Result<()> {
+    pub fn assert_value(&mut self, selector: impl Into<Locator>, expected: &str) -> Result<()> {
         self.with_current_harness_mut(|page| page.assert_value(selector, expected))
     }
 
-    pub fn assert_checked(&mut self, selector: &str, expected: bool) -> Result<()> {
+    pub fn assert_checked(&mut self, selector: impl Into<Locator>, expected: bool) -> Result<()> {
         self.with_current_harness_mut(|page| page.assert_checked(selector, expected))
     }
 
-    pub fn assert_exists(&mut self, selector: &str) -> Result<()> {
+    pub fn assert_exists(&mut self, selector: impl Into<Locator>) -> Result<()> {
         self.with_current_harness_mut(|page| page.assert_exists(selector))
     }
 
+    pub fn eval(&mut self, src: &str) -> Result<JsValue> {
+        self.with_current_harness_mut(|page| page.eval(src))
+    }
+
+    pub fn eval_async(&mut self, src: &str) -> Result<JsValue> {
+        self.with_current_harness_mut(|page| page.eval_async(src))
+    }
+
     pub fn take_trace_logs(&mut self) -> Result<Vec<String>> {
         self.with_current_harness_mut(|page| Ok(page.take_trace_logs()))
     }
 
+    pub fn take_console_messages(&mut self) -> Result<Vec<ConsoleMessage>> {
+        self.with_current_harness_mut(|page| Ok(page.take_console_messages()))
+    }
+
     pub fn take_downloads(&mut self) -> Result<Vec<DownloadArtifact>> {
         self.with_current_harness_mut(|page| Ok(page.take_downloads()))
     }
@@ -205,6 +438,10 @@ impl MockWindow {
     pub fn take_clipboard_writes(&mut self) -> Result<Vec<ClipboardWriteArtifact>> {
         self.with_current_harness_mut(|page| Ok(page.take_clipboard_writes()))
     }
+
+    pub fn take_form_submissions(&mut self) -> Result<Vec<FormSubmission>> {
+        self.with_current_harness_mut(|page| Ok(page.take_form_submissions()))
+    }
 }
 
 impl MockPage {
@@ -15,20 +15,29 @@ use std::sync::Arc;
 
 mod core_dom_utils;
 mod harness_api;
+mod resource_loader;
 mod runtime_state;
 mod runtime_values;
 mod script_ast;
 mod selector;
 
-pub use core_dom_utils::MockFile;
+pub use core_dom_utils::{DomRect, MockFile};
 pub use core_dom_utils::{Error, Result, ThrownValue};
-pub use harness_api::{Harness, KeyboardEventInit, MockPage, MockWindow};
+pub use harness_api::{
+    Harness, HarnessSnapshot, KeyboardEventInit, Locator, MockPage, MockWindow, MouseButton,
+};
+pub use resource_loader::{DirectoryResourceLoader, MemoryResourceLoader, ResourceLoader};
 pub use runtime_state::{
-    ClipboardPayloadArtifact, ClipboardWriteArtifact, DownloadArtifact, LocationNavigation,
-    LocationNavigationKind, PendingTimer,
+    ClipboardPayloadArtifact, ClipboardWriteArtifact, ConsoleLevel, ConsoleMessage,
+    DownloadArtifact, FetchDestination, FetchMatcher, FetchMockResponse, FetchOutcome,
+    FetchRequest, FormSubmission, LocationNavigation, LocationNavigationKind, PendingFetch,
+    PendingTimer, RecordedRequest,
 };
+pub use runtime_values::{JsValue, NodeHandle};
 
 pub(crate) use core_dom_utils::*;
+pub(crate) use harness_api::LocatorQuery;
+pub(crate) use resource_loader::ResourceLoaderHandle;
 pub(crate) use runtime_state::*;
 pub(crate) use runtime_values::*;
 pub(crate) use script_ast::*;
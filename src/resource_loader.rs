@@ -0,0 +1,157 @@
+use super::*;
+use std::fmt;
+use std::path::{Component, Path, PathBuf};
+
+pub trait ResourceLoader {
+    fn load(&mut self, request: &FetchRequest) -> FetchOutcome;
+}
+
+impl<F> ResourceLoader for F
+where
+    F: FnMut(&FetchRequest) -> FetchOutcome,
+{
+    fn load(&mut self, request: &FetchRequest) -> FetchOutcome {
+        self(request)
+    }
+}
+
+// Shared so a forked harness keeps loading through the same loader.
+#[derive(Clone)]
+pub(crate) struct ResourceLoaderHandle(pub(crate) Rc<RefCell<dyn ResourceLoader>>);
+
+impl fmt::Debug for ResourceLoaderHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ResourceLoaderHandle(..)")
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct MemoryResourceLoader {
+    resources: HashMap<String, FetchMockResponse>,
+}
+
+impl MemoryResourceLoader {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_resource(self, url: &str, body: &str) -> Self {
+        let response = FetchMockResponse::new(200)
+            .with_header("content-type", content_type_for_path(url))
+            .with_bytes(body.as_bytes());
+        self.with_response(url, response)
+    }
+
+    pub fn with_response(mut self, url: &str, response: FetchMockResponse) -> Self {
+        self.resources.insert(url.to_string(), response);
+        self
+    }
+}
+
+impl ResourceLoader for MemoryResourceLoader {
+    fn load(&mut self, request: &FetchRequest) -> FetchOutcome {
+        let url = request.url.split('#').next().unwrap_or_default();
+        let path = LocationParts::parse(url)
+            .filter(|parts| parts.has_authority)
+            .map(|parts| (parts.pathname.clone(), parts.search.clone()));
+        let mut candidates = vec![url.to_string()];
+        if let Some((pathname, search)) = path {
+            candidates.push(format!("{pathname}{search}"));
+            candidates.push(pathname);
+        }
+        candidates
+            .iter()
+            .find_map(|candidate| self.resources.get(candidate))
+            .map(|response| FetchOutcome::Response(response.clone()))
+            .unwrap_or(FetchOutcome::Fallthrough)
+    }
+}
+
+// Serves files below `root` for URLs of a single origin, like a static file server.
+#[derive(Debug, Clone)]
+pub struct DirectoryResourceLoader {
+    root: PathBuf,
+    origin: String,
+}
+
+impl DirectoryResourceLoader {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self {
+            root: root.into(),
+            origin: "http://localhost".to_string(),
+        }
+    }
+
+    pub fn with_origin(mut self, origin: &str) -> Self {
+        self.origin = origin.trim_end_matches('/').to_string();
+        self
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    pub fn url_for(&self, path: &str) -> String {
+        format!("{}/{}", self.origin, path.trim_start_matches('/'))
+    }
+
+    fn file_path(&self, url: &str) -> Option<PathBuf> {
+        let parts = LocationParts::parse(url)?;
+        if parts.origin() != self.origin {
+            return None;
+        }
+        let mut pathname = decode_uri_like(&parts.pathname, true).ok()?;
+        if pathname.ends_with('/') {
+            pathname.push_str("index.html");
+        }
+        let relative = Path::new(pathname.trim_start_matches('/'));
+        if relative
+            .components()
+            .any(|component| !matches!(component, Component::Normal(_)))
+        {
+            return None;
+        }
+        Some(self.root.join(relative))
+    }
+}
+
+impl ResourceLoader for DirectoryResourceLoader {
+    fn load(&mut self, request: &FetchRequest) -> FetchOutcome {
+        let Some(path) = self.file_path(&request.url) else {
+            return FetchOutcome::Fallthrough;
+        };
+        match std::fs::read(&path) {
+            Ok(bytes) => FetchOutcome::Response(
+                FetchMockResponse::new(200)
+                    .with_header("content-type", content_type_for_path(&request.url))
+                    .with_bytes(&bytes),
+            ),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                FetchOutcome::Response(FetchMockResponse::new(404))
+            }
+            Err(_) => FetchOutcome::NetworkError,
+        }
+    }
+}
+
+fn content_type_for_path(path: &str) -> &'static str {
+    let path = path.split(['?', '#']).next().unwrap_or_default();
+    let extension = path
+        .rsplit_once('.')
+        .map(|(_, extension)| extension.to_ascii_lowercase())
+        .unwrap_or_default();
+    match extension.as_str() {
+        "js" | "mjs" | "cjs" => "text/javascript",
+        "css" => "text/css",
+        "html" | "htm" => "text/html",
+        "json" | "map" => "application/json",
+        "svg" => "image/svg+xml",
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        "webp" => "image/webp",
+        "wasm" => "application/wasm",
+        "txt" => "text/plain",
+        _ => "application/octet-stream",
+    }
+}
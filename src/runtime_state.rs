@@ -1,5 +1,7 @@
 use super::*;
 use idna::domain_to_ascii;
+use std::cell::Cell;
+use std::fmt;
 
 #[derive(Debug, Clone, PartialEq)]
 pub(crate) struct ScriptHandler {
@@ -727,6 +729,36 @@ pub(crate) struct Listener {
     pub(crate) captured_env: Rc<RefCell<ScriptEnv>>,
     pub(crate) captured_pending_function_decls:
         Vec<Arc<HashMap<String, (ScriptHandler, bool, bool)>>>,
+    pub(crate) signal: Option<Rc<RefCell<ObjectValue>>>,
+    pub(crate) once: bool,
+    pub(crate) passive: bool,
+    // Identity of the JS function object, when the callback was a function value.
+    pub(crate) callback_id: Option<usize>,
+    // Shared between the stored listener and dispatch snapshots, so a listener
+    // removed mid-dispatch is skipped by the in-flight loop.
+    pub(crate) removed: Rc<Cell<bool>>,
+}
+
+impl Listener {
+    fn is_same_callback(&self, other: &Listener) -> bool {
+        match (self.callback_id, other.callback_id) {
+            (Some(left), Some(right)) => left == right,
+            (None, None) => self
+                .handler
+                .listener_callback_reference()
+                .zip(other.handler.listener_callback_reference())
+                .is_some_and(|(left, right)| left == right),
+            _ => false,
+        }
+    }
+
+    fn matches_removal(&self, handler: &ScriptHandler, callback_id: Option<usize>) -> bool {
+        match (self.callback_id, callback_id) {
+            (Some(left), Some(right)) => left == right,
+            (None, None) => self.handler == *handler,
+            _ => false,
+        }
+    }
 }
 
 #[derive(Debug, Default, Clone)]
@@ -744,22 +776,17 @@ impl ListenerStore {
             .or_default();
 
         // Match browser semantics: dedupe only when addEventListener() re-registers
-        // the same callback reference for the same type/capture pair.
+        // the same callback for the same type/capture pair.
         // Event handler properties (onclick, oninput, ...) are a separate slot and
         // must not be deduped against addEventListener listeners.
-        if !listener.is_event_handler_property {
-            if let Some(new_callback_ref) = listener.handler.listener_callback_reference() {
-                if listeners.iter().any(|existing| {
-                    !existing.is_event_handler_property
-                        && existing.capture == listener.capture
-                        && existing
-                            .handler
-                            .listener_callback_reference()
-                            .is_some_and(|existing_ref| existing_ref == new_callback_ref)
-                }) {
-                    return;
-                }
-            }
+        if !listener.is_event_handler_property
+            && listeners.iter().any(|existing| {
+                !existing.is_event_handler_property
+                    && existing.capture == listener.capture
+                    && existing.is_same_callback(&listener)
+            })
+        {
+            return;
         }
 
         listeners.push(listener);
@@ -771,6 +798,7 @@ impl ListenerStore {
         event: &str,
         capture: bool,
         handler: &ScriptHandler,
+        callback_id: Option<usize>,
     ) -> bool {
         let Some(events) = self.map.get_mut(&node_id) else {
             return false;
@@ -779,12 +807,14 @@ impl ListenerStore {
             return false;
         };
 
+        // Only the callback and capture flag identify a listener; once/passive/signal
+        // given to removeEventListener() are ignored.
         if let Some(pos) = listeners.iter().position(|listener| {
             !listener.is_event_handler_property
                 && listener.capture == capture
-                && listener.handler == *handler
+                && listener.matches_removal(handler, callback_id)
         }) {
-            listeners.remove(pos);
+            listeners.remove(pos).removed.set(true);
             if listeners.is_empty() {
                 events.remove(event);
             }
@@ -797,6 +827,25 @@ impl ListenerStore {
         false
     }
 
+    pub(crate) fn remove_signal_listeners(&mut self, signal: &Rc<RefCell<ObjectValue>>) {
+        for events in self.map.values_mut() {
+            for listeners in events.values_mut() {
+                listeners.retain(|listener| {
+                    let aborted = listener
+                        .signal
+                        .as_ref()
+                        .is_some_and(|listener_signal| Rc::ptr_eq(listener_signal, signal));
+                    if aborted {
+                        listener.removed.set(true);
+                    }
+                    !aborted
+                });
+            }
+            events.retain(|_, listeners| !listeners.is_empty());
+        }
+        self.map.retain(|_, events| !events.is_empty());
+    }
+
     pub(crate) fn remove_event_handler_property(
         &mut self,
         node_id: NodeId,
@@ -814,7 +863,7 @@ impl ListenerStore {
             .iter()
             .position(|listener| listener.is_event_handler_property && listener.handler == *handler)
         {
-            listeners.remove(pos);
+            listeners.remove(pos).removed.set(true);
             if listeners.is_empty() {
                 events.remove(event);
             }
@@ -851,6 +900,23 @@ impl ListenerStore {
         false
     }
 
+    pub(crate) fn remove_instance(&mut self, node_id: NodeId, event: &str, listener: &Listener) {
+        listener.removed.set(true);
+        let Some(events) = self.map.get_mut(&node_id) else {
+            return;
+        };
+        let Some(listeners) = events.get_mut(event) else {
+            return;
+        };
+        listeners.retain(|existing| !Rc::ptr_eq(&existing.removed, &listener.removed));
+        if listeners.is_empty() {
+            events.remove(event);
+        }
+        if events.is_empty() {
+            self.map.remove(&node_id);
+        }
+    }
+
     pub(crate) fn get(&self, node_id: NodeId, event: &str, capture: bool) -> Vec<Listener> {
         self.map
             .get(&node_id)
@@ -866,6 +932,271 @@ impl ListenerStore {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum EventInterface {
+    Event,
+    CustomEvent,
+    UiEvent,
+    FocusEvent,
+    InputEvent,
+    CompositionEvent,
+    MouseEvent,
+    DragEvent,
+    KeyboardEvent,
+    WheelEvent,
+    PointerEvent,
+    TouchEvent,
+    SubmitEvent,
+    ToggleEvent,
+    PopStateEvent,
+    HashChangeEvent,
+    StorageEvent,
+    MessageEvent,
+    ProgressEvent,
+    ClipboardEvent,
+    AnimationEvent,
+    TransitionEvent,
+    ErrorEvent,
+    BeforeUnloadEvent,
+    NavigateEvent,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum EventMemberKind {
+    Bool,
+    Long,
+    Double,
+    String,
+    NullableString,
+    Object,
+    Sequence,
+}
+
+impl EventInterface {
+    // Interfaces exposed through the shared `event_interface_constructor`; the
+    // others keep their dedicated constructor kinds.
+    pub(crate) const TABLE_DRIVEN: [Self; 15] = [
+        Self::UiEvent,
+        Self::FocusEvent,
+        Self::InputEvent,
+        Self::CompositionEvent,
+        Self::DragEvent,
+        Self::TouchEvent,
+        Self::SubmitEvent,
+        Self::ToggleEvent,
+        Self::PopStateEvent,
+        Self::StorageEvent,
+        Self::MessageEvent,
+        Self::ProgressEvent,
+        Self::ClipboardEvent,
+        Self::AnimationEvent,
+        Self::TransitionEvent,
+    ];
+
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            Self::Event => "Event",
+            Self::CustomEvent => "CustomEvent",
+            Self::UiEvent => "UIEvent",
+            Self::FocusEvent => "FocusEvent",
+            Self::InputEvent => "InputEvent",
+            Self::CompositionEvent => "CompositionEvent",
+            Self::MouseEvent => "MouseEvent",
+            Self::DragEvent => "DragEvent",
+            Self::KeyboardEvent => "KeyboardEvent",
+            Self::WheelEvent => "WheelEvent",
+            Self::PointerEvent => "PointerEvent",
+            Self::TouchEvent => "TouchEvent",
+            Self::SubmitEvent => "SubmitEvent",
+            Self::ToggleEvent => "ToggleEvent",
+            Self::PopStateEvent => "PopStateEvent",
+            Self::HashChangeEvent => "HashChangeEvent",
+            Self::StorageEvent => "StorageEvent",
+            Self::MessageEvent => "MessageEvent",
+            Self::ProgressEvent => "ProgressEvent",
+            Self::ClipboardEvent => "ClipboardEvent",
+            Self::AnimationEvent => "AnimationEvent",
+            Self::TransitionEvent => "TransitionEvent",
+            Self::ErrorEvent => "ErrorEvent",
+            Self::BeforeUnloadEvent => "BeforeUnloadEvent",
+            Self::NavigateEvent => "NavigateEvent",
+        }
+    }
+
+    pub(crate) fn from_name(name: &str) -> Option<Self> {
+        [
+            Self::Event,
+            Self::CustomEvent,
+            Self::MouseEvent,
+            Self::KeyboardEvent,
+            Self::WheelEvent,
+            Self::PointerEvent,
+            Self::HashChangeEvent,
+            Self::ErrorEvent,
+            Self::BeforeUnloadEvent,
+            Self::NavigateEvent,
+        ]
+        .into_iter()
+        .chain(Self::TABLE_DRIVEN)
+        .find(|interface| interface.name() == name)
+    }
+
+    pub(crate) fn parent(self) -> Option<Self> {
+        match self {
+            Self::Event => None,
+            Self::FocusEvent
+            | Self::InputEvent
+            | Self::CompositionEvent
+            | Self::MouseEvent
+            | Self::KeyboardEvent
+            | Self::TouchEvent => Some(Self::UiEvent),
+            Self::DragEvent | Self::WheelEvent | Self::PointerEvent => Some(Self::MouseEvent),
+            _ => Some(Self::Event),
+        }
+    }
+
+    pub(crate) fn extends(self, ancestor: Self) -> bool {
+        let mut current = Some(self);
+        while let Some(interface) = current {
+            if interface == ancestor {
+                return true;
+            }
+            current = interface.parent();
+        }
+        false
+    }
+
+    // Own (non-inherited) dictionary members and their IDL kinds.
+    pub(crate) fn members(self) -> &'static [(&'static str, EventMemberKind)] {
+        use EventMemberKind::*;
+        match self {
+            Self::UiEvent => &[("view", Object), ("detail", Long)],
+            Self::FocusEvent => &[("relatedTarget", Object)],
+            Self::InputEvent => &[
+                ("data", NullableString),
+                ("inputType", String),
+                ("isComposing", Bool),
+                ("dataTransfer", Object),
+            ],
+            Self::CompositionEvent => &[("data", String)],
+            Self::MouseEvent => &[
+                ("screenX", Double),
+                ("screenY", Double),
+                ("clientX", Double),
+                ("clientY", Double),
+                ("ctrlKey", Bool),
+                ("shiftKey", Bool),
+                ("altKey", Bool),
+                ("metaKey", Bool),
+                ("button", Long),
+                ("buttons", Long),
+                ("relatedTarget", Object),
+            ],
+            Self::DragEvent => &[("dataTransfer", Object)],
+            Self::TouchEvent => &[
+                ("touches", Sequence),
+                ("targetTouches", Sequence),
+                ("changedTouches", Sequence),
+                ("ctrlKey", Bool),
+                ("shiftKey", Bool),
+                ("altKey", Bool),
+                ("metaKey", Bool),
+            ],
+            Self::SubmitEvent => &[("submitter", Object)],
+            Self::ToggleEvent => &[("oldState", String), ("newState", String)],
+            Self::PopStateEvent => &[("state", Object), ("hasUAVisualTransition", Bool)],
+            Self::StorageEvent => &[
+                ("key", NullableString),
+                ("oldValue", NullableString),
+                ("newValue", NullableString),
+                ("url", String),
+                ("storageArea", Object),
+            ],
+            Self::MessageEvent => &[
+                ("data", Object),
+                ("origin", String),
+                ("lastEventId", String),
+                ("source", Object),
+                ("ports", Sequence),
+            ],
+            Self::ProgressEvent => &[
+                ("lengthComputable", Bool),
+                ("loaded", Long),
+                ("total", Long),
+            ],
+            Self::ClipboardEvent => &[("clipboardData", Object)],
+            Self::AnimationEvent => &[
+                ("animationName", String),
+                ("elapsedTime", Double),
+                ("pseudoElement", String),
+            ],
+            Self::TransitionEvent => &[
+                ("propertyName", String),
+                ("elapsedTime", Double),
+                ("pseudoElement", String),
+            ],
+            _ => &[],
+        }
+    }
+
+    // Table-driven members of this interface and its ancestors, ancestors first.
+    pub(crate) fn inherited_members(self) -> Vec<(&'static str, EventMemberKind)> {
+        let mut chain = Vec::new();
+        let mut current = Some(self);
+        while let Some(interface) = current {
+            chain.push(interface);
+            current = interface.parent();
+        }
+        let mut members = Vec::new();
+        for interface in chain.into_iter().rev() {
+            for member in interface.members() {
+                if !members.iter().any(|(name, _)| name == &member.0) {
+                    members.push(*member);
+                }
+            }
+        }
+        members
+    }
+
+    pub(crate) fn for_trusted_event(event_type: &str, has_input_type: bool) -> Self {
+        match event_type.to_ascii_lowercase().as_str() {
+            "focus" | "blur" | "focusin" | "focusout" => Self::FocusEvent,
+            "input" | "beforeinput" if has_input_type => Self::InputEvent,
+            "compositionstart" | "compositionupdate" | "compositionend" => Self::CompositionEvent,
+            "click" | "dblclick" | "auxclick" | "contextmenu" | "mousedown" | "mouseup"
+            | "mousemove" | "mouseover" | "mouseout" | "mouseenter" | "mouseleave" => {
+                Self::MouseEvent
+            }
+            "drag" | "dragstart" | "dragend" | "dragenter" | "dragover" | "dragleave" | "drop" => {
+                Self::DragEvent
+            }
+            "keydown" | "keyup" | "keypress" => Self::KeyboardEvent,
+            "wheel" => Self::WheelEvent,
+            "pointerover" | "pointerenter" | "pointerdown" | "pointermove" | "pointerrawupdate"
+            | "pointerup" | "pointercancel" | "pointerout" | "pointerleave"
+            | "gotpointercapture" | "lostpointercapture" => Self::PointerEvent,
+            "touchstart" | "touchmove" | "touchend" | "touchcancel" => Self::TouchEvent,
+            "submit" => Self::SubmitEvent,
+            "toggle" | "beforetoggle" => Self::ToggleEvent,
+            "popstate" => Self::PopStateEvent,
+            "hashchange" => Self::HashChangeEvent,
+            "storage" => Self::StorageEvent,
+            "message" | "messageerror" => Self::MessageEvent,
+            "progress" | "loadstart" | "loadend" => Self::ProgressEvent,
+            "copy" | "cut" | "paste" => Self::ClipboardEvent,
+            "animationstart" | "animationend" | "animationiteration" | "animationcancel" => {
+                Self::AnimationEvent
+            }
+            "transitionrun" | "transitionstart" | "transitionend" | "transitioncancel" => {
+                Self::TransitionEvent
+            }
+            "beforeunload" => Self::BeforeUnloadEvent,
+            "navigate" => Self::NavigateEvent,
+            _ => Self::Event,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub(crate) struct EventState {
     pub(crate) event_type: String,
@@ -880,16 +1211,15 @@ pub(crate) struct EventState {
     pub(crate) bubbles: bool,
     pub(crate) cancelable: bool,
     pub(crate) detail: Option<Value>,
-    pub(crate) hash_change_interface: bool,
+    pub(crate) interface: Option<EventInterface>,
+    pub(crate) interface_members: Vec<(String, Value)>,
     pub(crate) hash_change_old_url: String,
     pub(crate) hash_change_new_url: String,
-    pub(crate) error_event_interface: bool,
     pub(crate) error_event_message: String,
     pub(crate) error_event_filename: String,
     pub(crate) error_event_lineno: i64,
     pub(crate) error_event_colno: i64,
     pub(crate) error_event_error: Value,
-    pub(crate) before_unload_interface: bool,
     pub(crate) before_unload_return_value: String,
     pub(crate) state: Option<Value>,
     pub(crate) old_state: Option<String>,
@@ -903,6 +1233,9 @@ pub(crate) struct EventState {
     pub(crate) alt_key: bool,
     pub(crate) repeat: bool,
     pub(crate) is_composing: bool,
+    pub(crate) input_type: Option<String>,
+    pub(crate) input_data: Option<String>,
+    pub(crate) in_passive_listener: bool,
     pub(crate) delta_x: f64,
     pub(crate) delta_y: f64,
     pub(crate) delta_z: f64,
@@ -955,16 +1288,15 @@ impl EventState {
             bubbles: true,
             cancelable: true,
             detail: None,
-            hash_change_interface: false,
+            interface: None,
+            interface_members: Vec::new(),
             hash_change_old_url: String::new(),
             hash_change_new_url: String::new(),
-            error_event_interface: false,
             error_event_message: String::new(),
             error_event_filename: String::new(),
             error_event_lineno: 0,
             error_event_colno: 0,
             error_event_error: Value::Null,
-            before_unload_interface: event_type.eq_ignore_ascii_case("beforeunload"),
             before_unload_return_value: String::new(),
             state: None,
             old_state: None,
@@ -978,6 +1310,9 @@ impl EventState {
             alt_key: false,
             repeat: false,
             is_composing: false,
+            input_type: None,
+            input_data: None,
+            in_passive_listener: false,
             delta_x: 0.0,
             delta_y: 0.0,
             delta_z: 0.0,
@@ -1023,12 +1358,36 @@ impl EventState {
         event.cancelable = false;
         event
     }
+
+    pub(crate) fn interface(&self) -> EventInterface {
+        match self.interface {
+            Some(interface) => interface,
+            None if self.is_trusted => {
+                EventInterface::for_trusted_event(&self.event_type, self.input_type.is_some())
+            }
+            None => EventInterface::Event,
+        }
+    }
+
+    pub(crate) fn has_interface(&self, interface: EventInterface) -> bool {
+        self.interface().extends(interface)
+    }
 }
 
 #[derive(Debug, Clone)]
 pub(crate) struct ScriptSource {
+    pub(crate) element: NodeId,
     pub(crate) code: String,
+    pub(crate) src: Option<String>,
     pub(crate) is_module: bool,
+    pub(crate) timing: ScriptTiming,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ScriptTiming {
+    ParserBlocking,
+    Async,
+    Defer,
 }
 
 #[derive(Debug, Clone)]
@@ -1042,6 +1401,10 @@ pub(crate) enum ScheduledTaskKind {
     Timeout,
     Interval,
     AnimationFrame,
+    FetchResponse,
+    AbortSignalTimeout,
+    LayoutObservers,
+    WindowMessage,
 }
 
 #[derive(Debug, Clone)]
@@ -1069,6 +1432,7 @@ pub(crate) enum ScheduledMicrotask {
         reaction: PromiseReactionKind,
         settled: PromiseSettledValue,
     },
+    MutationObservers,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -1101,6 +1465,32 @@ pub struct DownloadArtifact {
     pub bytes: Vec<u8>,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FormSubmission {
+    pub method: String,
+    pub url: String,
+    pub enctype: String,
+    pub body: Vec<u8>,
+    pub submitter: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ConsoleLevel {
+    Debug,
+    Log,
+    Info,
+    Warn,
+    Error,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConsoleMessage {
+    pub level: ConsoleLevel,
+    pub args_rendered: String,
+    pub args: Vec<JsValue>,
+    pub timestamp_ms: i64,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ClipboardPayloadArtifact {
     pub mime_type: String,
@@ -1130,6 +1520,8 @@ pub(crate) struct LocationHistoryState {
     pub(crate) location_mock_pages: HashMap<String, String>,
     pub(crate) location_navigations: Vec<LocationNavigation>,
     pub(crate) location_reload_count: usize,
+    pub(crate) form_submissions: Vec<FormSubmission>,
+    pub(crate) document_generation: usize,
 }
 
 impl LocationHistoryState {
@@ -1148,6 +1540,8 @@ impl LocationHistoryState {
             location_mock_pages: HashMap::new(),
             location_navigations: Vec::new(),
             location_reload_count: 0,
+            form_submissions: Vec::new(),
+            document_generation: 0,
         }
     }
 }
@@ -1202,6 +1596,52 @@ pub(crate) enum ModuleExportBinding {
     Value(Value),
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ModuleStatus {
+    Linking,
+    Linked,
+    Evaluating,
+    Evaluated,
+    Errored,
+}
+
+// One entry of the module map. The export table is filled from the static
+// export statements at link time so cyclic importers can bind to it before
+// the module body has run. `error` keeps a failed evaluation's error so later
+// imports of the module rethrow it.
+#[derive(Debug, Clone)]
+pub(crate) struct ModuleRecord {
+    pub(crate) status: ModuleStatus,
+    pub(crate) body: Rc<Vec<Stmt>>,
+    pub(crate) requested_modules: Vec<String>,
+    pub(crate) exports: Rc<RefCell<HashMap<String, ModuleExportBinding>>>,
+    pub(crate) scope: Option<usize>,
+    pub(crate) error: Option<Error>,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct ModuleImportBinding {
+    pub(crate) module: String,
+    pub(crate) import_name: String,
+}
+
+// State of one running or finished module body. Its top-level env and every
+// closure created in it carry the index of this scope. `env` is the module's
+// environment record, shared with the closures created at its top level; a
+// lexical binding is missing from it until its declaration has run.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ModuleScope {
+    pub(crate) imports: HashMap<String, ModuleImportBinding>,
+    pub(crate) exports: Option<Rc<RefCell<HashMap<String, ModuleExportBinding>>>>,
+    pub(crate) env: Rc<RefCell<ScriptEnv>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct ImportMap {
+    pub(crate) imports: Vec<(String, String)>,
+    pub(crate) scopes: Vec<(String, Vec<(String, String)>)>,
+}
+
 #[derive(Debug, Default, Clone)]
 pub(crate) struct TdzScopeFrame {
     pub(crate) declared: HashSet<String>,
@@ -1256,9 +1696,11 @@ pub(crate) struct ScriptRuntimeState {
     pub(crate) tdz_scope_stack: Vec<TdzScopeFrame>,
     pub(crate) module_export_stack: Vec<Rc<RefCell<HashMap<String, ModuleExportBinding>>>>,
     pub(crate) module_referrer_stack: Vec<String>,
-    pub(crate) module_cache: HashMap<String, HashMap<String, Value>>,
+    pub(crate) modules: HashMap<String, ModuleRecord>,
     pub(crate) module_namespace_cache: HashMap<String, Value>,
-    pub(crate) loading_modules: HashSet<String>,
+    pub(crate) module_scopes: Vec<ModuleScope>,
+    pub(crate) pending_module_env: Option<Rc<RefCell<ScriptEnv>>>,
+    pub(crate) import_map: ImportMap,
     pub(crate) next_function_id: usize,
     pub(crate) next_private_slot_id: usize,
     pub(crate) function_private_bindings: HashMap<usize, HashMap<String, PrivateBindingRuntime>>,
@@ -1311,9 +1753,130 @@ pub(crate) struct DomRuntimeState {
     pub(crate) live_named_node_maps: HashMap<NodeId, Rc<RefCell<ObjectValue>>>,
     pub(crate) node_animations: Vec<NodeAnimationRecord>,
     pub(crate) pointer_capture_targets: HashMap<i64, NodeId>,
+    pub(crate) pointer_input: PointerInputState,
     pub(crate) shadow_roots: HashMap<NodeId, ShadowRootRecord>,
     pub(crate) dialog_return_values: HashMap<NodeId, String>,
+    pub(crate) modal_dialogs: Vec<NodeId>,
     pub(crate) click_in_progress: HashSet<NodeId>,
+    pub(crate) mutation_observers: MutationObserverState,
+    pub(crate) layout_observers: LayoutObserverState,
+    pub(crate) custom_elements: CustomElementRegistryState,
+    pub(crate) current_script: Option<NodeId>,
+    pub(crate) linked_stylesheets: HashMap<NodeId, String>,
+    pub(crate) style_sheet_cache: RefCell<StyleSheetCache>,
+}
+
+// The UA and author sheets parsed for the cascade, kept until the gathered
+// author sources change.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct StyleSheetCache {
+    pub(crate) author_sources: Vec<String>,
+    pub(crate) rules: Rc<Vec<CachedStyleRule>>,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct CachedStyleRule {
+    pub(crate) author: bool,
+    // Conditions of the enclosing `@media` blocks, evaluated at cascade time.
+    pub(crate) media: Vec<String>,
+    pub(crate) selectors: Vec<CachedStyleSelector>,
+    pub(crate) declarations: Vec<(String, String)>,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct CachedStyleSelector {
+    // `None` when the selector is a bare pseudo-element.
+    pub(crate) chain: Option<Vec<SelectorPart>>,
+    pub(crate) pseudo: Option<String>,
+    pub(crate) specificity: (u32, u32, u32),
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct MutationObserverEntry {
+    pub(crate) id: usize,
+    pub(crate) callback: Value,
+    pub(crate) object: Value,
+    pub(crate) records: Vec<Value>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct MutationObserverState {
+    pub(crate) next_id: usize,
+    pub(crate) observers: Vec<MutationObserverEntry>,
+    pub(crate) delivery_queued: bool,
+}
+
+// The harness mouse: position in viewport coordinates, pressed buttons and
+// what is needed to derive `click` targets and `detail` counts.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct PointerInputState {
+    pub(crate) client_x: f64,
+    pub(crate) client_y: f64,
+    pub(crate) buttons: i64,
+    pub(crate) press_target: Option<NodeId>,
+    pub(crate) suppress_mouse_events: bool,
+    pub(crate) click_count: i64,
+    pub(crate) last_press: Option<(NodeId, i64, i64)>,
+    pub(crate) notified_capture_target: Option<NodeId>,
+    // The hovered element and its ancestors as of the last boundary update,
+    // so hover can fall back to an ancestor when the element is removed.
+    pub(crate) hover_chain: Vec<NodeId>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum LayoutObserverKind {
+    Intersection,
+    Resize,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct LayoutObservation {
+    pub(crate) target: NodeId,
+    pub(crate) previous_threshold_index: i64,
+    pub(crate) previous_is_intersecting: bool,
+    pub(crate) border_box: bool,
+    pub(crate) last_size: (f64, f64),
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct LayoutObserverEntry {
+    pub(crate) id: usize,
+    pub(crate) kind: LayoutObserverKind,
+    pub(crate) callback: Value,
+    pub(crate) object: Value,
+    pub(crate) root: Option<NodeId>,
+    pub(crate) root_margin: [String; 4],
+    pub(crate) thresholds: Vec<f64>,
+    pub(crate) observations: Vec<LayoutObservation>,
+    pub(crate) records: Vec<Value>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct LayoutObserverState {
+    pub(crate) next_id: usize,
+    pub(crate) observers: Vec<LayoutObserverEntry>,
+    pub(crate) forced_intersection_ratios: HashMap<NodeId, f64>,
+    pub(crate) delivery_queued: bool,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct CustomElementDefinitionEntry {
+    pub(crate) name: String,
+    pub(crate) local_name: String,
+    pub(crate) constructor: Value,
+    pub(crate) prototype: Value,
+    pub(crate) connected_callback: Option<Value>,
+    pub(crate) disconnected_callback: Option<Value>,
+    pub(crate) adopted_callback: Option<Value>,
+    pub(crate) attribute_changed_callback: Option<Value>,
+    pub(crate) observed_attributes: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct CustomElementRegistryState {
+    pub(crate) definitions: Vec<CustomElementDefinitionEntry>,
+    pub(crate) when_defined: HashMap<String, Value>,
+    pub(crate) definition_running: bool,
 }
 
 impl Default for DomRuntimeState {
@@ -1334,9 +1897,17 @@ impl Default for DomRuntimeState {
             live_named_node_maps: HashMap::new(),
             node_animations: Vec::new(),
             pointer_capture_targets: HashMap::new(),
+            pointer_input: PointerInputState::default(),
             shadow_roots: HashMap::new(),
             dialog_return_values: HashMap::new(),
+            modal_dialogs: Vec::new(),
             click_in_progress: HashSet::new(),
+            mutation_observers: MutationObserverState::default(),
+            layout_observers: LayoutObserverState::default(),
+            custom_elements: CustomElementRegistryState::default(),
+            current_script: None,
+            linked_stylesheets: HashMap::new(),
+            style_sheet_cache: RefCell::default(),
         }
     }
 }
@@ -1367,10 +1938,22 @@ pub(crate) struct PlatformMockState {
     pub(crate) clipboard_write_error: Option<String>,
     pub(crate) fetch_mocks: HashMap<String, FetchMockResponse>,
     pub(crate) fetch_calls: Vec<String>,
+    pub(crate) fetch_mock_rules: Vec<FetchMockRule>,
+    pub(crate) fetch_requests: Vec<RecordedRequest>,
+    pub(crate) fetch_handler: Option<FetchHandler>,
+    pub(crate) resource_loader: Option<ResourceLoaderHandle>,
+    pub(crate) manual_fetch_mode: bool,
+    pub(crate) pending_fetches: Vec<PendingFetchState>,
     pub(crate) match_media_mocks: HashMap<String, bool>,
     pub(crate) match_media_calls: Vec<String>,
     pub(crate) default_match_media_matches: bool,
     pub(crate) alert_messages: Vec<String>,
+    pub(crate) console_messages: Vec<ConsoleMessage>,
+    pub(crate) console_group_depth: usize,
+    pub(crate) console_timers: HashMap<String, i64>,
+    pub(crate) console_counts: HashMap<String, i64>,
+    pub(crate) fail_on_console_error: bool,
+    pub(crate) pending_console_error: Option<String>,
     pub(crate) print_call_count: usize,
     pub(crate) confirm_responses: VecDeque<bool>,
     pub(crate) default_confirm_response: bool,
@@ -1379,10 +1962,208 @@ pub(crate) struct PlatformMockState {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub(crate) struct FetchMockResponse {
-    pub(crate) status: i64,
-    pub(crate) status_text: String,
-    pub(crate) body: String,
+pub struct FetchMockResponse {
+    pub status: i64,
+    pub status_text: String,
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+    pub redirected: bool,
+    pub response_type: String,
+    pub url: Option<String>,
+    pub latency_ms: i64,
+}
+
+impl FetchMockResponse {
+    pub fn new(status: i64) -> Self {
+        Self {
+            status,
+            status_text: Harness::default_fetch_status_text(status),
+            headers: Vec::new(),
+            body: Vec::new(),
+            redirected: false,
+            response_type: "basic".to_string(),
+            url: None,
+            latency_ms: 0,
+        }
+    }
+
+    pub fn text(body: &str) -> Self {
+        Self::new(200)
+            .with_header("content-type", "text/plain;charset=UTF-8")
+            .with_bytes(body.as_bytes())
+    }
+
+    pub fn json(body: &str) -> Self {
+        Self::new(200)
+            .with_header("content-type", "application/json")
+            .with_bytes(body.as_bytes())
+    }
+
+    pub fn with_status(mut self, status: i64) -> Self {
+        self.status = status;
+        self.status_text = Harness::default_fetch_status_text(status);
+        self
+    }
+
+    pub fn with_status_text(mut self, status_text: &str) -> Self {
+        self.status_text = status_text.to_string();
+        self
+    }
+
+    pub fn with_header(mut self, name: &str, value: &str) -> Self {
+        let name = name.trim().to_ascii_lowercase();
+        self.headers.retain(|(existing, _)| existing != &name);
+        self.headers.push((name, value.to_string()));
+        self
+    }
+
+    pub fn with_bytes(mut self, bytes: &[u8]) -> Self {
+        self.body = bytes.to_vec();
+        self
+    }
+
+    pub fn with_redirect(mut self, final_url: &str) -> Self {
+        self.redirected = true;
+        self.url = Some(final_url.to_string());
+        self
+    }
+
+    pub fn with_response_type(mut self, response_type: &str) -> Self {
+        self.response_type = response_type.to_string();
+        self
+    }
+
+    pub fn with_latency(mut self, latency_ms: i64) -> Self {
+        self.latency_ms = latency_ms.max(0);
+        self
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct FetchMatcher {
+    pub method: Option<String>,
+    pub url: String,
+    pub query: Vec<(String, String)>,
+    pub headers: Vec<(String, String)>,
+    pub json_body: Option<String>,
+}
+
+impl FetchMatcher {
+    pub fn new(url_pattern: &str) -> Self {
+        Self {
+            url: url_pattern.to_string(),
+            ..Self::default()
+        }
+    }
+
+    pub fn with_method(mut self, method: &str) -> Self {
+        self.method = Some(method.to_ascii_uppercase());
+        self
+    }
+
+    pub fn with_query(mut self, name: &str, value: &str) -> Self {
+        self.query.push((name.to_string(), value.to_string()));
+        self
+    }
+
+    pub fn with_header(mut self, name: &str, value: &str) -> Self {
+        self.headers
+            .push((name.trim().to_ascii_lowercase(), value.to_string()));
+        self
+    }
+
+    pub fn with_json_body(mut self, json: &str) -> Self {
+        self.json_body = Some(json.to_string());
+        self
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FetchDestination {
+    Fetch,
+    Script,
+    Module,
+    Style,
+    Worker,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FetchRequest {
+    pub destination: FetchDestination,
+    pub method: String,
+    pub url: String,
+    pub headers: Vec<(String, String)>,
+    pub body: Option<Vec<u8>>,
+}
+
+impl FetchRequest {
+    pub(crate) fn get(destination: FetchDestination, url: &str) -> Self {
+        Self {
+            destination,
+            method: "GET".to_string(),
+            url: url.to_string(),
+            headers: Vec::new(),
+            body: None,
+        }
+    }
+
+    pub fn header(&self, name: &str) -> Option<&str> {
+        let name = name.trim().to_ascii_lowercase();
+        self.headers
+            .iter()
+            .find(|(existing, _)| existing == &name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    pub fn body_text(&self) -> Option<String> {
+        self.body
+            .as_ref()
+            .map(|body| String::from_utf8_lossy(body).into_owned())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FetchOutcome {
+    Response(FetchMockResponse),
+    NetworkError,
+    Fallthrough,
+}
+
+pub(crate) type FetchHandlerFn = dyn FnMut(&FetchRequest) -> FetchOutcome;
+
+// Shared so a forked harness keeps answering through the same closure.
+#[derive(Clone)]
+pub(crate) struct FetchHandler(pub(crate) Rc<RefCell<FetchHandlerFn>>);
+
+impl fmt::Debug for FetchHandler {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("FetchHandler(..)")
+    }
+}
+
+pub type RecordedRequest = FetchRequest;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PendingFetch {
+    pub id: i64,
+    pub method: String,
+    pub url: String,
+    pub started_at_ms: i64,
+    pub due_at_ms: Option<i64>,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct PendingFetchState {
+    pub(crate) info: PendingFetch,
+    pub(crate) promise: Rc<RefCell<PromiseValue>>,
+    pub(crate) response: Option<FetchMockResponse>,
+    pub(crate) signal: Option<Rc<RefCell<ObjectValue>>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct FetchMockRule {
+    pub(crate) matcher: FetchMatcher,
+    pub(crate) response: FetchMockResponse,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -1411,6 +2192,7 @@ pub(crate) struct TraceState {
     pub(crate) enabled: bool,
     pub(crate) events: bool,
     pub(crate) timers: bool,
+    pub(crate) console: bool,
     pub(crate) logs: VecDeque<String>,
     pub(crate) log_limit: usize,
     pub(crate) to_stderr: bool,
@@ -1422,6 +2204,7 @@ impl Default for TraceState {
             enabled: false,
             events: true,
             timers: true,
+            console: true,
             logs: VecDeque::new(),
             log_limit: 10_000,
             to_stderr: true,
@@ -1429,12 +2212,82 @@ impl Default for TraceState {
     }
 }
 
+#[derive(Debug, Clone)]
+pub(crate) enum BrowsingContextRequest {
+    Open {
+        id: String,
+        url: String,
+        html: Option<String>,
+        name: String,
+        opener: Option<String>,
+    },
+    PostMessage {
+        target: String,
+        source: String,
+        origin: String,
+        target_origin: String,
+        data: Value,
+    },
+    Close {
+        id: String,
+    },
+    StorageChanged {
+        source: String,
+        origin: String,
+        url: String,
+        key: Option<String>,
+        old_value: Option<String>,
+        new_value: Option<String>,
+    },
+}
+
+// The `localStorage` items and cookie jar every same-origin page of a
+// `MockWindow` shares. Each page wraps the items in its own `Storage` object.
+#[derive(Debug, Clone)]
+pub(crate) struct SharedOriginStorage {
+    pub(crate) origin: String,
+    pub(crate) local_storage: Rc<RefCell<ArrayValue>>,
+    pub(crate) cookies: Rc<RefCell<Vec<CookieRecord>>>,
+}
+
+#[derive(Debug)]
+pub(crate) struct BrowsingContextState {
+    pub(crate) id: String,
+    pub(crate) name: String,
+    pub(crate) opener: Option<String>,
+    pub(crate) script_opened: bool,
+    // Only pages living in a `MockWindow` have anyone draining `requests`.
+    pub(crate) window_owned: bool,
+    pub(crate) next_child_id: usize,
+    pub(crate) named_children: Vec<(String, String)>,
+    pub(crate) proxies: Vec<(String, Value)>,
+    pub(crate) requests: Vec<BrowsingContextRequest>,
+}
+
+impl Default for BrowsingContextState {
+    fn default() -> Self {
+        Self {
+            id: "top".to_string(),
+            name: String::new(),
+            opener: None,
+            script_opened: false,
+            window_owned: false,
+            next_child_id: 1,
+            named_children: Vec::new(),
+            proxies: Vec::new(),
+            requests: Vec::new(),
+        }
+    }
+}
+
 #[derive(Debug)]
 pub(crate) struct BrowserApiState {
     pub(crate) next_url_object_id: usize,
     pub(crate) url_objects: HashMap<usize, Rc<RefCell<ObjectValue>>>,
     pub(crate) url_constructor_properties: Rc<RefCell<ObjectValue>>,
     pub(crate) local_storage_object: Rc<RefCell<ObjectValue>>,
+    pub(crate) session_storage_object: Rc<RefCell<ObjectValue>>,
+    pub(crate) session_storage_by_origin: HashMap<String, Rc<RefCell<ObjectValue>>>,
     pub(crate) cookie_store_object: Rc<RefCell<ObjectValue>>,
     pub(crate) cache_storage_object: Rc<RefCell<ObjectValue>>,
     pub(crate) caches_by_name: HashMap<String, Rc<RefCell<ObjectValue>>>,
@@ -1443,12 +2296,13 @@ pub(crate) struct BrowserApiState {
     pub(crate) window_closed: bool,
     pub(crate) window_screen_x: i64,
     pub(crate) window_screen_y: i64,
-    pub(crate) cookies: Vec<CookieRecord>,
+    pub(crate) cookies: Rc<RefCell<Vec<CookieRecord>>>,
     pub(crate) cookie_store_change_listeners: Vec<Value>,
     pub(crate) next_blob_url_id: usize,
     pub(crate) blob_url_objects: HashMap<String, Rc<RefCell<BlobValue>>>,
     pub(crate) downloads: Vec<DownloadArtifact>,
     pub(crate) clipboard_writes: Vec<ClipboardWriteArtifact>,
+    pub(crate) browsing_context: BrowsingContextState,
 }
 
 impl Default for BrowserApiState {
@@ -1458,6 +2312,8 @@ impl Default for BrowserApiState {
             url_objects: HashMap::new(),
             url_constructor_properties: Rc::new(RefCell::new(ObjectValue::default())),
             local_storage_object: Rc::new(RefCell::new(ObjectValue::default())),
+            session_storage_object: Rc::new(RefCell::new(ObjectValue::default())),
+            session_storage_by_origin: HashMap::new(),
             cookie_store_object: Rc::new(RefCell::new(ObjectValue::default())),
             cache_storage_object: Rc::new(RefCell::new(ObjectValue::default())),
             caches_by_name: HashMap::new(),
@@ -1466,12 +2322,13 @@ impl Default for BrowserApiState {
             window_closed: false,
             window_screen_x: 0,
             window_screen_y: 0,
-            cookies: Vec::new(),
+            cookies: Rc::new(RefCell::new(Vec::new())),
             cookie_store_change_listeners: Vec::new(),
             next_blob_url_id: 1,
             blob_url_objects: HashMap::new(),
             downloads: Vec::new(),
             clipboard_writes: Vec::new(),
+            browsing_context: BrowsingContextState::default(),
         }
     }
 }
@@ -1541,8 +2398,10 @@ pub(crate) struct SchedulerState {
     pub(crate) task_queue: Vec<ScheduledTask>,
     pub(crate) microtask_queue: VecDeque<ScheduledMicrotask>,
     pub(crate) now_ms: i64,
+    pub(crate) time_zone: String,
     pub(crate) timer_step_limit: usize,
     pub(crate) next_timer_id: i64,
+    pub(crate) next_internal_task_id: i64,
     pub(crate) next_task_order: i64,
     pub(crate) task_depth: usize,
     pub(crate) running_timer_id: Option<i64>,
@@ -1555,8 +2414,10 @@ impl Default for SchedulerState {
             task_queue: Vec::new(),
             microtask_queue: VecDeque::new(),
             now_ms: 0,
+            time_zone: "UTC".to_string(),
             timer_step_limit: 10_000,
             next_timer_id: 1,
+            next_internal_task_id: 1,
             next_task_order: 0,
             task_depth: 0,
             running_timer_id: None,
@@ -1572,6 +2433,14 @@ impl SchedulerState {
         id
     }
 
+    // Fetch settlement and other engine tasks draw from their own ids so they
+    // never shift the ids scripts get back from setTimeout.
+    pub(crate) fn allocate_internal_task_id(&mut self) -> i64 {
+        let id = self.next_internal_task_id;
+        self.next_internal_task_id += 1;
+        id
+    }
+
     pub(crate) fn allocate_task_order(&mut self) -> i64 {
         let order = self.next_task_order;
         self.next_task_order += 1;
@@ -1302,7 +1302,7 @@ pub(crate) struct IntlDateTimeComponents {
     pub(crate) second: u32,
     pub(crate) millisecond: u32,
     pub(crate) weekday: u32,
-    pub(crate) offset_minutes: i64,
+    pub(crate) timestamp_ms: i64,
 }
 
 #[derive(Debug, Clone)]
@@ -1394,3 +1394,114 @@ pub(crate) struct IntlLocaleData {
     pub(crate) numbering_system: Option<String>,
     pub(crate) numeric: Option<bool>,
 }
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NodeHandle(pub(crate) NodeId);
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum JsValue {
+    Undefined,
+    Null,
+    Bool(bool),
+    Number(f64),
+    BigInt(String),
+    String(String),
+    Symbol(Option<String>),
+    Array(Vec<JsValue>),
+    Object(Vec<(String, JsValue)>),
+    Date(i64),
+    Map(Vec<(JsValue, JsValue)>),
+    Set(Vec<JsValue>),
+    Node(NodeHandle),
+    NodeList(Vec<NodeHandle>),
+    Function,
+    Circular,
+    Opaque(String),
+}
+
+impl JsValue {
+    pub fn is_nullish(&self) -> bool {
+        matches!(self, Self::Undefined | Self::Null)
+    }
+
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            Self::Bool(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            Self::Number(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::String(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn as_array(&self) -> Option<&[JsValue]> {
+        match self {
+            Self::Array(values) | Self::Set(values) => Some(values),
+            _ => None,
+        }
+    }
+
+    pub fn as_node(&self) -> Option<NodeHandle> {
+        match self {
+            Self::Node(node) => Some(*node),
+            _ => None,
+        }
+    }
+
+    pub fn get(&self, key: &str) -> Option<&JsValue> {
+        match self {
+            Self::Object(entries) => entries
+                .iter()
+                .find_map(|(name, value)| (name == key).then_some(value)),
+            Self::Array(values) => key
+                .parse::<usize>()
+                .ok()
+                .and_then(|index| values.get(index)),
+            Self::Map(entries) => entries
+                .iter()
+                .find_map(|(entry_key, value)| (entry_key.as_str() == Some(key)).then_some(value)),
+            _ => None,
+        }
+    }
+}
+
+impl From<bool> for JsValue {
+    fn from(value: bool) -> Self {
+        Self::Bool(value)
+    }
+}
+
+impl From<f64> for JsValue {
+    fn from(value: f64) -> Self {
+        Self::Number(value)
+    }
+}
+
+impl From<i64> for JsValue {
+    fn from(value: i64) -> Self {
+        Self::Number(value as f64)
+    }
+}
+
+impl From<&str> for JsValue {
+    fn from(value: &str) -> Self {
+        Self::String(value.to_string())
+    }
+}
+
+impl From<String> for JsValue {
+    fn from(value: String) -> Self {
+        Self::String(value)
+    }
+}
@@ -32,6 +32,9 @@ pub(crate) enum SelectorPseudoClass {
     Focus,
     FocusWithin,
     Active,
+    Hover,
+    Defined,
+    Root,
     NthOfType(NthChildSelector),
     NthLastOfType(NthChildSelector),
     Not(Vec<Vec<SelectorPart>>),
@@ -134,6 +137,34 @@ pub(crate) fn parse_selector_chain(selector: &str) -> Result<Vec<SelectorPart>>
     Ok(steps)
 }
 
+// (ids, classes/attributes/pseudo-classes, types) of one complex selector.
+pub(crate) fn selector_specificity(parts: &[SelectorPart]) -> (u32, u32, u32) {
+    let mut specificity = (0, 0, 0);
+    for part in parts {
+        let step = &part.step;
+        specificity.0 += u32::from(step.id.is_some());
+        specificity.1 += (step.classes.len() + step.attrs.len()) as u32;
+        specificity.2 += u32::from(step.tag.is_some());
+        for pseudo in &step.pseudo_classes {
+            let inner = match pseudo {
+                SelectorPseudoClass::Where(_) => (0, 0, 0),
+                SelectorPseudoClass::Not(groups)
+                | SelectorPseudoClass::Is(groups)
+                | SelectorPseudoClass::Has(groups) => groups
+                    .iter()
+                    .map(|group| selector_specificity(group))
+                    .max()
+                    .unwrap_or_default(),
+                _ => (0, 1, 0),
+            };
+            specificity.0 += inner.0;
+            specificity.1 += inner.1;
+            specificity.2 += inner.2;
+        }
+    }
+    specificity
+}
+
 pub(crate) fn parse_selector_groups(selector: &str) -> Result<Vec<Vec<SelectorPart>>> {
     let groups = split_selector_groups(selector)?;
     let mut parsed = Vec::with_capacity(groups.len());
@@ -481,6 +512,27 @@ pub(crate) fn parse_selector_pseudo(
         }
     }
 
+    if let Some(rest) = tail.strip_prefix("hover") {
+        if rest.is_empty() || is_selector_continuation(rest.as_bytes().first()?) {
+            let consumed = start + "hover".len();
+            return Some((SelectorPseudoClass::Hover, consumed));
+        }
+    }
+
+    if let Some(rest) = tail.strip_prefix("defined") {
+        if rest.is_empty() || is_selector_continuation(rest.as_bytes().first()?) {
+            let consumed = start + "defined".len();
+            return Some((SelectorPseudoClass::Defined, consumed));
+        }
+    }
+
+    if let Some(rest) = tail.strip_prefix("root") {
+        if rest.is_empty() || is_selector_continuation(rest.as_bytes().first()?) {
+            let consumed = start + "root".len();
+            return Some((SelectorPseudoClass::Root, consumed));
+        }
+    }
+
     if let Some(rest) = tail.strip_prefix("enabled") {
         if rest.is_empty() || is_selector_continuation(rest.as_bytes().first()?) {
             let consumed = start + "enabled".len();
@@ -0,0 +1,268 @@
+use super::*;
+
+#[test]
+fn local_getters_follow_the_configured_time_zone() -> Result<()> {
+    let mut h = Harness::from_html("<p></p>")?;
+    assert_eq!(h.time_zone(), "UTC");
+    assert_eq!(
+        h.eval(
+            "new Date(1700000000000).getHours() + ':' + new Date(1700000000000).getTimezoneOffset()"
+        )?,
+        "22:0".into()
+    );
+
+    h.set_time_zone("America/New_York")?;
+    assert_eq!(h.time_zone(), "America/New_York");
+    assert_eq!(
+        h.eval(
+            r#"
+              const winter = new Date(Date.UTC(2024, 0, 15, 3, 30));
+              const summer = new Date(Date.UTC(2024, 6, 4, 16, 0));
+              [
+                winter.getFullYear(), winter.getMonth(), winter.getDate(), winter.getDay(),
+                winter.getHours(), winter.getMinutes(), winter.getUTCHours(),
+                winter.getTimezoneOffset(), summer.getHours(), summer.getTimezoneOffset()
+              ].join(',')
+            "#
+        )?,
+        "2024,0,14,0,22,30,3,300,12,240".into()
+    );
+
+    h.set_time_zone("Asia/Kolkata")?;
+    assert_eq!(
+        h.eval("new Date(Date.UTC(2024, 0, 1)).getTimezoneOffset() + ' ' + new Date(Date.UTC(2024, 0, 1)).getHours() + ':' + new Date(Date.UTC(2024, 0, 1)).getMinutes()")?,
+        "-330 5:30".into()
+    );
+    Ok(())
+}
+
+#[test]
+fn local_construction_handles_dst_gaps_and_overlaps() -> Result<()> {
+    let mut h = Harness::from_html("<p></p>")?;
+    h.set_time_zone("America/New_York")?;
+    assert_eq!(
+        h.eval(
+            r#"
+              const skipped = new Date(2024, 2, 10, 2, 30);
+              const repeated = new Date(2024, 10, 3, 1, 30);
+              const afterFallBack = new Date(repeated.getTime() + 3600000);
+              [
+                skipped.getHours() + ':' + skipped.getMinutes(),
+                skipped.toISOString(),
+                repeated.toISOString(),
+                afterFallBack.getHours() + ':' + afterFallBack.getTimezoneOffset()
+              ].join('|')
+            "#
+        )?,
+        "3:30|2024-03-10T07:30:00.000Z|2024-11-03T05:30:00.000Z|1:300".into()
+    );
+    assert_eq!(
+        h.eval("new Date('2024-07-01T09:00:00').toISOString() + '|' + new Date('2024-07-01').toISOString()")?,
+        "2024-07-01T13:00:00.000Z|2024-07-01T00:00:00.000Z".into()
+    );
+    Ok(())
+}
+
+#[test]
+fn local_setters_recompute_the_offset() -> Result<()> {
+    let mut h = Harness::from_html("<p></p>")?;
+    h.set_time_zone("America/New_York")?;
+    assert_eq!(
+        h.eval(
+            r#"
+              const d = new Date(2024, 0, 31, 12, 0);
+              d.setMonth(6, 4);
+              const local = d.getHours() + ':' + d.getTimezoneOffset();
+              d.setHours(23, 59, 59, 999);
+              const utc = new Date(0);
+              utc.setUTCFullYear(2024, 1, 29);
+              [local, d.toISOString(), utc.toISOString()].join('|')
+            "#
+        )?,
+        "12:240|2024-07-05T03:59:59.999Z|2024-02-29T00:00:00.000Z".into()
+    );
+    Ok(())
+}
+
+#[test]
+fn date_string_forms_include_offset_and_zone_name() -> Result<()> {
+    let mut h = Harness::from_html("<p id='out'></p>")?;
+    h.set_time_zone("America/New_York")?;
+    assert_eq!(
+        h.eval(
+            r#"
+              const d = new Date(1700000000000);
+              [d.toString(), d.toDateString(), d.toTimeString(), d.toUTCString()].join('|')
+            "#
+        )?,
+        "Tue Nov 14 2023 17:13:20 GMT-0500 (Eastern Standard Time)|Tue Nov 14 2023|17:13:20 GMT-0500 (Eastern Standard Time)|Tue, 14 Nov 2023 22:13:20 GMT".into()
+    );
+    assert_eq!(
+        h.eval("String(new Date(Date.UTC(2024, 6, 4, 16)))")?,
+        "Thu Jul 04 2024 12:00:00 GMT-0400 (Eastern Daylight Time)".into()
+    );
+    assert_eq!(
+        h.eval("const d = new Date(1700000000000); Date.parse(d.toString()) === d.getTime() && Date.parse(d.toUTCString()) === d.getTime()")?,
+        JsValue::Bool(true)
+    );
+
+    h.set_time_zone("Asia/Kolkata")?;
+    assert_eq!(
+        h.eval("'at ' + new Date(1700000000000)")?,
+        "at Wed Nov 15 2023 03:43:20 GMT+0530 (India Standard Time)".into()
+    );
+    Ok(())
+}
+
+#[test]
+fn locale_strings_default_to_the_harness_time_zone() -> Result<()> {
+    let mut h = Harness::from_html("<p></p>")?;
+    h.set_time_zone("America/Los_Angeles")?;
+    assert_eq!(
+        h.eval(
+            r#"
+              const d = new Date(1700000000000);
+              [
+                d.toLocaleString('en-US'),
+                d.toLocaleDateString('en-US'),
+                d.toLocaleTimeString('en-US'),
+                d.toLocaleString('en-US', { timeZone: 'UTC' }),
+                new Intl.DateTimeFormat('en-US').resolvedOptions().timeZone
+              ].join('|')
+            "#
+        )?,
+        "11/14/2023, 2:13:20 PM|11/14/2023|2:13:20 PM|11/14/2023, 10:13:20 PM|America/Los_Angeles"
+            .into()
+    );
+    Ok(())
+}
+
+#[test]
+fn intl_resolves_real_offsets_across_dst_for_any_zone() -> Result<()> {
+    let mut h = Harness::from_html("<p></p>")?;
+    assert_eq!(
+        h.eval(
+            r#"
+              const format = (zone, ms, timeZoneName) =>
+                new Intl.DateTimeFormat('en-US', {
+                  timeZone: zone,
+                  hour: 'numeric',
+                  minute: '2-digit',
+                  timeZoneName
+                }).format(new Date(ms));
+              [
+                format('Europe/Berlin', Date.UTC(2024, 2, 31, 0, 59), 'short'),
+                format('Europe/Berlin', Date.UTC(2024, 2, 31, 1, 0), 'short'),
+                format('Australia/Sydney', Date.UTC(2024, 0, 1), 'short'),
+                format('Australia/Sydney', Date.UTC(2024, 6, 1), 'short'),
+                new Intl.DateTimeFormat('en-AU', {
+                  timeZone: 'Australia/Sydney',
+                  timeZoneName: 'short'
+                }).formatToParts(new Date(Date.UTC(2024, 0, 1))).pop().value,
+                format('America/Chicago', Date.UTC(2024, 6, 1), 'long'),
+                format('Asia/Kathmandu', Date.UTC(2024, 0, 1), 'short')
+              ].join('|')
+            "#
+        )?,
+        "1:59 AM GMT+1|3:00 AM GMT+2|11:00 AM GMT+11|10:00 AM GMT+10|AEDT|7:00 PM Central Daylight Time|5:45 AM GMT+5:45".into()
+    );
+    Ok(())
+}
+
+#[test]
+fn far_future_dates_use_the_recurring_dst_rule() -> Result<()> {
+    let mut h = Harness::from_html("<p></p>")?;
+    h.set_time_zone("America/New_York")?;
+    assert_eq!(
+        h.eval(
+            r#"
+              const summer = new Date(Date.UTC(2050, 6, 1, 12));
+              const winter = new Date(Date.UTC(2050, 0, 1, 12));
+              summer.getTimezoneOffset() + ',' + winter.getTimezoneOffset() + ',' + summer.getHours()
+            "#
+        )?,
+        "240,300,8".into()
+    );
+    Ok(())
+}
+
+#[test]
+fn set_time_zone_rejects_unknown_zones_and_survives_snapshots() -> Result<()> {
+    let mut h = Harness::from_html("<p></p>")?;
+    let err = h
+        .set_time_zone("Mars/Olympus_Mons")
+        .expect_err("unknown time zone should be rejected");
+    assert!(
+        err.to_string()
+            .contains("invalid time zone: Mars/Olympus_Mons"),
+        "unexpected error: {err}"
+    );
+    assert_eq!(h.time_zone(), "UTC");
+
+    h.set_time_zone("Europe/London")?;
+    let snapshot = h.snapshot();
+    h.set_time_zone("Asia/Tokyo")?;
+    assert_eq!(h.eval("new Date(0).getHours()")?, JsValue::Number(9.0));
+    h.restore(&snapshot);
+    assert_eq!(h.time_zone(), "Europe/London");
+    assert_eq!(h.eval("new Date(0).getHours()")?, JsValue::Number(1.0));
+    Ok(())
+}
+
+#[test]
+fn ambiguous_zone_abbreviations_are_named_per_zone() -> Result<()> {
+    let mut h = Harness::from_html("<p></p>")?;
+    let zone_name = "String(new Date(Date.UTC(2024, 0, 15, 12))).split('(')[1]";
+    let cases = [
+        ("America/Havana", "Cuba Standard Time)"),
+        ("America/Chicago", "Central Standard Time)"),
+        ("Asia/Shanghai", "China Standard Time)"),
+        ("Asia/Taipei", "Taipei Standard Time)"),
+        ("Asia/Manila", "Philippine Standard Time)"),
+        ("Asia/Jerusalem", "Israel Standard Time)"),
+        ("Asia/Kolkata", "India Standard Time)"),
+    ];
+    for (zone, expected) in cases {
+        h.set_time_zone(zone)?;
+        assert_eq!(h.eval(zone_name)?, expected.into(), "{zone}");
+    }
+    h.set_time_zone("Europe/Dublin")?;
+    assert_eq!(
+        h.eval("String(new Date(Date.UTC(2024, 6, 1, 12))).split('(')[1]")?,
+        "Irish Standard Time)".into()
+    );
+    Ok(())
+}
+
+#[test]
+fn non_finite_setter_arguments_invalidate_the_date() -> Result<()> {
+    let mut h = Harness::from_html("<p></p>")?;
+    h.set_time_zone("America/New_York")?;
+    assert_eq!(
+        h.eval(
+            r#"
+              const d = new Date(2024, 0, 31, 12, 0);
+              const month = d.setMonth(NaN);
+              const invalid = [
+                Number.isNaN(month), Number.isNaN(d.getTime()), Number.isNaN(d.getDate()),
+                Number.isNaN(d.setDate(1)), String(d), d.toLocaleString('en-US'),
+                JSON.stringify({ d }),
+              ].join(',');
+              let iso;
+              try {
+                d.toISOString();
+              } catch (e) {
+                iso = String(e).split(':')[0];
+              }
+              const hours = new Date(0);
+              const noArgs = Number.isNaN(hours.setHours()) && Number.isNaN(+hours);
+              d.setFullYear(2024, 1, 29);
+              const infinite = new Date(0);
+              infinite.setUTCMinutes(Infinity);
+              [invalid, iso, noArgs, d.getDate(), Number.isNaN(infinite.getTime())].join('|')
+            "#
+        )?,
+        "true,true,true,true,Invalid Date,Invalid Date,{\"d\":null}|RangeError|true|29|true".into()
+    );
+    Ok(())
+}
@@ -0,0 +1,105 @@
+use super::*;
+
+#[test]
+fn parser_keeps_comments_and_doctype_as_nodes() -> Result<()> {
+    let html = r#"<!DOCTYPE html>
+        <html><head></head><body>
+        <div id='app'><!--[--><span>a</span><!-- text --><!--]--></div>
+        <p id='out'></p>
+        <script>
+          const app = document.getElementById('app');
+          const kinds = Array.from(app.childNodes).map((node) =>
+            node.nodeType + ':' + node.nodeName + ':' + node.nodeValue
+          );
+          const doctype = document.doctype;
+          document.getElementById('out').textContent = [
+            kinds.join(','),
+            app.textContent,
+            app.firstChild.data,
+            app.firstChild.length,
+            doctype.nodeType === Node.DOCUMENT_TYPE_NODE,
+            doctype.name,
+            doctype.nodeName,
+            String(doctype.textContent),
+            document.firstChild === doctype,
+          ].join('|');
+        </script>
+        </body></html>
+        "#;
+
+    let h = Harness::from_html(html)?;
+    h.assert_text(
+        "#out",
+        "8:#comment:[,1:SPAN:null,8:#comment: text ,8:#comment:]|a|[|1|true|html|html|null|true",
+    )?;
+    assert_eq!(
+        h.dump_dom("#app")?,
+        "<div id=\"app\"><!--[--><span>a</span><!-- text --><!--]--></div>"
+    );
+    Ok(())
+}
+
+#[test]
+fn created_comments_serialize_clone_and_update_data() -> Result<()> {
+    let html = r#"
+        <div id='root'></div>
+        <p id='out'></p>
+        <script>
+          const root = document.getElementById('root');
+          const anchor = document.createComment('anchor');
+          root.appendChild(anchor);
+          root.insertBefore(document.createTextNode('x'), anchor);
+          const pi = document.createProcessingInstruction('xml-stylesheet', 'href="a.css"');
+          root.appendChild(pi);
+
+          const copy = anchor.cloneNode(false);
+          copy.data = 'copy';
+          anchor.nodeValue = 'moved';
+          const deep = root.cloneNode(true);
+          document.getElementById('out').textContent = [
+            root.innerHTML,
+            copy.nodeType,
+            copy.data,
+            String(copy.parentNode),
+            deep.childNodes.length,
+            deep.lastChild.nodeType,
+            deep.lastChild.target,
+            deep.lastChild.isEqualNode(pi),
+            anchor.isEqualNode(copy),
+          ].join('|');
+        </script>
+        "#;
+
+    let h = Harness::from_html(html)?;
+    h.assert_text(
+        "#out",
+        "x<!--moved--><?xml-stylesheet href=\"a.css\">|8|copy|null|3|7|xml-stylesheet|true|false",
+    )?;
+    Ok(())
+}
+
+#[test]
+fn inner_html_and_tree_walker_handle_comment_nodes() -> Result<()> {
+    let html = r#"
+        <div id='root'></div>
+        <p id='out'></p>
+        <script>
+          const root = document.getElementById('root');
+          root.innerHTML = '<!DOCTYPE html><!--a--><b>B<!--b--></b><?php echo 1 ?><![CDATA[x]]>';
+          const walker = document.createTreeWalker(root, NodeFilter.SHOW_COMMENT);
+          const comments = [];
+          for (let node = walker.nextNode(); node; node = walker.nextNode()) {
+            comments.push(node.data);
+          }
+          document.getElementById('out').textContent =
+            comments.join(',') + '|' + root.childNodes.length + '|' + root.outerHTML;
+        </script>
+        "#;
+
+    let h = Harness::from_html(html)?;
+    h.assert_text(
+        "#out",
+        "a,b,?php echo 1 ?,[CDATA[x]]|4|<div id=\"root\"><!--a--><b>B<!--b--></b><!--?php echo 1 ?--><!--[CDATA[x]]--></div>",
+    )?;
+    Ok(())
+}
@@ -0,0 +1,304 @@
+use super::*;
+
+#[test]
+fn define_upgrades_parsed_elements_and_runs_lifecycle_callbacks() -> Result<()> {
+    let html = r#"
+        <my-counter id='counter' count='2'></my-counter>
+        <p id='out'></p>
+        <script>
+          const log = [];
+          class MyCounter extends HTMLElement {
+            static observedAttributes = ['count'];
+            constructor() {
+              super();
+              this.clicks = 0;
+              log.push('constructor');
+            }
+            connectedCallback() {
+              log.push('connected:' + this.id);
+            }
+            disconnectedCallback() {
+              log.push('disconnected');
+            }
+            attributeChangedCallback(name, oldValue, newValue) {
+              log.push(name + ':' + oldValue + '->' + newValue);
+            }
+            increment() {
+              this.clicks += 1;
+              return this.clicks;
+            }
+            get doubled() {
+              return this.clicks * 2;
+            }
+          }
+          const counter = document.getElementById('counter');
+          log.push('before:' + (counter.increment === undefined));
+          customElements.define('my-counter', MyCounter);
+          counter.increment();
+          counter.setAttribute('count', '3');
+          counter.setAttribute('title', 'ignored');
+          counter.remove();
+          document.body.appendChild(counter);
+          document.getElementById('out').textContent = log.join(',') + '|' + counter.doubled
+            + '|' + (counter instanceof MyCounter) + '|' + (customElements.get('my-counter') === MyCounter);
+        </script>
+    "#;
+    let h = Harness::from_html(html)?;
+    h.assert_text(
+        "#out",
+        "before:true,constructor,count:null->2,connected:counter,count:2->3,disconnected,connected:counter|2|true|true",
+    )?;
+    Ok(())
+}
+
+#[test]
+fn created_and_constructed_elements_are_upgraded_synchronously() -> Result<()> {
+    let html = r#"
+        <div id='host'></div>
+        <p id='out'></p>
+        <script>
+          const log = [];
+          class TodoItem extends HTMLElement {
+            constructor() {
+              super();
+              log.push('constructed:' + this.tagName);
+            }
+            connectedCallback() {
+              this.textContent = 'item';
+              log.push('connected');
+            }
+          }
+          customElements.define('todo-item', TodoItem);
+          const created = document.createElement('todo-item');
+          const constructed = new TodoItem();
+          log.push(created.isConnected + ':' + constructed.localName);
+          const host = document.getElementById('host');
+          host.appendChild(created);
+          host.innerHTML += '<todo-item></todo-item>';
+          document.getElementById('out').textContent = log.join(',') + '|'
+            + host.querySelectorAll('todo-item').length + '|' + host.textContent;
+        </script>
+    "#;
+    let h = Harness::from_html(html)?;
+    h.assert_text(
+        "#out",
+        "constructed:TODO-ITEM,constructed:TODO-ITEM,false:todo-item,connected,constructed:TODO-ITEM,connected,constructed:TODO-ITEM,connected|2|itemitem",
+    )?;
+    Ok(())
+}
+
+#[test]
+fn when_defined_and_defined_pseudo_class_track_registration() -> Result<()> {
+    let html = r#"
+        <user-card id='card'></user-card>
+        <span id='plain'></span>
+        <p id='out'></p>
+        <script>
+          const out = document.getElementById('out');
+          const before = document.querySelectorAll(':defined').length;
+          const undefinedBefore = document.querySelectorAll('user-card:not(:defined)').length;
+          customElements.whenDefined('user-card').then((constructor) => {
+            out.textContent += 'resolved:' + (constructor === customElements.get('user-card'))
+              + ':' + document.querySelectorAll('user-card:defined').length;
+          });
+          out.textContent = 'pending:' + undefinedBefore + ';';
+          customElements.define('user-card', class extends HTMLElement {});
+          window.definedCounts = before + '->' + document.querySelectorAll(':defined').length;
+        </script>
+    "#;
+    let mut h = Harness::from_html(html)?;
+    h.assert_text("#out", "pending:1;resolved:true:1")?;
+    let counts = h.eval("window.definedCounts")?;
+    let JsValue::String(counts) = counts else {
+        panic!("expected a string, got {counts:?}");
+    };
+    let (before, after) = counts.split_once("->").expect("counts separator");
+    assert_eq!(
+        after.parse::<usize>().unwrap(),
+        before.parse::<usize>().unwrap() + 1
+    );
+    Ok(())
+}
+
+#[test]
+fn customized_built_in_elements_upgrade_through_is_attribute() -> Result<()> {
+    let html = r#"
+        <button id='parsed' is='confirm-button'>Delete</button>
+        <p id='out'></p>
+        <script>
+          class ConfirmButton extends HTMLButtonElement {
+            constructor() {
+              super();
+              this.confirmed = false;
+            }
+            confirm() {
+              this.confirmed = true;
+              return this.textContent;
+            }
+          }
+          customElements.define('confirm-button', ConfirmButton, { extends: 'button' });
+          const parsed = document.getElementById('parsed');
+          const created = document.createElement('button', { is: 'confirm-button' });
+          const constructed = new ConfirmButton();
+          const autonomous = document.createElement('confirm-button');
+          document.getElementById('out').textContent = [
+            parsed.confirm(),
+            parsed.confirmed,
+            created.confirmed,
+            constructed.outerHTML,
+            autonomous.confirm === undefined,
+            document.querySelectorAll('button:defined').length,
+          ].join('|');
+        </script>
+    "#;
+    let h = Harness::from_html(html)?;
+    h.assert_text(
+        "#out",
+        "Delete|true|false|<button is=\"confirm-button\"></button>|true|1",
+    )?;
+    Ok(())
+}
+
+#[test]
+fn adopted_callback_runs_when_moving_into_another_document() -> Result<()> {
+    let html = r#"
+        <p id='out'></p>
+        <script>
+          const log = [];
+          customElements.define('moving-box', class extends HTMLElement {
+            connectedCallback() { log.push('connected'); }
+            disconnectedCallback() { log.push('disconnected'); }
+            adoptedCallback(oldDocument, newDocument) {
+              log.push([
+                'adopted',
+                oldDocument === document,
+                newDocument === document,
+                (oldDocument === document ? newDocument : oldDocument).querySelector('main') !== null,
+              ].join(':'));
+            }
+          });
+          const box = document.createElement('moving-box');
+          document.body.appendChild(box);
+          const other = new DOMParser().parseFromString('<main></main>', 'text/html');
+          other.body.appendChild(box);
+          document.body.appendChild(box);
+          document.getElementById('out').textContent = log.join(',');
+        </script>
+    "#;
+    let h = Harness::from_html(html)?;
+    h.assert_text(
+        "#out",
+        "connected,disconnected,adopted:true:false:true,connected,disconnected,adopted:false:true:true,connected",
+    )?;
+    Ok(())
+}
+
+#[test]
+fn define_rejects_invalid_names_and_duplicates() -> Result<()> {
+    let mut h = Harness::from_html(
+        "<script>customElements.define('x-one', class extends HTMLElement {});</script>",
+    )?;
+    let err = h
+        .eval("customElements.define('nodash', class extends HTMLElement {})")
+        .expect_err("names without a hyphen are invalid");
+    assert!(format!("{err}").contains("SyntaxError"));
+    let err = h
+        .eval("customElements.define('x-one', class extends HTMLElement {})")
+        .expect_err("names can only be defined once");
+    assert!(format!("{err}").contains("NotSupportedError"));
+    let err = h
+        .eval("customElements.define('x-two', class extends HTMLElement {}, { extends: 'x-one' })")
+        .expect_err("extends must name a built-in element");
+    assert!(format!("{err}").contains("NotSupportedError"));
+    Ok(())
+}
+
+#[test]
+fn upgrade_applies_definitions_to_detached_trees() -> Result<()> {
+    let html = r#"
+        <p id='out'></p>
+        <script>
+          customElements.define('lazy-panel', class extends HTMLElement {
+            constructor() {
+              super();
+              this.ready = true;
+            }
+          });
+          const template = document.createElement('div');
+          template.innerHTML = '<lazy-panel></lazy-panel>';
+          const panel = template.firstElementChild;
+          const before = panel.ready;
+          customElements.upgrade(template);
+          document.getElementById('out').textContent = before + ':' + panel.ready;
+        </script>
+    "#;
+    let h = Harness::from_html(html)?;
+    h.assert_text("#out", "undefined:true")?;
+    Ok(())
+}
+
+#[test]
+fn reactions_run_before_the_dom_method_returns() -> Result<()> {
+    let html = r#"
+        <p id='out'></p>
+        <script>
+          const log = [];
+          customElements.define('x-probe', class extends HTMLElement {
+            static observedAttributes = ['class'];
+            connectedCallback() {
+              log.push('connected');
+            }
+            attributeChangedCallback(name, oldValue, newValue) {
+              log.push(name + ':' + oldValue + '->' + newValue);
+            }
+          });
+          const el = document.createElement('x-probe');
+          const out = document.getElementById('out');
+          out.textContent = (document.body.appendChild(el), log.join(',') || 'none');
+          out.textContent += '|' + (el.classList.add('on'), log.join(','));
+        </script>
+    "#;
+    let h = Harness::from_html(html)?;
+    h.assert_text("#out", "connected|connected,class:null->on")?;
+    Ok(())
+}
+
+#[test]
+fn callback_and_constructor_exceptions_are_reported() -> Result<()> {
+    let html = r#"
+        <x-item id='a'></x-item>
+        <x-item id='b' broken></x-item>
+        <x-item id='c'></x-item>
+        <p id='out'></p>
+        <script>
+          const log = [];
+          customElements.whenDefined('x-item').then(() => {
+            document.getElementById('out').textContent += '|defined';
+          });
+          customElements.define('x-item', class extends HTMLElement {
+            constructor() {
+              super();
+              if (this.hasAttribute('broken')) throw new Error('bad ' + this.id);
+              log.push('upgraded:' + this.id);
+            }
+            connectedCallback() {
+              throw new Error('connect ' + this.id);
+            }
+          });
+          document.getElementById('out').textContent = log.join(',');
+        </script>
+    "#;
+    let mut h = Harness::from_html(html)?;
+    h.assert_text("#out", "upgraded:a,upgraded:c|defined")?;
+    let errors = h
+        .take_console_messages()
+        .into_iter()
+        .filter(|message| message.level == ConsoleLevel::Error)
+        .map(|message| message.args_rendered)
+        .collect::<Vec<_>>();
+    assert_eq!(errors.len(), 3);
+    assert!(errors[0].starts_with("Uncaught") && errors[0].contains("connect a"));
+    assert!(errors[1].contains("bad b"));
+    assert!(errors[2].contains("connect c"));
+    Ok(())
+}
@@ -0,0 +1,175 @@
+use super::*;
+
+#[test]
+fn event_interface_constructors_apply_init_members_and_defaults() -> Result<()> {
+    let html = r#"
+        <button id='b'></button>
+        <p id='out'></p>
+        <script>
+          const b = document.getElementById('b');
+          const focus = new FocusEvent('focus', { relatedTarget: b });
+          const input = new InputEvent('beforeinput', { inputType: 'insertText', data: 'x' });
+          const storage = new StorageEvent('storage');
+          const progress = new ProgressEvent('progress', { lengthComputable: 1, loaded: 5, total: 10 });
+          const toggle = new ToggleEvent('toggle', { oldState: 'closed', newState: 'open' });
+          const message = new MessageEvent('message', { data: { n: 1 }, origin: 'https://a.test' });
+          document.getElementById('out').textContent = [
+            focus.type,
+            focus.relatedTarget === b,
+            focus.detail,
+            input.inputType,
+            input.data,
+            input.isComposing,
+            String(storage.key),
+            String(storage.newValue),
+            storage.url,
+            progress.lengthComputable,
+            progress.loaded + '/' + progress.total,
+            toggle.oldState + '>' + toggle.newState,
+            message.data.n,
+            message.origin,
+            message.ports.length,
+          ].join('|');
+        </script>
+        "#;
+
+    let h = Harness::from_html(html)?;
+    h.assert_text(
+        "#out",
+        "focus|true|0|insertText|x|false|null|null||true|5/10|closed>open|1|https://a.test|0",
+    )?;
+    Ok(())
+}
+
+#[test]
+fn event_interfaces_follow_the_inheritance_chain_for_instanceof() -> Result<()> {
+    let html = r#"
+        <p id='out'></p>
+        <script>
+          const focus = new FocusEvent('focus');
+          const drag = new DragEvent('dragstart');
+          const submit = new SubmitEvent('submit');
+          document.getElementById('out').textContent = [
+            focus instanceof FocusEvent,
+            focus instanceof UIEvent,
+            focus instanceof Event,
+            focus instanceof MouseEvent,
+            drag instanceof MouseEvent,
+            drag instanceof UIEvent,
+            submit instanceof SubmitEvent,
+            submit instanceof UIEvent,
+            new Event('x') instanceof FocusEvent,
+          ].join('|');
+        </script>
+        "#;
+
+    let h = Harness::from_html(html)?;
+    h.assert_text("#out", "true|true|true|false|true|true|true|false|false")?;
+    Ok(())
+}
+
+#[test]
+fn dispatched_interface_events_keep_their_members_in_listeners() -> Result<()> {
+    let html = r#"
+        <div id='target'></div>
+        <p id='out'></p>
+        <script>
+          const target = document.getElementById('target');
+          target.addEventListener('animationend', (event) => {
+            document.getElementById('out').textContent = [
+              event instanceof AnimationEvent,
+              event.animationName,
+              event.elapsedTime,
+              event.pseudoElement === '',
+            ].join('|');
+          });
+          target.dispatchEvent(new AnimationEvent('animationend', { animationName: 'fade', elapsedTime: 1.5 }));
+        </script>
+        "#;
+
+    let h = Harness::from_html(html)?;
+    h.assert_text("#out", "true|fade|1.5|true")?;
+    Ok(())
+}
+
+#[test]
+fn trusted_focus_events_carry_related_target() -> Result<()> {
+    let html = r#"
+        <input id='a'>
+        <input id='b'>
+        <p id='out'></p>
+        <script>
+          const log = [];
+          const name = (node) => (node ? node.id : 'null');
+          for (const id of ['a', 'b']) {
+            const el = document.getElementById(id);
+            for (const type of ['blur', 'focus']) {
+              el.addEventListener(type, (event) => {
+                log.push(type + ':' + id + ':' + name(event.relatedTarget) + ':' +
+                  (event instanceof FocusEvent));
+                document.getElementById('out').textContent = log.join(',');
+              });
+            }
+          }
+        </script>
+        "#;
+
+    let mut h = Harness::from_html(html)?;
+    h.focus("#a")?;
+    h.focus("#b")?;
+    h.assert_text("#out", "focus:a:null:true,blur:a:b:true,focus:b:a:true")?;
+    Ok(())
+}
+
+#[test]
+fn trusted_submit_and_input_events_use_their_interfaces() -> Result<()> {
+    let html = r#"
+        <form id='f'>
+          <input id='name'>
+          <button id='send' type='submit'>Send</button>
+        </form>
+        <p id='out'></p>
+        <script>
+          const log = [];
+          const out = document.getElementById('out');
+          document.getElementById('name').addEventListener('input', (event) => {
+            log.push('input:' + (event instanceof InputEvent) + ':' + event.inputType + ':' + event.data);
+            out.textContent = log.join(',');
+          });
+          document.getElementById('f').addEventListener('submit', (event) => {
+            event.preventDefault();
+            log.push('submit:' + (event instanceof SubmitEvent) + ':' + event.submitter.id);
+            out.textContent = log.join(',');
+          });
+        </script>
+        "#;
+
+    let mut h = Harness::from_html(html)?;
+    h.type_text("#name", "hi")?;
+    h.click("#send")?;
+    h.assert_text("#out", "input:true:insertText:hi,submit:true:send")?;
+    Ok(())
+}
+
+#[test]
+fn trusted_toggle_events_report_old_and_new_state() -> Result<()> {
+    let html = r#"
+        <details id='d'><summary id='s'>More</summary>Body</details>
+        <p id='out'></p>
+        <script>
+          document.getElementById('d').addEventListener('toggle', (event) => {
+            document.getElementById('out').textContent = [
+              event instanceof ToggleEvent,
+              event.oldState,
+              event.newState,
+            ].join('|');
+          });
+        </script>
+        "#;
+
+    let mut h = Harness::from_html(html)?;
+    h.click("#s")?;
+    h.flush()?;
+    h.assert_text("#out", "true|closed|open")?;
+    Ok(())
+}
@@ -0,0 +1,143 @@
+use super::*;
+
+#[test]
+fn once_listeners_are_removed_before_the_callback_runs() -> Result<()> {
+    let html = r#"
+        <button id='btn'>run</button>
+        <p id='out'></p>
+        <script>
+          const btn = document.getElementById('btn');
+          const log = [];
+          function onFirst(event) {
+            log.push('once:' + event.type);
+            btn.dispatchEvent(new Event('ping'));
+          }
+          btn.addEventListener('ping', onFirst, { once: true });
+          btn.addEventListener('ping', () => log.push('always'));
+          function readLog() {
+            return log.join(',');
+          }
+        </script>
+        "#;
+
+    let mut h = Harness::from_html(html)?;
+    h.eval("btn.dispatchEvent(new Event('ping'))")?;
+    assert_eq!(h.eval("readLog()")?, "once:ping,always,always".into());
+    h.eval("btn.dispatchEvent(new Event('ping'))")?;
+    assert_eq!(
+        h.eval("readLog()")?,
+        "once:ping,always,always,always".into()
+    );
+
+    h.eval("btn.addEventListener('ping', onFirst, { once: true })")?;
+    h.eval("btn.dispatchEvent(new Event('ping'))")?;
+    assert_eq!(
+        h.eval("readLog()")?,
+        "once:ping,always,always,always,always,once:ping,always".into()
+    );
+    Ok(())
+}
+
+#[test]
+fn passive_listeners_cannot_cancel_the_default_action() -> Result<()> {
+    let html = r#"
+        <input id='agree' type='checkbox'>
+        <p id='out'></p>
+        <script>
+          const box = document.getElementById('agree');
+          const seen = [];
+          box.addEventListener('click', (event) => {
+            event.preventDefault();
+            seen.push('passive:' + event.defaultPrevented + ':' + event.cancelable);
+          }, { passive: true });
+          box.addEventListener('click', (event) => {
+            seen.push('active:' + event.defaultPrevented);
+            document.getElementById('out').textContent = seen.join(',');
+          });
+        </script>
+        "#;
+
+    let mut h = Harness::from_html(html)?;
+    h.enable_trace(true);
+    h.click("#agree")?;
+    h.assert_text("#out", "passive:false:true,active:false")?;
+    h.assert_checked("#agree", true)?;
+    let logs = h.take_trace_logs();
+    assert!(
+        logs.iter()
+            .any(|line| line.contains("preventDefault ignored in passive click listener")),
+        "missing passive warning: {logs:?}"
+    );
+    Ok(())
+}
+
+#[test]
+fn dispatch_uses_a_snapshot_of_the_listener_list() -> Result<()> {
+    let html = r#"
+        <button id='btn'>run</button>
+        <p id='out'></p>
+        <script>
+          const btn = document.getElementById('btn');
+          const log = [];
+          function late() { log.push('late'); }
+          function second() { log.push('second'); }
+          btn.addEventListener('click', () => {
+            log.push('first');
+            btn.addEventListener('click', late);
+            btn.removeEventListener('click', second);
+          });
+          btn.addEventListener('click', second);
+          function readLog() {
+            return log.join(',');
+          }
+        </script>
+        "#;
+
+    let mut h = Harness::from_html(html)?;
+    h.click("#btn")?;
+    assert_eq!(h.eval("readLog()")?, "first".into());
+    h.click("#btn")?;
+    assert_eq!(h.eval("readLog()")?, "first,first,late".into());
+    Ok(())
+}
+
+#[test]
+fn remove_event_listener_matches_callback_and_capture_only() -> Result<()> {
+    let html = r#"
+        <button id='btn'>run</button>
+        <script>
+          const btn = document.getElementById('btn');
+          const log = [];
+          function record(event) { log.push('record:' + event.eventPhase); }
+          btn.addEventListener('click', record, { once: false, passive: true });
+          btn.addEventListener('click', record);
+          btn.addEventListener('click', record, true);
+          const makeHandler = (name) => () => log.push(name);
+          for (const handler of [makeHandler('a'), makeHandler('b')]) {
+            btn.addEventListener('click', handler);
+          }
+          function readLog() {
+            return log.join(',');
+          }
+        </script>
+        "#;
+
+    let mut h = Harness::from_html(html)?;
+    h.click("#btn")?;
+    assert_eq!(h.eval("readLog()")?, "record:2,record:2,a,b".into());
+
+    h.eval("btn.removeEventListener('click', record, { passive: false, once: true })")?;
+    h.click("#btn")?;
+    assert_eq!(
+        h.eval("readLog()")?,
+        "record:2,record:2,a,b,record:2,a,b".into()
+    );
+
+    h.eval("btn.removeEventListener('click', record, { capture: true })")?;
+    h.click("#btn")?;
+    assert_eq!(
+        h.eval("readLog()")?,
+        "record:2,record:2,a,b,record:2,a,b,a,b".into()
+    );
+    Ok(())
+}
@@ -3152,7 +3152,7 @@ fn get_computed_style_property_value_works() -> Result<()> {
 
     let mut h = Harness::from_html(html)?;
     h.click("#btn")?;
-    h.assert_text("#result", "red:")?;
+    h.assert_text("#result", "rgb(255, 0, 0):0px")?;
     Ok(())
 }
 
@@ -0,0 +1,137 @@
+use super::*;
+
+#[test]
+fn get_submission_navigates_with_urlencoded_query() -> Result<()> {
+    let html = r#"
+        <form id='f' action='/search' method='get'>
+          <input name='q' value='rust tester'>
+          <input type='checkbox' name='exact' checked>
+          <input type='checkbox' name='skip'>
+          <button id='go' name='mode' value='fast'>Go</button>
+        </form>
+        "#;
+
+    let mut h = Harness::from_html_with_url("https://app.local/start?old=1", html)?;
+    h.click("#go")?;
+    assert_eq!(
+        h.take_form_submissions(),
+        vec![FormSubmission {
+            method: "GET".to_string(),
+            url: "https://app.local/search?q=rust+tester&exact=on&mode=fast".to_string(),
+            enctype: "application/x-www-form-urlencoded".to_string(),
+            body: Vec::new(),
+            submitter: Some("#go".to_string()),
+        }]
+    );
+    assert_eq!(
+        h.take_location_navigations(),
+        vec![LocationNavigation {
+            kind: LocationNavigationKind::Assign,
+            from: "https://app.local/start?old=1".to_string(),
+            to: "https://app.local/search?q=rust+tester&exact=on&mode=fast".to_string(),
+        }]
+    );
+    Ok(())
+}
+
+#[test]
+fn post_submission_loads_mock_page_and_records_body() -> Result<()> {
+    let html = r#"
+        <form id='f' action='/login' method='post'>
+          <input name='user' value='a&b'>
+          <textarea name='note'>x</textarea>
+          <button id='go'>Sign in</button>
+        </form>
+        "#;
+
+    let mut h = Harness::from_html_with_url("https://app.local/", html)?;
+    h.set_location_mock_page("https://app.local/login", "<p id='done'>Welcome</p>");
+    h.click("#go")?;
+    h.assert_text("#done", "Welcome")?;
+    let submissions = h.take_form_submissions();
+    assert_eq!(submissions.len(), 1);
+    assert_eq!(submissions[0].method, "POST");
+    assert_eq!(submissions[0].url, "https://app.local/login");
+    assert_eq!(submissions[0].body, b"user=a%26b&note=x".to_vec());
+    Ok(())
+}
+
+#[test]
+fn submitter_overrides_form_attributes() -> Result<()> {
+    let html = r#"
+        <form id='f' action='/a' method='get'>
+          <input name='k' value='v'>
+          <button id='alt' formaction='/b' formmethod='post' formenctype='text/plain'>Alt</button>
+        </form>
+        "#;
+
+    let mut h = Harness::from_html_with_url("https://app.local/", html)?;
+    h.click("#alt")?;
+    let submissions = h.take_form_submissions();
+    assert_eq!(submissions.len(), 1);
+    assert_eq!(submissions[0].method, "POST");
+    assert_eq!(submissions[0].url, "https://app.local/b");
+    assert_eq!(submissions[0].enctype, "text/plain");
+    assert_eq!(submissions[0].body, b"k=v\r\n".to_vec());
+    Ok(())
+}
+
+#[test]
+fn multipart_submission_includes_file_bytes() -> Result<()> {
+    let html = r#"
+        <form id='f' action='/upload' method='post' enctype='multipart/form-data'>
+          <input name='title' value='doc'>
+          <input id='file' type='file' name='attachment'>
+        </form>
+        "#;
+
+    let mut h = Harness::from_html_with_url("https://app.local/", html)?;
+    h.set_input_files("#file", &[MockFile::new("a.txt").with_text("hello")])?;
+    h.submit("#f")?;
+    let submissions = h.take_form_submissions();
+    assert_eq!(submissions.len(), 1);
+    assert_eq!(
+        submissions[0].enctype,
+        "multipart/form-data; boundary=----BrowserTesterFormBoundary"
+    );
+    assert_eq!(
+        String::from_utf8(submissions[0].body.clone()).unwrap_or_default(),
+        "------BrowserTesterFormBoundary\r\n\
+         Content-Disposition: form-data; name=\"title\"\r\n\r\n\
+         doc\r\n\
+         ------BrowserTesterFormBoundary\r\n\
+         Content-Disposition: form-data; name=\"attachment\"; filename=\"a.txt\"\r\n\
+         Content-Type: text/plain\r\n\r\n\
+         hello\r\n\
+         ------BrowserTesterFormBoundary--\r\n"
+    );
+    assert_eq!(submissions[0].submitter, None);
+    Ok(())
+}
+
+#[test]
+fn prevented_and_new_window_submissions_do_not_navigate() -> Result<()> {
+    let html = r#"
+        <form id='stay' action='/x'>
+          <input name='a' value='1'>
+        </form>
+        <form id='popup' action='/y' target='_blank'>
+          <input name='b' value='2'>
+        </form>
+        <script>
+          document.getElementById('stay').addEventListener('submit', (event) => {
+            event.preventDefault();
+          });
+        </script>
+        "#;
+
+    let mut h = Harness::from_html_with_url("https://app.local/", html)?;
+    h.submit("#stay")?;
+    assert!(h.take_form_submissions().is_empty());
+    h.submit("#popup")?;
+    let submissions = h.take_form_submissions();
+    assert_eq!(submissions.len(), 1);
+    assert_eq!(submissions[0].url, "https://app.local/y?b=2");
+    assert!(h.take_location_navigations().is_empty());
+    Ok(())
+}
@@ -0,0 +1,225 @@
+use super::*;
+
+#[test]
+fn element_rect_override_feeds_layout_properties() -> Result<()> {
+    let mut h = Harness::from_html(
+        "<div id='box' style='border: 2px solid black; padding: 4px'></div><p id='out'></p>",
+    )?;
+    h.set_element_rect("#box", DomRect::new(10.0, 300.0, 120.5, 40.0))?;
+    h.scroll_to(0, 100)?;
+    let html = r#"
+        const box = document.getElementById('box');
+        const rect = box.getBoundingClientRect();
+        [
+          box.offsetLeft, box.offsetTop, box.offsetWidth, box.offsetHeight,
+          box.clientWidth, box.clientHeight,
+          rect.x, rect.top, rect.width, rect.bottom,
+        ].join(',')
+    "#;
+    assert_eq!(
+        h.eval(html)?,
+        "10,300,121,40,116,36,10,200,120.5,240".into()
+    );
+    Ok(())
+}
+
+#[test]
+fn intersection_observer_reports_initial_state_and_scroll_changes() -> Result<()> {
+    let mut h = Harness::from_html(
+        r#"
+        <img id='lazy' data-src='/photo.png'>
+        <p id='out'></p>
+        <script>
+          const out = document.getElementById('out');
+          const observer = new IntersectionObserver((entries, self) => {
+            for (const entry of entries) {
+              out.textContent += [
+                entry.target.id,
+                entry.isIntersecting,
+                entry.intersectionRatio,
+                entry.boundingClientRect.top,
+                entry instanceof IntersectionObserverEntry,
+                self === observer,
+              ].join(':') + ';';
+              if (entry.isIntersecting) {
+                entry.target.src = entry.target.dataset.src;
+                self.unobserve(entry.target);
+              }
+            }
+          }, { threshold: [0, 0.5] });
+          observer.observe(document.getElementById('lazy'));
+        </script>
+        "#,
+    )?;
+    h.set_element_rect("#lazy", DomRect::new(0.0, 1000.0, 100.0, 100.0))?;
+    h.assert_text("#out", "")?;
+    h.run_due_timers()?;
+    h.assert_text("#out", "lazy:false:0:0:true:true;")?;
+
+    h.scroll_to(0, 282)?;
+    h.run_due_timers()?;
+    h.assert_text(
+        "#out",
+        "lazy:false:0:0:true:true;lazy:true:0.5:718:true:true;",
+    )?;
+    assert_eq!(
+        h.eval("document.getElementById('lazy').getAttribute('src')")?,
+        "/photo.png".into()
+    );
+
+    h.scroll_to(0, 0)?;
+    h.run_due_timers()?;
+    h.assert_text(
+        "#out",
+        "lazy:false:0:0:true:true;lazy:true:0.5:718:true:true;",
+    )?;
+    Ok(())
+}
+
+#[test]
+fn set_intersecting_forces_ratio_and_crosses_thresholds() -> Result<()> {
+    let mut h = Harness::from_html(
+        r#"
+        <div id='sentinel'></div>
+        <p id='out'></p>
+        <script>
+          const observer = new IntersectionObserver((entries) => {
+            document.getElementById('out').textContent += entries
+              .map((entry) => entry.isIntersecting + '@' + entry.intersectionRatio)
+              .join(',') + ';';
+          }, { rootMargin: '0px 0px 200px', threshold: [0.25, 0.75] });
+          window.marginText = observer.rootMargin + '|' + observer.thresholds.join(',');
+          observer.observe(document.getElementById('sentinel'));
+        </script>
+        "#,
+    )?;
+    assert_eq!(
+        h.eval("window.marginText")?,
+        "0px 0px 200px 0px|0.25,0.75".into()
+    );
+    h.set_intersecting("#sentinel", 0.0)?;
+    h.set_intersecting("#sentinel", 0.5)?;
+    h.run_due_timers()?;
+    h.set_intersecting("#sentinel", 0.6)?;
+    h.run_due_timers()?;
+    h.set_intersecting("#sentinel", 1.0)?;
+    h.flush()?;
+    h.assert_text("#out", "false@0,true@0.5;true@1;")?;
+    assert!(h.set_intersecting("#sentinel", 1.5).is_err());
+    Ok(())
+}
+
+#[test]
+fn resize_observer_reports_content_and_border_box_sizes() -> Result<()> {
+    let mut h = Harness::from_html(
+        r#"
+        <div id='panel' style='padding: 10px'></div>
+        <p id='out'></p>
+        <script>
+          const out = document.getElementById('out');
+          const observer = new ResizeObserver((entries) => {
+            for (const entry of entries) {
+              out.textContent += [
+                entry.target.id,
+                entry.contentRect.x,
+                entry.contentRect.width,
+                entry.contentRect.height,
+                entry.borderBoxSize[0].inlineSize,
+                entry.contentBoxSize[0].blockSize,
+                entry instanceof ResizeObserverEntry,
+              ].join(':') + ';';
+            }
+          });
+          observer.observe(document.getElementById('panel'));
+          window.resizeObserverKind = observer instanceof ResizeObserver;
+        </script>
+        "#,
+    )?;
+    h.run_due_timers()?;
+    h.assert_text("#out", "panel:10:0:0:0:0:true;")?;
+
+    h.set_element_rect("#panel", DomRect::new(0.0, 0.0, 320.0, 120.0))?;
+    h.set_element_rect("#panel", DomRect::new(50.0, 50.0, 320.0, 120.0))?;
+    h.run_due_timers()?;
+    h.assert_text(
+        "#out",
+        "panel:10:0:0:0:0:true;panel:10:300:100:320:100:true;",
+    )?;
+    assert_eq!(h.eval("window.resizeObserverKind")?, JsValue::Bool(true));
+
+    h.set_element_rect("#panel", DomRect::new(0.0, 0.0, 100.0, 100.0))?;
+    h.eval("document.getElementById('out').textContent = ''")?;
+    h.run_due_timers()?;
+    h.assert_text("#out", "panel:10:80:80:100:80:true;")?;
+    Ok(())
+}
+
+#[test]
+fn disconnected_observers_do_not_run_queued_callbacks() -> Result<()> {
+    let mut h = Harness::from_html(
+        r#"
+        <div id='box'></div>
+        <p id='out'>none</p>
+        <script>
+          const observer = new ResizeObserver(() => {
+            document.getElementById('out').textContent = 'called';
+          });
+          observer.observe(document.getElementById('box'));
+          observer.disconnect();
+        </script>
+        "#,
+    )?;
+    h.set_element_rect("#box", DomRect::new(0.0, 0.0, 10.0, 10.0))?;
+    h.flush()?;
+    h.assert_text("#out", "none")?;
+    Ok(())
+}
+
+#[test]
+fn layout_observers_follow_dom_style_and_viewport_changes() -> Result<()> {
+    let mut h = Harness::from_html(
+        r#"
+        <div id='box'></div>
+        <p id='out'></p>
+        <script>
+          const out = document.getElementById('out');
+          const box = document.getElementById('box');
+          new IntersectionObserver((entries) => {
+            for (const entry of entries) out.textContent += 'io:' + entry.isIntersecting + ';';
+          }).observe(box);
+          new ResizeObserver((entries) => {
+            for (const entry of entries) out.textContent += 'ro:' + entry.contentRect.width + ';';
+          }).observe(box);
+        </script>
+        "#,
+    )?;
+    h.run_due_timers()?;
+    h.assert_text("#out", "io:false;ro:0;")?;
+    h.set_element_rect("#box", DomRect::new(0.0, 900.0, 50.0, 50.0))?;
+    h.run_due_timers()?;
+    h.assert_text("#out", "io:false;ro:0;ro:50;")?;
+
+    h.eval("document.getElementById('out').textContent = ''; window.resizeTo(1024, 1000)")?;
+    h.run_due_timers()?;
+    h.assert_text("#out", "io:true;")?;
+
+    h.eval("document.getElementById('out').textContent = ''; box.style.display = 'none'")?;
+    h.run_due_timers()?;
+    h.assert_text("#out", "io:false;ro:0;")?;
+
+    h.eval("document.getElementById('out').textContent = ''; box.style.display = ''")?;
+    h.run_due_timers()?;
+    h.assert_text("#out", "io:true;ro:50;")?;
+
+    h.eval("document.getElementById('out').textContent = ''; box.hidden = true")?;
+    h.run_due_timers()?;
+    h.assert_text("#out", "io:false;ro:0;")?;
+
+    h.eval("document.getElementById('out').textContent = ''; box.hidden = false; box.remove()")?;
+    h.run_due_timers()?;
+    h.assert_text("#out", "")?;
+
+    let id = h.eval("String(setTimeout(() => {}, 10))")?;
+    assert_eq!(id, "1".into());
+    Ok(())
+}
@@ -0,0 +1,193 @@
+use super::*;
+
+#[test]
+fn mutation_records_are_delivered_in_one_microtask_checkpoint() -> Result<()> {
+    let html = r#"
+        <ul id='list'></ul>
+        <p id='out'></p>
+        <script>
+          const log = [];
+          const list = document.getElementById('list');
+          const observer = new MutationObserver((records, self) => {
+            log.push('observer:' + records.length + ':' + (self === observer));
+          });
+          observer.observe(list, { childList: true });
+          list.appendChild(document.createElement('li'));
+          Promise.resolve().then(() => log.push('promise'));
+          list.appendChild(document.createElement('li'));
+          log.push('sync');
+          queueMicrotask(() => {
+            document.getElementById('out').textContent = log.join(',');
+          });
+        </script>
+    "#;
+    let h = Harness::from_html(html)?;
+    h.assert_text("#out", "sync,observer:2:true,promise")?;
+    Ok(())
+}
+
+#[test]
+fn child_list_records_carry_siblings_and_node_lists() -> Result<()> {
+    let html = r#"
+        <div id='root'><span id='a'>a</span><span id='c'>c</span></div>
+        <p id='out'></p>
+        <script>
+          const root = document.getElementById('root');
+          const a = document.getElementById('a');
+          const c = document.getElementById('c');
+          const b = document.createElement('span');
+          b.id = 'b';
+          const observer = new MutationObserver((records) => {
+            document.getElementById('out').textContent = records.map((record) => [
+              record.type,
+              record.target === root,
+              Array.from(record.addedNodes).map((node) => node.id).join('+'),
+              Array.from(record.removedNodes).map((node) => node.id).join('+'),
+              record.previousSibling ? record.previousSibling.id : 'null',
+              record.nextSibling ? record.nextSibling.id : 'null',
+            ].join(':')).join(' ');
+          });
+          observer.observe(root, { childList: true });
+          root.insertBefore(b, c);
+          root.removeChild(a);
+          root.replaceChild(a, c);
+        </script>
+    "#;
+    let h = Harness::from_html(html)?;
+    h.assert_text(
+        "#out",
+        "childList:true:b::a:c childList:true::a:null:b childList:true:a:c:b:null",
+    )?;
+    Ok(())
+}
+
+#[test]
+fn attribute_records_honor_old_value_and_filter() -> Result<()> {
+    let html = r#"
+        <button id='btn' class='idle' title='t'>Go</button>
+        <p id='out'></p>
+        <script>
+          const btn = document.getElementById('btn');
+          const observer = new MutationObserver((records) => {
+            document.getElementById('out').textContent = records
+              .map((record) => record.type + ':' + record.attributeName + ':' + record.oldValue)
+              .join(',');
+          });
+          observer.observe(btn, { attributeOldValue: true, attributeFilter: ['class', 'data-state'] });
+          btn.className = 'busy';
+          btn.setAttribute('title', 'ignored');
+          btn.setAttribute('data-state', 'on');
+          btn.removeAttribute('data-state');
+          btn.removeAttribute('data-missing');
+        </script>
+    "#;
+    let h = Harness::from_html(html)?;
+    h.assert_text(
+        "#out",
+        "attributes:class:idle,attributes:data-state:null,attributes:data-state:on",
+    )?;
+    Ok(())
+}
+
+#[test]
+fn class_list_and_style_writes_produce_attribute_records() -> Result<()> {
+    let html = r#"
+        <div id='box' class='a'></div>
+        <p id='out'></p>
+        <script>
+          const box = document.getElementById('box');
+          const observer = new MutationObserver((records) => {
+            document.getElementById('out').textContent = records
+              .map((record) => record.attributeName + '=' + record.oldValue)
+              .join(' ');
+          });
+          observer.observe(box, { attributes: true, attributeOldValue: true });
+          box.classList.add('b');
+          box.classList.remove('a');
+          box.classList.replace('b', 'c');
+          box.classList.toggle('c');
+          box.style.color = 'red';
+          box.style.color = '';
+        </script>
+    "#;
+    let h = Harness::from_html(html)?;
+    h.assert_text(
+        "#out",
+        "class=a class=a b class=b class=c style=null style=color: red;",
+    )?;
+    Ok(())
+}
+
+#[test]
+fn subtree_observer_sees_character_data_changes() -> Result<()> {
+    let html = r#"
+        <section id='root'><p id='msg'>hello</p></section>
+        <p id='out'></p>
+        <script>
+          const root = document.getElementById('root');
+          const msg = document.getElementById('msg');
+          const text = msg.firstChild;
+          const observer = new MutationObserver((records) => {
+            document.getElementById('out').textContent = records
+              .map((record) => record.type + ':' + (record.target === text) + ':' + record.oldValue)
+              .join(',');
+          });
+          observer.observe(root, { subtree: true, characterDataOldValue: true });
+          text.data = 'hi';
+          text.textContent = 'hey';
+          msg.setAttribute('class', 'x');
+        </script>
+    "#;
+    let h = Harness::from_html(html)?;
+    h.assert_text("#out", "characterData:true:hello,characterData:true:hi")?;
+    Ok(())
+}
+
+#[test]
+fn take_records_and_disconnect_drain_pending_records() -> Result<()> {
+    let html = r#"
+        <div id='box'></div>
+        <p id='out'></p>
+        <script>
+          let calls = 0;
+          const box = document.getElementById('box');
+          const observer = new MutationObserver(() => { calls += 1; });
+          observer.observe(box, { attributes: true, childList: true });
+          box.setAttribute('data-a', '1');
+          const taken = observer.takeRecords();
+          box.appendChild(document.createElement('i'));
+          observer.disconnect();
+          box.setAttribute('data-b', '2');
+          queueMicrotask(() => {
+            document.getElementById('out').textContent = [
+              taken.length,
+              taken[0].attributeName,
+              observer.takeRecords().length,
+              calls,
+              observer instanceof MutationObserver,
+              typeof window.MutationObserver,
+            ].join('|');
+          });
+        </script>
+    "#;
+    let h = Harness::from_html(html)?;
+    h.assert_text("#out", "1|data-a|0|0|true|function")?;
+    Ok(())
+}
+
+#[test]
+fn observe_rejects_invalid_options() -> Result<()> {
+    let mut h = Harness::from_html("<div id='box'></div>")?;
+    let err = h
+        .eval("new MutationObserver(() => {}).observe(document.getElementById('box'), {})")
+        .expect_err("observe without any mutation kind should throw");
+    assert!(format!("{err}").contains("TypeError"));
+    let err = h
+        .eval(
+            "new MutationObserver(() => {}).observe(document.getElementById('box'), \
+             { attributes: false, attributeOldValue: true })",
+        )
+        .expect_err("attributeOldValue with attributes: false should throw");
+    assert!(format!("{err}").contains("TypeError"));
+    Ok(())
+}
@@ -0,0 +1,263 @@
+use super::*;
+
+const LOGGING_SCRIPT: &str = r#"
+  <script>
+    const log = [];
+    const types = [
+      'pointerover', 'pointerenter', 'pointerout', 'pointerleave',
+      'mouseover', 'mouseenter', 'mouseout', 'mouseleave',
+      'pointermove', 'mousemove', 'pointerdown', 'mousedown', 'pointerup', 'mouseup',
+      'click', 'dblclick', 'auxclick', 'contextmenu', 'focus', 'blur'
+    ];
+    for (const el of document.querySelectorAll('[data-log]')) {
+      for (const type of types) {
+        el.addEventListener(type, (e) => {
+          if (e.target !== e.currentTarget && e.bubbles) return;
+          const fields = e.button === undefined
+            ? ''
+            : '(' + [e.button, e.buttons, e.detail, e.clientX, e.clientY].join('/') + ')';
+          log.push(el.id + ':' + type + fields);
+        });
+      }
+    }
+  </script>
+"#;
+
+fn logging_harness(body: &str) -> Result<Harness> {
+    Harness::from_html(&format!("{body}{LOGGING_SCRIPT}"))
+}
+
+#[test]
+fn hover_fires_boundary_events_and_feeds_hover_pseudo_class() -> Result<()> {
+    let mut h = logging_harness(
+        "<div id='menu' data-log><button id='item' data-log>Open</button></div><p id='other' data-log>x</p>",
+    )?;
+    h.set_element_rect("#menu", DomRect::new(0.0, 0.0, 200.0, 100.0))?;
+    h.set_element_rect("#item", DomRect::new(10.0, 10.0, 40.0, 20.0))?;
+    h.set_element_rect("#other", DomRect::new(0.0, 200.0, 50.0, 50.0))?;
+
+    h.hover("#item")?;
+    assert_eq!(
+        h.eval("log.splice(0).join(' ')")?,
+        "item:pointerover(-1/0/0/30/20) menu:pointerenter(-1/0/0/30/20) item:pointerenter(-1/0/0/30/20) \
+         item:mouseover(0/0/0/30/20) menu:mouseenter(0/0/0/30/20) item:mouseenter(0/0/0/30/20) \
+         item:pointermove(-1/0/0/30/20) item:mousemove(0/0/0/30/20)"
+            .into()
+    );
+    assert_eq!(
+        h.eval("[...document.querySelectorAll(':hover')].map((el) => el.id).join(',')")?,
+        "menu,item".into()
+    );
+
+    h.hover("#other")?;
+    assert_eq!(
+        h.eval("log.splice(0).filter((entry) => !entry.includes('move')).map((entry) => entry.split('(')[0]).join(' ')")?,
+        "item:pointerout item:pointerleave menu:pointerleave item:mouseout item:mouseleave menu:mouseleave \
+         other:pointerover other:pointerenter other:mouseover other:mouseenter"
+            .into()
+    );
+
+    h.unhover()?;
+    assert_eq!(
+        h.eval("log.splice(0).map((entry) => entry.split('(')[0]).join(' ')")?,
+        "other:pointerout other:pointerleave other:mouseout other:mouseleave".into()
+    );
+    assert_eq!(
+        h.eval("document.querySelectorAll(':hover').length")?,
+        JsValue::Number(0.0)
+    );
+    Ok(())
+}
+
+#[test]
+fn hover_rules_in_stylesheets_drive_visibility() -> Result<()> {
+    let html = r#"
+        <style>.tooltip { display: none; } .has-tooltip:hover .tooltip { display: block; }</style>
+        <span class='has-tooltip' id='anchor'>?<span class='tooltip' id='tip'>Help</span></span>
+        "#;
+    let mut h = Harness::from_html(html)?;
+    assert!(!h.is_visible("#tip")?);
+    h.hover("#anchor")?;
+    assert!(h.is_visible("#tip")?);
+    h.unhover()?;
+    assert!(!h.is_visible("#tip")?);
+    Ok(())
+}
+
+#[test]
+fn double_click_counts_clicks_and_fires_dblclick() -> Result<()> {
+    let mut h = logging_harness("<button id='cell' data-log>Edit</button>")?;
+    h.double_click("#cell")?;
+    assert_eq!(
+        h.eval("log.filter((entry) => !/over|enter|move/.test(entry)).join(' ')")?,
+        "cell:pointerdown(0/1/0/0/0) cell:mousedown(0/1/1/0/0) cell:focus cell:pointerup(0/0/0/0/0) \
+         cell:mouseup(0/0/1/0/0) cell:click(0/0/1/0/0) cell:pointerdown(0/1/0/0/0) cell:mousedown(0/1/2/0/0) \
+         cell:pointerup(0/0/0/0/0) cell:mouseup(0/0/2/0/0) cell:click(0/0/2/0/0) cell:dblclick(0/0/2/0/0)"
+            .into()
+    );
+
+    // A third quick press counts on; presses further apart start over.
+    h.eval("log.length = 0")?;
+    h.mouse_down(MouseButton::Left)?;
+    h.mouse_up(MouseButton::Left)?;
+    h.advance_time(600)?;
+    h.mouse_down(MouseButton::Left)?;
+    h.mouse_up(MouseButton::Left)?;
+    h.advance_time(600)?;
+    h.mouse_down(MouseButton::Left)?;
+    h.mouse_up(MouseButton::Left)?;
+    assert_eq!(
+        h.eval("log.filter((entry) => /click/.test(entry)).join(' ')")?,
+        "cell:click(0/0/3/0/0) cell:click(0/0/1/0/0) cell:click(0/0/1/0/0)".into()
+    );
+    Ok(())
+}
+
+#[test]
+fn right_click_fires_auxclick_and_contextmenu_without_click() -> Result<()> {
+    let mut h =
+        logging_harness("<input id='field'><div id='row' data-log tabindex='-1'>Row</div>")?;
+    h.focus("#field")?;
+    h.right_click("#row")?;
+    assert_eq!(
+        h.eval("log.filter((entry) => !/over|enter|move/.test(entry)).join(' ')")?,
+        "row:pointerdown(2/2/0/0/0) row:mousedown(2/2/1/0/0) row:focus row:pointerup(2/0/0/0/0) \
+         row:mouseup(2/0/1/0/0) row:auxclick(2/0/1/0/0) row:contextmenu(2/0/1/0/0)"
+            .into()
+    );
+    Ok(())
+}
+
+#[test]
+fn press_move_release_uses_coordinates_active_state_and_common_ancestor() -> Result<()> {
+    let mut h = logging_harness(
+        "<ul id='list' data-log><li id='first' data-log>1</li><li id='second' data-log>2</li></ul>",
+    )?;
+    h.set_element_rect("#list", DomRect::new(0.0, 0.0, 100.0, 100.0))?;
+    h.set_element_rect("#first", DomRect::new(0.0, 0.0, 100.0, 50.0))?;
+    h.set_element_rect("#second", DomRect::new(0.0, 50.0, 100.0, 50.0))?;
+
+    h.mouse_move(20.0, 10.0)?;
+    h.mouse_down(MouseButton::Left)?;
+    assert_eq!(
+        h.eval("[...document.querySelectorAll(':active')].map((el) => el.id).join(',')")?,
+        "list,first".into()
+    );
+    h.eval("log.length = 0")?;
+    h.mouse_move(30.0, 70.0)?;
+    h.mouse_up(MouseButton::Left)?;
+    assert_eq!(
+        h.eval("log.filter((entry) => /move|up|click/.test(entry)).join(' ')")?,
+        "second:pointermove(-1/1/0/30/70) second:mousemove(0/1/0/30/70) second:pointerup(0/0/0/30/70) \
+         second:mouseup(0/0/1/30/70) list:click(0/0/1/30/70)"
+            .into()
+    );
+    assert_eq!(
+        h.eval("document.querySelectorAll(':active').length")?,
+        JsValue::Number(0.0)
+    );
+    Ok(())
+}
+
+#[test]
+fn pointer_capture_retargets_events_until_release() -> Result<()> {
+    let html = r#"
+        <div id='thumb'>thumb</div><div id='track'>track</div>
+        <script>
+          const log = [];
+          const thumb = document.getElementById('thumb');
+          thumb.addEventListener('pointerdown', (e) => thumb.setPointerCapture(e.pointerId));
+          for (const el of [thumb, document.getElementById('track')]) {
+            for (const type of ['gotpointercapture', 'lostpointercapture', 'pointermove', 'mousemove', 'pointerup', 'click']) {
+              el.addEventListener(type, (e) => log.push(el.id + ':' + type + (e.pointerId ? ':' + e.pointerId + ':' + e.pointerType : '')));
+            }
+          }
+        </script>
+        "#;
+    let mut h = Harness::from_html(html)?;
+    h.set_element_rect("#thumb", DomRect::new(0.0, 0.0, 20.0, 20.0))?;
+    h.set_element_rect("#track", DomRect::new(100.0, 0.0, 200.0, 20.0))?;
+    h.hover("#thumb")?;
+    h.eval("log.length = 0")?;
+    h.mouse_down(MouseButton::Left)?;
+    h.mouse_move(150.0, 10.0)?;
+    h.mouse_up(MouseButton::Left)?;
+    h.mouse_move(160.0, 10.0)?;
+    assert_eq!(
+        h.eval("log.join(' ')")?,
+        "thumb:gotpointercapture:1:mouse thumb:pointermove:1:mouse thumb:mousemove \
+         thumb:pointerup:1:mouse thumb:lostpointercapture:1:mouse thumb:click \
+         track:pointermove:1:mouse track:mousemove"
+            .into()
+    );
+    Ok(())
+}
+
+#[test]
+fn canceled_pointerdown_suppresses_mouse_events_but_not_focus_or_click() -> Result<()> {
+    let html = r#"
+        <input id='field'>
+        <p id='result'></p>
+        <script>
+          const log = [];
+          const field = document.getElementById('field');
+          field.addEventListener('pointerdown', (e) => e.preventDefault());
+          for (const type of ['mousedown', 'mouseup', 'focus', 'click']) {
+            field.addEventListener(type, (e) => log.push(type + ':' + e.isTrusted));
+          }
+        </script>
+        "#;
+    let mut h = Harness::from_html(html)?;
+    h.hover("#field")?;
+    h.mouse_down(MouseButton::Left)?;
+    h.mouse_up(MouseButton::Left)?;
+    assert_eq!(
+        h.eval("log.join(' ') + '|' + (document.activeElement === field)")?,
+        "focus:true click:true|true".into()
+    );
+    Ok(())
+}
+
+#[test]
+fn canceled_pointerdown_keeps_mouse_boundary_events() -> Result<()> {
+    let mut h = logging_harness(
+        "<button id='source' data-log>a</button><button id='dest' data-log>b</button>",
+    )?;
+    h.set_element_rect("#source", DomRect::new(0.0, 0.0, 50.0, 20.0))?;
+    h.set_element_rect("#dest", DomRect::new(100.0, 0.0, 50.0, 20.0))?;
+    h.eval(
+        "document.getElementById('source').addEventListener('pointerdown', (e) => e.preventDefault())",
+    )?;
+
+    h.hover("#source")?;
+    h.mouse_down(MouseButton::Left)?;
+    h.eval("log.length = 0")?;
+    h.mouse_move(125.0, 10.0)?;
+    assert_eq!(
+        h.eval("log.splice(0).map((entry) => entry.split('(')[0]).join(' ')")?,
+        "source:pointerout source:pointerleave source:mouseout source:mouseleave \
+         dest:pointerover dest:pointerenter dest:mouseover dest:mouseenter dest:pointermove"
+            .into()
+    );
+    Ok(())
+}
+
+#[test]
+fn removing_the_hovered_element_hands_hover_to_its_ancestor() -> Result<()> {
+    let mut h = logging_harness(
+        "<div id='menu' data-log><button id='item' data-log>Open</button><span id='label' data-log>x</span></div>",
+    )?;
+    h.set_element_rect("#menu", DomRect::new(0.0, 0.0, 200.0, 100.0))?;
+    h.set_element_rect("#item", DomRect::new(10.0, 10.0, 40.0, 20.0))?;
+    h.set_element_rect("#label", DomRect::new(100.0, 10.0, 40.0, 20.0))?;
+
+    h.hover("#item")?;
+    h.eval("document.getElementById('item').remove(); log.length = 0")?;
+    h.hover("#label")?;
+    assert_eq!(
+        h.eval("log.splice(0).filter((entry) => !entry.includes('move')).map((entry) => entry.split('(')[0]).join(' ')")?,
+        "menu:pointerout menu:mouseout label:pointerover label:pointerenter label:mouseover label:mouseenter"
+            .into()
+    );
+    Ok(())
+}
@@ -0,0 +1,202 @@
+use super::*;
+
+const STORAGE_LOG_PAGE: &str = r#"
+    <p id='out'></p>
+    <script>
+      window.addEventListener('storage', (event) => {
+        document.getElementById('out').textContent += [
+          event.key,
+          event.oldValue,
+          event.newValue,
+          event.url,
+          event.storageArea === localStorage,
+          event instanceof StorageEvent,
+          event.isTrusted,
+        ].join('|') + ';';
+      });
+    </script>
+"#;
+
+#[test]
+fn same_origin_pages_share_local_storage_and_get_storage_events() -> Result<()> {
+    let mut win = MockWindow::new();
+    win.open_page("https://app.local/a", STORAGE_LOG_PAGE)?;
+    win.open_page("https://other.local/", STORAGE_LOG_PAGE)?;
+    win.open_page("https://app.local/b", STORAGE_LOG_PAGE)?;
+
+    win.eval("localStorage.setItem('theme', 'dark')")?;
+    win.eval("localStorage.setItem('theme', 'dark')")?;
+    win.eval("localStorage.removeItem('theme')")?;
+    win.eval("localStorage.setItem('lang', 'en'); localStorage.clear()")?;
+    win.assert_text("#out", "")?;
+
+    win.switch_to("https://app.local/a")?;
+    win.assert_text(
+        "#out",
+        "theme||dark|https://app.local/b|true|true|true;\
+         theme|dark||https://app.local/b|true|true|true;\
+         lang||en|https://app.local/b|true|true|true;\
+         |||https://app.local/b|true|true|true;",
+    )?;
+
+    win.switch_to("https://other.local/")?;
+    win.assert_text("#out", "")?;
+    win.eval("localStorage.setItem('theme', 'light')")?;
+    win.switch_to("https://app.local/a")?;
+    assert_eq!(win.eval("localStorage.getItem('theme')")?, JsValue::Null);
+    assert!(win.current_document()?.local_storage_items().is_empty());
+    Ok(())
+}
+
+#[test]
+fn storage_property_assignment_notifies_other_pages() -> Result<()> {
+    let mut win = MockWindow::new();
+    win.open_page("https://app.local/a", STORAGE_LOG_PAGE)?;
+    win.open_page("https://app.local/b", "<p>b</p>")?;
+
+    win.eval("localStorage.count = 1; localStorage.count = '1'")?;
+    win.switch_to("https://app.local/a")?;
+    win.assert_text("#out", "count||1|https://app.local/b|true|true|true;")?;
+    assert_eq!(
+        win.current_document()?.local_storage_items(),
+        vec![("count".to_string(), "1".to_string())]
+    );
+    Ok(())
+}
+
+#[test]
+fn standalone_harness_storage_changes_are_not_queued() -> Result<()> {
+    let mut h = Harness::from_html("<p></p>")?;
+    h.eval("localStorage.setItem('a', '1'); localStorage.removeItem('a')")?;
+    assert!(h.take_browsing_context_requests().is_empty());
+    Ok(())
+}
+
+#[test]
+fn same_origin_pages_share_the_cookie_jar() -> Result<()> {
+    let mut win = MockWindow::new();
+    win.open_page(
+        "https://app.local/login",
+        "<script>document.cookie = 'sid=abc; path=/';</script>",
+    )?;
+    win.open_page("https://app.local/account", "<p>account</p>")?;
+    assert_eq!(win.eval("document.cookie")?, "sid=abc".into());
+
+    win.eval("document.cookie = 'theme=dark; path=/'")?;
+    win.switch_to("https://app.local/login")?;
+    assert_eq!(win.eval("document.cookie")?, "sid=abc; theme=dark".into());
+
+    win.open_page("https://other.local/", "<p>other</p>")?;
+    assert_eq!(win.eval("document.cookie")?, "".into());
+    Ok(())
+}
+
+#[test]
+fn session_storage_is_per_page_and_survives_navigation() -> Result<()> {
+    let mut h = Harness::from_html_with_url_and_session_storage(
+        "https://app.local/start",
+        r#"
+            <button id='next'>Next</button>
+            <script>
+              document.getElementById('next').addEventListener('click', () => {
+                sessionStorage.setItem('step', '1');
+                location.assign('/next');
+              });
+            </script>
+        "#,
+        &[("draft", "seed")],
+    )?;
+    h.set_location_mock_page(
+        "https://app.local/next",
+        r#"
+            <p id='out'></p>
+            <script>
+              document.getElementById('out').textContent =
+                sessionStorage.getItem('draft') + '|' + sessionStorage.getItem('step') +
+                '|' + localStorage.length + '|' + (window.sessionStorage === sessionStorage);
+            </script>
+        "#,
+    );
+
+    h.click("#next")?;
+    h.assert_text("#out", "seed|1|0|true")?;
+    assert_eq!(
+        h.session_storage_items(),
+        vec![
+            ("draft".to_string(), "seed".to_string()),
+            ("step".to_string(), "1".to_string()),
+        ]
+    );
+    assert!(h.local_storage_items().is_empty());
+    Ok(())
+}
+
+#[test]
+fn session_storage_is_keyed_by_origin_across_navigation() -> Result<()> {
+    let mut h = Harness::from_html_with_url_and_session_storage(
+        "https://app.local/start",
+        "<script>sessionStorage.setItem('step', '1');</script>",
+        &[("draft", "seed")],
+    )?;
+    h.set_location_mock_page(
+        "https://other.local/",
+        r#"
+            <p id='out'></p>
+            <script>
+              document.getElementById('out').textContent =
+                sessionStorage.length + '|' + (window.sessionStorage === sessionStorage);
+              sessionStorage.setItem('other', 'x');
+            </script>
+        "#,
+    );
+    h.set_location_mock_page(
+        "https://app.local/back",
+        r#"
+            <p id='out'></p>
+            <script>
+              document.getElementById('out').textContent = [
+                sessionStorage.getItem('draft'),
+                sessionStorage.getItem('step'),
+                sessionStorage.getItem('other'),
+              ].join('|');
+            </script>
+        "#,
+    );
+
+    h.eval("location.assign('https://other.local/')")?;
+    h.assert_text("#out", "0|true")?;
+    assert_eq!(h.eval("sessionStorage.getItem('other')")?, "x".into());
+    assert_eq!(
+        h.session_storage_items(),
+        vec![("other".to_string(), "x".to_string())]
+    );
+
+    h.eval("location.assign('https://app.local/back')")?;
+    h.assert_text("#out", "seed|1|")?;
+    assert_eq!(
+        h.eval("sessionStorage.length + '|' + sessionStorage.getItem('other')")?,
+        "2|null".into()
+    );
+    Ok(())
+}
+
+#[test]
+fn session_storage_is_not_shared_between_pages() -> Result<()> {
+    let mut win = MockWindow::new();
+    win.open_page(
+        "https://app.local/a",
+        "<script>sessionStorage.setItem('tab', 'a');</script>",
+    )?;
+    win.open_page("https://app.local/b", STORAGE_LOG_PAGE)?;
+    assert_eq!(win.eval("sessionStorage.getItem('tab')")?, JsValue::Null);
+    win.eval("sessionStorage.setItem('tab', 'b')")?;
+
+    win.switch_to("https://app.local/a")?;
+    assert_eq!(
+        win.current_document()?.session_storage_items(),
+        vec![("tab".to_string(), "a".to_string())]
+    );
+    win.switch_to("https://app.local/b")?;
+    win.assert_text("#out", "")?;
+    Ok(())
+}
@@ -0,0 +1,149 @@
+use super::*;
+
+#[test]
+fn harness_eval_returns_completion_value_of_trailing_expression() -> Result<()> {
+    let html = r#"
+        <script>
+          let counter = 41;
+          function bump() { counter += 1; return counter; }
+        </script>
+        "#;
+
+    let mut h = Harness::from_html(html)?;
+    assert_eq!(h.eval("bump()")?, JsValue::Number(42.0));
+    assert_eq!(h.eval("counter")?, JsValue::Number(42.0));
+    assert_eq!(
+        h.eval("const local = 'a' + 'b'; local.toUpperCase()")?,
+        "AB".into()
+    );
+    assert_eq!(h.eval("let unused = 1;")?, JsValue::Undefined);
+    assert_eq!(h.eval("null")?, JsValue::Null);
+    assert_eq!(h.eval("1 / 2")?, JsValue::Number(0.5));
+    assert_eq!(
+        h.eval("10n ** 20n")?,
+        JsValue::BigInt("100000000000000000000".into())
+    );
+    Ok(())
+}
+
+#[test]
+fn harness_eval_converts_structured_values() -> Result<()> {
+    let html = r#"
+        <div id='box'><span class='item'>a</span><span class='item'>b</span></div>
+        <script>
+          var state = {
+            name: 'cart',
+            items: [1, 'two', true, undefined],
+            when: new Date(86400000),
+            tags: new Set(['x', 'y']),
+            index: new Map([['k', { nested: 1 }]]),
+          };
+        </script>
+        "#;
+
+    let mut h = Harness::from_html(html)?;
+    let state = h.eval("state")?;
+    assert_eq!(state.get("name"), Some(&JsValue::from("cart")));
+    assert_eq!(
+        state.get("items"),
+        Some(&JsValue::Array(vec![
+            JsValue::Number(1.0),
+            JsValue::from("two"),
+            JsValue::Bool(true),
+            JsValue::Undefined,
+        ]))
+    );
+    assert_eq!(state.get("when"), Some(&JsValue::Date(86_400_000)));
+    assert_eq!(
+        state.get("tags"),
+        Some(&JsValue::Set(vec!["x".into(), "y".into()]))
+    );
+    assert_eq!(
+        state.get("index").and_then(|index| index.get("k")),
+        Some(&JsValue::Object(vec![(
+            "nested".into(),
+            JsValue::Number(1.0)
+        )]))
+    );
+
+    let node = h.eval("document.getElementById('box')")?;
+    assert_eq!(node, JsValue::Node(h.node_handle("#box")?));
+    let list = h.eval("document.querySelectorAll('.item')")?;
+    assert!(matches!(list, JsValue::NodeList(ref nodes) if nodes.len() == 2));
+    assert_eq!(h.eval("() => 1")?, JsValue::Function);
+    Ok(())
+}
+
+#[test]
+fn harness_eval_marks_circular_references_and_keeps_shared_ones() -> Result<()> {
+    let mut h = Harness::from_html("<p></p>")?;
+    let value = h.eval(
+        "const shared = { v: 1 }; const root = { a: shared, b: shared }; root.self = root; root",
+    )?;
+    assert_eq!(
+        value.get("a"),
+        Some(&JsValue::Object(vec![("v".into(), JsValue::Number(1.0))]))
+    );
+    assert_eq!(value.get("a"), value.get("b"));
+    assert_eq!(value.get("self"), Some(&JsValue::Circular));
+    Ok(())
+}
+
+#[test]
+fn harness_eval_surfaces_thrown_errors() -> Result<()> {
+    let mut h = Harness::from_html("<p></p>")?;
+    let err = h
+        .eval("throw new Error('boom')")
+        .expect_err("throw should surface as an error");
+    assert!(matches!(err, Error::ScriptThrown(_)), "unexpected: {err:?}");
+    Ok(())
+}
+
+#[test]
+fn harness_eval_async_awaits_returned_promise() -> Result<()> {
+    let html = r#"
+        <script>
+          async function load() {
+            await Promise.resolve();
+            return { ok: true };
+          }
+        </script>
+        "#;
+
+    let mut h = Harness::from_html(html)?;
+    assert_eq!(
+        h.eval_async("load()")?,
+        JsValue::Object(vec![("ok".into(), JsValue::Bool(true))])
+    );
+    assert_eq!(h.eval_async("7")?, JsValue::Number(7.0));
+
+    let err = h
+        .eval_async("Promise.reject(new Error('nope'))")
+        .expect_err("rejection should surface as an error");
+    assert!(matches!(err, Error::ScriptThrown(_)), "unexpected: {err:?}");
+
+    let err = h
+        .eval_async("new Promise(() => {})")
+        .expect_err("a never-settling promise should fail");
+    assert!(
+        matches!(err, Error::ScriptRuntime(_)),
+        "unexpected: {err:?}"
+    );
+    Ok(())
+}
+
+#[test]
+fn harness_eval_async_runs_due_timers_without_advancing_clock() -> Result<()> {
+    let mut h = Harness::from_html("<p></p>")?;
+    let value = h.eval_async("new Promise((resolve) => setTimeout(() => resolve('done'), 0))")?;
+    assert_eq!(value, "done".into());
+
+    let err = h
+        .eval_async("new Promise((resolve) => setTimeout(() => resolve('late'), 50))")
+        .expect_err("future timers need advance_time");
+    assert!(
+        matches!(err, Error::ScriptRuntime(_)),
+        "unexpected: {err:?}"
+    );
+    Ok(())
+}
@@ -0,0 +1,178 @@
+use super::*;
+
+#[test]
+fn focus_order_puts_positive_tabindex_first_and_skips_unfocusable_elements() -> Result<()> {
+    let html = r#"
+        <button id='plain'>plain</button>
+        <input id='second' tabindex='2'>
+        <a id='first' href='#' tabindex='1'>first</a>
+        <button id='disabled' disabled>disabled</button>
+        <div inert><button id='inert'>inert</button></div>
+        <div hidden><button id='hidden'>hidden</button></div>
+        <div style='display: none'><button id='undisplayed'>undisplayed</button></div>
+        <details>
+          <summary id='summary'>more</summary>
+          <button id='collapsed'>collapsed</button>
+        </details>
+        <span id='skipped' tabindex='-1'>skipped</span>
+        <div id='custom' tabindex='0'>custom</div>
+        <input type='hidden' id='hidden-input'>
+        "#;
+
+    let h = Harness::from_html(html)?;
+    assert_eq!(
+        h.focus_order(),
+        vec!["#first", "#second", "#plain", "#summary", "#custom"]
+    );
+    Ok(())
+}
+
+#[test]
+fn press_tab_moves_focus_with_keydown_and_focus_events() -> Result<()> {
+    let html = r#"
+        <input id='a'>
+        <button id='b'>b</button>
+        <p id='out'></p>
+        <script>
+          const log = [];
+          for (const id of ['a', 'b']) {
+            const el = document.getElementById(id);
+            for (const type of ['keydown', 'focusout', 'blur', 'focusin', 'focus']) {
+              el.addEventListener(type, (event) => {
+                const key = event.key ? ':' + event.key + (event.shiftKey ? '+shift' : '') : '';
+                log.push(type + '@' + id + key);
+              });
+            }
+          }
+          function readLog() {
+            const text = log.join(' ');
+            log.length = 0;
+            return text;
+          }
+          function activeId() {
+            const active = document.activeElement;
+            return active === document.body ? 'body' : active.id;
+          }
+        </script>
+        "#;
+
+    let mut h = Harness::from_html(html)?;
+    h.press_tab()?;
+    assert_eq!(h.eval("readLog()")?, "focusin@a focus@a".into());
+    assert_eq!(h.eval("activeId()")?, "a".into());
+
+    h.press_tab()?;
+    assert_eq!(
+        h.eval("readLog()")?,
+        "keydown@a:Tab focusout@a blur@a focusin@b focus@b".into()
+    );
+
+    h.press_shift_tab()?;
+    assert_eq!(
+        h.eval("readLog()")?,
+        "keydown@b:Tab+shift focusout@b blur@b focusin@a focus@a".into()
+    );
+
+    h.press_shift_tab()?;
+    assert_eq!(
+        h.eval("readLog()")?,
+        "keydown@a:Tab+shift focusout@a blur@a".into()
+    );
+    assert_eq!(h.eval("activeId()")?, "body".into());
+    Ok(())
+}
+
+#[test]
+fn press_tab_honours_prevent_default_on_keydown() -> Result<()> {
+    let html = r#"
+        <input id='trap'>
+        <button id='next'>next</button>
+        <script>
+          document.getElementById('trap').addEventListener('keydown', (event) => {
+            if (event.key === 'Tab') event.preventDefault();
+          });
+          function activeId() {
+            const active = document.activeElement;
+            return active === document.body ? 'body' : active.id;
+          }
+        </script>
+        "#;
+
+    let mut h = Harness::from_html(html)?;
+    h.focus("#trap")?;
+    h.press_tab()?;
+    assert_eq!(h.eval("activeId()")?, "trap".into());
+    Ok(())
+}
+
+#[test]
+fn focus_order_walks_into_shadow_roots_and_slotted_content() -> Result<()> {
+    let html = r#"
+        <button id='before'>before</button>
+        <div id='host'><button id='light' slot='action'>light</button></div>
+        <button id='after'>after</button>
+        <script>
+          const root = document.getElementById('host').attachShadow({ mode: 'open' });
+          root.innerHTML =
+            "<input id='inner'><slot name='action'></slot><button id='tail'>tail</button>";
+          let focused = 'none';
+          root.querySelector('#inner').addEventListener('focus', () => {
+            focused = 'inner';
+          });
+          function focusedInShadow() {
+            return focused;
+          }
+        </script>
+        "#;
+
+    let mut h = Harness::from_html(html)?;
+    assert_eq!(
+        h.focus_order(),
+        vec!["#before", "#inner", "#light", "#tail", "#after"]
+    );
+    h.press_tab()?;
+    h.press_tab()?;
+    assert_eq!(h.eval("focusedInShadow()")?, "inner".into());
+
+    h.eval("document.getElementById('host').remove()")?;
+    assert_eq!(h.focus_order(), vec!["#before", "#after"]);
+    Ok(())
+}
+
+#[test]
+fn focus_order_is_confined_to_the_topmost_modal_dialog() -> Result<()> {
+    let html = r#"
+        <button id='outside'>outside</button>
+        <dialog id='settings'>
+          <input id='name'>
+          <button id='close'>close</button>
+        </dialog>
+        <script>
+          const dialog = document.getElementById('settings');
+          function open(modal) {
+            if (modal) dialog.showModal(); else dialog.show();
+          }
+          function activeId() {
+            const active = document.activeElement;
+            return active === document.body ? 'body' : active.id;
+          }
+        </script>
+        "#;
+
+    let mut h = Harness::from_html(html)?;
+    assert_eq!(h.focus_order(), vec!["#outside"]);
+
+    h.eval("open(false)")?;
+    assert_eq!(h.focus_order(), vec!["#outside", "#name", "#close"]);
+    h.eval("dialog.close()")?;
+
+    h.eval("open(true)")?;
+    assert_eq!(h.focus_order(), vec!["#name", "#close"]);
+    h.press_tab()?;
+    h.press_tab()?;
+    assert_eq!(h.eval("activeId()")?, "close".into());
+
+    h.eval("dialog.close()")?;
+    assert_eq!(h.focus_order(), vec!["#outside"]);
+    Ok(())
+}
@@ -0,0 +1,149 @@
+use super::*;
+
+#[test]
+fn type_keys_fires_per_key_event_sequence_and_edits_at_the_caret() -> Result<()> {
+    let html = r#"
+        <form id='search'>
+          <input id='q' value='ab'>
+        </form>
+        <p id='log'></p>
+        <script>
+          const q = document.getElementById('q');
+          const log = [];
+          for (const type of ['keydown', 'keypress', 'beforeinput', 'input', 'keyup']) {
+            q.addEventListener(type, (event) => {
+              const detail = event.inputType
+                ? event.inputType + ':' + event.data
+                : event.key + ':' + event.code;
+              log.push(type + '(' + detail + ')');
+            });
+          }
+          document.getElementById('search').addEventListener('submit', (event) => {
+            event.preventDefault();
+            log.push('submit=' + q.value);
+          });
+          function readLog() {
+            const text = log.join(' ');
+            log.length = 0;
+            return text;
+          }
+        </script>
+        "#;
+
+    let mut h = Harness::from_html(html)?;
+    h.type_keys("#q", "C")?;
+    assert_eq!(
+        h.eval("readLog()")?,
+        "keydown(C:KeyC) keypress(C:KeyC) beforeinput(insertText:C) input(insertText:C) keyup(C:KeyC)"
+            .into()
+    );
+
+    h.type_keys("#q", "d{Backspace}{ArrowLeft}{ArrowLeft}!{End}?")?;
+    h.assert_value("#q", "a!bC?")?;
+    assert_eq!(
+        h.eval("q.selectionStart + ':' + q.selectionEnd")?,
+        "5:5".into()
+    );
+    h.eval("readLog()")?;
+
+    h.type_keys("#q", "{Home}{Delete}{Enter}")?;
+    assert_eq!(
+        h.eval("readLog()")?,
+        "keydown(Home:Home) keyup(Home:Home) \
+         keydown(Delete:Delete) beforeinput(deleteContentForward:null) \
+         input(deleteContentForward:null) keyup(Delete:Delete) \
+         keydown(Enter:Enter) keypress(Enter:Enter) submit=!bC? keyup(Enter:Enter)"
+            .into()
+    );
+    Ok(())
+}
+
+#[test]
+fn type_keys_respects_prevent_default_maxlength_and_readonly() -> Result<()> {
+    let html = r#"
+        <input id='digits' maxlength='4'>
+        <input id='locked' readonly value='fixed'>
+        <p id='out'></p>
+        <script>
+          const digits = document.getElementById('digits');
+          digits.addEventListener('keydown', (event) => {
+            if (event.key === 'x') event.preventDefault();
+          });
+          digits.addEventListener('beforeinput', (event) => {
+            if (event.data === '-') event.preventDefault();
+          });
+          let inputs = 0;
+          digits.addEventListener('input', () => { inputs += 1; });
+          document.getElementById('locked').addEventListener('keyup', (event) => {
+            document.getElementById('out').textContent += event.key;
+          });
+        </script>
+        "#;
+
+    let mut h = Harness::from_html(html)?;
+    h.type_keys("#digits", "1x2-345")?;
+    h.assert_value("#digits", "1234")?;
+    assert_eq!(h.eval("inputs")?, JsValue::Number(4.0));
+
+    h.type_keys("#digits", "{Control+a}9")?;
+    h.assert_value("#digits", "9")?;
+
+    h.type_keys("#locked", "ab{Backspace}")?;
+    h.assert_value("#locked", "fixed")?;
+    h.assert_text("#out", "abBackspace")?;
+
+    h.press_key(
+        "#digits",
+        KeyboardEventInit {
+            key: "7".to_string(),
+            ..KeyboardEventInit::default()
+        },
+    )?;
+    h.assert_value("#digits", "97")?;
+    Ok(())
+}
+
+#[test]
+fn type_keys_handles_textarea_lines_selection_and_tab_focus() -> Result<()> {
+    let html = r#"
+        <textarea id='notes'></textarea>
+        <button id='later' tabindex='0'>later</button>
+        <button id='disabled' disabled>disabled</button>
+        <input id='hidden' type='hidden'>
+        <input id='last'>
+        <p id='out'></p>
+        <script>
+          function activeId() {
+            const active = document.activeElement;
+            return active ? active.id : '';
+          }
+          document.getElementById('later').addEventListener('click', () => {
+            document.getElementById('out').textContent += 'later;';
+          });
+        </script>
+        "#;
+
+    let mut h = Harness::from_html(html)?;
+    h.type_keys(
+        "#notes",
+        "one{Enter}three{ArrowUp}{End}{Shift+ArrowLeft}{Shift+ArrowLeft}X",
+    )?;
+    h.assert_value("#notes", "oX\nthree")?;
+
+    h.type_keys("#notes", "{Tab}")?;
+    assert_eq!(h.eval("activeId()")?, "later".into());
+    h.type_keys("#later", "{Tab}")?;
+    assert_eq!(h.eval("activeId()")?, "last".into());
+    h.type_keys("#last", "{Shift+Tab} {Enter}")?;
+    assert_eq!(h.eval("activeId()")?, "later".into());
+    h.assert_text("#out", "later;later;")?;
+
+    let err = h
+        .type_keys("#last", "{Hyper+a}")
+        .expect_err("unknown modifiers should be rejected");
+    assert!(
+        matches!(&err, Error::ScriptRuntime(message) if message.contains("Hyper")),
+        "unexpected error: {err:?}"
+    );
+    Ok(())
+}
@@ -0,0 +1,131 @@
+use super::*;
+
+#[test]
+fn role_locators_use_implicit_roles_and_accessible_names() -> Result<()> {
+    let html = r#"
+        <h1>Account settings</h1>
+        <button id='cancel'>Cancel</button>
+        <button id='save'><img src='disk.png' alt='Save'> changes</button>
+        <button aria-label='Close dialog'>×</button>
+        <span id='delete-label'>Delete</span><span id='delete-hint'>account</span>
+        <div role='button' aria-labelledby='delete-label delete-hint'>🗑</div>
+        <a href='/help' title='Open help'><img src='help.png' alt=''></a>
+        <input type='submit'>
+        <button hidden>Save hidden</button>
+        <div aria-hidden='true'><button>Save in hidden tree</button></div>
+        <p id='out'></p>
+        <script>
+          for (const button of document.querySelectorAll('button, [role=button], input')) {
+            button.addEventListener('click', () => {
+              document.getElementById('out').textContent += (button.id || button.type || 'x') + ';';
+            });
+          }
+        </script>
+        "#;
+
+    let mut h = Harness::from_html(html)?;
+    h.click(h.get_by_role("button").with_name("save"))?;
+    h.click(h.get_by_role("button").with_name("Submit").exact(true))?;
+    h.assert_text("#out", "save;submit;")?;
+
+    assert_eq!(
+        h.accessible_name(h.get_by_role("heading"))?,
+        "Account settings"
+    );
+    assert_eq!(h.accessible_name("#save")?, "Save changes");
+    assert_eq!(
+        h.accessible_name(h.get_by_role("button").with_name("Delete account"))?,
+        "Delete account"
+    );
+    assert_eq!(h.accessible_name(h.get_by_role("link"))?, "Open help");
+    assert_eq!(h.count(h.get_by_role("button"))?, 5);
+    assert_eq!(h.count(h.get_by_role("button").with_name("Save"))?, 1);
+    h.assert_exists(h.get_by_role("button").with_name("Close dialog"))?;
+
+    let err = h
+        .click(h.get_by_role("button").with_name("Save").exact(true))
+        .expect_err("exact name should not match 'Save changes'");
+    match err {
+        Error::SelectorNotFound(selector) => {
+            assert_eq!(selector, r#"role=button[name="Save"s]"#);
+        }
+        other => panic!("unexpected error: {other:?}"),
+    }
+    Ok(())
+}
+
+#[test]
+fn label_placeholder_and_test_id_locators_drive_form_actions() -> Result<()> {
+    let html = r#"
+        <form id='signup'>
+          <label for='email'>Email address</label>
+          <input id='email' type='email'>
+          <label>Subscribe <input type='checkbox' id='subscribe'></label>
+          <span id='plan-label'>Plan</span>
+          <select aria-labelledby='plan-label' id='plan'>
+            <option value='free'>Free</option>
+            <option value='pro'>Pro</option>
+          </select>
+          <textarea aria-label='Notes' id='notes'></textarea>
+          <input placeholder='Search products' id='search'>
+          <button type='button' data-testid='signup-submit'>Go</button>
+        </form>
+        "#;
+
+    let mut h = Harness::from_html(html)?;
+    h.type_text(h.get_by_label("email"), "a@example.test")?;
+    h.set_checked(h.get_by_label("Subscribe"), true)?;
+    h.set_select_value(h.get_by_label("Plan"), "pro")?;
+    h.type_text(h.get_by_label("Notes"), "hello")?;
+    h.type_text(h.get_by_placeholder("search"), "pens")?;
+
+    h.assert_value("#email", "a@example.test")?;
+    h.assert_checked(h.get_by_role("checkbox").with_name("Subscribe"), true)?;
+    h.assert_value(h.get_by_role("combobox").with_name("Plan"), "pro")?;
+    h.assert_value(h.get_by_role("textbox").with_name("Notes"), "hello")?;
+    h.assert_value(
+        h.get_by_role("textbox").with_name("Search products"),
+        "pens",
+    )?;
+    h.assert_text(h.get_by_test_id("signup-submit"), "Go")?;
+    assert_eq!(h.count(h.get_by_test_id("signup"))?, 0);
+    assert_eq!(h.count(h.get_by_label("Email address").exact(true))?, 1);
+    Ok(())
+}
+
+#[test]
+fn text_locators_pick_the_innermost_visible_match() -> Result<()> {
+    let html = r#"
+        <main>
+          <section>
+            <p id='greeting'>Welcome <b>back</b>, Ada</p>
+            <p id='other'>Welcome</p>
+            <p style='display: none'>Welcome hidden</p>
+            <ul><li>First</li><li id='second'>Second</li></ul>
+          </section>
+        </main>
+        "#;
+
+    let h = Harness::from_html(html)?;
+    h.assert_text(h.get_by_text("welcome back"), "Welcome back, Ada")?;
+    assert_eq!(h.count(h.get_by_text("Welcome"))?, 2);
+    assert_eq!(h.count(h.get_by_text("Welcome").exact(true))?, 1);
+    h.assert_text(h.get_by_text("Welcome").nth(1), "Welcome")?;
+    assert_eq!(
+        h.dump_dom(h.get_by_role("listitem").nth(1))?,
+        "<li id=\"second\">Second</li>"
+    );
+    assert_eq!(
+        h.node_handle(h.get_by_text("Second"))?,
+        h.node_handle("#second")?
+    );
+
+    let err = h
+        .assert_text(h.get_by_text("Missing"), "x")
+        .expect_err("missing text should not resolve");
+    assert!(
+        matches!(&err, Error::SelectorNotFound(selector) if selector == r#"text="Missing"i"#),
+        "unexpected error: {err:?}"
+    );
+    Ok(())
+}
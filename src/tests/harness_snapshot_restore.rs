@@ -0,0 +1,137 @@
+use super::*;
+
+#[test]
+fn restore_rewinds_dom_script_state_and_listeners() -> Result<()> {
+    let html = r#"
+        <input id='name'>
+        <button id='add'>add</button>
+        <ul id='list'></ul>
+        <p id='count'>0</p>
+        <script>
+          const items = [];
+          let clicks = 0;
+          document.getElementById('add').addEventListener('click', () => {
+            clicks += 1;
+            items.push(document.getElementById('name').value);
+            const li = document.createElement('li');
+            li.textContent = items[items.length - 1];
+            document.getElementById('list').appendChild(li);
+            document.getElementById('count').textContent = clicks + ':' + items.length;
+          });
+        </script>
+        "#;
+
+    let mut h = Harness::from_html(html)?;
+    h.type_text("#name", "milk")?;
+    h.click("#add")?;
+    let fixture = h.snapshot();
+
+    h.type_text("#name", "eggs")?;
+    h.click("#add")?;
+    h.click("#add")?;
+    h.assert_text("#count", "3:3")?;
+    h.assert_text("#list", "milkeggseggs")?;
+
+    h.restore(&fixture);
+    h.assert_text("#count", "1:1")?;
+    h.assert_text("#list", "milk")?;
+    h.assert_value("#name", "milk")?;
+    h.type_text("#name", "bread")?;
+    h.click("#add")?;
+    h.assert_text("#count", "2:2")?;
+    h.assert_text("#list", "milkbread")?;
+
+    h.restore(&fixture);
+    h.assert_text("#count", "1:1")?;
+    Ok(())
+}
+
+#[test]
+fn fork_preserves_aliasing_and_isolates_branches() -> Result<()> {
+    let html = r#"
+        <p id='out'></p>
+        <script>
+          const shared = { n: 1 };
+          const holder = { first: shared, second: shared, list: [shared] };
+          holder.self = holder;
+          const controller = new AbortController();
+          let aborts = 0;
+          controller.signal.addEventListener('abort', () => { aborts += 1; });
+          function cancel() {
+            controller.abort();
+          }
+          function abortState() {
+            return aborts + ':' + controller.signal.aborted;
+          }
+          function bump() {
+            holder.first.n += 1;
+            return [shared.n, holder.second.n, holder.list[0].n, holder.self === holder].join(',');
+          }
+        </script>
+        "#;
+
+    let mut original = Harness::from_html(html)?;
+    let mut fork = original.fork();
+    assert_eq!(fork.eval("bump()")?, "2,2,2,true".into());
+    assert_eq!(fork.eval("bump()")?, "3,3,3,true".into());
+    assert_eq!(original.eval("bump()")?, "2,2,2,true".into());
+
+    fork.eval("cancel()")?;
+    assert_eq!(fork.eval("abortState()")?, "1:true".into());
+    assert_eq!(original.eval("abortState()")?, "0:false".into());
+    original.eval("cancel()")?;
+    assert_eq!(original.eval("abortState()")?, "1:true".into());
+    Ok(())
+}
+
+#[test]
+fn forks_keep_independent_timers_promises_and_fetch_mocks() -> Result<()> {
+    let html = r#"
+        <p id='out'></p>
+        <script>
+          const out = document.getElementById('out');
+          function load() {
+            setTimeout(() => { out.textContent += 'timer;'; }, 100);
+            fetch('/data').then(
+              (r) => r.text().then((text) => { out.textContent += text + ';'; }),
+              () => { out.textContent += 'failed;'; }
+            );
+          }
+        </script>
+        "#;
+
+    let mut h = Harness::from_html_with_url("https://example.test/", html)?;
+    h.set_manual_fetch_mode(true);
+    h.eval("load()")?;
+    let mut fork = h.fork();
+    let pending = fork.pending_fetches();
+    assert_eq!(pending.len(), 1);
+
+    fork.resolve_pending_fetch(pending[0].id, FetchMockResponse::text("fork"))?;
+    fork.advance_time(100)?;
+    fork.assert_text("#out", "fork;timer;")?;
+
+    h.assert_text("#out", "")?;
+    assert_eq!(h.pending_timers().len(), 1);
+    h.advance_time(100)?;
+    h.reject_pending_fetch(pending[0].id)?;
+    h.assert_text("#out", "timer;failed;")?;
+    Ok(())
+}
+
+#[test]
+fn forks_of_window_pages_stand_alone_while_restores_stay_owned() -> Result<()> {
+    let mut win = MockWindow::new();
+    win.open_page("https://app.local/", "<p>app</p>")?;
+    let fixture = win.current_document()?.snapshot();
+
+    let mut fork = win.current_document()?.fork();
+    fork.eval("window.open('/popup', 'side')")?;
+    assert!(fork.take_browsing_context_requests().is_empty());
+
+    let page = win.current_document_mut()?;
+    page.restore(&fixture);
+    page.eval("window.open('/popup', 'side')")?;
+    assert_eq!(page.take_browsing_context_requests().len(), 1);
+    Ok(())
+}
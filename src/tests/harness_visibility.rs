@@ -0,0 +1,136 @@
+use super::*;
+
+#[test]
+fn visibility_follows_display_visibility_and_hidden_ancestors() -> Result<()> {
+    let html = r#"
+      <html><head><style>
+        .gone { display: none; }
+        .ghost { visibility: hidden; }
+        .shown { visibility: visible; }
+        [hidden].forced { display: block; }
+      </style></head><body>
+      <div id='plain'>plain</div>
+      <div class='gone'><span id='in-gone'>x</span></div>
+      <div class='ghost'>
+        <span id='in-ghost'>x</span>
+        <span id='re-shown' class='shown'>x</span>
+      </div>
+      <p id='hidden-attr' hidden>x</p>
+      <p id='forced' class='forced' hidden>x</p>
+      <div hidden='until-found'><span id='until-found'>x</span></div>
+      <template><p id='templated'>x</p></template>
+      </body></html>
+    "#;
+
+    let h = Harness::from_html(html)?;
+    h.assert_visible("#plain")?;
+    h.assert_visible("#re-shown")?;
+    h.assert_visible("#forced")?;
+    assert!(!h.is_visible("#in-gone")?);
+    assert!(!h.is_visible("#in-ghost")?);
+    assert!(!h.is_visible("#hidden-attr")?);
+    assert!(!h.is_visible("#until-found")?);
+    assert!(!h.is_visible("head")?);
+    assert!(!h.is_visible("#templated")?);
+
+    let err = h
+        .assert_visible("#in-gone")
+        .expect_err("element under display:none should not be visible");
+    match err {
+        Error::AssertionFailed {
+            expected, actual, ..
+        } => {
+            assert_eq!(expected, "visible");
+            assert_eq!(actual, "hidden");
+        }
+        other => panic!("unexpected error: {other:?}"),
+    }
+    Ok(())
+}
+
+#[test]
+fn visibility_tracks_details_and_dialog_state() -> Result<()> {
+    let html = r#"
+      <details id='details'>
+        <summary id='summary'>More</summary>
+        <p id='body'>Body</p>
+      </details>
+      <dialog id='dialog'><p id='dialog-body'>Hi</p></dialog>
+      <button id='toggle'>toggle</button>
+      <script>
+        document.getElementById('toggle').addEventListener('click', () => {
+          document.getElementById('details').open = true;
+          document.getElementById('dialog').show();
+        });
+      </script>
+    "#;
+
+    let mut h = Harness::from_html(html)?;
+    h.assert_visible("#summary")?;
+    assert!(!h.is_visible("#body")?);
+    assert!(!h.is_visible("#dialog-body")?);
+
+    h.click("#toggle")?;
+    h.assert_visible("#body")?;
+    h.assert_visible("#dialog-body")?;
+    Ok(())
+}
+
+#[test]
+fn check_visibility_uses_the_same_model() -> Result<()> {
+    let html = r#"
+      <div id='outer' style='display: none'><span id='inner'>x</span></div>
+      <span id='ghost' style='visibility: hidden'>x</span>
+      <span id='clear' style='opacity: 0'>x</span>
+      <script>
+        const el = (id) => document.getElementById(id);
+        const detached = document.createElement('div');
+        window.result = [
+          el('inner').checkVisibility(),
+          el('ghost').checkVisibility(),
+          el('ghost').checkVisibility({ visibilityProperty: true }),
+          el('clear').checkVisibility(),
+          el('clear').checkVisibility({ opacityProperty: true }),
+          detached.checkVisibility(),
+        ].join(',');
+      </script>
+    "#;
+
+    let mut h = Harness::from_html(html)?;
+    assert_eq!(
+        h.eval("window.result")?,
+        "false,true,false,true,false,false".into()
+    );
+    Ok(())
+}
+
+#[test]
+fn style_sheet_hidden_elements_drop_out_of_locators_focus_and_layout() -> Result<()> {
+    let html = r#"
+      <html><head><style>
+        .gone { display: none; }
+        .ghost { visibility: hidden; }
+      </style></head><body>
+      <button id='shown'>Save</button>
+      <div class='gone'><button id='in-gone'>Save</button></div>
+      <div class='ghost'><button id='in-ghost'>Save</button></div>
+      <div id='box' class='gone'></div>
+      </body></html>
+    "#;
+
+    let mut h = Harness::from_html(html)?;
+    assert_eq!(h.count(h.get_by_role("button"))?, 1);
+    assert_eq!(h.focus_order(), vec!["#shown"]);
+
+    h.set_element_rect("#box", DomRect::new(10.0, 20.0, 100.0, 50.0))?;
+    let read = "const box = document.getElementById('box'); \
+                [box.offsetWidth, box.offsetTop, box.getBoundingClientRect().width].join(',')";
+    assert_eq!(h.eval(read)?, "0,0,0".into());
+    h.eval("document.getElementById('box').classList.remove('gone')")?;
+    assert_eq!(h.eval(read)?, "100,20,100".into());
+
+    h.eval("document.querySelector('style').textContent = ''")?;
+    assert_eq!(h.count(h.get_by_role("button"))?, 3);
+    assert_eq!(h.focus_order(), vec!["#shown", "#in-gone", "#in-ghost"]);
+    Ok(())
+}
@@ -0,0 +1,237 @@
+use super::*;
+
+#[test]
+fn imported_let_bindings_stay_live() -> Result<()> {
+    let loader = MemoryResourceLoader::new()
+        .with_resource(
+            "/counter.js",
+            "export let count = 0; export function increment() { count += 1; } export const read = () => count;",
+        )
+        .with_resource(
+            "/main.js",
+            r#"
+              import { count as current, increment, read } from './counter.js';
+              import * as counter from './counter.js';
+              const seen = [current];
+              increment();
+              seen.push(current, counter.count, read());
+              window.later = () => { increment(); return current + '/' + counter.count; };
+              document.getElementById('out').textContent = seen.join(',');
+            "#,
+        );
+    let html = "<p id='out'></p><script type='module' src='/main.js'></script>";
+    let mut h = Harness::from_html_with_resource_loader("https://app.test/", html, loader)?;
+    h.assert_text("#out", "0,1,1,1")?;
+    assert_eq!(h.eval("window.later()")?, "2/2".into());
+    Ok(())
+}
+
+#[test]
+fn same_name_imports_stay_live_and_modules_keep_separate_scopes() -> Result<()> {
+    let loader = MemoryResourceLoader::new()
+        .with_resource(
+            "/counter.js",
+            "export let count = 0; export function inc() { count += 1; } let label = 'counter';",
+        )
+        .with_resource(
+            "/other.js",
+            "let label = 'other'; export const otherLabel = () => label;",
+        )
+        .with_resource(
+            "/main.js",
+            r#"
+              import { count, inc } from './counter.js';
+              import { otherLabel } from './other.js';
+              inc();
+              inc();
+              let blocked = 'no';
+              try { count = 5; } catch (e) { blocked = 'yes'; }
+              function shadow(count) { return count; }
+              { const count = 'block'; window.inBlock = count; }
+              document.getElementById('out').textContent = [
+                count, blocked, shadow('param'), window.inBlock, otherLabel(), typeof label,
+              ].join(',');
+            "#,
+        );
+    let html = "<p id='out'></p><script type='module' src='/main.js'></script>";
+    let h = Harness::from_html_with_resource_loader("https://app.test/", html, loader)?;
+    h.assert_text("#out", "2,yes,param,block,other,undefined")?;
+    Ok(())
+}
+
+#[test]
+fn failed_module_evaluation_is_rethrown_to_later_importers() -> Result<()> {
+    let loader = MemoryResourceLoader::new().with_resource(
+        "/broken.js",
+        "window.brokenRuns = (window.brokenRuns || 0) + 1; throw 'boom'; export const never = 1;",
+    );
+    let html = r#"
+        <p id='out'></p>
+        <script type='module'>
+          const out = document.getElementById('out');
+          const fail = (error) => String(error);
+          import('./broken.js').then(() => 'loaded', fail).then((first) =>
+            import('./broken.js').then(() => 'loaded', fail).then((second) => {
+              out.textContent = [first, second, window.brokenRuns].join('|');
+            })
+          );
+        </script>
+    "#;
+    let h = Harness::from_html_with_resource_loader("https://app.test/", html, loader)?;
+    h.assert_text("#out", "boom|boom|1")?;
+    Ok(())
+}
+
+#[test]
+fn cyclic_imports_see_each_others_bindings() -> Result<()> {
+    let loader = MemoryResourceLoader::new()
+        .with_resource(
+            "/even.js",
+            r#"
+              import { isOdd } from './odd.js';
+              export function isEven(n) { return n === 0 ? true : isOdd(n - 1); }
+              export const evenLoaded = 'even';
+            "#,
+        )
+        .with_resource(
+            "/odd.js",
+            r#"
+              import { isEven, evenLoaded } from './even.js';
+              let atLoad;
+              try { atLoad = typeof evenLoaded; } catch (e) { atLoad = String(e).split(':')[0]; }
+              export const seenAtLoad = atLoad;
+              export function isOdd(n) { return n === 0 ? false : isEven(n - 1); }
+              export const seenLater = () => evenLoaded;
+            "#,
+        )
+        .with_resource(
+            "/main.js",
+            r#"
+              import { isEven } from './even.js';
+              import { isOdd, seenAtLoad, seenLater } from './odd.js';
+              document.getElementById('out').textContent =
+                [isEven(10), isOdd(7), isEven(3), seenAtLoad, seenLater()].join(',');
+            "#,
+        );
+    let html = "<p id='out'></p><script type='module' src='/main.js'></script>";
+    let h = Harness::from_html_with_resource_loader("https://app.test/", html, loader)?;
+    h.assert_text("#out", "true,true,false,ReferenceError,even")?;
+    Ok(())
+}
+
+#[test]
+fn import_maps_remap_bare_specifiers_prefixes_and_scopes() -> Result<()> {
+    let loader = MemoryResourceLoader::new()
+        .with_resource("/vendor/lit/index.js", "export const name = 'lit';")
+        .with_resource("/vendor/lib/util.js", "export const name = 'util';")
+        .with_resource(
+            "/vendor/lib-legacy/util.js",
+            "export const name = 'legacy-util';",
+        )
+        .with_resource(
+            "/legacy/app.js",
+            "import { name } from 'lib/util.js'; export const legacyName = name;",
+        )
+        .with_resource(
+            "/main.js",
+            r#"
+              import { name as lit } from 'lit';
+              import { name as util } from 'lib/util.js';
+              import { legacyName } from './legacy/app.js';
+              document.getElementById('out').textContent =
+                [lit, util, legacyName, import.meta.resolve('lib/util.js')].join(',');
+            "#,
+        );
+    let html = r#"
+        <script type='importmap'>
+          {
+            "imports": { "lit": "/vendor/lit/index.js", "lib/": "/vendor/lib/" },
+            "scopes": { "/legacy/": { "lib/": "/vendor/lib-legacy/" } }
+          }
+        </script>
+        <p id='out'></p>
+        <script type='module' src='/main.js'></script>
+    "#;
+    let h = Harness::from_html_with_resource_loader("https://app.test/", html, loader)?;
+    h.assert_text(
+        "#out",
+        "lit,util,legacy-util,https://app.test/vendor/lib/util.js",
+    )?;
+    Ok(())
+}
+
+#[test]
+fn unmapped_bare_specifiers_are_rejected_with_type_error() -> Result<()> {
+    let html = r#"
+        <p id='out'></p>
+        <script type='module'>
+          const out = document.getElementById('out');
+          import('lodash').then(
+            () => { out.textContent = 'loaded'; },
+            (error) => { out.textContent = String(error); }
+          );
+        </script>
+    "#;
+    let h = Harness::from_html(html)?;
+    h.assert_text(
+        "#out",
+        "TypeError: Failed to resolve module specifier \"lodash\". Relative references must start with either \"/\", \"./\", or \"../\".",
+    )?;
+
+    let err = Harness::from_html("<script type='module'>import 'lodash';</script>")
+        .expect_err("bare import without an import map should fail");
+    assert!(
+        err.to_string()
+            .contains("Failed to resolve module specifier \"lodash\""),
+        "unexpected error: {err}"
+    );
+    Ok(())
+}
+
+#[test]
+fn missing_named_export_fails_before_any_module_runs() -> Result<()> {
+    let loader = MemoryResourceLoader::new()
+        .with_resource("/dep.js", "window.depRan = true; export const present = 1;")
+        .with_resource("/main.js", "import { absent } from './dep.js';");
+    let html = "<script type='module' src='/main.js'></script>";
+    let err = Harness::from_html_with_resource_loader("https://app.test/", html, loader)
+        .expect_err("linking should fail");
+    assert!(
+        err.to_string()
+            .contains("module './dep.js' does not provide an export named 'absent'"),
+        "unexpected error: {err}"
+    );
+    Ok(())
+}
+
+#[test]
+fn base64_data_modules_decode_utf8_source() -> Result<()> {
+    // export default 'é';
+    let html = r#"
+        <p id='out'></p>
+        <script type='module'>
+          import text from "data:text/javascript;base64,ZXhwb3J0IGRlZmF1bHQgJ8OpJzs=";
+          document.getElementById('out').textContent = text;
+        </script>
+    "#;
+    let h = Harness::from_html(html)?;
+    h.assert_text("#out", "é")?;
+    Ok(())
+}
+
+#[test]
+fn top_level_await_finishes_before_importers_run() -> Result<()> {
+    let loader = MemoryResourceLoader::new()
+        .with_resource(
+            "/config.js",
+            "export const config = await Promise.resolve('prod');",
+        )
+        .with_resource(
+            "/main.js",
+            "import { config } from './config.js'; document.getElementById('out').textContent = config;",
+        );
+    let html = "<p id='out'></p><script type='module' src='/main.js'></script>";
+    let h = Harness::from_html_with_resource_loader("https://app.test/", html, loader)?;
+    h.assert_text("#out", "prod")?;
+    Ok(())
+}
@@ -4,6 +4,7 @@ mod async_generator_function_helpers;
 mod async_generator_helpers;
 mod async_iterator_helpers;
 mod collections_url_typed_arrays;
+mod date_time_zones;
 mod dom_abbr_element;
 mod dom_address_element;
 mod dom_anchor_element;
@@ -28,6 +29,8 @@ mod dom_cite_element;
 mod dom_code_element;
 mod dom_col_element;
 mod dom_colgroup_element;
+mod dom_comment_and_doctype_nodes;
+mod dom_custom_elements;
 mod dom_data_element;
 mod dom_data_transfer;
 mod dom_data_transfer_clear_data_method;
@@ -114,6 +117,8 @@ mod dom_element_toggle_attribute_method;
 mod dom_em_element;
 mod dom_embed_element;
 mod dom_error_event;
+mod dom_event_interfaces;
+mod dom_event_listener_options;
 mod dom_event_target_dispatch_event_method;
 mod dom_events_input_runtime;
 mod dom_events_overview;
@@ -122,6 +127,7 @@ mod dom_figcaption_element;
 mod dom_figure_element;
 mod dom_footer_element;
 mod dom_form_element;
+mod dom_form_submission;
 mod dom_global_attributes;
 mod dom_hash_change_event;
 mod dom_head_element;
@@ -141,6 +147,7 @@ mod dom_ins_element;
 mod dom_kbd_element;
 mod dom_keyboard_event;
 mod dom_label_element;
+mod dom_layout_observers;
 mod dom_legend_element;
 mod dom_li_element;
 mod dom_link_element;
@@ -150,6 +157,7 @@ mod dom_mark_element;
 mod dom_menu_element;
 mod dom_meta_element;
 mod dom_meter_element;
+mod dom_mutation_observer;
 mod dom_named_node_map;
 mod dom_nav_element;
 mod dom_navigate_event;
@@ -165,6 +173,7 @@ mod dom_output_element;
 mod dom_p_element;
 mod dom_picture_element;
 mod dom_pointer_event;
+mod dom_pointer_interactions;
 mod dom_pre_element;
 mod dom_progress_element;
 mod dom_q_element;
@@ -182,6 +191,7 @@ mod dom_slot_element;
 mod dom_small_element;
 mod dom_source_element;
 mod dom_span_element;
+mod dom_storage_shared_contexts;
 mod dom_strong_element;
 mod dom_style_element;
 mod dom_sub_element;
@@ -207,6 +217,12 @@ mod dom_wbr_element;
 mod dom_wheel_event;
 mod generator_function_helpers;
 mod generator_helpers;
+mod harness_eval_js_value;
+mod harness_focus_navigation;
+mod harness_keyboard_typing;
+mod harness_locators;
+mod harness_snapshot_restore;
+mod harness_visibility;
 mod issue_100_array_literal_spread_expression;
 mod issue_101_template_literal_typed_array_misclassification;
 mod issue_102_worker_regex_exec;
@@ -240,13 +256,18 @@ mod issue_98_html_select_element_global;
 mod issue_99_dispatch_paste_bubbles;
 mod iterator_helpers;
 mod language_core_expressions;
+mod language_module_graph;
 mod numeric_intl_dom_mutations;
 mod operators_advanced_selectors;
 mod runtime_dom_query_index_object;
 mod runtime_global_this;
 mod selector_engine_dom_tree;
 mod timers_numbers_intl_basics;
+mod webapi_abort_controller;
+mod webapi_console;
 mod webapi_data_builtins;
+mod webapi_fetch_mocking;
+mod webapi_resource_loading;
 mod webapi_text_decoder;
 mod webapi_text_decoder_stream;
 mod webapi_text_encoder;
@@ -255,3 +276,4 @@ mod webapi_url_create_object_url;
 mod window_forms_trace;
 mod window_get_computed_style;
 mod window_get_selection;
+mod window_open_browsing_contexts;
@@ -6,6 +6,13 @@ fn selector_parse_supports_active() {
     assert_eq!(active.pseudo_classes, vec![SelectorPseudoClass::Active]);
 }
 
+#[test]
+fn selector_parse_supports_hover() {
+    let hover = parse_selector_step("li:hover").expect("parse should succeed");
+    assert_eq!(hover.pseudo_classes, vec![SelectorPseudoClass::Hover]);
+    assert!(parse_selector_step("li:hovered").is_err());
+}
+
 #[test]
 fn selector_parse_supports_not() {
     let by_id = parse_selector_step("span:not(#x)").expect("parse should succeed");
@@ -1537,7 +1537,13 @@ fn intl_supported_values_of_examples_work() -> Result<()> {
               collation.join(',') + '|' +
               currency.join(',') + '|' +
               numberingSystem.join(',') + '|' +
-              timeZone.join(',') + '|' +
+              [
+                timeZone.length > 300,
+                timeZone.includes('America/New_York'),
+                timeZone.includes('Pacific/Chatham'),
+                timeZone.includes('US/Eastern'),
+                timeZone[timeZone.length - 1]
+              ].join(',') + '|' +
               unit.join(',');
           });
         </script>
@@ -1547,7 +1553,7 @@ fn intl_supported_values_of_examples_work() -> Result<()> {
     h.click("#btn")?;
     h.assert_text(
             "#result",
-            "gregory,islamic-umalqura,japanese|default,emoji,phonebk|EUR,JPY,USD|arab,latn,thai|Africa/Cairo,America/Los_Angeles,America/New_York,Asia/Jerusalem,Asia/Kolkata,Asia/Seoul,Asia/Shanghai,Asia/Tokyo,Australia/Sydney,Europe/Berlin,Europe/London,Europe/Paris,UTC|day,hour,meter,minute,month,second,week,year",
+            "gregory,islamic-umalqura,japanese|default,emoji,phonebk|EUR,JPY,USD|arab,latn,thai|true,true,true,false,UTC|day,hour,meter,minute,month,second,week,year",
         )?;
 
     let html_error = r#"
@@ -0,0 +1,153 @@
+use super::*;
+
+#[test]
+fn abort_controller_signals_state_reason_and_abort_event() -> Result<()> {
+    let mut h = Harness::from_html("<p></p>")?;
+    h.eval(
+        r#"
+        var log = [];
+        var controller = new AbortController();
+        var signal = controller.signal;
+        signal.addEventListener('abort', (event) => log.push('listener:' + event.type));
+        signal.onabort = () => log.push('onabort');
+        log.push(String(signal.aborted));
+        controller.abort();
+        controller.abort('ignored');
+        log.push(String(signal.aborted), signal.reason.name, String(signal.reason.code));
+        try {
+          signal.throwIfAborted();
+        } catch (err) {
+          log.push('thrown:' + err.name);
+        }
+        var custom = AbortSignal.abort('stale');
+        log.push(String(custom.aborted), custom.reason);
+        log.push(String(signal instanceof AbortSignal), String(signal.reason instanceof DOMException));
+        "#,
+    )?;
+    assert_eq!(
+        h.eval("log.join('|')")?,
+        "false|listener:abort|onabort|true|AbortError|20|thrown:AbortError|true|stale|true|true"
+            .into()
+    );
+    Ok(())
+}
+
+#[test]
+fn aborting_rejects_pending_fetches_with_abort_error() -> Result<()> {
+    let html = r#"
+        <input id='q'>
+        <ul id='results'></ul>
+        <p id='log'></p>
+        <script>
+          let controller = null;
+          const log = document.getElementById('log');
+          function search(term) {
+            if (controller) controller.abort();
+            controller = new AbortController();
+            fetch('/search?q=' + term, { signal: controller.signal })
+              .then((response) => response.text())
+              .then((text) => { document.getElementById('results').textContent = text + ':' + term; })
+              .catch((err) => { log.textContent += err.name + ':' + term + ';'; });
+          }
+        </script>
+        "#;
+
+    let mut h = Harness::from_html_with_url("https://example.test/", html)?;
+    h.add_fetch_mock(
+        FetchMatcher::new("https://example.test/search"),
+        FetchMockResponse::text("results").with_latency(100),
+    );
+    h.eval("search('a'); search('ab');")?;
+    assert_eq!(h.pending_fetches().len(), 1);
+    h.assert_text("#log", "AbortError:a;")?;
+
+    h.advance_time(100)?;
+    h.assert_text("#results", "results:ab")?;
+    h.assert_text("#log", "AbortError:a;")?;
+
+    assert_eq!(
+        h.eval_async("fetch('/search', { signal: AbortSignal.abort() }).catch((err) => err.name)")?,
+        "AbortError".into()
+    );
+    Ok(())
+}
+
+#[test]
+fn abort_signal_timeout_and_any_follow_the_fake_clock() -> Result<()> {
+    let mut h = Harness::from_html_with_url("https://example.test/", "<p></p>")?;
+    h.set_manual_fetch_mode(true);
+    h.eval(
+        r#"
+        var outcome = 'pending';
+        var user = new AbortController();
+        var combined = AbortSignal.any([user.signal, AbortSignal.timeout(500)]);
+        fetch('/slow', { signal: combined }).catch((err) => { outcome = err.name; });
+        "#,
+    )?;
+    assert!(h.pending_timers().is_empty());
+
+    h.advance_time(499)?;
+    assert_eq!(h.eval("outcome")?, "pending".into());
+    h.advance_time(1)?;
+    assert_eq!(h.eval("outcome")?, "TimeoutError".into());
+    assert_eq!(
+        h.eval("combined.reason.message")?,
+        "signal timed out".into()
+    );
+    assert_eq!(h.eval("user.signal.aborted")?, JsValue::Bool(false));
+    assert!(h.pending_fetches().is_empty());
+    Ok(())
+}
+
+#[test]
+fn abort_signal_any_marks_dependents_before_abort_events_fire() -> Result<()> {
+    let mut h = Harness::from_html("<p></p>")?;
+    h.eval(
+        r#"
+        var log = [];
+        var source = new AbortController();
+        var combined = AbortSignal.any([source.signal]);
+        var nested = AbortSignal.any([combined]);
+        source.signal.addEventListener('abort', () => {
+          log.push('source:' + combined.aborted + ',' + nested.aborted + ',' + nested.reason);
+        });
+        combined.addEventListener('abort', () => { log.push('combined'); });
+        nested.addEventListener('abort', () => { log.push('nested'); });
+        source.abort('stop');
+        "#,
+    )?;
+    assert_eq!(
+        h.eval("log.join('|')")?,
+        "source:true,true,stop|combined|nested".into()
+    );
+    Ok(())
+}
+
+#[test]
+fn listeners_registered_with_a_signal_are_removed_on_abort() -> Result<()> {
+    let html = r#"
+        <button id='btn'>run</button>
+        <p id='out'></p>
+        <script>
+          const out = document.getElementById('out');
+          const controller = new AbortController();
+          const btn = document.getElementById('btn');
+          btn.addEventListener('click', () => { out.textContent += 'a'; }, { signal: controller.signal });
+          btn.addEventListener('click', () => { out.textContent += 'b'; });
+          const signal = controller.signal;
+          window.addEventListener('resize', () => { out.textContent += 'r'; }, { capture: false, signal });
+          btn.addEventListener('click', () => { out.textContent += 'x'; }, { signal: AbortSignal.abort() });
+        </script>
+        "#;
+
+    let mut h = Harness::from_html(html)?;
+    h.click("#btn")?;
+    h.eval("window.dispatchEvent(new Event('resize'))")?;
+    h.assert_text("#out", "abr")?;
+
+    h.eval("controller.abort()")?;
+    h.click("#btn")?;
+    h.eval("window.dispatchEvent(new Event('resize'))")?;
+    h.assert_text("#out", "abrb")?;
+    Ok(())
+}
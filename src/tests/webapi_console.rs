@@ -0,0 +1,156 @@
+use super::*;
+
+#[test]
+fn console_methods_are_captured_with_level_args_and_timestamp() -> Result<()> {
+    let html = r#"
+        <button id='run'>run</button>
+        <script>
+          console.log('boot', 1, true);
+          document.getElementById('run').addEventListener('click', () => {
+            console.info({ id: 7, tags: ['a', 'b'] });
+            console.warn('careful');
+            console.error('%s failed with %d', 'save', 42.9);
+            console.debug(null, undefined);
+          });
+        </script>
+        "#;
+
+    let mut h = Harness::from_html(html)?;
+    let boot = h.take_console_messages();
+    assert_eq!(boot.len(), 1);
+    assert_eq!(boot[0].level, ConsoleLevel::Log);
+    assert_eq!(boot[0].args_rendered, "boot 1 true");
+    assert_eq!(
+        boot[0].args,
+        vec![
+            JsValue::from("boot"),
+            JsValue::Number(1.0),
+            JsValue::Bool(true)
+        ]
+    );
+    assert_eq!(boot[0].timestamp_ms, 0);
+
+    h.advance_time(25)?;
+    h.click("#run")?;
+    let messages = h.take_console_messages();
+    let summary = messages
+        .iter()
+        .map(|message| (message.level, message.args_rendered.as_str()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        summary,
+        vec![
+            (ConsoleLevel::Info, "{id: 7, tags: ['a', 'b']}"),
+            (ConsoleLevel::Warn, "careful"),
+            (ConsoleLevel::Error, "save failed with 42"),
+            (ConsoleLevel::Debug, "null undefined"),
+        ]
+    );
+    assert!(messages.iter().all(|message| message.timestamp_ms == 25));
+    assert!(h.take_console_messages().is_empty());
+    Ok(())
+}
+
+#[test]
+fn console_group_count_time_and_assert_follow_browser_formatting() -> Result<()> {
+    let html = r#"
+        <script>
+          console.group('outer');
+          console.count();
+          console.count();
+          console.count('clicks');
+          console.groupEnd();
+          console.assert(true, 'never shown');
+          console.assert(1 > 2, 'math is', 'broken');
+          console.time('load');
+        </script>
+        "#;
+
+    let mut h = Harness::from_html(html)?;
+    h.advance_time(40)?;
+    h.eval("console.timeEnd('load'); console.timeEnd('load'); console.trace('here')")?;
+
+    let rendered = h
+        .take_console_messages()
+        .into_iter()
+        .map(|message| message.args_rendered)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        rendered,
+        vec![
+            "outer",
+            "  default: 1",
+            "  default: 2",
+            "  clicks: 1",
+            "Assertion failed: math is broken",
+            "load: 40ms",
+            "Timer 'load' does not exist",
+            "Trace: here",
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+fn fail_on_console_error_fails_the_action_that_logged_it() -> Result<()> {
+    let html = r#"
+        <button id='bad'>bad</button>
+        <button id='good'>good</button>
+        <script>
+          document.getElementById('bad').addEventListener('click', () => {
+            Promise.resolve().then(() => console.error('async', 'boom'));
+          });
+          document.getElementById('good').addEventListener('click', () => {
+            console.log('fine');
+          });
+        </script>
+        "#;
+
+    let mut h = Harness::from_html(html)?;
+    h.set_fail_on_console_error(true);
+    h.click("#good")?;
+
+    let err = h
+        .click("#bad")
+        .expect_err("console.error should fail the action");
+    match err {
+        Error::ScriptRuntime(message) => {
+            assert_eq!(message, "console.error was called: async boom");
+        }
+        other => panic!("unexpected error: {other:?}"),
+    }
+    h.click("#good")?;
+
+    h.set_fail_on_console_error(false);
+    h.click("#bad")?;
+    let levels = h
+        .take_console_messages()
+        .into_iter()
+        .map(|message| message.level)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        levels,
+        vec![
+            ConsoleLevel::Log,
+            ConsoleLevel::Error,
+            ConsoleLevel::Log,
+            ConsoleLevel::Error,
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+fn console_messages_are_mirrored_to_trace_logs() -> Result<()> {
+    let mut h = Harness::from_html("<p></p>")?;
+    h.enable_trace(true);
+    h.set_trace_stderr(false);
+    h.eval("console.warn('low disk', 5)")?;
+    assert_eq!(h.take_trace_logs(), vec!["[console] warn low disk 5"]);
+
+    h.set_trace_console(false);
+    h.eval("console.log('quiet')")?;
+    assert!(h.take_trace_logs().is_empty());
+    assert_eq!(h.take_console_messages().len(), 2);
+    Ok(())
+}
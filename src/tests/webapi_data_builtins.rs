@@ -4398,7 +4398,7 @@ fn dom_parser_and_tree_walker_basics_are_supported() -> Result<()> {
             }
 
             const commentWalker = doc.createTreeWalker(doc.body, NodeFilter.SHOW_COMMENT);
-            const noComment = commentWalker.nextNode() === null;
+            const comment = commentWalker.nextNode();
             root.remove();
 
             document.getElementById('result').textContent =
@@ -4406,7 +4406,7 @@ fn dom_parser_and_tree_walker_basics_are_supported() -> Result<()> {
               (root.nodeType === Node.ELEMENT_NODE) + ':' +
               (Node.TEXT_NODE === 3) + ':' +
               values.join(',') + ':' +
-              noComment + ':' +
+              comment.data + ':' +
               (doc.getElementById('root') === null);
           });
         </script>
@@ -4414,7 +4414,7 @@ fn dom_parser_and_tree_walker_basics_are_supported() -> Result<()> {
 
     let mut h = Harness::from_html(html)?;
     h.click("#btn")?;
-    h.assert_text("#result", "true:true:true:A,B:true:true")?;
+    h.assert_text("#result", "true:true:true:A,B:x:true")?;
     Ok(())
 }
 
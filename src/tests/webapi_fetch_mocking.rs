@@ -0,0 +1,356 @@
+use super::*;
+
+#[test]
+fn fetch_mock_rules_match_method_url_glob_query_headers_and_json_body() -> Result<()> {
+    let html = r#"
+        <p id='out'></p>
+        <script>
+          const out = document.getElementById('out');
+          async function run() {
+            const list = await fetch('/api/items?page=2&sort=name');
+            const created = await fetch('/api/items', {
+              method: 'POST',
+              headers: { 'Content-Type': 'application/json', 'X-Token': 'abc' },
+              body: JSON.stringify({ tags: ['a'], name: 'pen' }),
+            });
+            const rejected = await fetch('/api/items', {
+              method: 'POST',
+              headers: { 'Content-Type': 'application/json' },
+              body: JSON.stringify({ name: 'other' }),
+            });
+            out.textContent = [
+              list.status, await list.text(),
+              created.status, (await created.json()).id,
+              rejected.status,
+            ].join('|');
+          }
+        </script>
+        "#;
+
+    let mut h = Harness::from_html_with_url("https://example.test/app", html)?;
+    h.add_fetch_mock(
+        FetchMatcher::new("https://example.test/api/*")
+            .with_method("get")
+            .with_query("page", "2"),
+        FetchMockResponse::text("page two"),
+    );
+    h.add_fetch_mock(
+        FetchMatcher::new("/api/items")
+            .with_method("POST")
+            .with_header("x-token", "abc")
+            .with_json_body(r#"{"name":"pen","tags":["a"]}"#),
+        FetchMockResponse::json(r#"{"id":7}"#).with_status(201),
+    );
+    h.set_fetch_mock_response("https://example.test/api/items", 400, "bad");
+    h.eval("run()")?;
+    h.assert_text("#out", "200|page two|201|7|400")?;
+
+    let requests = h.take_fetch_requests();
+    assert_eq!(requests.len(), 3);
+    let created = &requests[1];
+    assert_eq!(created.method, "POST");
+    assert_eq!(created.url, "https://example.test/api/items");
+    assert_eq!(created.header("content-type"), Some("application/json"));
+    assert_eq!(created.header("X-Token"), Some("abc"));
+    assert_eq!(
+        created.body_text().as_deref(),
+        Some(r#"{"tags":["a"],"name":"pen"}"#)
+    );
+    assert_eq!(requests[0].body, None);
+    Ok(())
+}
+
+#[test]
+fn fetch_mock_responses_expose_headers_binary_bodies_and_redirect_metadata() -> Result<()> {
+    let mut h = Harness::from_html_with_url("https://example.test/", "<p id='out'></p>")?;
+    h.add_fetch_mock(
+        FetchMatcher::new("https://example.test/logo.png"),
+        FetchMockResponse::new(200)
+            .with_header("Content-Type", "image/png")
+            .with_bytes(&[0x89, b'P', b'N', 0xff])
+            .with_redirect("https://cdn.example.test/logo.png")
+            .with_response_type("cors"),
+    );
+    h.eval(
+        r#"
+        fetch('/logo.png').then(async (response) => {
+          const bytes = await response.clone().bytes();
+          const blob = await response.blob();
+          document.getElementById('out').textContent = [
+            response.headers.get('content-type'),
+            response.headers.has('x-missing'),
+            response.redirected,
+            response.type,
+            response.url,
+            bytes.length,
+            bytes[0],
+            bytes[3],
+            blob.type,
+            blob.size,
+          ].join('|');
+        });
+        "#,
+    )?;
+    h.assert_text(
+        "#out",
+        "image/png|false|true|cors|https://cdn.example.test/logo.png|4|137|255|image/png|4",
+    )?;
+    Ok(())
+}
+
+#[test]
+fn fetch_response_clone_owns_its_headers_and_body() -> Result<()> {
+    let mut h = Harness::from_html_with_url("https://example.test/", "<p></p>")?;
+    h.add_fetch_mock(
+        FetchMatcher::new("/data"),
+        FetchMockResponse::text("payload").with_header("X-Version", "1"),
+    );
+    let result = h.eval_async(
+        r#"
+        fetch('/data').then(async (response) => {
+          const copy = response.clone();
+          copy.headers.set('x-version', '2');
+          response.headers.append('x-extra', 'yes');
+          return [
+            response.headers.get('x-version'),
+            copy.headers.get('x-version'),
+            copy.headers.has('x-extra'),
+            await copy.text(),
+            await response.text(),
+          ].join('|');
+        })
+        "#,
+    )?;
+    assert_eq!(result, "1|2|false|payload|payload".into());
+    Ok(())
+}
+
+#[test]
+fn fetch_request_objects_contribute_method_headers_and_body() -> Result<()> {
+    let mut h = Harness::from_html_with_url("https://example.test/", "<p></p>")?;
+    h.add_fetch_mock(
+        FetchMatcher::new("https://example.test/form").with_method("PUT"),
+        FetchMockResponse::text("saved"),
+    );
+    let text = h.eval_async(
+        r#"
+        const request = new Request('/form', {
+          method: 'PUT',
+          headers: { Accept: 'text/plain' },
+          body: new URLSearchParams({ a: '1', b: 'x y' }),
+        });
+        fetch(request).then((response) => response.text())
+        "#,
+    )?;
+    assert_eq!(text, "saved".into());
+
+    let requests = h.take_fetch_requests();
+    assert_eq!(
+        requests,
+        vec![RecordedRequest {
+            destination: FetchDestination::Fetch,
+            method: "PUT".to_string(),
+            url: "https://example.test/form".to_string(),
+            headers: vec![("accept".to_string(), "text/plain".to_string())],
+            body: Some(b"a=1&b=x+y".to_vec()),
+        }]
+    );
+    assert!(h.take_fetch_requests().is_empty());
+    Ok(())
+}
+
+#[test]
+fn fetch_handler_drives_stateful_backends_before_static_mocks() -> Result<()> {
+    let html = r#"
+        <p id='out'></p>
+        <script>
+          async function save(name) {
+            for (let attempt = 0; attempt < 3; attempt++) {
+              const response = await fetch('/api/items', { method: 'POST', body: name });
+              if (response.ok) return (await response.json()).id;
+            }
+            return 'gave up';
+          }
+        </script>
+        "#;
+
+    let mut h = Harness::from_html_with_url("https://example.test/", html)?;
+    h.set_fetch_mock("https://example.test/static.txt", "from static mock");
+    let mut next_id = 100;
+    let mut failed_once = false;
+    h.set_fetch_handler(move |request| {
+        if request.destination != FetchDestination::Fetch {
+            return FetchOutcome::Fallthrough;
+        }
+        match (request.method.as_str(), request.url.as_str()) {
+            ("POST", "https://example.test/api/items") => {
+                if !failed_once {
+                    failed_once = true;
+                    return FetchOutcome::Response(FetchMockResponse::new(500));
+                }
+                next_id += 1;
+                FetchOutcome::Response(FetchMockResponse::json(&format!(
+                    r#"{{"id":{next_id},"name":"{}"}}"#,
+                    request.body_text().unwrap_or_default()
+                )))
+            }
+            (_, "https://example.test/offline") => FetchOutcome::NetworkError,
+            _ => FetchOutcome::Fallthrough,
+        }
+    });
+
+    assert_eq!(h.eval_async("save('a')")?, JsValue::Number(101.0));
+    assert_eq!(h.eval_async("save('b')")?, JsValue::Number(102.0));
+    assert_eq!(
+        h.eval_async("fetch('/static.txt').then((response) => response.text())")?,
+        "from static mock".into()
+    );
+    assert_eq!(
+        h.eval_async("fetch('/offline').then(() => 'ok', (err) => String(err))")?,
+        "TypeError: Failed to fetch".into()
+    );
+    assert_eq!(h.take_fetch_requests().len(), 5);
+
+    h.clear_fetch_handler();
+    assert_eq!(
+        h.eval_async("fetch('/offline').then(() => 'ok', () => 'rejected')")?,
+        "rejected".into()
+    );
+    Ok(())
+}
+
+#[test]
+fn fetch_handler_serves_module_sources() -> Result<()> {
+    let mut h = Harness::from_html_with_url("https://example.test/", "<p id='out'></p>")?;
+    let mut requested = Vec::new();
+    h.set_fetch_handler(move |request| {
+        requested.push(request.url.clone());
+        match request.destination {
+            FetchDestination::Module if request.url.ends_with("/math.js") => {
+                FetchOutcome::Response(FetchMockResponse::text(&format!(
+                    "export const calls = {};",
+                    requested.len()
+                )))
+            }
+            _ => FetchOutcome::NetworkError,
+        }
+    });
+
+    assert_eq!(
+        h.eval_async("import('/lib/math.js').then((ns) => ns.calls)")?,
+        JsValue::Number(1.0)
+    );
+    let err = h
+        .eval_async("import('/lib/missing.js')")
+        .expect_err("network errors should reject the import");
+    assert!(
+        matches!(err, Error::ScriptThrown(_)),
+        "unexpected error: {err:?}"
+    );
+    Ok(())
+}
+
+#[test]
+fn fetch_mock_latency_runs_on_the_fake_clock() -> Result<()> {
+    let html = r#"
+        <p id='status'>idle</p>
+        <p id='log'></p>
+        <script>
+          const status = document.getElementById('status');
+          const log = document.getElementById('log');
+          function load() {
+            status.textContent = 'loading';
+            fetch('/slow').then((r) => r.text()).then((text) => {
+              log.textContent += text + ';';
+              status.textContent = 'done';
+            });
+            fetch('/fast').then((r) => r.text()).then((text) => {
+              log.textContent += text + ';';
+            });
+            setTimeout(() => { log.textContent += 'timer;'; }, 50);
+          }
+        </script>
+        "#;
+
+    let mut h = Harness::from_html_with_url("https://example.test/", html)?;
+    h.add_fetch_mock(
+        FetchMatcher::new("/slow"),
+        FetchMockResponse::text("slow").with_latency(200),
+    );
+    h.add_fetch_mock(
+        FetchMatcher::new("/fast"),
+        FetchMockResponse::text("fast").with_latency(20),
+    );
+    h.eval("load()")?;
+    h.assert_text("#status", "loading")?;
+    let timers = h.pending_timers();
+    assert_eq!(timers.len(), 1);
+    assert_eq!(timers[0].id, 1, "fetch latency must not consume timer ids");
+    let pending = h.pending_fetches();
+    assert_eq!(pending.len(), 2);
+    assert_eq!(pending[0].url, "https://example.test/slow");
+    assert_eq!(pending[0].due_at_ms, Some(200));
+
+    h.advance_time(60)?;
+    h.assert_text("#log", "fast;timer;")?;
+    h.assert_text("#status", "loading")?;
+    assert_eq!(h.clear_all_timers(), 0);
+
+    h.advance_time(140)?;
+    h.assert_text("#log", "fast;timer;slow;")?;
+    h.assert_text("#status", "done")?;
+    assert!(h.pending_fetches().is_empty());
+    Ok(())
+}
+
+#[test]
+fn manual_fetch_mode_leaves_requests_pending_until_resolved() -> Result<()> {
+    let html = r#"
+        <p id='out'></p>
+        <script>
+          const out = document.getElementById('out');
+          function request(path) {
+            fetch(path, { method: 'POST' }).then(
+              (r) => r.text().then((text) => { out.textContent += path + '=' + r.status + ':' + text + ';'; }),
+              (err) => { out.textContent += path + '=' + err + ';'; }
+            );
+          }
+        </script>
+        "#;
+
+    let mut h = Harness::from_html_with_url("https://example.test/", html)?;
+    h.set_manual_fetch_mode(true);
+    h.eval("request('/a'); request('/b')")?;
+    let pending = h.pending_fetches();
+    assert_eq!(
+        pending
+            .iter()
+            .map(|fetch| (fetch.method.as_str(), fetch.url.as_str(), fetch.due_at_ms))
+            .collect::<Vec<_>>(),
+        vec![
+            ("POST", "https://example.test/a", None),
+            ("POST", "https://example.test/b", None),
+        ]
+    );
+    h.advance_time(1000)?;
+    h.assert_text("#out", "")?;
+
+    h.resolve_pending_fetch(
+        pending[1].id,
+        FetchMockResponse::text("second").with_status(201),
+    )?;
+    h.assert_text("#out", "/b=201:second;")?;
+    h.reject_pending_fetch(pending[0].id)?;
+    h.assert_text("#out", "/b=201:second;/a=TypeError: Failed to fetch;")?;
+    assert!(h.pending_fetches().is_empty());
+
+    let err = h
+        .reject_pending_fetch(pending[0].id)
+        .expect_err("settled fetches are no longer pending");
+    assert!(
+        matches!(err, Error::ScriptRuntime(_)),
+        "unexpected error: {err:?}"
+    );
+    assert_eq!(h.take_fetch_requests().len(), 2);
+    Ok(())
+}
@@ -0,0 +1,225 @@
+use super::*;
+
+#[test]
+fn external_scripts_follow_browser_execution_order_and_fire_events() -> Result<()> {
+    let loader = MemoryResourceLoader::new()
+        .with_resource(
+            "/js/blocking.js",
+            "var current = document.currentScript; window.order.push('blocking:' + current.id);",
+        )
+        .with_resource("/js/async.js", "window.order.push('async');")
+        .with_resource("/js/defer.js", "window.order.push('defer');")
+        .with_resource(
+            "https://app.test/js/module.js",
+            "window.order.push('module');",
+        );
+    let html = r#"
+        <script>
+          window.order = [];
+          window.events = [];
+          const log = (event) => window.events.push(event.target.id + ':' + event.type);
+          for (const id of ['blocking', 'async', 'defer', 'module', 'missing']) {
+            document.getElementById(id).addEventListener('load', log);
+            document.getElementById(id).addEventListener('error', log);
+          }
+        </script>
+        <script id='module' type='module' src='/js/module.js'></script>
+        <script type='module'>window.order.push('inline-module');</script>
+        <script id='defer' defer src='js/defer.js'></script>
+        <script id='async' async src='/js/async.js'></script>
+        <script id='missing' src='/js/missing.js'></script>
+        <script id='blocking' src='/js/blocking.js'></script>
+        <script nomodule>window.order.push('nomodule');</script>
+        <script>
+          window.order.push('inline:' + (document.currentScript === null));
+          document.addEventListener('DOMContentLoaded', () => window.order.push('ready'));
+        </script>
+    "#;
+    let mut h =
+        Harness::from_html_with_resource_loader("https://app.test/index.html", html, loader)?;
+    assert_eq!(
+        h.eval("window.order.join(',')")?,
+        "blocking:blocking,inline:false,async,module,inline-module,defer,ready".into()
+    );
+    assert_eq!(
+        h.eval("window.events.join(',')")?,
+        "missing:error,blocking:load,async:load,module:load,defer:load".into()
+    );
+    assert_eq!(h.eval("String(document.currentScript)")?, "null".into());
+    Ok(())
+}
+
+#[test]
+fn modules_and_dynamic_imports_resolve_through_the_loader() -> Result<()> {
+    let loader = MemoryResourceLoader::new()
+        .with_resource(
+            "/app/main.js",
+            "import { greet } from './lib/greet.js'; window.greeting = greet('main');",
+        )
+        .with_resource(
+            "/app/lib/greet.js",
+            "export function greet(name) { return 'hello ' + name; }",
+        )
+        .with_resource("/app/lazy.js", "export default 'lazy';");
+    let html = r#"
+        <p id='out'></p>
+        <script type='module' src='/app/main.js'></script>
+        <script type='module'>
+          import('/app/lazy.js').then((module) => {
+            document.getElementById('out').textContent = window.greeting + '|' + module.default;
+          });
+        </script>
+    "#;
+    let mut h = Harness::from_html_with_resource_loader("https://app.test/", html, loader)?;
+    h.flush()?;
+    h.assert_text("#out", "hello main|lazy")?;
+    Ok(())
+}
+
+#[test]
+fn linked_stylesheets_feed_computed_style_and_fire_load_events() -> Result<()> {
+    let loader = MemoryResourceLoader::new()
+        .with_resource("/css/site.css", ".banner { color: rgb(255, 0, 0); }")
+        .with_response("/css/broken.css", FetchMockResponse::new(500));
+    let html = r#"
+        <link id='site' rel='stylesheet' href='/css/site.css'>
+        <link id='broken' rel='stylesheet' href='/css/broken.css'>
+        <link id='print' rel='alternate stylesheet' href='/css/site.css'>
+        <div class='banner'>hi</div>
+        <script>
+          window.events = [];
+          for (const link of document.querySelectorAll('link')) {
+            link.addEventListener('load', () => window.events.push(link.id + ':load'));
+            link.addEventListener('error', () => window.events.push(link.id + ':error'));
+          }
+          window.color = getComputedStyle(document.querySelector('.banner')).color;
+        </script>
+    "#;
+    let mut h = Harness::from_html_with_resource_loader("https://app.test/", html, loader)?;
+    assert_eq!(h.eval("window.color")?, "rgb(255, 0, 0)".into());
+    assert_eq!(
+        h.eval("window.events.join(',')")?,
+        "site:load,broken:error".into()
+    );
+    Ok(())
+}
+
+#[test]
+fn resource_loader_falls_through_to_fetch_mocks() -> Result<()> {
+    let mut h = Harness::from_html_with_url("https://app.test/", "<p id='out'></p>")?;
+    let requests = Rc::new(RefCell::new(Vec::new()));
+    let seen = requests.clone();
+    h.set_resource_loader(move |request: &FetchRequest| {
+        seen.borrow_mut()
+            .push(format!("{:?}:{}", request.destination, request.url));
+        if request.url.ends_with("/worker.js") {
+            FetchOutcome::Response(FetchMockResponse::text(
+                "self.onmessage = (event) => self.postMessage(event.data * 2);",
+            ))
+        } else {
+            FetchOutcome::Fallthrough
+        }
+    });
+    h.set_fetch_mock("/mocked.js", "export const value = 'mocked';");
+    h.eval(
+        r#"
+        const worker = new Worker('/worker.js');
+        worker.onmessage = (event) => {
+          import('/mocked.js').then((module) => {
+            document.getElementById('out').textContent = event.data + ':' + module.value;
+          });
+        };
+        worker.postMessage(21);
+        "#,
+    )?;
+    h.flush()?;
+    h.assert_text("#out", "42:mocked")?;
+    assert_eq!(
+        requests.borrow().as_slice(),
+        [
+            "Worker:https://app.test/worker.js".to_string(),
+            "Module:https://app.test/mocked.js".to_string()
+        ]
+    );
+    Ok(())
+}
+
+// Removes the fixture directory even when an assertion fails.
+struct TempDir(std::path::PathBuf);
+
+impl TempDir {
+    fn new(name: &str) -> Self {
+        let path = std::env::temp_dir().join(format!("{name}-{}", std::process::id()));
+        std::fs::create_dir_all(&path).expect("create fixture directory");
+        Self(path)
+    }
+
+    fn write(&self, relative: &str, contents: &str) {
+        let path = self.0.join(relative);
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).expect("create fixture directory");
+        }
+        std::fs::write(path, contents).expect("write fixture");
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.0);
+    }
+}
+
+#[test]
+fn from_dir_serves_the_entry_page_and_its_assets() -> Result<()> {
+    let fixture = TempDir::new("browser-tester-from-dir");
+    fixture.write(
+        "index.html",
+        r#"<link rel='stylesheet' href='/assets/app.css'>
+        <h1 id='title'>loading</h1>
+        <script type='module' src='/assets/app.js'></script>"#,
+    );
+    fixture.write("assets/app.css", "h1 { font-weight: 700; }");
+    fixture.write(
+        "assets/app.js",
+        "import { title } from './title.js';\ndocument.getElementById('title').textContent = title + ' ' + location.href;",
+    );
+    fixture.write("assets/title.js", "export const title = 'ready';");
+
+    let h = Harness::from_dir(&fixture.0, "index.html")?;
+    h.assert_text("#title", "ready http://localhost/index.html")?;
+    let h = Harness::from_file(fixture.0.join("index.html"))?;
+    h.assert_text("#title", "ready http://localhost/index.html")?;
+    Ok(())
+}
+
+#[test]
+fn directory_loader_refuses_paths_outside_its_root() {
+    let fixture = TempDir::new("browser-tester-traversal");
+    fixture.write("secret.txt", "secret");
+    fixture.write("site/index.html", "<p>site</p>");
+
+    let mut loader = DirectoryResourceLoader::new(fixture.0.join("site"));
+    let mut load = |url: &str| {
+        loader.load(&FetchRequest {
+            destination: FetchDestination::Fetch,
+            method: "GET".to_string(),
+            url: url.to_string(),
+            headers: Vec::new(),
+            body: None,
+        })
+    };
+    assert!(matches!(
+        load("http://localhost/index.html"),
+        FetchOutcome::Response(response) if response.status == 200
+    ));
+    // `%2F` survives URL parsing, so the decoded path still holds a raw `..`.
+    for url in [
+        "http://localhost/..%2Fsecret.txt",
+        "http://localhost/%2e%2e%2fsecret.txt",
+    ] {
+        assert!(
+            matches!(load(url), FetchOutcome::Fallthrough),
+            "{url} escaped the loader root"
+        );
+    }
+}
@@ -1246,17 +1246,17 @@ fn html_entities_more_named_references_are_decoded() -> Result<()> {
     let h = Harness::from_html(html)?;
     h.assert_text(
             "#result",
-            "\u{00A3}\u{00D7}\u{00F7}\u{00AB}\u{00BB}\u{2153}\u{2155}\u{2159}\u{215B}\u{2154}\u{2156}\u{00BE}\u{2157}\u{215C}\u{2158}\u{215A}\u{215E}",
+            "\u{00A3}\u{00D7}\u{00F7}\u{00AB}\u{00BB}\u{2153}\u{2155}\u{2159}\u{215B}\u{2154}\u{2156}\u{00BE}\u{2157}\u{215C}\u{2158}\u{215A}\u{215D}",
         )?;
     Ok(())
 }
 
 #[test]
-fn html_entities_unknown_reference_boundary_cases_are_preserved() -> Result<()> {
-    let html = "<p id='result'>&frac12x;&frac34;&poundfoo;&pound;&frac12abc;</p>";
+fn html_entities_legacy_prefix_boundary_cases_use_longest_match() -> Result<()> {
+    let html = "<p id='result'>&frac12x;&frac34;&poundfoo;&pound;&frac12abc;&notin;&notit;</p>";
 
     let h = Harness::from_html(html)?;
-    h.assert_text("#result", "&frac12x;¾&poundfoo;£&frac12abc;")?;
+    h.assert_text("#result", "½x;¾£foo;£½abc;∉¬it;")?;
     Ok(())
 }
 
@@ -1278,6 +1278,44 @@ fn html_entities_without_semicolon_hex_and_decimal_numeric_are_decoded() -> Resu
     Ok(())
 }
 
+#[test]
+fn html_entities_use_the_full_named_reference_table() -> Result<()> {
+    let html = "<p id='result'>&hellip;&mdash;&rarr;&check;&Aacute;&CounterClockwiseContourIntegral;&NotNestedGreaterGreater;&fjlig;</p>";
+
+    let h = Harness::from_html(html)?;
+    h.assert_text("#result", "…—→✓Á\u{2233}\u{2AA2}\u{0338}fj")?;
+    Ok(())
+}
+
+#[test]
+fn html_entities_in_attributes_keep_legacy_references_before_equals_or_alphanumerics() -> Result<()>
+{
+    let html = r#"
+        <a id='link' href='/search?q=1&copy=2&not;x&amp=3&lt;&ampx&amp'>go</a>
+        <p id='result'></p>
+        <script>
+          document.getElementById('result').textContent =
+            document.getElementById('link').getAttribute('href');
+        </script>
+        "#;
+
+    let h = Harness::from_html(html)?;
+    h.assert_text("#result", "/search?q=1&copy=2¬x&amp=3<&ampx&")?;
+    Ok(())
+}
+
+#[test]
+fn html_entities_numeric_references_follow_replacement_rules() -> Result<()> {
+    let html = "<p id='result'>&#0;|&#x80;|&#150;|&#xD800;|&#x110000;|&#99999999999;|&#x;|&#65</p>";
+
+    let h = Harness::from_html(html)?;
+    h.assert_text(
+        "#result",
+        "\u{FFFD}|€|\u{2013}|\u{FFFD}|\u{FFFD}|\u{FFFD}|&#x;|A",
+    )?;
+    Ok(())
+}
+
 #[test]
 fn prevent_default_works_on_submit() -> Result<()> {
     let html = r#"
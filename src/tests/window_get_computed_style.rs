@@ -24,7 +24,10 @@ fn get_computed_style_returns_live_values_and_property_aliases() -> Result<()> {
 
     let mut h = Harness::from_html(html)?;
     h.click("#run")?;
-    h.assert_text("#result", "blue:red:red:lime:red")?;
+    h.assert_text(
+        "#result",
+        "rgb(0, 0, 255):rgb(255, 0, 0):rgb(255, 0, 0):rgb(0, 255, 0):rgb(255, 0, 0)",
+    )?;
     Ok(())
 }
 
@@ -53,7 +56,10 @@ fn get_computed_style_supports_pseudo_argument_and_global_alias_chain() -> Resul
 
     let mut h = Harness::from_html(html)?;
     h.click("#run")?;
-    h.assert_text("#result", "\" rocks!\":\" rocks!\":green:blue")?;
+    h.assert_text(
+        "#result",
+        "\" rocks!\":\" rocks!\":rgb(0, 128, 0):rgb(0, 0, 255)",
+    )?;
     Ok(())
 }
 
@@ -131,6 +137,162 @@ fn computed_style_object_is_read_only() -> Result<()> {
 
     let mut h = Harness::from_html(html)?;
     h.click("#run")?;
-    h.assert_text("#result", "true:blue")?;
+    h.assert_text("#result", "true:rgb(0, 0, 255)")?;
+    Ok(())
+}
+
+#[test]
+fn get_computed_style_orders_rules_by_importance_specificity_and_source() -> Result<()> {
+    let html = r#"
+      <style>
+        #card .title { color: green; }
+        .title { color: red; font-weight: 300 !important; }
+        p.title { color: blue; }
+        .note { color: orange !important; }
+        .note { color: purple; }
+      </style>
+      <div id='card'>
+        <p class='title' style='font-weight: 900'>Title</p>
+        <p class='note' style='color: black'>Note</p>
+      </div>
+      <script>
+        const title = getComputedStyle(document.querySelector('.title'));
+        const note = getComputedStyle(document.querySelector('.note'));
+        window.result = [title.color, title.fontWeight, note.color].join(':');
+      </script>
+    "#;
+
+    let mut h = Harness::from_html(html)?;
+    assert_eq!(
+        h.eval("window.result")?,
+        "rgb(0, 128, 0):300:rgb(255, 165, 0)".into()
+    );
+    Ok(())
+}
+
+#[test]
+fn get_computed_style_applies_ua_defaults_inheritance_and_initial_values() -> Result<()> {
+    let html = r#"
+      <html><head><style>
+        section { color: teal; visibility: hidden; }
+        .reset { color: initial; }
+        .again { visibility: inherit; display: inherit; }
+      </style></head><body>
+      <section id='outer'>
+        <span id='plain'>a</span>
+        <span id='reset' class='reset'>b</span>
+        <div id='again' class='again'>c</div>
+        <ul><li id='item'>d</li></ul>
+        <table><tr><td id='cell'>e</td></tr></table>
+      </section>
+      <script>
+        const style = (id) => getComputedStyle(document.getElementById(id));
+        window.result = [
+          style('outer').display,
+          style('plain').display,
+          style('plain').color,
+          style('plain').visibility,
+          style('reset').color,
+          style('again').display,
+          style('item').display,
+          style('cell').display,
+          style('plain').position,
+          style('outer').paddingTop,
+          getComputedStyle(document.body).marginLeft,
+          getComputedStyle(document.head).display,
+        ].join(',');
+      </script>
+      </body></html>
+    "#;
+
+    let mut h = Harness::from_html(html)?;
+    assert_eq!(
+        h.eval("window.result")?,
+        "block,inline,rgb(0, 128, 128),hidden,rgb(0, 0, 0),block,list-item,table-cell,static,0px,8px,none"
+            .into()
+    );
+    Ok(())
+}
+
+#[test]
+fn get_computed_style_resolves_custom_properties_media_and_adopted_sheets() -> Result<()> {
+    let html = r#"
+      <html><head><style>
+        :root { --accent: red; --gap: 4px; }
+        .panel { --accent: navy; color: var(--accent); margin: var(--gap) 2px; }
+        .panel span { border-color: var(--missing, var(--accent)); outline-color: var(--missing); }
+        @media (max-width: 600px) { .panel { display: none; } }
+        @media (min-width: 601px) { .panel { display: flex; } }
+      </style></head><body>
+      <div class='panel'><span id='inner'>x</span></div>
+      <script>
+        const sheet = new CSSStyleSheet();
+        sheet.replaceSync('.panel span { font-style: italic; }');
+        document.adoptedStyleSheets = [sheet];
+        const panel = getComputedStyle(document.querySelector('.panel'));
+        const inner = getComputedStyle(document.getElementById('inner'));
+        window.result = [
+          panel.color,
+          panel.marginTop,
+          panel.marginLeft,
+          panel.display,
+          panel.getPropertyValue('--accent'),
+          getComputedStyle(document.documentElement).getPropertyValue('--accent'),
+          inner.color,
+          inner.borderColor,
+          inner.outlineColor,
+          inner.fontStyle,
+        ].join(',');
+      </script>
+      </body></html>
+    "#;
+
+    let mut h = Harness::from_html(html)?;
+    assert_eq!(
+        h.eval("window.result")?,
+        "rgb(0, 0, 128),4px,2px,flex,navy,red,rgb(0, 0, 128),rgb(0, 0, 128),,italic".into()
+    );
+    Ok(())
+}
+
+#[test]
+fn get_computed_style_resolves_colors_to_rgb() -> Result<()> {
+    let html = r#"
+      <html><head><style>
+        #box {
+          color: hsl(120, 100%, 25%);
+          background-color: #0f08;
+          border-top-color: currentcolor;
+          outline-color: rgba(255 0 0 / 50%);
+          caret-color: transparent;
+          text-decoration-color: #336699;
+        }
+        #child { color: currentcolor; background-color: canvas; }
+      </style></head><body>
+      <div id='box'><span id='child'>x</span></div>
+      <script>
+        const box = getComputedStyle(document.getElementById('box'));
+        const child = getComputedStyle(document.getElementById('child'));
+        window.result = [
+          box.color,
+          box.backgroundColor,
+          box.borderTopColor,
+          box.outlineColor,
+          box.caretColor,
+          box.textDecorationColor,
+          child.color,
+          child.backgroundColor,
+        ].join('|');
+      </script>
+      </body></html>
+    "#;
+
+    let mut h = Harness::from_html(html)?;
+    assert_eq!(
+        h.eval("window.result")?,
+        "rgb(0, 128, 0)|rgba(0, 255, 0, 0.533)|rgb(0, 128, 0)|rgba(255, 0, 0, 0.5)|\
+         rgba(0, 0, 0, 0)|rgb(51, 102, 153)|rgb(0, 128, 0)|canvas"
+            .into()
+    );
     Ok(())
 }
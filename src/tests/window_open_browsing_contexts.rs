@@ -0,0 +1,243 @@
+use super::*;
+
+#[test]
+fn popup_posts_to_opener_and_closes_itself() -> Result<()> {
+    let mut win = MockWindow::new();
+    win.open_page(
+        "https://app.local/",
+        r#"
+            <button id='login'>Log in</button>
+            <p id='out'></p>
+            <script>
+              let popup = null;
+              window.addEventListener('message', (event) => {
+                if (event.origin !== 'https://auth.example') return;
+                document.getElementById('out').textContent =
+                  event.data.code + '|' + (event.source === popup) + '|' + event.isTrusted;
+              });
+              document.getElementById('login').addEventListener('click', () => {
+                popup = window.open('https://auth.example/authorize', 'oauth', 'width=500');
+              });
+            </script>
+        "#,
+    )?;
+    win.current_document_mut()?.set_location_mock_page(
+        "https://auth.example/authorize",
+        r#"
+            <script>
+              window.opener.postMessage({ code: 'abc' }, 'https://app.local');
+              window.close();
+            </script>
+        "#,
+    );
+
+    win.click("#login")?;
+    win.assert_text("#out", "abc|true|true")?;
+    assert_eq!(win.page_count(), 1);
+    assert_eq!(win.eval("popup.closed")?, JsValue::Bool(true));
+    Ok(())
+}
+
+#[test]
+fn post_message_respects_target_origin() -> Result<()> {
+    let mut win = MockWindow::new();
+    win.open_page(
+        "https://app.local/",
+        r#"
+            <p id='out'>none</p>
+            <script>
+              window.addEventListener('message', (event) => {
+                document.getElementById('out').textContent = event.data;
+              });
+            </script>
+        "#,
+    )?;
+    win.current_document_mut()?.set_location_mock_page(
+        "https://auth.example/cb",
+        r#"
+            <script>
+              window.opener.postMessage('wrong', 'https://evil.example');
+              window.opener.postMessage('star', '*');
+            </script>
+        "#,
+    );
+
+    win.eval("window.open('https://auth.example/cb')")?;
+    win.switch_to_index(0)?;
+    win.assert_text("#out", "star")?;
+    assert_eq!(win.page_count(), 2);
+    Ok(())
+}
+
+#[test]
+fn named_contexts_are_reused_and_closable_from_the_opener() -> Result<()> {
+    let mut win = MockWindow::new();
+    win.open_page(
+        "https://app.local/",
+        r#"
+            <a id='ext' href='/help' target='_blank'>Help</a>
+            <script>
+              const first = window.open('/one', 'side');
+              const second = window.open('/two', 'side');
+            </script>
+        "#,
+    )?;
+
+    assert_eq!(win.page_count(), 2);
+    assert_eq!(win.eval("first === second")?, JsValue::Bool(true));
+    win.switch_to("https://app.local/two")?;
+    assert_eq!(win.eval("window.name")?, "side".into());
+    assert_eq!(win.eval("window.opener !== null")?, JsValue::Bool(true));
+
+    win.switch_to_index(0)?;
+    win.click("#ext")?;
+    assert_eq!(win.page_count(), 3);
+    win.switch_to("https://app.local/help")?;
+    assert_eq!(win.eval("window.opener === null")?, JsValue::Bool(true));
+
+    win.switch_to_index(0)?;
+    win.eval("first.close()")?;
+    assert_eq!(win.page_count(), 2);
+    assert_eq!(win.eval("first.closed")?, JsValue::Bool(true));
+    Ok(())
+}
+
+#[test]
+fn links_and_forms_keep_the_opener_unless_blank_or_rel_drops_it() -> Result<()> {
+    let mut win = MockWindow::new();
+    win.open_page(
+        "https://app.local/",
+        r#"
+            <a id='named' href='/named' target='side'>Named</a>
+            <a id='blank-opener' href='/blank-opener' target='_blank' rel='opener'>Opener</a>
+            <a id='named-noreferrer' href='/noreferrer' target='other' rel='noreferrer'>No</a>
+            <form id='search' action='/search' target='results'>
+              <input name='q' value='x'>
+            </form>
+            <form id='blank-form' action='/blank-form' target='_blank'></form>
+            <form id='form-opener' action='/form-opener' target='_blank' rel='opener'></form>
+        "#,
+    )?;
+
+    for link in ["#named", "#blank-opener", "#named-noreferrer"] {
+        win.switch_to_index(0)?;
+        win.click(link)?;
+    }
+    for form in ["#search", "#blank-form", "#form-opener"] {
+        win.switch_to_index(0)?;
+        win.submit(form)?;
+    }
+    assert_eq!(win.page_count(), 7);
+
+    for (url, has_opener) in [
+        ("https://app.local/named", true),
+        ("https://app.local/blank-opener", true),
+        ("https://app.local/noreferrer", false),
+        ("https://app.local/search?q=x", true),
+        ("https://app.local/blank-form", false),
+        ("https://app.local/form-opener", true),
+    ] {
+        win.switch_to(url)?;
+        assert_eq!(
+            win.eval("window.opener !== null")?,
+            JsValue::Bool(has_opener),
+            "{url}"
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn opener_and_popup_exchange_messages_through_event_source() -> Result<()> {
+    let mut win = MockWindow::new();
+    win.open_page(
+        "https://app.local/",
+        r#"
+            <button id='go'>Go</button>
+            <p id='out'></p>
+            <script>
+              window.addEventListener('message', (event) => {
+                document.getElementById('out').textContent = event.data.reply;
+              });
+              document.getElementById('go').addEventListener('click', () => {
+                const child = window.open('/child');
+                child.postMessage({ text: 'ping' }, '/');
+              });
+            </script>
+        "#,
+    )?;
+    win.current_document_mut()?.set_location_mock_page(
+        "https://app.local/child",
+        r#"
+            <script>
+              window.addEventListener('message', (event) => {
+                event.source.postMessage({ reply: event.data.text + ':pong' }, event.origin);
+              });
+            </script>
+        "#,
+    );
+
+    win.click("#go")?;
+    win.assert_text("#out", "ping:pong")?;
+    Ok(())
+}
+
+#[test]
+fn window_open_without_mock_window_returns_a_proxy() -> Result<()> {
+    let html = r#"
+        <p id='out'></p>
+        <script>
+          const popup = window.open('https://app.local/x', '_blank');
+          const none = window.open('https://app.local/y', '_blank', 'noopener');
+          document.getElementById('out').textContent = [
+            typeof popup.postMessage,
+            popup.closed,
+            none,
+            window.opener,
+          ].join('|');
+        </script>
+        "#;
+
+    let mut h = Harness::from_html(html)?;
+    h.assert_text("#out", "function|false||")?;
+    h.eval("popup.postMessage('lost', '*'); popup.close()")?;
+    assert!(h.take_browsing_context_requests().is_empty());
+    Ok(())
+}
+
+#[test]
+fn cross_page_messages_are_queued_as_tasks_on_the_receiver() -> Result<()> {
+    let mut win = MockWindow::new();
+    win.open_page(
+        "https://app.local/",
+        r#"
+            <button id='go'>Go</button>
+            <script>
+              let child = null;
+              document.getElementById('go').addEventListener('click', () => {
+                child = window.open('/child');
+                child.postMessage('hi', '/');
+              });
+            </script>
+        "#,
+    )?;
+    win.current_document_mut()?.set_location_mock_page(
+        "https://app.local/child",
+        r#"
+            <p id='log'></p>
+            <script>
+              const log = [];
+              setTimeout(() => { log.push('timer'); }, 0);
+              window.addEventListener('message', (event) => {
+                log.push('message:' + event.data);
+                document.getElementById('log').textContent = log.join('|');
+              });
+            </script>
+        "#,
+    );
+
+    win.click("#go")?;
+    win.switch_to("https://app.local/child")?;
+    win.assert_text("#log", "timer|message:hi")?;
+    Ok(())
+}